@@ -0,0 +1,188 @@
+use crate::input::UserEvent;
+
+/// Type of a command argument. The kind decides how the argument is parsed
+/// and which values are suggested when completing it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ArgumentKind {
+    Integer,
+    Number,
+    /// Accepts `true`, `false`, `on`, `off`, `1` and `0`.
+    Boolean,
+    /// A single word, or multiple words in double quotes.
+    Word,
+    /// The rest of the line, including spaces. Only valid as the last
+    /// argument.
+    Text,
+    /// One of the given words.
+    Choice(&'static [&'static str]),
+}
+
+impl ArgumentKind {
+    const BOOLEAN_VALUES: &'static [&'static str] = &["false", "true"];
+
+    /// Values that are suggested when completing an argument of this kind.
+    pub fn suggestions(self) -> &'static [&'static str] {
+        match self {
+            ArgumentKind::Boolean => Self::BOOLEAN_VALUES,
+            ArgumentKind::Choice(choices) => choices,
+            _ => &[],
+        }
+    }
+
+    fn parse(self, value: &str) -> Option<ArgumentValue> {
+        match self {
+            ArgumentKind::Integer => value.parse().ok().map(ArgumentValue::Integer),
+            ArgumentKind::Number => value.parse().ok().map(ArgumentValue::Number),
+            ArgumentKind::Boolean => match value.to_ascii_lowercase().as_str() {
+                "true" | "on" | "1" => Some(ArgumentValue::Boolean(true)),
+                "false" | "off" | "0" => Some(ArgumentValue::Boolean(false)),
+                _ => None,
+            },
+            ArgumentKind::Word | ArgumentKind::Text => Some(ArgumentValue::Text(value.to_owned())),
+            ArgumentKind::Choice(choices) => choices
+                .iter()
+                .find(|choice| choice.eq_ignore_ascii_case(value))
+                .map(|choice| ArgumentValue::Text(choice.to_string())),
+        }
+    }
+
+    fn expected(self) -> String {
+        match self {
+            ArgumentKind::Integer => "an integer".to_owned(),
+            ArgumentKind::Number => "a number".to_owned(),
+            ArgumentKind::Boolean => "true or false".to_owned(),
+            ArgumentKind::Word | ArgumentKind::Text => "text".to_owned(),
+            ArgumentKind::Choice(choices) => format!("one of {}", choices.join(", ")),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct Argument {
+    pub name: &'static str,
+    pub kind: ArgumentKind,
+    pub optional: bool,
+}
+
+impl Argument {
+    pub const fn required(name: &'static str, kind: ArgumentKind) -> Self {
+        Self {
+            name,
+            kind,
+            optional: false,
+        }
+    }
+
+    pub const fn optional(name: &'static str, kind: ArgumentKind) -> Self {
+        Self {
+            name,
+            kind,
+            optional: true,
+        }
+    }
+
+    /// Parse a single value, returning a message for the user if the value
+    /// is invalid.
+    pub fn parse(&self, value: &str) -> Result<ArgumentValue, String> {
+        self.kind
+            .parse(value)
+            .ok_or_else(|| format!("invalid value `{value}` for {}, expected {}", self.name, self.kind.expected()))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum ArgumentValue {
+    Integer(i64),
+    Number(f32),
+    Boolean(bool),
+    Text(String),
+}
+
+/// Parsed arguments of a command, in the order they were declared. Optional
+/// arguments that were not given are `None`.
+#[derive(Debug, Default, PartialEq)]
+pub struct Arguments(pub(super) Vec<Option<ArgumentValue>>);
+
+impl Arguments {
+    fn get(&self, index: usize) -> Option<&ArgumentValue> {
+        self.0.get(index).and_then(Option::as_ref)
+    }
+
+    pub fn integer(&self, index: usize) -> Option<i64> {
+        match self.get(index)? {
+            ArgumentValue::Integer(value) => Some(*value),
+            _ => None,
+        }
+    }
+
+    pub fn number(&self, index: usize) -> Option<f32> {
+        match self.get(index)? {
+            ArgumentValue::Number(value) => Some(*value),
+            ArgumentValue::Integer(value) => Some(*value as f32),
+            _ => None,
+        }
+    }
+
+    pub fn boolean(&self, index: usize) -> Option<bool> {
+        match self.get(index)? {
+            ArgumentValue::Boolean(value) => Some(*value),
+            _ => None,
+        }
+    }
+
+    pub fn text(&self, index: usize) -> Option<&str> {
+        match self.get(index)? {
+            ArgumentValue::Text(value) => Some(value),
+            _ => None,
+        }
+    }
+}
+
+/// Local type alias to simplify the command.
+type CommandAction = Box<dyn Fn(&Arguments) -> Result<Vec<UserEvent>, String>>;
+
+/// A command that can be executed from the console. Executing a command
+/// turns its arguments into user events, so commands can do everything the
+/// interface can do.
+pub struct ConsoleCommand {
+    pub name: &'static str,
+    pub help: &'static str,
+    pub arguments: &'static [Argument],
+    action: CommandAction,
+}
+
+impl ConsoleCommand {
+    pub fn new(
+        name: &'static str,
+        help: &'static str,
+        arguments: &'static [Argument],
+        action: impl Fn(&Arguments) -> Result<Vec<UserEvent>, String> + 'static,
+    ) -> Self {
+        debug_assert!(
+            arguments.iter().rev().skip(1).all(|argument| argument.kind != ArgumentKind::Text),
+            "text arguments have to be the last argument of command {name}"
+        );
+
+        Self {
+            name,
+            help,
+            arguments,
+            action: Box::new(action),
+        }
+    }
+
+    /// Name and arguments of the command, with optional arguments in square
+    /// brackets, e.g. `effect <file> [x] [y]`.
+    pub fn usage(&self) -> String {
+        self.arguments
+            .iter()
+            .fold(self.name.to_owned(), |usage, argument| match argument.optional {
+                true => format!("{usage} [{}]", argument.name),
+                false => format!("{usage} <{}>", argument.name),
+            })
+    }
+
+    pub fn execute(&self, arguments: &Arguments) -> Result<Vec<UserEvent>, String> {
+        (self.action)(arguments)
+    }
+}
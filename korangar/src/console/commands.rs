@@ -0,0 +1,160 @@
+use cgmath::{Point3, Vector2};
+use korangar_debug::logging::{LogFilter, set_log_filter};
+
+use super::{Argument, ArgumentKind, CommandRegistry, ConsoleCommand};
+use crate::graphics::RenderSettings;
+use crate::input::UserEvent;
+use crate::interface::application::InternalThemeKind;
+use crate::world::VideoControl;
+
+const THEME_KINDS: &[&str] = &["main", "menu", "game"];
+const VIDEO_ACTIONS: &[&str] = &["pause", "resume", "speed", "seek"];
+
+fn teleport_camera() -> ConsoleCommand {
+    const ARGUMENTS: &[Argument] = &[
+        Argument::required("x", ArgumentKind::Number),
+        Argument::required("y", ArgumentKind::Number),
+        Argument::required("z", ArgumentKind::Number),
+    ];
+
+    ConsoleCommand::new(
+        "camera",
+        "enable the debug camera and move it to a position",
+        ARGUMENTS,
+        |arguments| {
+            let position = Point3::new(
+                arguments.number(0).unwrap(),
+                arguments.number(1).unwrap(),
+                arguments.number(2).unwrap(),
+            );
+
+            Ok(vec![UserEvent::TeleportDebugCamera(position)])
+        },
+    )
+}
+
+fn time_of_day() -> ConsoleCommand {
+    const ARGUMENTS: &[Argument] = &[Argument::required("hours", ArgumentKind::Number)];
+
+    ConsoleCommand::new("time", "set the time of day in hours", ARGUMENTS, |arguments| {
+        let hours = arguments.number(0).unwrap();
+
+        match (0.0..=24.0).contains(&hours) {
+            true => Ok(vec![UserEvent::SetTimeOfDay(hours)]),
+            false => Err(format!("hours need to be between 0 and 24, got {hours}")),
+        }
+    })
+}
+
+fn render_flag() -> ConsoleCommand {
+    const ARGUMENTS: &[Argument] = &[
+        Argument::required("setting", ArgumentKind::Choice(RenderSettings::FLAGS)),
+        Argument::optional("enabled", ArgumentKind::Boolean),
+    ];
+
+    ConsoleCommand::new(
+        "render",
+        "toggle a render setting, or set it if a value is given",
+        ARGUMENTS,
+        |arguments| {
+            let flag = RenderSettings::FLAGS.iter().find(|flag| Some(**flag) == arguments.text(0)).unwrap();
+
+            Ok(vec![UserEvent::SetRenderFlag {
+                flag,
+                enabled: arguments.boolean(1),
+            }])
+        },
+    )
+}
+
+fn reload_theme() -> ConsoleCommand {
+    const ARGUMENTS: &[Argument] = &[Argument::required("kind", ArgumentKind::Choice(THEME_KINDS))];
+
+    ConsoleCommand::new("theme", "reload a theme from its file", ARGUMENTS, |arguments| {
+        let theme_kind = match arguments.text(0).unwrap() {
+            "main" => InternalThemeKind::Main,
+            "menu" => InternalThemeKind::Menu,
+            _ => InternalThemeKind::Game,
+        };
+
+        Ok(vec![UserEvent::ReloadTheme { theme_kind }])
+    })
+}
+
+fn spawn_effect() -> ConsoleCommand {
+    const ARGUMENTS: &[Argument] = &[
+        Argument::required("file", ArgumentKind::Word),
+        Argument::optional("x", ArgumentKind::Integer),
+        Argument::optional("y", ArgumentKind::Integer),
+    ];
+
+    ConsoleCommand::new(
+        "effect",
+        "spawn an effect file on a tile, or on the player if no tile is given",
+        ARGUMENTS,
+        |arguments| {
+            let position = match (arguments.integer(1), arguments.integer(2)) {
+                (Some(x), Some(y)) if x >= 0 && y >= 0 => Some(Vector2::new(x as usize, y as usize)),
+                (None, None) => None,
+                _ => return Err("the tile needs a positive x and y coordinate".to_owned()),
+            };
+
+            Ok(vec![UserEvent::SpawnEffect {
+                file: arguments.text(0).unwrap().to_owned(),
+                position,
+            }])
+        },
+    )
+}
+
+fn log_filter() -> ConsoleCommand {
+    const ARGUMENTS: &[Argument] = &[Argument::required("filter", ArgumentKind::Text)];
+
+    ConsoleCommand::new(
+        "log",
+        "set the log filter, e.g. warning,korangar::loaders=debug",
+        ARGUMENTS,
+        |arguments| {
+            let filter: LogFilter = arguments.text(0).unwrap().parse()?;
+            set_log_filter(filter);
+
+            Ok(Vec::new())
+        },
+    )
+}
+
+fn control_videos() -> ConsoleCommand {
+    const ARGUMENTS: &[Argument] = &[
+        Argument::required("action", ArgumentKind::Choice(VIDEO_ACTIONS)),
+        Argument::optional("value", ArgumentKind::Number),
+    ];
+
+    ConsoleCommand::new(
+        "video",
+        "pause or resume the videos of the map, set their speed or seek to a time in seconds",
+        ARGUMENTS,
+        |arguments| {
+            let control = match (arguments.text(0).unwrap(), arguments.number(1)) {
+                ("pause", _) => VideoControl::Pause,
+                ("resume", _) => VideoControl::Resume,
+                ("speed", Some(speed)) if (0.1..=4.0).contains(&speed) => VideoControl::Speed(speed as f64),
+                ("speed", _) => return Err("speed needs a value between 0.1 and 4".to_owned()),
+                (_, Some(seconds)) if seconds >= 0.0 => VideoControl::Seek((seconds * 1000.0) as i64),
+                _ => return Err("seek needs a positive time in seconds".to_owned()),
+            };
+
+            Ok(vec![UserEvent::ControlVideos(control)])
+        },
+    )
+}
+
+/// Register the commands of all subsystems of the client.
+pub fn register_client_commands(registry: &mut CommandRegistry) {
+    registry.register(teleport_camera());
+    registry.register(time_of_day());
+    registry.register(render_flag());
+    registry.register(reload_theme());
+    registry.register(spawn_effect());
+    registry.register(log_filter());
+    registry.register(control_videos());
+}
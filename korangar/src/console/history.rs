@@ -0,0 +1,49 @@
+use korangar_debug::logging::{Colorize, print_debug};
+use korangar_interface::state::{PlainTrackedState, TrackedState};
+use ron::ser::PrettyConfig;
+
+/// Lines entered into the console, persisted across sessions.
+pub struct ConsoleHistory {
+    entries: PlainTrackedState<Vec<String>>,
+}
+
+impl ConsoleHistory {
+    const FILE_NAME: &'static str = "client/console_history.ron";
+
+    pub fn new() -> Self {
+        let entries = Self::load().unwrap_or_else(|| {
+            print_debug!("failed to load console history from {}", Self::FILE_NAME.magenta());
+            Default::default()
+        });
+
+        Self {
+            entries: PlainTrackedState::new(entries),
+        }
+    }
+
+    pub fn load() -> Option<Vec<String>> {
+        print_debug!("loading console history from {}", Self::FILE_NAME.magenta());
+
+        std::fs::read_to_string(Self::FILE_NAME)
+            .ok()
+            .and_then(|data| ron::from_str(&data).ok())
+    }
+
+    pub fn save(&self) {
+        print_debug!("saving console history to {}", Self::FILE_NAME.magenta());
+
+        let data = ron::ser::to_string_pretty(&*self.entries.get(), PrettyConfig::new()).unwrap();
+        std::fs::write(Self::FILE_NAME, data).expect("unable to write file");
+    }
+
+    /// State shared with the input field of the console.
+    pub fn entries(&self) -> PlainTrackedState<Vec<String>> {
+        self.entries.clone()
+    }
+}
+
+impl Drop for ConsoleHistory {
+    fn drop(&mut self) {
+        self.save();
+    }
+}
@@ -0,0 +1,12 @@
+//! Developer console. Commands are registered in a [`CommandRegistry`] and
+//! turn their typed arguments into [`UserEvent`](crate::input::UserEvent)s.
+
+mod command;
+mod commands;
+mod history;
+mod registry;
+
+pub use self::command::{Argument, ArgumentKind, ArgumentValue, Arguments, ConsoleCommand};
+pub use self::commands::register_client_commands;
+pub use self::history::ConsoleHistory;
+pub use self::registry::{CommandRegistry, Completion, ConsoleOutput};
@@ -0,0 +1,372 @@
+use super::command::{ArgumentKind, Arguments, ConsoleCommand};
+use crate::input::UserEvent;
+
+const HELP_COMMAND: &str = "help";
+const CLEAR_COMMAND: &str = "clear";
+
+/// Result of executing a line in the console.
+#[derive(Debug)]
+pub enum ConsoleOutput {
+    Events(Vec<UserEvent>),
+    Text(Vec<String>),
+    Clear,
+}
+
+/// Result of completing a line in the console.
+#[derive(Debug, Default, PartialEq)]
+pub struct Completion {
+    /// The completed line, if anything could be completed.
+    pub text: Option<String>,
+    /// All values that match the word being completed.
+    pub candidates: Vec<String>,
+}
+
+/// A word of a console line and the byte offset it starts at.
+struct Token {
+    start: usize,
+    value: String,
+}
+
+/// Split a line into words. Words in double quotes may contain spaces.
+fn tokenize(line: &str) -> Vec<Token> {
+    let mut tokens = Vec::new();
+    let mut current: Option<Token> = None;
+    let mut quoted = false;
+
+    for (index, character) in line.char_indices() {
+        match character {
+            '"' => {
+                quoted = !quoted;
+                current.get_or_insert_with(|| Token {
+                    start: index,
+                    value: String::new(),
+                });
+            }
+            character if character.is_whitespace() && !quoted => tokens.extend(current.take()),
+            character => current
+                .get_or_insert_with(|| Token {
+                    start: index,
+                    value: String::new(),
+                })
+                .value
+                .push(character),
+        }
+    }
+
+    tokens.extend(current);
+    tokens
+}
+
+/// Longest prefix that all values share.
+fn common_prefix<'a>(mut values: impl Iterator<Item = &'a str>) -> &'a str {
+    let Some(first) = values.next() else {
+        return "";
+    };
+
+    values.fold(first, |prefix, value| {
+        let length = prefix
+            .char_indices()
+            .zip(value.chars())
+            .take_while(|((_, left), right)| left == right)
+            .last()
+            .map(|((index, character), _)| index + character.len_utf8())
+            .unwrap_or(0);
+
+        &prefix[..length]
+    })
+}
+
+/// All commands that can be executed from the console. Subsystems add their
+/// commands with [`register`](Self::register).
+#[derive(Default)]
+pub struct CommandRegistry {
+    commands: Vec<ConsoleCommand>,
+}
+
+impl CommandRegistry {
+    pub fn register(&mut self, command: ConsoleCommand) {
+        assert!(
+            self.get(command.name).is_none() && ![HELP_COMMAND, CLEAR_COMMAND].contains(&command.name),
+            "console command {} is registered twice",
+            command.name
+        );
+
+        self.commands.push(command);
+        self.commands.sort_by_key(|command| command.name);
+    }
+
+    pub fn get(&self, name: &str) -> Option<&ConsoleCommand> {
+        self.commands.iter().find(|command| command.name == name)
+    }
+
+    fn command_names(&self) -> impl Iterator<Item = &'static str> + '_ {
+        [CLEAR_COMMAND, HELP_COMMAND]
+            .into_iter()
+            .chain(self.commands.iter().map(|command| command.name))
+    }
+
+    fn help(&self, name: Option<&str>) -> Result<ConsoleOutput, String> {
+        let lines = match name {
+            Some(name) => {
+                let command = self.get(name).ok_or_else(|| format!("unknown command `{name}`"))?;
+                let mut lines = vec![command.usage(), format!("  {}", command.help)];
+
+                lines.extend(command.arguments.iter().filter_map(|argument| match argument.kind {
+                    ArgumentKind::Choice(choices) => Some(format!("  {}: {}", argument.name, choices.join(", "))),
+                    _ => None,
+                }));
+
+                lines
+            }
+            None => {
+                let mut lines = vec![
+                    format!("{HELP_COMMAND} [command]: show all commands or the usage of a single command"),
+                    format!("{CLEAR_COMMAND}: clear the console"),
+                    "@<command>: send a command to the server".to_owned(),
+                ];
+
+                lines.extend(self.commands.iter().map(|command| format!("{}: {}", command.usage(), command.help)));
+                lines
+            }
+        };
+
+        Ok(ConsoleOutput::Text(lines))
+    }
+
+    /// Parse the arguments of a command.
+    fn parse_arguments(command: &ConsoleCommand, line: &str, tokens: &[Token]) -> Result<Arguments, String> {
+        let mut values = Vec::with_capacity(command.arguments.len());
+        let mut tokens = tokens.iter();
+
+        for argument in command.arguments {
+            let value = match argument.kind {
+                // Take the rest of the line as it was typed.
+                ArgumentKind::Text => tokens.next().map(|token| {
+                    let text = line[token.start..].trim_end().to_owned();
+                    tokens.by_ref().for_each(drop);
+                    text
+                }),
+                _ => tokens.next().map(|token| token.value.clone()),
+            };
+
+            match value {
+                Some(value) => values.push(Some(argument.parse(&value)?)),
+                None if argument.optional => values.push(None),
+                None => return Err(format!("missing argument {}; usage: {}", argument.name, command.usage())),
+            }
+        }
+
+        match tokens.next() {
+            Some(token) => Err(format!("unexpected argument `{}`; usage: {}", token.value, command.usage())),
+            None => Ok(Arguments(values)),
+        }
+    }
+
+    /// Execute a line of console input. Lines starting with `@` are sent to
+    /// the server unchanged.
+    pub fn execute(&self, line: &str) -> Result<ConsoleOutput, String> {
+        let line = line.trim();
+
+        if line.starts_with('@') {
+            return Ok(ConsoleOutput::Events(vec![UserEvent::SendMessage(line.to_owned())]));
+        }
+
+        let tokens = tokenize(line);
+        let Some((name, arguments)) = tokens.split_first() else {
+            return Ok(ConsoleOutput::Events(Vec::new()));
+        };
+
+        match name.value.as_str() {
+            HELP_COMMAND if arguments.len() <= 1 => self.help(arguments.first().map(|token| token.value.as_str())),
+            CLEAR_COMMAND if arguments.is_empty() => Ok(ConsoleOutput::Clear),
+            name => {
+                let command = self
+                    .get(name)
+                    .ok_or_else(|| format!("unknown command `{name}`; type {HELP_COMMAND} for a list of commands"))?;
+                let arguments = Self::parse_arguments(command, line, arguments)?;
+
+                command.execute(&arguments).map(ConsoleOutput::Events)
+            }
+        }
+    }
+
+    /// Complete the word at the end of a line. Command names are completed
+    /// for the first word, argument values with a fixed set of choices for all
+    /// other words.
+    pub fn complete(&self, line: &str) -> Completion {
+        let tokens = tokenize(line);
+        let ends_with_space = line.ends_with(char::is_whitespace);
+
+        // The word that is being completed and the index of the argument it belongs
+        // to. `None` for the command name.
+        let (partial, start, argument_index) = match (tokens.split_last(), ends_with_space) {
+            (None, _) => ("", line.len(), None),
+            (Some(_), true) => ("", line.len(), Some(tokens.len() - 1)),
+            (Some((last, rest)), false) => (last.value.as_str(), last.start, rest.len().checked_sub(1)),
+        };
+
+        let suggestions: Vec<&str> = match argument_index {
+            None => self.command_names().collect(),
+            Some(index) => match tokens[0].value.as_str() {
+                HELP_COMMAND if index == 0 => self.command_names().collect(),
+                name => self
+                    .get(name)
+                    .and_then(|command| command.arguments.get(index))
+                    .map(|argument| argument.kind.suggestions().to_vec())
+                    .unwrap_or_default(),
+            },
+        };
+
+        let candidates: Vec<&str> = suggestions
+            .into_iter()
+            .filter(|suggestion| suggestion.starts_with(partial))
+            .collect();
+
+        let completed_word = match candidates.as_slice() {
+            [] => None,
+            [candidate] => Some(format!("{candidate} ")),
+            candidates => Some(common_prefix(candidates.iter().copied()).to_owned()).filter(|prefix| prefix.len() > partial.len()),
+        };
+
+        Completion {
+            text: completed_word.map(|word| format!("{}{word}", &line[..start])),
+            candidates: candidates.into_iter().map(str::to_owned).collect(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod console {
+    use super::{CommandRegistry, Completion, ConsoleOutput, common_prefix, tokenize};
+    use crate::console::{Argument, ArgumentKind, ConsoleCommand};
+    use crate::input::UserEvent;
+
+    const FLAGS: &[&str] = &["show_map", "show_markers", "show_objects"];
+    const RENDER_ARGUMENTS: &[Argument] = &[
+        Argument::required("flag", ArgumentKind::Choice(FLAGS)),
+        Argument::optional("enabled", ArgumentKind::Boolean),
+    ];
+    const SAY_ARGUMENTS: &[Argument] = &[
+        Argument::required("times", ArgumentKind::Integer),
+        Argument::required("message", ArgumentKind::Text),
+    ];
+
+    fn registry() -> CommandRegistry {
+        let mut registry = CommandRegistry::default();
+
+        registry.register(ConsoleCommand::new(
+            "render",
+            "toggle a render flag",
+            RENDER_ARGUMENTS,
+            |arguments| {
+                let message = format!("{} {:?}", arguments.text(0).unwrap(), arguments.boolean(1));
+                Ok(vec![UserEvent::SendMessage(message)])
+            },
+        ));
+
+        registry.register(ConsoleCommand::new("say", "say something", SAY_ARGUMENTS, |arguments| {
+            let message = arguments.text(1).unwrap().repeat(arguments.integer(0).unwrap() as usize);
+            Ok(vec![UserEvent::SendMessage(message)])
+        }));
+
+        registry
+    }
+
+    fn sent_message(output: Result<ConsoleOutput, String>) -> String {
+        match output {
+            Ok(ConsoleOutput::Events(events)) => match events.as_slice() {
+                [UserEvent::SendMessage(message)] => message.clone(),
+                _ => panic!("unexpected events {events:?}"),
+            },
+            output => panic!("unexpected output {output:?}"),
+        }
+    }
+
+    #[test]
+    fn tokenize_quotes() {
+        let tokens = tokenize("  effect \"two words\" 12");
+        let values: Vec<_> = tokens.iter().map(|token| (token.start, token.value.as_str())).collect();
+
+        assert_eq!(values, [(2, "effect"), (9, "two words"), (21, "12")]);
+        assert!(tokenize("   ").is_empty());
+    }
+
+    #[test]
+    fn common_prefixes() {
+        assert_eq!(common_prefix(["show_map", "show_markers"].into_iter()), "show_ma");
+        assert_eq!(common_prefix(["a", "b"].into_iter()), "");
+        assert_eq!(common_prefix(std::iter::empty()), "");
+    }
+
+    #[test]
+    fn execute_commands() {
+        let registry = registry();
+
+        assert_eq!(sent_message(registry.execute("render SHOW_MAP off")), "show_map Some(false)");
+        assert_eq!(sent_message(registry.execute("render show_objects")), "show_objects None");
+        assert_eq!(
+            sent_message(registry.execute("say 2 hello  there ")),
+            "hello  therehello  there"
+        );
+        assert_eq!(sent_message(registry.execute(" @jobchange 4001")), "@jobchange 4001");
+        assert!(matches!(registry.execute("clear"), Ok(ConsoleOutput::Clear)));
+        assert!(matches!(registry.execute("help render"), Ok(ConsoleOutput::Text(lines)) if lines[0] == "render <flag> [enabled]"));
+    }
+
+    #[test]
+    fn report_errors() {
+        let registry = registry();
+
+        assert!(registry.execute("teleport").unwrap_err().starts_with("unknown command `teleport`"));
+        assert!(registry.execute("render").unwrap_err().starts_with("missing argument flag"));
+        assert!(
+            registry
+                .execute("render show_water")
+                .unwrap_err()
+                .contains("expected one of show_map")
+        );
+        assert!(
+            registry
+                .execute("render show_map maybe")
+                .unwrap_err()
+                .contains("expected true or false")
+        );
+        assert!(
+            registry
+                .execute("render show_map on now")
+                .unwrap_err()
+                .starts_with("unexpected argument `now`")
+        );
+        assert!(registry.execute("say many words").unwrap_err().contains("expected an integer"));
+    }
+
+    #[test]
+    fn complete_commands() {
+        let registry = registry();
+
+        assert_eq!(registry.complete("re"), Completion {
+            text: Some("render ".to_owned()),
+            candidates: vec!["render".to_owned()],
+        });
+        assert_eq!(registry.complete("").candidates, ["clear", "help", "render", "say"]);
+        assert_eq!(registry.complete("x"), Completion::default());
+        assert_eq!(registry.complete("help s").text.as_deref(), Some("help say "));
+    }
+
+    #[test]
+    fn complete_arguments() {
+        let registry = registry();
+
+        let completion = registry.complete("render show_m");
+        assert_eq!(completion.text.as_deref(), Some("render show_ma"));
+        assert_eq!(completion.candidates, ["show_map", "show_markers"]);
+
+        assert_eq!(registry.complete("render show_map ").candidates, ["false", "true"]);
+        assert_eq!(
+            registry.complete("render show_map t").text.as_deref(),
+            Some("render show_map true ")
+        );
+        assert_eq!(registry.complete("render show_ma").text, None);
+        assert_eq!(registry.complete("say 1 ").text, None);
+    }
+}
@@ -8,7 +8,7 @@ use std::sync::atomic::{AtomicU64, Ordering};
 
 use bytemuck::{Pod, Zeroable, bytes_of, cast_slice};
 #[cfg(feature = "debug")]
-use korangar_debug::logging::{Colorize, print_debug};
+use korangar_debug::logging::log_error;
 use wgpu::util::StagingBelt;
 use wgpu::{BindingResource, BindingType, BufferBindingType, BufferDescriptor, BufferSlice, BufferUsages, CommandEncoder, Device, Queue};
 
@@ -204,7 +204,7 @@ impl Buffer<u64> {
                 }
                 Err(_error) => {
                     #[cfg(feature = "debug")]
-                    print_debug!("[{}] failed to map picker buffer: {:?}", "error".red(), _error);
+                    log_error!("failed to map picker buffer: {:?}", _error);
                 }
             }
         });
@@ -22,6 +22,7 @@ pub enum BindlessSupport {
     None,
 }
 
+#[derive(Debug)]
 pub struct Capabilities {
     supported_msaa: Vec<Msaa>,
     bindless: BindlessSupport,
@@ -4,7 +4,7 @@ use std::time::Instant;
 
 use cgmath::Vector2;
 #[cfg(feature = "debug")]
-use korangar_debug::logging::{Colorize, print_debug};
+use korangar_debug::logging::{Colorize, log_error, print_debug};
 #[cfg(feature = "debug")]
 use korangar_debug::profile_block;
 use rayon::ThreadPool;
@@ -422,10 +422,7 @@ impl GraphicsEngine {
                 high_quality_interface = false;
 
                 #[cfg(feature = "debug")]
-                print_debug!(
-                    "[{}] can't enable high quality interface because texture would be too large",
-                    "error".red()
-                );
+                log_error!("can't enable high quality interface because texture would be too large");
             }
         }
 
@@ -441,10 +438,7 @@ impl GraphicsEngine {
                 ssaa = Ssaa::Off;
 
                 #[cfg(feature = "debug")]
-                print_debug!(
-                    "[{}] can't enable super sampling because texture would be too large",
-                    "error".red()
-                );
+                log_error!("can't enable super sampling because texture would be too large");
             }
         }
 
@@ -16,9 +16,7 @@ use wgpu::{
 use crate::graphics::passes::{
     BindGroupCount, ColorAttachmentCount, DepthAttachmentCount, Drawer, PickerRenderPassContext, RenderPassContext,
 };
-use crate::graphics::{
-    BindlessSupport, Buffer, Capabilities, EntityInstruction, GlobalContext, PickerTarget, Prepare, RenderInstruction, Texture,
-};
+use crate::graphics::{BindlessSupport, Buffer, Capabilities, EntityInstruction, GlobalContext, Prepare, RenderInstruction, Texture};
 
 const SHADER: ShaderModuleDescriptor = include_wgsl!("shader/entity.wgsl");
 const SHADER_BINDLESS: ShaderModuleDescriptor = include_wgsl!("shader/entity_bindless.wgsl");
@@ -234,8 +232,7 @@ impl Prepare for PickerEntityDrawer {
                 .for_each(|instruction| {
                     self.draw_count += 1;
 
-                    let picker_target = PickerTarget::Entity(instruction.entity_id);
-                    let (identifier_high, identifier_low) = picker_target.into();
+                    let (identifier_high, identifier_low) = instruction.picker_target.into();
 
                     let mut texture_index = texture_views.len() as i32;
                     let id = instruction.texture.get_id();
@@ -274,8 +271,7 @@ impl Prepare for PickerEntityDrawer {
                 .for_each(|instruction| {
                     self.draw_count += 1;
 
-                    let picker_target = PickerTarget::Entity(instruction.entity_id);
-                    let (identifier_high, identifier_low) = picker_target.into();
+                    let (identifier_high, identifier_low) = instruction.picker_target.into();
 
                     self.instance_data.push(InstanceData {
                         world: instruction.world.into(),
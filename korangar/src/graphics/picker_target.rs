@@ -22,6 +22,7 @@ pub(super) enum PickerValueType {
     EntityMarker,
     #[cfg(feature = "debug")]
     ShadowMarker,
+    GroundItem,
 }
 
 /// Encoding of a `PickerTarget` as `u64` has the following format:
@@ -36,6 +37,7 @@ pub enum PickerTarget {
         y: u16,
     },
     Entity(EntityId),
+    GroundItem(EntityId),
     #[cfg(feature = "debug")]
     Marker(MarkerIdentifier),
 }
@@ -58,6 +60,10 @@ impl From<u64> for PickerTarget {
             return Self::Entity(EntityId(data as u32));
         }
 
+        if data >> 32 == PickerValueType::GroundItem as u64 {
+            return Self::GroundItem(EntityId(data as u32));
+        }
+
         #[cfg(feature = "debug")]
         if data >> 32 == PickerValueType::ObjectMarker as u64 {
             return Self::Marker(MarkerIdentifier::Object(data as u32));
@@ -105,6 +111,7 @@ impl From<PickerTarget> for (u32, u32) {
             PickerTarget::Nothing => (PickerValueType::Nothing as u32, 0),
             PickerTarget::Tile { x, y } => (PickerValueType::Tile as u32, ((x as u32) << 16) | y as u32),
             PickerTarget::Entity(EntityId(entity_id)) => (PickerValueType::Entity as u32, entity_id),
+            PickerTarget::GroundItem(EntityId(entity_id)) => (PickerValueType::GroundItem as u32, entity_id),
             #[cfg(feature = "debug")]
             PickerTarget::Marker(marker_identifier) => match marker_identifier {
                 MarkerIdentifier::Object(index) => (PickerValueType::ObjectMarker as u32, index.key()),
@@ -282,4 +289,11 @@ mod encoding {
     fn decode_entity() {
         assert_eq!(PickerTarget::from(ENCODED_ENTITY_ID), PickerTarget::Entity(ENTITY_ID));
     }
+
+    #[test]
+    fn encode_decode_ground_item() {
+        let target = PickerTarget::GroundItem(ENTITY_ID);
+
+        assert_eq!(PickerTarget::from(u64::from(target)), target);
+    }
 }
@@ -235,6 +235,75 @@ pub struct RenderSettings {
 
 #[cfg(feature = "debug")]
 impl RenderSettings {
+    /// Names of all settings that can be toggled.
+    pub const FLAGS: &'static [&'static str] = &[
+        "show_frames_per_second",
+        "frustum_culling",
+        "show_bounding_boxes",
+        "show_map",
+        "show_objects",
+        "show_entities",
+        "show_entities_paper",
+        "show_entities_debug",
+        "show_water",
+        "show_indicators",
+        "show_ambient_light",
+        "show_directional_light",
+        "show_point_lights",
+        "show_particle_lights",
+        "use_debug_camera",
+        "show_wireframe",
+        "show_object_markers",
+        "show_light_markers",
+        "show_sound_markers",
+        "show_effect_markers",
+        "show_particle_markers",
+        "show_entity_markers",
+        "show_shadow_markers",
+        "show_map_tiles",
+        "show_pathing",
+        "show_picker_buffer",
+        "show_directional_shadow_map",
+        "show_light_culling_count_buffer",
+        "show_font_map",
+    ];
+
+    /// Get a setting that can be toggled by its name.
+    pub fn flag_mut(&mut self, name: &str) -> Option<&mut bool> {
+        match name {
+            "show_frames_per_second" => Some(&mut self.show_frames_per_second),
+            "frustum_culling" => Some(&mut self.frustum_culling),
+            "show_bounding_boxes" => Some(&mut self.show_bounding_boxes),
+            "show_map" => Some(&mut self.show_map),
+            "show_objects" => Some(&mut self.show_objects),
+            "show_entities" => Some(&mut self.show_entities),
+            "show_entities_paper" => Some(&mut self.show_entities_paper),
+            "show_entities_debug" => Some(&mut self.show_entities_debug),
+            "show_water" => Some(&mut self.show_water),
+            "show_indicators" => Some(&mut self.show_indicators),
+            "show_ambient_light" => Some(&mut self.show_ambient_light),
+            "show_directional_light" => Some(&mut self.show_directional_light),
+            "show_point_lights" => Some(&mut self.show_point_lights),
+            "show_particle_lights" => Some(&mut self.show_particle_lights),
+            "use_debug_camera" => Some(&mut self.use_debug_camera),
+            "show_wireframe" => Some(&mut self.show_wireframe),
+            "show_object_markers" => Some(&mut self.show_object_markers),
+            "show_light_markers" => Some(&mut self.show_light_markers),
+            "show_sound_markers" => Some(&mut self.show_sound_markers),
+            "show_effect_markers" => Some(&mut self.show_effect_markers),
+            "show_particle_markers" => Some(&mut self.show_particle_markers),
+            "show_entity_markers" => Some(&mut self.show_entity_markers),
+            "show_shadow_markers" => Some(&mut self.show_shadow_markers),
+            "show_map_tiles" => Some(&mut self.show_map_tiles),
+            "show_pathing" => Some(&mut self.show_pathing),
+            "show_picker_buffer" => Some(&mut self.show_picker_buffer),
+            "show_directional_shadow_map" => Some(&mut self.show_directional_shadow_map),
+            "show_light_culling_count_buffer" => Some(&mut self.show_light_culling_count_buffer),
+            "show_font_map" => Some(&mut self.show_font_map),
+            _ => None,
+        }
+    }
+
     pub fn show_buffers(&self) -> bool {
         self.show_directional_shadow_map
             || self.show_picker_buffer
@@ -1,16 +1,24 @@
+#[cfg(feature = "debug")]
+use cgmath::Point3;
 use cgmath::Vector2;
 use korangar_interface::ElementEvent;
 use korangar_interface::event::ClickAction;
-use korangar_networking::ShopItem;
+use korangar_networking::{CompanionType, InventoryItem, ShopItem};
+#[cfg(feature = "debug")]
+use ragnarok_packets::handler::ServerType;
 use ragnarok_packets::{
-    AccountId, BuyOrSellOption, CharacterId, CharacterServerInformation, EntityId, HotbarSlot, ShopId, SoldItemInformation, TilePosition,
+    AccountId, BuyOrSellOption, CharacterId, CharacterServerInformation, EntityId, HomunculusCommand, HotbarSlot, InventoryIndex, MailId,
+    MailType, MercenaryCommand, OpenBuyingStoreItemInformation, OpenVendingStoreItemInformation, PetCommand, ShopId, SkillId,
+    SoldItemInformation, StatType, TilePosition,
 };
 
 use crate::interface::application::{InterfaceSettings, InternalThemeKind};
 use crate::interface::resource::Move;
 use crate::loaders::ServiceId;
+use crate::settings::{InputAction, KeyChord};
 #[cfg(feature = "debug")]
-use crate::world::MarkerIdentifier;
+use crate::world::{AnimationControl, MarkerIdentifier};
+use crate::world::{ResourceMetadata, VideoControl};
 
 #[derive(Clone, Debug)]
 // TODO: A lot of these are not user events, just a element events
@@ -28,12 +36,28 @@ pub enum UserEvent {
     CameraRotate(f32),
     CameraResetRotation,
     OpenMenuWindow,
+    OpenStatusWindow,
+    RaiseStat(StatType),
+    UpgradeSkill(SkillId),
     OpenInventoryWindow,
+    OpenCartWindow,
     OpenEquipmentWindow,
     OpenSkillTreeWindow,
     OpenGraphicsSettingsWindow,
     OpenAudioSettingsWindow,
+    OpenKeyBindingsWindow,
+    CaptureKeyBinding(InputAction),
+    SetKeyBinding {
+        action: InputAction,
+        chord: KeyChord,
+    },
+    ClearKeyBinding(InputAction),
+    ResetKeyBindings,
     OpenFriendsWindow,
+    OpenHomunculusWindow,
+    OpenMercenaryWindow,
+    OpenPetWindow,
+    OpenMailboxWindow,
     ToggleShowInterface,
     SetThemeFile {
         theme_file: String,
@@ -53,6 +77,8 @@ pub enum UserEvent {
     CancelSwitchCharacterSlot,
     SwitchCharacterSlot(usize),
     RequestPlayerMove(Vector2<usize>),
+    /// Move the player in the direction of the stick, relative to the camera.
+    MoveInDirection(Vector2<f32>),
     RequestPlayerInteract(EntityId),
     RequestWarpToMap(String, TilePosition),
     SendMessage(String),
@@ -60,8 +86,18 @@ pub enum UserEvent {
     CloseDialog(EntityId),
     ChooseDialogOption(EntityId, i8),
     MoveResource(Move),
+    UseItem(InventoryIndex),
+    /// An item was released outside of any window.
+    RequestDropItem(InventoryItem<ResourceMetadata>),
+    DropItem {
+        index: InventoryIndex,
+        amount: u16,
+    },
+    RequestPickUpItem(EntityId),
     CastSkill(HotbarSlot),
     StopSkill(HotbarSlot),
+    PreviousHotbarRow,
+    NextHotbarRow,
     AddFriend(String),
     RemoveFriend {
         account_id: AccountId,
@@ -87,6 +123,52 @@ pub enum UserEvent {
         items: Vec<SoldItemInformation>,
     },
     FocusChatWindow,
+    HomunculusCommand(HomunculusCommand),
+    RenameHomunculus(String),
+    MercenaryCommand(MercenaryCommand),
+    PetCommand(PetCommand),
+    RenamePet(String),
+    RecallCompanion(CompanionType),
+    StartVending {
+        title: String,
+        items: Vec<OpenVendingStoreItemInformation>,
+    },
+    CancelVending,
+    CloseVendingStore,
+    StartBuyingStore {
+        title: String,
+        zeny_limit: u32,
+        items: Vec<OpenBuyingStoreItemInformation>,
+    },
+    CancelBuyingStore,
+    CloseBuyingStore,
+    RemoveCart,
+    CloseMailbox,
+    RequestMoreMails,
+    ReadMail {
+        mail_type: MailType,
+        mail_id: MailId,
+    },
+    DeleteMail {
+        mail_type: MailType,
+        mail_id: MailId,
+    },
+    TakeMailZeny {
+        mail_type: MailType,
+        mail_id: MailId,
+    },
+    TakeMailItems {
+        mail_type: MailType,
+        mail_id: MailId,
+    },
+    OpenWriteMailWindow,
+    CheckMailRecipient(String),
+    SendMail {
+        title: String,
+        text: String,
+        zeny: u64,
+    },
+    CancelWriteMail,
     #[cfg(feature = "debug")]
     OpenMarkerDetails(MarkerIdentifier),
     #[cfg(feature = "debug")]
@@ -100,13 +182,34 @@ pub enum UserEvent {
     #[cfg(feature = "debug")]
     OpenTimeWindow,
     #[cfg(feature = "debug")]
-    SetDawn,
+    OpenConsoleWindow,
+    #[cfg(feature = "debug")]
+    OpenSceneInspectorWindow,
+    /// Pause, step or force the animation of an entity.
     #[cfg(feature = "debug")]
-    SetNoon,
+    ControlEntityAnimation {
+        entity_id: EntityId,
+        control: AnimationControl,
+    },
+    /// Pause, resume, seek or change the speed of all videos of the map.
+    ControlVideos(VideoControl),
+    /// Set the time of day in hours.
     #[cfg(feature = "debug")]
-    SetDusk,
+    SetTimeOfDay(f32),
     #[cfg(feature = "debug")]
-    SetMidnight,
+    TeleportDebugCamera(Point3<f32>),
+    /// Set a flag of the render settings, or toggle it if no value is given.
+    #[cfg(feature = "debug")]
+    SetRenderFlag {
+        flag: &'static str,
+        enabled: Option<bool>,
+    },
+    /// Spawn an effect on a tile, or on the player if no tile is given.
+    #[cfg(feature = "debug")]
+    SpawnEffect {
+        file: String,
+        position: Option<Vector2<usize>>,
+    },
     #[cfg(feature = "debug")]
     OpenThemeViewerWindow,
     #[cfg(feature = "debug")]
@@ -116,6 +219,11 @@ pub enum UserEvent {
     #[cfg(feature = "debug")]
     ClearPacketHistory,
     #[cfg(feature = "debug")]
+    SendRawPacket {
+        server_type: ServerType,
+        bytes: Vec<u8>,
+    },
+    #[cfg(feature = "debug")]
     CameraLookAround(Vector2<f32>),
     #[cfg(feature = "debug")]
     CameraMoveForward,
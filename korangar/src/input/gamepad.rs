@@ -0,0 +1,355 @@
+use std::mem::variant_count;
+
+use cgmath::{InnerSpace, Vector2, Vector3};
+#[cfg(feature = "debug")]
+use korangar_debug::logging::log_error;
+use korangar_util::pathing::{PathFinder, Traversable};
+
+use super::Key;
+
+/// Stick deflection below which the stick is considered to be at rest.
+const STICK_DEAD_ZONE: f32 = 0.25;
+/// Maximum number of tiles the player walks per movement request.
+const MOVEMENT_DISTANCE: f32 = 4.0;
+/// Maximum number of steps the path to a movement target may be longer than
+/// the direct way. Targets behind walls would otherwise send the player on a
+/// long detour away from the stick direction.
+const MAXIMUM_DETOUR: usize = 2;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum GamepadButton {
+    South,
+    East,
+    North,
+    West,
+    LeftShoulder,
+    RightShoulder,
+    LeftTrigger,
+    RightTrigger,
+    Select,
+    Start,
+    DPadUp,
+    DPadDown,
+    DPadLeft,
+    DPadRight,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum GamepadAxis {
+    LeftStickX,
+    LeftStickY,
+}
+
+/// Input event of a gamepad. These are produced by the [`GamepadBackend`],
+/// but can also be created directly to simulate a controller.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum GamepadEvent {
+    ButtonPressed(GamepadButton),
+    ButtonReleased(GamepadButton),
+    /// The value of the axis in the range `-1.0..=1.0`. Positive values point
+    /// right and up.
+    AxisChanged(GamepadAxis, f32),
+    Disconnected,
+}
+
+/// Current state of the buttons and sticks of the gamepad.
+pub struct GamepadState {
+    buttons: [Key; variant_count::<GamepadButton>()],
+    left_stick: Vector2<f32>,
+}
+
+impl Default for GamepadState {
+    fn default() -> Self {
+        Self {
+            buttons: [Key::default(); variant_count::<GamepadButton>()],
+            left_stick: Vector2::new(0.0, 0.0),
+        }
+    }
+}
+
+impl GamepadState {
+    pub fn handle_event(&mut self, event: GamepadEvent) {
+        match event {
+            GamepadEvent::ButtonPressed(button) => self.buttons[button as usize].set_down(true),
+            GamepadEvent::ButtonReleased(button) => self.buttons[button as usize].set_down(false),
+            GamepadEvent::AxisChanged(GamepadAxis::LeftStickX, value) => self.left_stick.x = value.clamp(-1.0, 1.0),
+            GamepadEvent::AxisChanged(GamepadAxis::LeftStickY, value) => self.left_stick.y = value.clamp(-1.0, 1.0),
+            GamepadEvent::Disconnected => {
+                self.buttons
+                    .iter_mut()
+                    .filter(|button| button.down())
+                    .for_each(|button| button.set_down(false));
+                self.left_stick = Vector2::new(0.0, 0.0);
+            }
+        }
+    }
+
+    pub fn update(&mut self) {
+        self.buttons.iter_mut().for_each(|button| button.update());
+    }
+
+    pub fn button(&self, button: GamepadButton) -> &Key {
+        &self.buttons[button as usize]
+    }
+
+    /// Direction of the left stick, or `None` if it's inside the dead zone.
+    pub fn stick_direction(&self) -> Option<Vector2<f32>> {
+        (self.left_stick.magnitude() > STICK_DEAD_ZONE).then(|| self.left_stick.normalize())
+    }
+}
+
+/// Convert a stick direction into a direction on the tile grid, so that
+/// pushing the stick up always moves the player away from the camera.
+pub fn stick_to_grid_direction(stick_direction: Vector2<f32>, view_direction: Vector3<f32>) -> Vector2<f32> {
+    let forward = Vector2::new(view_direction.x, view_direction.z);
+
+    if forward.magnitude2() == 0.0 {
+        return stick_direction;
+    }
+
+    let forward = forward.normalize();
+    let right = Vector2::new(forward.y, -forward.x);
+
+    forward * stick_direction.y + right * stick_direction.x
+}
+
+/// Find the furthest tile in the given direction that can be reached without
+/// a long detour, so the movement request can be sent through the regular
+/// pathing.
+pub fn movement_target(
+    map: &impl Traversable,
+    path_finder: &mut PathFinder,
+    start: Vector2<usize>,
+    direction: Vector2<f32>,
+) -> Option<Vector2<usize>> {
+    let mut distance = MOVEMENT_DISTANCE;
+
+    while distance >= 1.0 {
+        let x = start.x as f32 + (direction.x * distance).round();
+        let y = start.y as f32 + (direction.y * distance).round();
+        distance -= 1.0;
+
+        if x < 0.0 || y < 0.0 {
+            continue;
+        }
+
+        let target = Vector2::new(x as usize, y as usize);
+
+        if target == start || !map.is_walkable(target) {
+            continue;
+        }
+
+        // Diagonal steps cover both axes, so the direct way is as long as the larger
+        // distance on either axis.
+        let direct_steps = start.x.abs_diff(target.x).max(start.y.abs_diff(target.y));
+
+        if let Some(path) = path_finder.find_walkable_path(map, start, target)
+            && path.len() - 1 <= direct_steps + MAXIMUM_DETOUR
+        {
+            return Some(target);
+        }
+    }
+
+    None
+}
+
+/// Reads events from the connected controllers.
+pub struct GamepadBackend {
+    gilrs: Option<gilrs::Gilrs>,
+}
+
+impl GamepadBackend {
+    pub fn new() -> Self {
+        let gilrs = gilrs::Gilrs::new()
+            .inspect_err(|_error| {
+                #[cfg(feature = "debug")]
+                log_error!("failed to initialize gamepad support: {:?}", _error);
+            })
+            .ok();
+
+        Self { gilrs }
+    }
+
+    pub fn poll(&mut self) -> Vec<GamepadEvent> {
+        let Some(gilrs) = self.gilrs.as_mut() else {
+            return Vec::new();
+        };
+
+        let mut events = Vec::new();
+
+        while let Some(gilrs::Event { event, .. }) = gilrs.next_event() {
+            let event = match event {
+                gilrs::EventType::ButtonPressed(button, _) => convert_button(button).map(GamepadEvent::ButtonPressed),
+                gilrs::EventType::ButtonReleased(button, _) => convert_button(button).map(GamepadEvent::ButtonReleased),
+                gilrs::EventType::AxisChanged(gilrs::Axis::LeftStickX, value, _) => {
+                    Some(GamepadEvent::AxisChanged(GamepadAxis::LeftStickX, value))
+                }
+                gilrs::EventType::AxisChanged(gilrs::Axis::LeftStickY, value, _) => {
+                    Some(GamepadEvent::AxisChanged(GamepadAxis::LeftStickY, value))
+                }
+                gilrs::EventType::Disconnected => Some(GamepadEvent::Disconnected),
+                _ => None,
+            };
+
+            events.extend(event);
+        }
+
+        events
+    }
+}
+
+fn convert_button(button: gilrs::Button) -> Option<GamepadButton> {
+    match button {
+        gilrs::Button::South => Some(GamepadButton::South),
+        gilrs::Button::East => Some(GamepadButton::East),
+        gilrs::Button::North => Some(GamepadButton::North),
+        gilrs::Button::West => Some(GamepadButton::West),
+        gilrs::Button::LeftTrigger => Some(GamepadButton::LeftShoulder),
+        gilrs::Button::RightTrigger => Some(GamepadButton::RightShoulder),
+        gilrs::Button::LeftTrigger2 => Some(GamepadButton::LeftTrigger),
+        gilrs::Button::RightTrigger2 => Some(GamepadButton::RightTrigger),
+        gilrs::Button::Select => Some(GamepadButton::Select),
+        gilrs::Button::Start => Some(GamepadButton::Start),
+        gilrs::Button::DPadUp => Some(GamepadButton::DPadUp),
+        gilrs::Button::DPadDown => Some(GamepadButton::DPadDown),
+        gilrs::Button::DPadLeft => Some(GamepadButton::DPadLeft),
+        gilrs::Button::DPadRight => Some(GamepadButton::DPadRight),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod synthetic_input {
+    use cgmath::{Vector2, Vector3};
+    use korangar_util::pathing::{PathFinder, Traversable};
+
+    use super::{GamepadAxis, GamepadButton, GamepadEvent, GamepadState, movement_target, stick_to_grid_direction};
+
+    struct TestMap {
+        width: usize,
+        height: usize,
+        blocked: Vec<Vector2<usize>>,
+    }
+
+    impl Traversable for TestMap {
+        fn is_walkable(&self, position: Vector2<usize>) -> bool {
+            position.x < self.width && position.y < self.height && !self.blocked.contains(&position)
+        }
+
+        fn is_snipeable(&self, position: Vector2<usize>) -> bool {
+            self.is_walkable(position)
+        }
+    }
+
+    fn assert_close(left: Vector2<f32>, right: Vector2<f32>) {
+        assert!(
+            (left.x - right.x).abs() < 1e-5 && (left.y - right.y).abs() < 1e-5,
+            "{left:?} != {right:?}"
+        );
+    }
+
+    #[test]
+    fn button_press_and_release() {
+        let mut state = GamepadState::default();
+
+        state.handle_event(GamepadEvent::ButtonPressed(GamepadButton::South));
+        state.update();
+        assert!(state.button(GamepadButton::South).pressed());
+        assert!(state.button(GamepadButton::South).down());
+
+        state.update();
+        assert!(!state.button(GamepadButton::South).pressed());
+
+        state.handle_event(GamepadEvent::ButtonReleased(GamepadButton::South));
+        state.update();
+        assert!(state.button(GamepadButton::South).released());
+        assert!(!state.button(GamepadButton::East).down());
+    }
+
+    #[test]
+    fn stick_dead_zone() {
+        let mut state = GamepadState::default();
+
+        state.handle_event(GamepadEvent::AxisChanged(GamepadAxis::LeftStickX, 0.1));
+        assert_eq!(state.stick_direction(), None);
+
+        state.handle_event(GamepadEvent::AxisChanged(GamepadAxis::LeftStickY, 0.8));
+        assert!(state.stick_direction().is_some());
+
+        state.handle_event(GamepadEvent::Disconnected);
+        assert_eq!(state.stick_direction(), None);
+    }
+
+    #[test]
+    fn stick_relative_to_camera() {
+        let view_direction = Vector3::new(0.0, -0.5, 1.0);
+
+        assert_close(
+            stick_to_grid_direction(Vector2::new(0.0, 1.0), view_direction),
+            Vector2::new(0.0, 1.0),
+        );
+        assert_close(
+            stick_to_grid_direction(Vector2::new(1.0, 0.0), view_direction),
+            Vector2::new(1.0, 0.0),
+        );
+
+        let view_direction = Vector3::new(-1.0, -0.5, 0.0);
+
+        assert_close(
+            stick_to_grid_direction(Vector2::new(0.0, 1.0), view_direction),
+            Vector2::new(-1.0, 0.0),
+        );
+    }
+
+    #[test]
+    fn movement_stops_at_obstacle() {
+        let map = TestMap {
+            width: 10,
+            height: 10,
+            blocked: vec![Vector2::new(5, 8), Vector2::new(5, 7)],
+        };
+        let mut path_finder = PathFinder::default();
+        let start = Vector2::new(5, 4);
+
+        assert_eq!(
+            movement_target(&map, &mut path_finder, start, Vector2::new(0.0, 1.0)),
+            Some(Vector2::new(5, 6))
+        );
+        assert_eq!(
+            movement_target(&map, &mut path_finder, start, Vector2::new(0.0, -1.0)),
+            Some(Vector2::new(5, 0))
+        );
+    }
+
+    #[test]
+    fn movement_avoids_detours() {
+        // A wall in front of the player with a single gap far to the side.
+        let map = TestMap {
+            width: 10,
+            height: 10,
+            blocked: (0..9).map(|x| Vector2::new(x, 4)).collect(),
+        };
+        let mut path_finder = PathFinder::default();
+        let start = Vector2::new(5, 2);
+
+        assert_eq!(
+            movement_target(&map, &mut path_finder, start, Vector2::new(0.0, 1.0)),
+            Some(Vector2::new(5, 3))
+        );
+    }
+
+    #[test]
+    fn no_movement_outside_of_map() {
+        let map = TestMap {
+            width: 10,
+            height: 10,
+            blocked: Vec::new(),
+        };
+        let mut path_finder = PathFinder::default();
+
+        assert_eq!(
+            movement_target(&map, &mut path_finder, Vector2::new(0, 0), Vector2::new(-1.0, 0.0)),
+            None
+        );
+    }
+}
@@ -1,4 +1,5 @@
 mod event;
+mod gamepad;
 mod key;
 mod mode;
 
@@ -9,16 +10,17 @@ use std::sync::atomic::{AtomicU64, Ordering};
 use cgmath::Vector2;
 use korangar_interface::Interface;
 use korangar_interface::application::FocusState;
-use korangar_interface::elements::{ElementCell, Focus};
-use korangar_interface::event::ClickAction;
-#[cfg(feature = "debug")]
-use korangar_interface::state::{PlainTrackedState, TrackedState};
+use korangar_interface::elements::{ElementCell, Focus, FocusMode};
+use korangar_interface::event::{ClickAction, EditAction};
+use korangar_interface::state::{PlainRemote, PlainTrackedState, TrackedState};
 use ragnarok_packets::{ClientTick, HotbarSlot};
 use winit::dpi::PhysicalPosition;
-use winit::event::{ElementState, MouseButton, MouseScrollDelta};
+use winit::event::{ElementState, Ime, MouseButton, MouseScrollDelta};
 use winit::keyboard::KeyCode;
 
 pub use self::event::UserEvent;
+pub use self::gamepad::{GamepadBackend, movement_target, stick_to_grid_direction};
+use self::gamepad::{GamepadButton, GamepadEvent, GamepadState};
 pub use self::key::Key;
 pub use self::mode::{Grabbed, MouseInputMode};
 use crate::graphics::PickerTarget;
@@ -27,11 +29,36 @@ use crate::graphics::RenderSettings;
 use crate::interface::application::InterfaceSettings;
 use crate::interface::cursor::{MouseCursor, MouseCursorState};
 use crate::interface::layout::{ScreenPosition, ScreenSize};
-use crate::interface::resource::PartialMove;
+use crate::interface::resource::{ItemSource, PartialMove};
+use crate::settings::{InputAction, InputTrigger, KeyBindings, KeyChord};
 
 const MOUSE_SCOLL_MULTIPLIER: f32 = 30.0;
 const KEY_COUNT: usize = variant_count::<KeyCode>();
 const DOUBLE_CLICK_TIME_MS: u32 = 500;
+/// Time between two movement requests while the stick is held.
+const STICK_MOVEMENT_INTERVAL_MS: u32 = 200;
+/// Gamepad buttons that trigger the first slots of the hotbar.
+const GAMEPAD_HOTBAR_BUTTONS: [GamepadButton; 4] = [
+    GamepadButton::West,
+    GamepadButton::North,
+    GamepadButton::LeftTrigger,
+    GamepadButton::RightTrigger,
+];
+/// Mouse buttons besides the left and right one, which can be used in key
+/// bindings.
+const EXTRA_MOUSE_BUTTONS: [MouseButton; 3] = [MouseButton::Middle, MouseButton::Back, MouseButton::Forward];
+/// Keys that only act as modifiers and can't trigger a key binding on their
+/// own.
+const MODIFIER_KEYS: [KeyCode; 8] = [
+    KeyCode::ControlLeft,
+    KeyCode::ControlRight,
+    KeyCode::AltLeft,
+    KeyCode::AltRight,
+    KeyCode::ShiftLeft,
+    KeyCode::ShiftRight,
+    KeyCode::SuperLeft,
+    KeyCode::SuperRight,
+];
 
 struct PreviousMouseButton {
     button: MouseButton,
@@ -47,11 +74,25 @@ pub struct InputSystem {
     scroll_delta: f32,
     left_mouse_button: Key,
     right_mouse_button: Key,
+    extra_mouse_buttons: [Key; EXTRA_MOUSE_BUTTONS.len()],
     keys: [Key; KEY_COUNT],
     mouse_input_mode: MouseInputMode,
     input_buffer: Vec<char>,
     picker_value: Arc<AtomicU64>,
     previous_mouse_button: PreviousMouseButton,
+    /// Last key that was pressed, excluding modifiers.
+    last_pressed_key: Option<KeyCode>,
+    /// Action that the next pressed key will be bound to.
+    key_binding_capture: PlainTrackedState<Option<InputAction>>,
+    /// Keys pressed since the last frame, including repeated key presses.
+    pressed_keys: Vec<KeyCode>,
+    clipboard: Option<arboard::Clipboard>,
+    /// Input method events since the last frame.
+    ime_events: Vec<Ime>,
+    gamepad: GamepadState,
+    /// Set when the gamepad was used more recently than the mouse.
+    gamepad_active: bool,
+    previous_stick_movement: ClientTick,
 }
 
 impl InputSystem {
@@ -66,6 +107,7 @@ impl InputSystem {
 
         let left_mouse_button = Key::default();
         let right_mouse_button = Key::default();
+        let extra_mouse_buttons = [Key::default(); EXTRA_MOUSE_BUTTONS.len()];
         let keys = [Key::default(); KEY_COUNT];
 
         let mouse_input_mode = MouseInputMode::None;
@@ -74,6 +116,14 @@ impl InputSystem {
             button: MouseButton::Left,
             tick: ClientTick::new(0),
         };
+        let last_pressed_key = None;
+        let key_binding_capture = PlainTrackedState::default();
+        let pressed_keys = Vec::new();
+        let clipboard = arboard::Clipboard::new().ok();
+        let ime_events = Vec::new();
+        let gamepad = GamepadState::default();
+        let gamepad_active = false;
+        let previous_stick_movement = ClientTick::new(0);
 
         Self {
             previous_mouse_position,
@@ -84,17 +134,27 @@ impl InputSystem {
             scroll_delta,
             left_mouse_button,
             right_mouse_button,
+            extra_mouse_buttons,
             keys,
             mouse_input_mode,
             input_buffer,
             picker_value,
             previous_mouse_button,
+            last_pressed_key,
+            key_binding_capture,
+            pressed_keys,
+            clipboard,
+            ime_events,
+            gamepad,
+            gamepad_active,
+            previous_stick_movement,
         }
     }
 
     pub fn reset(&mut self) {
         self.left_mouse_button.reset();
         self.right_mouse_button.reset();
+        self.extra_mouse_buttons.iter_mut().for_each(|button| button.reset());
         self.keys.iter_mut().for_each(|key| key.reset());
         self.mouse_input_mode = MouseInputMode::None;
     }
@@ -104,6 +164,7 @@ impl InputSystem {
             left: position.x as f32,
             top: position.y as f32,
         };
+        self.gamepad_active = false;
     }
 
     pub fn update_mouse_buttons(&mut self, button: MouseButton, state: ElementState) {
@@ -112,7 +173,11 @@ impl InputSystem {
         match button {
             MouseButton::Left => self.left_mouse_button.set_down(pressed),
             MouseButton::Right => self.right_mouse_button.set_down(pressed),
-            _ignored => {}
+            other => {
+                if let Some(index) = EXTRA_MOUSE_BUTTONS.iter().position(|button| *button == other) {
+                    self.extra_mouse_buttons[index].set_down(pressed);
+                }
+            }
         }
     }
 
@@ -126,12 +191,31 @@ impl InputSystem {
     pub fn update_keyboard(&mut self, key_code: KeyCode, state: ElementState) {
         let pressed = matches!(state, ElementState::Pressed);
         self.keys[key_code as usize].set_down(pressed);
+
+        if pressed && !MODIFIER_KEYS.contains(&key_code) {
+            self.last_pressed_key = Some(key_code);
+            self.pressed_keys.push(key_code);
+        }
+    }
+
+    pub fn update_gamepad(&mut self, event: GamepadEvent) {
+        self.gamepad.handle_event(event);
+        self.gamepad_active = true;
+    }
+
+    /// Returns `true` if the gamepad was used more recently than the mouse.
+    pub fn is_gamepad_active(&self) -> bool {
+        self.gamepad_active
     }
 
     pub fn buffer_character(&mut self, character: char) {
         self.input_buffer.push(character);
     }
 
+    pub fn update_ime(&mut self, ime: Ime) {
+        self.ime_events.push(ime);
+    }
+
     pub fn update_delta(&mut self) {
         self.mouse_delta = self.new_mouse_position - self.previous_mouse_position;
         self.previous_mouse_position = self.new_mouse_position;
@@ -141,13 +225,263 @@ impl InputSystem {
 
         self.left_mouse_button.update();
         self.right_mouse_button.update();
+        self.extra_mouse_buttons.iter_mut().for_each(|button| button.update());
         self.keys.iter_mut().for_each(|key| key.update());
+        self.gamepad.update();
     }
 
     fn get_key(&self, key_code: KeyCode) -> &Key {
         &self.keys[key_code as usize]
     }
 
+    fn get_trigger(&self, trigger: InputTrigger) -> Option<&Key> {
+        match trigger {
+            InputTrigger::Key(key_code) => Some(self.get_key(key_code)),
+            InputTrigger::Mouse(MouseButton::Left) => Some(&self.left_mouse_button),
+            InputTrigger::Mouse(MouseButton::Right) => Some(&self.right_mouse_button),
+            InputTrigger::Mouse(button) => EXTRA_MOUSE_BUTTONS
+                .iter()
+                .position(|extra_button| *extra_button == button)
+                .map(|index| &self.extra_mouse_buttons[index]),
+        }
+    }
+
+    fn modifiers_down(&self) -> (bool, bool, bool) {
+        let control = self.get_key(KeyCode::ControlLeft).down() || self.get_key(KeyCode::ControlRight).down();
+        let alt = self.get_key(KeyCode::AltLeft).down() || self.get_key(KeyCode::AltRight).down();
+        let shift = self.get_key(KeyCode::ShiftLeft).down() || self.get_key(KeyCode::ShiftRight).down();
+
+        (control, alt, shift)
+    }
+
+    /// Check if any chord bound to the action was pressed this frame with
+    /// exactly its modifiers held down.
+    fn action_pressed(&self, key_bindings: &KeyBindings, action: InputAction) -> bool {
+        let (control, alt, shift) = self.modifiers_down();
+
+        key_bindings.get_chords(action).iter().any(|chord| {
+            chord.control == control
+                && chord.alt == alt
+                && chord.shift == shift
+                && self.get_trigger(chord.trigger).is_some_and(Key::pressed)
+        })
+    }
+
+    /// Check if the trigger of any chord bound to the action was released this
+    /// frame, independent of the modifiers.
+    fn action_released(&self, key_bindings: &KeyBindings, action: InputAction) -> bool {
+        key_bindings
+            .get_chords(action)
+            .iter()
+            .any(|chord| self.get_trigger(chord.trigger).is_some_and(Key::released))
+    }
+
+    /// The chord that was pressed since the last frame, if any.
+    fn pressed_chord(&mut self) -> Option<KeyChord> {
+        let trigger = self.last_pressed_key.take().map(InputTrigger::Key).or_else(|| {
+            EXTRA_MOUSE_BUTTONS
+                .iter()
+                .zip(self.extra_mouse_buttons.iter())
+                .find(|(_, key)| key.pressed())
+                .map(|(button, _)| InputTrigger::Mouse(*button))
+        })?;
+        let (control, alt, shift) = self.modifiers_down();
+
+        Some(KeyChord {
+            control,
+            alt,
+            shift,
+            trigger,
+        })
+    }
+
+    /// Bind the next pressed key to the given action.
+    pub fn capture_key_binding(&mut self, action: InputAction) {
+        self.key_binding_capture.set(Some(action));
+    }
+
+    pub fn key_binding_capture_remote(&self) -> PlainRemote<Option<InputAction>> {
+        self.key_binding_capture.new_remote()
+    }
+
+    fn click_focused_element(
+        interface: &mut Interface<InterfaceSettings>,
+        application: &InterfaceSettings,
+        focus_state: &mut FocusState<InterfaceSettings>,
+        focused_element: &ElementCell<InterfaceSettings>,
+        focused_window: usize,
+        events: &mut Vec<UserEvent>,
+    ) {
+        let actions = interface.left_click_element(focused_element, focused_window);
+
+        for action in actions {
+            // TODO: remove and replace with proper event
+            match action {
+                ClickAction::Custom(event) => events.push(event),
+                ClickAction::OpenWindow(prototype_window) => interface.open_window(application, focus_state, prototype_window.as_ref()),
+                ClickAction::CloseWindow => interface.close_window(focus_state, focused_window),
+                _ => {}
+            }
+        }
+    }
+
+    /// Handle the actions returned by the focused element after a key press.
+    #[allow(clippy::too_many_arguments)]
+    fn handle_focused_actions(
+        &mut self,
+        interface: &mut Interface<InterfaceSettings>,
+        application: &InterfaceSettings,
+        focus_state: &mut FocusState<InterfaceSettings>,
+        focused_element: &ElementCell<InterfaceSettings>,
+        focused_window: usize,
+        actions: Vec<ClickAction<InterfaceSettings>>,
+        events: &mut Vec<UserEvent>,
+    ) {
+        for action in actions {
+            match action {
+                // is handled in the interface
+                ClickAction::ChangeEvent(..) => {}
+                ClickAction::FocusElement => {
+                    let element_cell = focused_element.clone();
+                    let new_focused_element = focused_element.borrow().focus_next(element_cell, None, Focus::downwards());
+
+                    focus_state.set_focused_element(new_focused_element, focused_window);
+                }
+                ClickAction::FocusNext(focus_mode) => {
+                    let element_cell = focused_element.clone();
+                    let new_focused_element = focused_element.borrow().focus_next(element_cell, None, Focus::new(focus_mode));
+
+                    focus_state.update_focused_element(new_focused_element, focused_window);
+                }
+                ClickAction::Custom(event) => events.push(event),
+                ClickAction::MoveInterface => self.mouse_input_mode = MouseInputMode::MoveInterface(focused_window),
+                ClickAction::DragElement => self.mouse_input_mode = MouseInputMode::DragElement((focused_element.clone(), focused_window)),
+                // TODO: should just move immediately ?
+                ClickAction::Move(..) => {}
+                ClickAction::OpenWindow(prototype_window) => interface.open_window(application, focus_state, prototype_window.as_ref()),
+                ClickAction::CloseWindow => interface.close_window(focus_state, focused_window),
+                ClickAction::OpenPopup {
+                    element,
+                    position_tracker,
+                    size_tracker,
+                } => interface.open_popup(element, position_tracker, size_tracker, focused_window),
+                ClickAction::ClosePopup => interface.close_popup(focused_window),
+            }
+        }
+    }
+
+    /// Translate a key press into an edit action for the focused element.
+    /// Copying and cutting go through the system clipboard.
+    fn edit_action(&mut self, key_code: KeyCode, focused_element: &ElementCell<InterfaceSettings>) -> Option<EditAction> {
+        let (control, _, shift) = self.modifiers_down();
+
+        match key_code {
+            KeyCode::ArrowLeft => Some(EditAction::MoveLeft {
+                word: control,
+                select: shift,
+            }),
+            KeyCode::ArrowRight => Some(EditAction::MoveRight {
+                word: control,
+                select: shift,
+            }),
+            KeyCode::Home => Some(EditAction::MoveHome { select: shift }),
+            KeyCode::End => Some(EditAction::MoveEnd { select: shift }),
+            KeyCode::ArrowUp => Some(EditAction::HistoryPrevious),
+            KeyCode::ArrowDown => Some(EditAction::HistoryNext),
+            KeyCode::Backspace => Some(EditAction::DeleteBackward { word: control }),
+            KeyCode::Delete => Some(EditAction::DeleteForward { word: control }),
+            KeyCode::KeyA if control => Some(EditAction::SelectAll),
+            KeyCode::KeyZ if control && shift => Some(EditAction::Redo),
+            KeyCode::KeyZ if control => Some(EditAction::Undo),
+            KeyCode::KeyY if control => Some(EditAction::Redo),
+            KeyCode::KeyC | KeyCode::KeyX if control => {
+                let text = focused_element.borrow().selected_text()?;
+
+                if let Some(clipboard) = &mut self.clipboard {
+                    let _ = clipboard.set_text(text);
+                }
+
+                (key_code == KeyCode::KeyX).then_some(EditAction::DeleteSelection)
+            }
+            KeyCode::KeyV if control => self.clipboard.as_mut()?.get_text().ok().map(EditAction::Insert),
+            _ => None,
+        }
+    }
+
+    fn gamepad_user_events(
+        &mut self,
+        interface: &mut Interface<InterfaceSettings>,
+        application: &InterfaceSettings,
+        focus_state: &mut FocusState<InterfaceSettings>,
+        events: &mut Vec<UserEvent>,
+        client_tick: ClientTick,
+    ) {
+        let pressed = |button| self.gamepad.button(button).pressed();
+
+        let focus_mode = if pressed(GamepadButton::DPadUp) || pressed(GamepadButton::DPadLeft) {
+            Some(FocusMode::FocusPrevious)
+        } else if pressed(GamepadButton::DPadDown) || pressed(GamepadButton::DPadRight) {
+            Some(FocusMode::FocusNext)
+        } else {
+            None
+        };
+
+        match focus_state.get_focused_element() {
+            Some((focused_element, focused_window)) => {
+                if let Some(focus_mode) = focus_mode {
+                    let new_focused_element = focused_element
+                        .borrow()
+                        .focus_next(focused_element.clone(), None, Focus::new(focus_mode));
+
+                    focus_state.update_focused_element(new_focused_element, focused_window);
+                }
+
+                if pressed(GamepadButton::South) {
+                    Self::click_focused_element(interface, application, focus_state, &focused_element, focused_window, events);
+                }
+
+                if pressed(GamepadButton::East) {
+                    focus_state.remove_focus();
+                }
+            }
+            None if focus_mode.is_some() => interface.first_focused_element(focus_state),
+            None => {}
+        }
+
+        if pressed(GamepadButton::Select) {
+            interface.first_focused_element(focus_state);
+        }
+
+        if pressed(GamepadButton::Start) {
+            events.push(UserEvent::OpenMenuWindow);
+        }
+
+        if pressed(GamepadButton::LeftShoulder) {
+            events.push(UserEvent::PreviousHotbarRow);
+        }
+
+        if pressed(GamepadButton::RightShoulder) {
+            events.push(UserEvent::NextHotbarRow);
+        }
+
+        for (slot, button) in GAMEPAD_HOTBAR_BUTTONS.into_iter().enumerate() {
+            if pressed(button) {
+                events.push(UserEvent::CastSkill(HotbarSlot(slot as u16)));
+            }
+
+            if self.gamepad.button(button).released() {
+                events.push(UserEvent::StopSkill(HotbarSlot(slot as u16)));
+            }
+        }
+
+        if let Some(direction) = self.gamepad.stick_direction()
+            && client_tick.0.wrapping_sub(self.previous_stick_movement.0) >= STICK_MOVEMENT_INTERVAL_MS
+        {
+            self.previous_stick_movement = client_tick;
+            events.push(UserEvent::MoveInDirection(direction));
+        }
+    }
+
     #[allow(clippy::type_complexity)]
     #[cfg_attr(feature = "debug", korangar_debug::profile("update user input"))]
     pub fn user_events(
@@ -156,6 +490,7 @@ impl InputSystem {
         application: &InterfaceSettings,
         focus_state: &mut FocusState<InterfaceSettings>,
         mouse_cursor: &mut MouseCursor,
+        key_bindings: &KeyBindings,
         #[cfg(feature = "debug")] render_settings: &PlainTrackedState<RenderSettings>,
         client_tick: ClientTick,
     ) -> (
@@ -294,6 +629,9 @@ impl InputSystem {
                                 {
                                     events.push(UserEvent::MoveResource(resource_move));
                                 }
+                            } else if window_index.is_none() && source == ItemSource::Inventory {
+                                // Items that are released outside of any window are dropped on the ground.
+                                events.push(UserEvent::RequestDropItem(item));
                             }
                         }
                         MouseInputMode::MoveSkill(source, skill) => {
@@ -391,9 +729,27 @@ impl InputSystem {
         }
 
         let characters = self.input_buffer.drain(..).collect::<Vec<_>>();
+        let pressed_keys = std::mem::take(&mut self.pressed_keys);
+        let ime_events = std::mem::take(&mut self.ime_events);
+        let pressed_chord = self.pressed_chord();
         let mut process_keys = true;
 
-        if let Some((focused_element, focused_window)) = &focus_state.get_focused_element() {
+        let capture = *self.key_binding_capture.get();
+
+        if let Some(action) = capture {
+            // While capturing, no other action should be triggered by the key press.
+            process_keys = false;
+
+            if let Some(chord) = pressed_chord {
+                if chord != KeyChord::key(KeyCode::Escape) {
+                    events.push(UserEvent::SetKeyBinding { action, chord });
+                }
+
+                self.key_binding_capture.set(None);
+            }
+        }
+
+        if process_keys && let Some((focused_element, focused_window)) = &focus_state.get_focused_element() {
             // this will currently not affect the following statements, which is a bit
             // strange
             if self.get_key(KeyCode::Escape).pressed() {
@@ -402,34 +758,69 @@ impl InputSystem {
             }
 
             if self.get_key(KeyCode::Tab).pressed() {
-                let new_focused_element = focused_element
-                    .borrow()
-                    .focus_next(focused_element.clone(), None, Focus::new(shift_down.into()));
+                // Elements with completion use tab to complete their text. Shift + tab
+                // always moves the focus.
+                let (key_handled, actions) = match shift_down {
+                    true => (false, Vec::new()),
+                    false => interface.edit_element(application, focused_element, *focused_window, EditAction::Complete),
+                };
+
+                match key_handled {
+                    true => self.handle_focused_actions(
+                        interface,
+                        application,
+                        focus_state,
+                        focused_element,
+                        *focused_window,
+                        actions,
+                        &mut events,
+                    ),
+                    false => {
+                        let new_focused_element =
+                            focused_element
+                                .borrow()
+                                .focus_next(focused_element.clone(), None, Focus::new(shift_down.into()));
+
+                        focus_state.update_focused_element(new_focused_element, *focused_window);
+                    }
+                }
 
-                focus_state.update_focused_element(new_focused_element, *focused_window);
                 process_keys = false;
             }
 
             if self.get_key(KeyCode::Enter).pressed() {
-                let actions = interface.left_click_element(focused_element, *focused_window);
-
-                for action in actions {
-                    // TODO: remove and replace with proper event
-                    match action {
-                        ClickAction::Custom(event) => events.push(event),
-                        ClickAction::OpenWindow(prototype_window) => {
-                            interface.open_window(application, focus_state, prototype_window.as_ref())
-                        }
-                        ClickAction::CloseWindow => interface.close_window(focus_state, *focused_window),
-                        _ => {}
-                    }
+                // Elements that accept text handle enter themselves, all others are clicked.
+                let (key_handled, actions) = interface.input_character_element(application, focused_element, *focused_window, '\r');
+
+                match key_handled {
+                    true => self.handle_focused_actions(
+                        interface,
+                        application,
+                        focus_state,
+                        focused_element,
+                        *focused_window,
+                        actions,
+                        &mut events,
+                    ),
+                    false => Self::click_focused_element(
+                        interface,
+                        application,
+                        focus_state,
+                        focused_element,
+                        *focused_window,
+                        &mut events,
+                    ),
                 }
 
                 process_keys = false;
             }
         }
 
-        if self.get_key(KeyCode::ControlLeft).down() && self.get_key(KeyCode::KeyQ).pressed() && focus_state.focused_window().is_some() {
+        if process_keys
+            && self.get_key(KeyCode::ControlLeft).down()
+            && self.get_key(KeyCode::KeyQ).pressed()
+            && focus_state.focused_window().is_some()
+        {
             let window_index = focus_state.get_focused_window().unwrap();
 
             if interface.get_window(window_index).is_closable() {
@@ -440,125 +831,131 @@ impl InputSystem {
         }
 
         if let Some((focused_element, focused_window)) = &focus_state.get_focused_element() {
+            for ime in ime_events {
+                let edit_action = match ime {
+                    Ime::Preedit(text, cursor) => EditAction::Preedit { text, cursor },
+                    Ime::Commit(text) => EditAction::Insert(text),
+                    Ime::Disabled => EditAction::Preedit {
+                        text: String::new(),
+                        cursor: None,
+                    },
+                    Ime::Enabled => continue,
+                };
+
+                let (_, actions) = interface.edit_element(application, focused_element, *focused_window, edit_action);
+
+                self.handle_focused_actions(
+                    interface,
+                    application,
+                    focus_state,
+                    focused_element,
+                    *focused_window,
+                    actions,
+                    &mut events,
+                );
+            }
+        }
+
+        if process_keys && let Some((focused_element, focused_window)) = &focus_state.get_focused_element() {
+            for key_code in pressed_keys {
+                // Keys like backspace or the arrow keys belong to the input method
+                // while it is composing.
+                if focused_element.borrow().is_composing() {
+                    process_keys = false;
+                    continue;
+                }
+
+                let Some(edit_action) = self.edit_action(key_code, focused_element) else {
+                    continue;
+                };
+
+                let (key_handled, actions) = interface.edit_element(application, focused_element, *focused_window, edit_action);
+
+                if key_handled {
+                    process_keys = false;
+                }
+
+                self.handle_focused_actions(
+                    interface,
+                    application,
+                    focus_state,
+                    focused_element,
+                    *focused_window,
+                    actions,
+                    &mut events,
+                );
+            }
+
             for character in characters {
                 match character {
                     // ignore since we need to handle tab knowing the state of shift
                     '\t' => {}
                     '\x1b' => {}
                     valid => {
-                        let (key_handled, actions) = interface.input_character_element(focused_element, *focused_window, valid);
+                        let (key_handled, actions) =
+                            interface.input_character_element(application, focused_element, *focused_window, valid);
 
                         if key_handled {
                             process_keys = false;
                         }
 
-                        for action in actions {
-                            match action {
-                                // is handled in the interface
-                                ClickAction::ChangeEvent(..) => {}
-                                ClickAction::FocusElement => {
-                                    let element_cell = focused_element.clone();
-                                    let new_focused_element = focused_element.borrow().focus_next(element_cell, None, Focus::downwards());
-
-                                    focus_state.set_focused_element(new_focused_element, *focused_window);
-                                }
-                                ClickAction::FocusNext(focus_mode) => {
-                                    let element_cell = focused_element.clone();
-                                    let new_focused_element =
-                                        focused_element.borrow().focus_next(element_cell, None, Focus::new(focus_mode));
-
-                                    focus_state.update_focused_element(new_focused_element, *focused_window);
-                                }
-                                ClickAction::Custom(event) => events.push(event),
-                                ClickAction::MoveInterface => self.mouse_input_mode = MouseInputMode::MoveInterface(*focused_window),
-                                ClickAction::DragElement => {
-                                    self.mouse_input_mode = MouseInputMode::DragElement((focused_element.clone(), *focused_window))
-                                }
-                                // TODO: should just move immediately ?
-                                ClickAction::Move(..) => {}
-                                ClickAction::OpenWindow(prototype_window) => {
-                                    interface.open_window(application, focus_state, prototype_window.as_ref())
-                                }
-                                ClickAction::CloseWindow => interface.close_window(focus_state, *focused_window),
-                                ClickAction::OpenPopup {
-                                    element,
-                                    position_tracker,
-                                    size_tracker,
-                                } => interface.open_popup(element, position_tracker, size_tracker, *focused_window),
-                                ClickAction::ClosePopup => interface.close_popup(*focused_window),
-                            }
-                        }
+                        self.handle_focused_actions(
+                            interface,
+                            application,
+                            focus_state,
+                            focused_element,
+                            *focused_window,
+                            actions,
+                            &mut events,
+                        );
                     }
                 }
             }
         }
 
         if process_keys {
-            let alt_down = self.get_key(KeyCode::AltLeft).down();
-            let control_down = self.get_key(KeyCode::ControlLeft).down();
-
             if self.get_key(KeyCode::Tab).pressed() {
                 interface.first_focused_element(focus_state);
             }
 
-            if self.get_key(KeyCode::Escape).pressed() {
-                events.push(UserEvent::OpenMenuWindow);
-            }
-
-            if alt_down && self.get_key(KeyCode::KeyE).pressed() {
-                events.push(UserEvent::OpenInventoryWindow);
-            }
-
-            if control_down && self.get_key(KeyCode::KeyH).pressed() {
-                events.push(UserEvent::ToggleShowInterface);
-            }
-
-            if self.get_key(KeyCode::KeyJ).pressed() {
-                events.push(UserEvent::CastSkill(HotbarSlot(0)));
-            }
-
-            if self.get_key(KeyCode::KeyJ).released() {
-                events.push(UserEvent::StopSkill(HotbarSlot(0)));
-            }
-
-            if self.get_key(KeyCode::KeyL).pressed() {
-                events.push(UserEvent::CastSkill(HotbarSlot(1)));
-            }
-
-            if self.get_key(KeyCode::KeyL).released() {
-                events.push(UserEvent::StopSkill(HotbarSlot(1)));
-            }
-
-            if self.get_key(KeyCode::KeyU).pressed() {
-                events.push(UserEvent::CastSkill(HotbarSlot(2)));
-            }
-
-            if self.get_key(KeyCode::KeyU).released() {
-                events.push(UserEvent::StopSkill(HotbarSlot(2)));
-            }
-
-            if self.get_key(KeyCode::Enter).pressed() {
-                events.push(UserEvent::FocusChatWindow);
-            }
-
-            #[cfg(feature = "debug")]
-            if control_down && self.get_key(KeyCode::KeyM).pressed() {
-                events.push(UserEvent::OpenMapsWindow);
-            }
-
-            #[cfg(feature = "debug")]
-            if control_down && self.get_key(KeyCode::KeyR).pressed() {
-                events.push(UserEvent::OpenRenderSettingsWindow);
-            }
+            for action in InputAction::all() {
+                if self.action_pressed(key_bindings, action) {
+                    let event = match action {
+                        InputAction::OpenMenu => UserEvent::OpenMenuWindow,
+                        InputAction::OpenInventory => UserEvent::OpenInventoryWindow,
+                        InputAction::OpenEquipment => UserEvent::OpenEquipmentWindow,
+                        InputAction::OpenCart => UserEvent::OpenCartWindow,
+                        InputAction::OpenSkillTree => UserEvent::OpenSkillTreeWindow,
+                        InputAction::OpenStatus => UserEvent::OpenStatusWindow,
+                        InputAction::OpenHomunculus => UserEvent::OpenHomunculusWindow,
+                        InputAction::OpenKeyBindings => UserEvent::OpenKeyBindingsWindow,
+                        InputAction::ToggleInterface => UserEvent::ToggleShowInterface,
+                        InputAction::FocusChat => UserEvent::FocusChatWindow,
+                        InputAction::Hotbar(slot) => UserEvent::CastSkill(HotbarSlot(slot as u16)),
+                        InputAction::PreviousHotbarRow => UserEvent::PreviousHotbarRow,
+                        InputAction::NextHotbarRow => UserEvent::NextHotbarRow,
+                        #[cfg(feature = "debug")]
+                        InputAction::OpenMaps => UserEvent::OpenMapsWindow,
+                        #[cfg(feature = "debug")]
+                        InputAction::OpenRenderSettings => UserEvent::OpenRenderSettingsWindow,
+                        #[cfg(feature = "debug")]
+                        InputAction::OpenTime => UserEvent::OpenTimeWindow,
+                        #[cfg(feature = "debug")]
+                        InputAction::OpenPackets => UserEvent::OpenPacketWindow,
+                        #[cfg(not(feature = "debug"))]
+                        InputAction::OpenMaps | InputAction::OpenRenderSettings | InputAction::OpenTime | InputAction::OpenPackets => {
+                            continue;
+                        }
+                    };
 
-            #[cfg(feature = "debug")]
-            if control_down && self.get_key(KeyCode::KeyT).pressed() {
-                events.push(UserEvent::OpenTimeWindow);
-            }
+                    events.push(event);
+                }
 
-            #[cfg(feature = "debug")]
-            if control_down && self.get_key(KeyCode::KeyP).pressed() {
-                events.push(UserEvent::OpenPacketWindow);
+                if let InputAction::Hotbar(slot) = action
+                    && self.action_released(key_bindings, action)
+                {
+                    events.push(UserEvent::StopSkill(HotbarSlot(slot as u16)));
+                }
             }
 
             #[cfg(feature = "debug")]
@@ -609,6 +1006,10 @@ impl InputSystem {
             }
         }
 
+        if capture.is_none() {
+            self.gamepad_user_events(interface, application, focus_state, &mut events, client_tick);
+        }
+
         if window_index.is_none() && (self.mouse_input_mode.is_none() || self.mouse_input_mode.is_walk()) {
             let last_pixel_value = self.picker_value.load(Ordering::Acquire);
             let picker_target = PickerTarget::from(last_pixel_value);
@@ -617,6 +1018,7 @@ impl InputSystem {
                 if self.left_mouse_button.pressed() {
                     match picker_target {
                         PickerTarget::Entity(entity_id) => events.push(UserEvent::RequestPlayerInteract(entity_id)),
+                        PickerTarget::GroundItem(entity_id) => events.push(UserEvent::RequestPickUpItem(entity_id)),
                         PickerTarget::Tile { x, y } => {
                             let position = Vector2::new(x as usize, y as usize);
                             self.mouse_input_mode = MouseInputMode::Walk(position);
@@ -1,6 +1,7 @@
 use std::marker::ConstParamTy;
 use std::sync::Arc;
 
+use encoding_rs::{EUC_KR, Encoding};
 #[cfg(feature = "debug")]
 use korangar_debug::logging::{Colorize, print_debug};
 use korangar_interface::application::{Application, ScalingTrait};
@@ -247,6 +248,8 @@ pub struct InterfaceSettings {
     scaling: MutableRange<Scaling, korangar_interface::event::Resolve>,
     #[hidden_element]
     themes: Themes,
+    #[hidden_element]
+    encoding: &'static Encoding,
 }
 
 impl InterfaceSettings {
@@ -272,6 +275,7 @@ impl InterfaceSettings {
             game_theme: ThemeSelector(game_theme),
             scaling: MutableRange::new(scaling, Scaling::new(0.5), Scaling::new(2.5)),
             themes,
+            encoding: EUC_KR,
         }
     }
 
@@ -291,6 +295,12 @@ impl InterfaceSettings {
     pub fn get_fonts(&self) -> &[String] {
         &self.fonts
     }
+
+    /// Set the encoding of the text sent to the server, which limits the
+    /// length of input fields.
+    pub fn set_encoding(&mut self, encoding: &'static Encoding) {
+        self.encoding = encoding;
+    }
 }
 
 impl InterfaceSettings {
@@ -351,6 +361,10 @@ impl Application for InterfaceSettings {
             InterfaceThemeKind::Main => &self.themes.main,
         }
     }
+
+    fn encoded_length(&self, text: &str) -> usize {
+        self.encoding.encode(text).0.len()
+    }
 }
 
 impl Drop for InterfaceSettings {
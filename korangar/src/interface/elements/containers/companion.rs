@@ -0,0 +1,158 @@
+use korangar_interface::elements::{ContainerState, Element, ElementCell, ElementState, ElementWrap, Focus, Text, WeakElementCell};
+use korangar_interface::event::{ChangeEvent, HoverInformation};
+use korangar_interface::layout::PlacementResolver;
+use korangar_interface::state::{PlainRemote, Remote};
+use korangar_interface::{dimension_bound, size_bound};
+
+use crate::graphics::Color;
+use crate::input::MouseInputMode;
+use crate::interface::application::InterfaceSettings;
+use crate::interface::layout::{ScreenClip, ScreenPosition, ScreenSize};
+use crate::interface::theme::InterfaceTheme;
+use crate::inventory::Companion;
+use crate::renderer::InterfaceRenderer;
+
+/// Displays the properties of one of the player's companions as a list of
+/// labeled values.
+pub struct CompanionStatus<T: 'static> {
+    companion: PlainRemote<Companion<T>>,
+    get_values: fn(&T) -> Vec<(&'static str, String)>,
+    missing_text: &'static str,
+    weak_self: Option<WeakElementCell<InterfaceSettings>>,
+    state: ContainerState<InterfaceSettings>,
+}
+
+impl<T: 'static> CompanionStatus<T> {
+    pub fn new(
+        companion: PlainRemote<Companion<T>>,
+        get_values: fn(&T) -> Vec<(&'static str, String)>,
+        missing_text: &'static str,
+    ) -> Self {
+        let elements = match companion.get().information.as_ref() {
+            Some(information) => get_values(information)
+                .into_iter()
+                .flat_map(|(label, value)| {
+                    [
+                        Text::default()
+                            .with_text(label.to_owned())
+                            .with_foreground_color(|_| Color::monochrome_u8(200))
+                            .with_width(dimension_bound!(50%))
+                            .wrap(),
+                        Text::default().with_text(value).with_width(dimension_bound!(!)).wrap(),
+                    ]
+                })
+                .collect(),
+            None => vec![
+                Text::default()
+                    .with_text(missing_text.to_owned())
+                    .with_foreground_color(|_| Color::monochrome_u8(150))
+                    .wrap(),
+            ],
+        };
+
+        let weak_self = None;
+        let state = ContainerState::new(elements);
+
+        Self {
+            companion,
+            get_values,
+            missing_text,
+            weak_self,
+            state,
+        }
+    }
+}
+
+impl<T: 'static> Element<InterfaceSettings> for CompanionStatus<T> {
+    fn get_state(&self) -> &ElementState<InterfaceSettings> {
+        &self.state.state
+    }
+
+    fn get_state_mut(&mut self) -> &mut ElementState<InterfaceSettings> {
+        &mut self.state.state
+    }
+
+    fn link_back(&mut self, weak_self: WeakElementCell<InterfaceSettings>, weak_parent: Option<WeakElementCell<InterfaceSettings>>) {
+        self.weak_self = Some(weak_self.clone());
+        self.state.link_back(weak_self, weak_parent);
+    }
+
+    fn is_focusable(&self) -> bool {
+        self.state.is_focusable::<false>()
+    }
+
+    fn focus_next(
+        &self,
+        self_cell: ElementCell<InterfaceSettings>,
+        caller_cell: Option<ElementCell<InterfaceSettings>>,
+        focus: Focus,
+    ) -> Option<ElementCell<InterfaceSettings>> {
+        self.state.focus_next::<false>(self_cell, caller_cell, focus)
+    }
+
+    fn restore_focus(&self, self_cell: ElementCell<InterfaceSettings>) -> Option<ElementCell<InterfaceSettings>> {
+        self.state.restore_focus(self_cell)
+    }
+
+    fn resolve(
+        &mut self,
+        placement_resolver: &mut PlacementResolver<InterfaceSettings>,
+        application: &InterfaceSettings,
+        theme: &InterfaceTheme,
+    ) {
+        let size_bound = &size_bound!(100%, ?);
+        self.state
+            .resolve(placement_resolver, application, theme, size_bound, ScreenSize::uniform(3.0));
+    }
+
+    fn update(&mut self) -> Option<ChangeEvent> {
+        if self.companion.consume_changed() {
+            let weak_parent = self.state.state.parent_element.take();
+            let weak_self = self.weak_self.take().unwrap();
+
+            *self = Self::new(self.companion.clone(), self.get_values, self.missing_text);
+            // important: link back after creating elements, otherwise focus navigation and
+            // scrolling would break
+            self.link_back(weak_self, weak_parent);
+
+            return Some(ChangeEvent::RESOLVE_WINDOW);
+        }
+
+        None
+    }
+
+    fn hovered_element(&self, mouse_position: ScreenPosition, mouse_mode: &MouseInputMode) -> HoverInformation<InterfaceSettings> {
+        match mouse_mode {
+            MouseInputMode::None => self.state.hovered_element(mouse_position, mouse_mode, false),
+            _ => HoverInformation::Missed,
+        }
+    }
+
+    fn render(
+        &self,
+        renderer: &InterfaceRenderer,
+        application: &InterfaceSettings,
+        theme: &InterfaceTheme,
+        parent_position: ScreenPosition,
+        screen_clip: ScreenClip,
+        hovered_element: Option<&dyn Element<InterfaceSettings>>,
+        focused_element: Option<&dyn Element<InterfaceSettings>>,
+        mouse_mode: &MouseInputMode,
+        second_theme: bool,
+    ) {
+        let mut renderer = self
+            .state
+            .state
+            .element_renderer(renderer, application, parent_position, screen_clip);
+
+        self.state.render(
+            &mut renderer,
+            application,
+            theme,
+            hovered_element,
+            focused_element,
+            mouse_mode,
+            second_theme,
+        );
+    }
+}
@@ -1,51 +1,66 @@
-use korangar_interface::elements::{ContainerState, Element, ElementCell, ElementState, ElementWrap, Focus, WeakElementCell};
+use korangar_interface::elements::{
+    ButtonBuilder, ContainerState, Element, ElementCell, ElementState, ElementWrap, Focus, Text, WeakElementCell,
+};
 use korangar_interface::event::{ChangeEvent, HoverInformation};
 use korangar_interface::layout::PlacementResolver;
-use korangar_interface::size_bound;
 use korangar_interface::state::{PlainRemote, Remote};
+use korangar_interface::{dimension_bound, size_bound};
 use ragnarok_packets::HotbarSlot;
 
-use crate::input::MouseInputMode;
+use crate::graphics::Color;
+use crate::input::{MouseInputMode, UserEvent};
 use crate::interface::application::InterfaceSettings;
-use crate::interface::elements::SkillBox;
+use crate::interface::elements::HotkeyBox;
 use crate::interface::layout::{ScreenClip, ScreenPosition, ScreenSize};
-use crate::interface::resource::SkillSource;
 use crate::interface::theme::InterfaceTheme;
-use crate::inventory::Skill;
+use crate::inventory::{HOTBAR_ROW_COUNT, HotbarRows};
 use crate::renderer::InterfaceRenderer;
 
 pub struct HotbarContainer {
-    skills: PlainRemote<[Option<Skill>; 10]>,
+    rows: PlainRemote<HotbarRows>,
+    current_row: PlainRemote<usize>,
     state: ContainerState<InterfaceSettings>,
 }
 
 impl HotbarContainer {
-    pub fn new(skills: PlainRemote<[Option<Skill>; 10]>) -> Self {
+    pub fn new(rows: PlainRemote<HotbarRows>, current_row: PlainRemote<usize>) -> Self {
         let elements = {
-            let skills = skills.get();
+            let rows = rows.get();
+            let current_row = *current_row.get();
 
-            skills
+            let mut elements: Vec<ElementCell<InterfaceSettings>> = rows[current_row]
                 .iter()
                 .cloned()
                 .enumerate()
-                .map(|(slot, skill)| {
-                    let skill_source = SkillSource::Hotbar {
-                        slot: HotbarSlot(slot as u16),
-                    };
-                    let skill_box = SkillBox::new(
-                        skill,
-                        skill_source,
-                        Box::new(move |mouse_mode| matches!(mouse_mode, MouseInputMode::MoveSkill(source, _) if *source != skill_source)),
-                    );
-
-                    skill_box.wrap()
-                })
-                .collect()
+                .map(|(slot, entry)| HotkeyBox::new(entry, HotbarSlot(slot as u16)).wrap())
+                .collect();
+
+            elements.extend([
+                ButtonBuilder::new()
+                    .with_text("<")
+                    .with_event(UserEvent::PreviousHotbarRow)
+                    .with_width_bound(dimension_bound!(25%))
+                    .build()
+                    .wrap(),
+                Text::default()
+                    .with_text(format!("Row {} / {HOTBAR_ROW_COUNT}", current_row + 1))
+                    .with_foreground_color(|_| Color::monochrome_u8(200))
+                    .with_width(dimension_bound!(50%))
+                    .wrap(),
+                ButtonBuilder::new()
+                    .with_text(">")
+                    .with_event(UserEvent::NextHotbarRow)
+                    .with_width_bound(dimension_bound!(!))
+                    .build()
+                    .wrap(),
+            ]);
+
+            elements
         };
 
         let state = ContainerState::new(elements);
 
-        Self { skills, state }
+        Self { rows, current_row, state }
     }
 }
 
@@ -91,11 +106,11 @@ impl Element<InterfaceSettings> for HotbarContainer {
     }
 
     fn update(&mut self) -> Option<ChangeEvent> {
-        if self.skills.consume_changed() {
+        if self.rows.consume_changed() | self.current_row.consume_changed() {
             let weak_self = self.state.state.self_element.take().unwrap();
             let weak_parent = self.state.state.parent_element.take();
 
-            *self = Self::new(self.skills.clone());
+            *self = Self::new(self.rows.clone(), self.current_row.clone());
             // important: link back after creating elements, otherwise focus navigation and
             // scrolling would break
             self.link_back(weak_self, weak_parent);
@@ -108,7 +123,9 @@ impl Element<InterfaceSettings> for HotbarContainer {
 
     fn hovered_element(&self, mouse_position: ScreenPosition, mouse_mode: &MouseInputMode) -> HoverInformation<InterfaceSettings> {
         match mouse_mode {
-            MouseInputMode::MoveSkill(..) | MouseInputMode::None => self.state.hovered_element(mouse_position, mouse_mode, false),
+            MouseInputMode::MoveSkill(..) | MouseInputMode::MoveItem(..) | MouseInputMode::None => {
+                self.state.hovered_element(mouse_position, mouse_mode, false)
+            }
             _ => HoverInformation::Missed,
         }
     }
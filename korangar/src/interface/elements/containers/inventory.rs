@@ -1,4 +1,4 @@
-use korangar_interface::elements::{ContainerState, Element, ElementCell, ElementState, ElementWrap, Focus, WeakElementCell};
+use korangar_interface::elements::{ContainerState, Element, ElementCell, ElementState, ElementWrap, Focus, Grid, WeakElementCell};
 use korangar_interface::event::{ChangeEvent, HoverInformation};
 use korangar_interface::layout::PlacementResolver;
 use korangar_interface::size_bound;
@@ -15,26 +15,39 @@ use crate::interface::theme::InterfaceTheme;
 use crate::renderer::InterfaceRenderer;
 use crate::world::ResourceMetadata;
 
+/// Maximum number of slots in a single row.
+const SLOTS_PER_ROW: usize = 8;
+
 pub struct InventoryContainer {
     items: PlainRemote<Vec<InventoryItem<ResourceMetadata>>>,
+    source: ItemSource,
+    slot_count: usize,
     state: ContainerState<InterfaceSettings>,
 }
 
 impl InventoryContainer {
-    pub fn new(items: PlainRemote<Vec<InventoryItem<ResourceMetadata>>>) -> Self {
-        let elements = {
+    /// Displays the given items in a grid of `slot_count` slots. Items dropped
+    /// on the container are moved to the `source`.
+    pub fn new(items: PlainRemote<Vec<InventoryItem<ResourceMetadata>>>, source: ItemSource, slot_count: usize) -> Self {
+        let slots = {
             let items = items.get();
 
-            (0..40)
+            (0..slot_count)
                 .map(|index| items.get(index).cloned())
-                .map(|item| ItemBox::new(item, ItemSource::Inventory, Box::new(|_| false)))
+                .map(|item| ItemBox::new(item, source, Box::new(|_| false)))
                 .map(ElementWrap::wrap)
                 .collect()
         };
 
-        let state = ContainerState::new(elements);
+        let columns = slot_count.clamp(1, SLOTS_PER_ROW);
+        let state = ContainerState::new(vec![Grid::new(slots, columns, 30.0).wrap()]);
 
-        Self { items, state }
+        Self {
+            items,
+            source,
+            slot_count,
+            state,
+        }
     }
 }
 
@@ -84,7 +97,7 @@ impl Element<InterfaceSettings> for InventoryContainer {
             let weak_parent = self.state.state.parent_element.take();
             let weak_self = self.state.state.self_element.take().unwrap();
 
-            *self = Self::new(self.items.clone());
+            *self = Self::new(self.items.clone(), self.source, self.slot_count);
             // important: link back after creating elements, otherwise focus navigation and
             // scrolling would break
             self.link_back(weak_self, weak_parent);
@@ -108,9 +121,9 @@ impl Element<InterfaceSettings> for InventoryContainer {
             return None;
         };
 
-        (source != ItemSource::Inventory).then_some(Move::Item {
+        (source != self.source).then_some(Move::Item {
             source,
-            destination: ItemSource::Inventory,
+            destination: self.source,
             item,
         })
     }
@@ -0,0 +1,178 @@
+use korangar_interface::elements::{
+    ButtonBuilder, ContainerState, Element, ElementCell, ElementState, ElementWrap, Focus, Text, WeakElementCell,
+};
+use korangar_interface::event::{ChangeEvent, HoverInformation};
+use korangar_interface::layout::PlacementResolver;
+use korangar_interface::state::{PlainRemote, Remote};
+use korangar_interface::{dimension_bound, size_bound};
+
+use crate::graphics::Color;
+use crate::input::{MouseInputMode, UserEvent};
+use crate::interface::application::InterfaceSettings;
+use crate::interface::layout::{ScreenClip, ScreenPosition, ScreenSize};
+use crate::interface::theme::InterfaceTheme;
+use crate::renderer::InterfaceRenderer;
+use crate::settings::{InputAction, KeyBindings};
+
+/// Lists the key bindings of all actions. Actions that share a key chord with
+/// another action are highlighted.
+pub struct KeyBindingsView {
+    key_bindings: PlainRemote<KeyBindings>,
+    capture: PlainRemote<Option<InputAction>>,
+    weak_self: Option<WeakElementCell<InterfaceSettings>>,
+    state: ContainerState<InterfaceSettings>,
+}
+
+impl KeyBindingsView {
+    pub fn new(key_bindings: PlainRemote<KeyBindings>, capture: PlainRemote<Option<InputAction>>) -> Self {
+        let elements = {
+            let key_bindings = key_bindings.get();
+            let capture = *capture.get();
+
+            InputAction::all()
+                .into_iter()
+                .flat_map(|action| {
+                    let label_color = match key_bindings.has_conflict(action) {
+                        true => Color::rgb_u8(220, 100, 100),
+                        false => Color::monochrome_u8(200),
+                    };
+                    let chords = match capture == Some(action) {
+                        true => "Press a key...".to_owned(),
+                        false => key_bindings
+                            .get_chords(action)
+                            .iter()
+                            .map(ToString::to_string)
+                            .collect::<Vec<_>>()
+                            .join(", "),
+                    };
+
+                    [
+                        Text::default()
+                            .with_text(action.to_string())
+                            .with_foreground_color(move |_| label_color)
+                            .with_width(dimension_bound!(35%))
+                            .wrap(),
+                        Text::default().with_text(chords).with_width(dimension_bound!(35%)).wrap(),
+                        ButtonBuilder::new()
+                            .with_text("Add")
+                            .with_event(UserEvent::CaptureKeyBinding(action))
+                            .with_width_bound(dimension_bound!(15%))
+                            .build()
+                            .wrap(),
+                        ButtonBuilder::new()
+                            .with_text("Clear")
+                            .with_event(UserEvent::ClearKeyBinding(action))
+                            .with_disabled_selector(move || capture.is_some())
+                            .with_width_bound(dimension_bound!(!))
+                            .build()
+                            .wrap(),
+                    ]
+                })
+                .collect()
+        };
+
+        let weak_self = None;
+        let state = ContainerState::new(elements);
+
+        Self {
+            key_bindings,
+            capture,
+            weak_self,
+            state,
+        }
+    }
+}
+
+impl Element<InterfaceSettings> for KeyBindingsView {
+    fn get_state(&self) -> &ElementState<InterfaceSettings> {
+        &self.state.state
+    }
+
+    fn get_state_mut(&mut self) -> &mut ElementState<InterfaceSettings> {
+        &mut self.state.state
+    }
+
+    fn link_back(&mut self, weak_self: WeakElementCell<InterfaceSettings>, weak_parent: Option<WeakElementCell<InterfaceSettings>>) {
+        self.weak_self = Some(weak_self.clone());
+        self.state.link_back(weak_self, weak_parent);
+    }
+
+    fn is_focusable(&self) -> bool {
+        self.state.is_focusable::<false>()
+    }
+
+    fn focus_next(
+        &self,
+        self_cell: ElementCell<InterfaceSettings>,
+        caller_cell: Option<ElementCell<InterfaceSettings>>,
+        focus: Focus,
+    ) -> Option<ElementCell<InterfaceSettings>> {
+        self.state.focus_next::<false>(self_cell, caller_cell, focus)
+    }
+
+    fn restore_focus(&self, self_cell: ElementCell<InterfaceSettings>) -> Option<ElementCell<InterfaceSettings>> {
+        self.state.restore_focus(self_cell)
+    }
+
+    fn resolve(
+        &mut self,
+        placement_resolver: &mut PlacementResolver<InterfaceSettings>,
+        application: &InterfaceSettings,
+        theme: &InterfaceTheme,
+    ) {
+        let size_bound = &size_bound!(100%, ?);
+        self.state
+            .resolve(placement_resolver, application, theme, size_bound, ScreenSize::uniform(3.0));
+    }
+
+    fn update(&mut self) -> Option<ChangeEvent> {
+        if self.key_bindings.consume_changed() | self.capture.consume_changed() {
+            let weak_parent = self.state.state.parent_element.take();
+            let weak_self = self.weak_self.take().unwrap();
+
+            *self = Self::new(self.key_bindings.clone(), self.capture.clone());
+            // important: link back after creating elements, otherwise focus navigation and
+            // scrolling would break
+            self.link_back(weak_self, weak_parent);
+
+            return Some(ChangeEvent::RESOLVE_WINDOW);
+        }
+
+        None
+    }
+
+    fn hovered_element(&self, mouse_position: ScreenPosition, mouse_mode: &MouseInputMode) -> HoverInformation<InterfaceSettings> {
+        match mouse_mode {
+            MouseInputMode::None => self.state.hovered_element(mouse_position, mouse_mode, false),
+            _ => HoverInformation::Missed,
+        }
+    }
+
+    fn render(
+        &self,
+        renderer: &InterfaceRenderer,
+        application: &InterfaceSettings,
+        theme: &InterfaceTheme,
+        parent_position: ScreenPosition,
+        screen_clip: ScreenClip,
+        hovered_element: Option<&dyn Element<InterfaceSettings>>,
+        focused_element: Option<&dyn Element<InterfaceSettings>>,
+        mouse_mode: &MouseInputMode,
+        second_theme: bool,
+    ) {
+        let mut renderer = self
+            .state
+            .state
+            .element_renderer(renderer, application, parent_position, screen_clip);
+
+        self.state.render(
+            &mut renderer,
+            application,
+            theme,
+            hovered_element,
+            focused_element,
+            mouse_mode,
+            second_theme,
+        );
+    }
+}
@@ -0,0 +1,375 @@
+use korangar_interface::elements::{
+    ButtonBuilder, ContainerState, Element, ElementCell, ElementState, ElementWrap, Expandable, Focus, Text, WeakElementCell,
+};
+use korangar_interface::event::{ChangeEvent, HoverInformation};
+use korangar_interface::layout::PlacementResolver;
+use korangar_interface::state::{PlainRemote, Remote};
+use korangar_interface::{dimension_bound, size_bound};
+use ragnarok_packets::{MailFlags, MailInformation};
+
+use crate::graphics::Color;
+use crate::input::{MouseInputMode, UserEvent};
+use crate::interface::application::InterfaceSettings;
+use crate::interface::layout::{ScreenClip, ScreenPosition, ScreenSize};
+use crate::interface::theme::InterfaceTheme;
+use crate::inventory::OpenedMail;
+use crate::renderer::InterfaceRenderer;
+
+/// Lists all mails in the mailbox.
+pub struct MailView {
+    mails: PlainRemote<Vec<MailInformation>>,
+    weak_self: Option<WeakElementCell<InterfaceSettings>>,
+    state: ContainerState<InterfaceSettings>,
+}
+
+impl MailView {
+    pub fn new(mails: PlainRemote<Vec<MailInformation>>) -> Self {
+        let elements = {
+            let mails = mails.get();
+
+            match mails.is_empty() {
+                true => vec![Text::default().with_text("No mails".to_owned()).wrap()],
+                false => mails.iter().map(Self::mail_to_element).collect(),
+            }
+        };
+
+        let weak_self = None;
+        let state = ContainerState::new(elements);
+
+        Self { mails, weak_self, state }
+    }
+
+    fn mail_to_element(mail: &MailInformation) -> ElementCell<InterfaceSettings> {
+        let mut attachments = Vec::new();
+
+        if mail.flags.contains(MailFlags::ZENY) {
+            attachments.push("zeny");
+        }
+
+        if mail.flags.contains(MailFlags::ITEM) {
+            attachments.push("items");
+        }
+
+        let mut elements = vec![Text::default().with_text(format!("From: {}", mail.sender)).wrap()];
+
+        if !attachments.is_empty() {
+            elements.push(
+                Text::default()
+                    .with_text(format!("Attached: {}", attachments.join(", ")))
+                    .with_foreground_color(|_| Color::monochrome_u8(200))
+                    .wrap(),
+            );
+        }
+
+        elements.push(
+            Text::default()
+                .with_text(format!("Expires in {} days", mail.expiration_time / 86400))
+                .with_foreground_color(|_| Color::monochrome_u8(200))
+                .wrap(),
+        );
+        elements.push(
+            ButtonBuilder::new()
+                .with_text("Read")
+                .with_event(UserEvent::ReadMail {
+                    mail_type: mail.mail_type,
+                    mail_id: mail.mail_id,
+                })
+                .with_width_bound(dimension_bound!(50%))
+                .build()
+                .wrap(),
+        );
+        elements.push(
+            ButtonBuilder::new()
+                .with_text("Delete")
+                .with_event(UserEvent::DeleteMail {
+                    mail_type: mail.mail_type,
+                    mail_id: mail.mail_id,
+                })
+                .with_width_bound(dimension_bound!(!))
+                .build()
+                .wrap(),
+        );
+
+        let display = match mail.is_read {
+            0 => format!("* {}", mail.title),
+            _ => mail.title.clone(),
+        };
+
+        Expandable::new(display, elements, false).wrap()
+    }
+}
+
+impl Element<InterfaceSettings> for MailView {
+    fn get_state(&self) -> &ElementState<InterfaceSettings> {
+        &self.state.state
+    }
+
+    fn get_state_mut(&mut self) -> &mut ElementState<InterfaceSettings> {
+        &mut self.state.state
+    }
+
+    fn link_back(&mut self, weak_self: WeakElementCell<InterfaceSettings>, weak_parent: Option<WeakElementCell<InterfaceSettings>>) {
+        self.weak_self = Some(weak_self.clone());
+        self.state.link_back(weak_self, weak_parent);
+    }
+
+    fn is_focusable(&self) -> bool {
+        self.state.is_focusable::<false>()
+    }
+
+    fn focus_next(
+        &self,
+        self_cell: ElementCell<InterfaceSettings>,
+        caller_cell: Option<ElementCell<InterfaceSettings>>,
+        focus: Focus,
+    ) -> Option<ElementCell<InterfaceSettings>> {
+        self.state.focus_next::<false>(self_cell, caller_cell, focus)
+    }
+
+    fn restore_focus(&self, self_cell: ElementCell<InterfaceSettings>) -> Option<ElementCell<InterfaceSettings>> {
+        self.state.restore_focus(self_cell)
+    }
+
+    fn resolve(
+        &mut self,
+        placement_resolver: &mut PlacementResolver<InterfaceSettings>,
+        application: &InterfaceSettings,
+        theme: &InterfaceTheme,
+    ) {
+        self.state.resolve(
+            placement_resolver,
+            application,
+            theme,
+            &size_bound!(100%, ?),
+            ScreenSize::default(),
+        );
+    }
+
+    fn update(&mut self) -> Option<ChangeEvent> {
+        if self.mails.consume_changed() {
+            let weak_parent = self.state.state.parent_element.take();
+            let weak_self = self.weak_self.take().unwrap();
+
+            *self = Self::new(self.mails.clone());
+            // important: link back after creating elements, otherwise focus navigation and
+            // scrolling would break
+            self.link_back(weak_self, weak_parent);
+
+            return Some(ChangeEvent::RESOLVE_WINDOW);
+        }
+
+        None
+    }
+
+    fn hovered_element(&self, mouse_position: ScreenPosition, mouse_mode: &MouseInputMode) -> HoverInformation<InterfaceSettings> {
+        match mouse_mode {
+            MouseInputMode::None => self.state.hovered_element(mouse_position, mouse_mode, false),
+            _ => HoverInformation::Missed,
+        }
+    }
+
+    fn render(
+        &self,
+        renderer: &InterfaceRenderer,
+        application: &InterfaceSettings,
+        theme: &InterfaceTheme,
+        parent_position: ScreenPosition,
+        screen_clip: ScreenClip,
+        hovered_element: Option<&dyn Element<InterfaceSettings>>,
+        focused_element: Option<&dyn Element<InterfaceSettings>>,
+        mouse_mode: &MouseInputMode,
+        second_theme: bool,
+    ) {
+        let mut renderer = self
+            .state
+            .state
+            .element_renderer(renderer, application, parent_position, screen_clip);
+
+        self.state.render(
+            &mut renderer,
+            application,
+            theme,
+            hovered_element,
+            focused_element,
+            mouse_mode,
+            second_theme,
+        );
+    }
+}
+
+/// Displays the content and attachments of the mail that was opened last.
+pub struct OpenedMailView {
+    mail: PlainRemote<Option<OpenedMail>>,
+    weak_self: Option<WeakElementCell<InterfaceSettings>>,
+    state: ContainerState<InterfaceSettings>,
+}
+
+impl OpenedMailView {
+    pub fn new(mail: PlainRemote<Option<OpenedMail>>) -> Self {
+        let elements = match mail.get().as_ref() {
+            Some(mail) => Self::mail_to_elements(mail),
+            None => vec![Text::default().with_text("No mail selected".to_owned()).wrap()],
+        };
+
+        let weak_self = None;
+        let state = ContainerState::new(elements);
+
+        Self { mail, weak_self, state }
+    }
+
+    fn mail_to_elements(mail: &OpenedMail) -> Vec<ElementCell<InterfaceSettings>> {
+        let mut elements = vec![
+            Text::default().with_text(mail.title.clone()).wrap(),
+            Text::default()
+                .with_text(format!("From: {}", mail.sender))
+                .with_foreground_color(|_| Color::monochrome_u8(200))
+                .wrap(),
+            Text::default().with_text(mail.text.clone()).wrap(),
+        ];
+
+        if mail.zeny > 0 {
+            elements.push(
+                Text::default()
+                    .with_text(format!("{} zeny", mail.zeny))
+                    .with_width(dimension_bound!(50%))
+                    .wrap(),
+            );
+            elements.push(
+                ButtonBuilder::new()
+                    .with_text("Take zeny")
+                    .with_event(UserEvent::TakeMailZeny {
+                        mail_type: mail.mail_type,
+                        mail_id: mail.mail_id,
+                    })
+                    .with_width_bound(dimension_bound!(!))
+                    .build()
+                    .wrap(),
+            );
+        }
+
+        if !mail.items.is_empty() {
+            elements.extend(
+                mail.items
+                    .iter()
+                    .map(|item| Text::default().with_text(format!("{}x {}", item.amount, item.name)).wrap()),
+            );
+            elements.push(
+                ButtonBuilder::new()
+                    .with_text("Take items")
+                    .with_event(UserEvent::TakeMailItems {
+                        mail_type: mail.mail_type,
+                        mail_id: mail.mail_id,
+                    })
+                    .build()
+                    .wrap(),
+            );
+        }
+
+        elements.push(
+            ButtonBuilder::new()
+                .with_text("Delete")
+                .with_event(UserEvent::DeleteMail {
+                    mail_type: mail.mail_type,
+                    mail_id: mail.mail_id,
+                })
+                .build()
+                .wrap(),
+        );
+
+        elements
+    }
+}
+
+impl Element<InterfaceSettings> for OpenedMailView {
+    fn get_state(&self) -> &ElementState<InterfaceSettings> {
+        &self.state.state
+    }
+
+    fn get_state_mut(&mut self) -> &mut ElementState<InterfaceSettings> {
+        &mut self.state.state
+    }
+
+    fn link_back(&mut self, weak_self: WeakElementCell<InterfaceSettings>, weak_parent: Option<WeakElementCell<InterfaceSettings>>) {
+        self.weak_self = Some(weak_self.clone());
+        self.state.link_back(weak_self, weak_parent);
+    }
+
+    fn is_focusable(&self) -> bool {
+        self.state.is_focusable::<false>()
+    }
+
+    fn focus_next(
+        &self,
+        self_cell: ElementCell<InterfaceSettings>,
+        caller_cell: Option<ElementCell<InterfaceSettings>>,
+        focus: Focus,
+    ) -> Option<ElementCell<InterfaceSettings>> {
+        self.state.focus_next::<false>(self_cell, caller_cell, focus)
+    }
+
+    fn restore_focus(&self, self_cell: ElementCell<InterfaceSettings>) -> Option<ElementCell<InterfaceSettings>> {
+        self.state.restore_focus(self_cell)
+    }
+
+    fn resolve(
+        &mut self,
+        placement_resolver: &mut PlacementResolver<InterfaceSettings>,
+        application: &InterfaceSettings,
+        theme: &InterfaceTheme,
+    ) {
+        let size_bound = &size_bound!(100%, ?);
+        self.state
+            .resolve(placement_resolver, application, theme, size_bound, ScreenSize::uniform(3.0));
+    }
+
+    fn update(&mut self) -> Option<ChangeEvent> {
+        if self.mail.consume_changed() {
+            let weak_parent = self.state.state.parent_element.take();
+            let weak_self = self.weak_self.take().unwrap();
+
+            *self = Self::new(self.mail.clone());
+            // important: link back after creating elements, otherwise focus navigation and
+            // scrolling would break
+            self.link_back(weak_self, weak_parent);
+
+            return Some(ChangeEvent::RESOLVE_WINDOW);
+        }
+
+        None
+    }
+
+    fn hovered_element(&self, mouse_position: ScreenPosition, mouse_mode: &MouseInputMode) -> HoverInformation<InterfaceSettings> {
+        match mouse_mode {
+            MouseInputMode::None => self.state.hovered_element(mouse_position, mouse_mode, false),
+            _ => HoverInformation::Missed,
+        }
+    }
+
+    fn render(
+        &self,
+        renderer: &InterfaceRenderer,
+        application: &InterfaceSettings,
+        theme: &InterfaceTheme,
+        parent_position: ScreenPosition,
+        screen_clip: ScreenClip,
+        hovered_element: Option<&dyn Element<InterfaceSettings>>,
+        focused_element: Option<&dyn Element<InterfaceSettings>>,
+        mouse_mode: &MouseInputMode,
+        second_theme: bool,
+    ) {
+        let mut renderer = self
+            .state
+            .state
+            .element_renderer(renderer, application, parent_position, screen_clip);
+
+        self.state.render(
+            &mut renderer,
+            application,
+            theme,
+            hovered_element,
+            focused_element,
+            mouse_mode,
+            second_theme,
+        );
+    }
+}
@@ -1,19 +1,33 @@
+mod cart;
 mod character;
+mod companion;
 mod dialog;
 mod equipment;
 mod friends;
 mod hotbar;
 mod inventory;
+mod keybindings;
+mod mail;
 #[cfg(feature = "debug")]
 mod packet;
+#[cfg(feature = "debug")]
+mod scene;
 mod skill_tree;
+mod status;
 
+pub use self::cart::CartStatus;
 pub use self::character::CharacterPreview;
+pub use self::companion::CompanionStatus;
 pub use self::dialog::{DialogContainer, DialogElement};
 pub use self::equipment::EquipmentContainer;
 pub use self::friends::FriendView;
 pub use self::hotbar::HotbarContainer;
 pub use self::inventory::InventoryContainer;
+pub use self::keybindings::KeyBindingsView;
+pub use self::mail::{MailView, OpenedMailView};
+#[cfg(feature = "debug")]
+pub use self::packet::{DecodeFunction, PacketFilter, PacketHistoryCallback, PacketHistoryRemote, PacketQuery, PacketView};
 #[cfg(feature = "debug")]
-pub use self::packet::{PacketHistoryCallback, PacketHistoryRemote, PacketView};
+pub use self::scene::SceneView;
 pub use self::skill_tree::SkillTreeContainer;
+pub use self::status::StatusView;
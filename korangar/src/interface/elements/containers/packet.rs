@@ -1,26 +1,123 @@
 use std::cell::RefCell;
-use std::fmt::{Display, Formatter, Result};
+use std::fmt::{self, Display, Formatter};
+use std::fs::File;
+use std::io::{self, BufWriter, Write};
+use std::path::{Path, PathBuf};
 use std::rc::{Rc, Weak};
-use std::sync::{LazyLock, Mutex, MutexGuard};
+use std::sync::{LazyLock, Mutex, MutexGuard, TryLockError};
+use std::time::{SystemTime, UNIX_EPOCH};
 
 use korangar_debug::profiling::RingBuffer;
 use korangar_interface::application::Application;
-use korangar_interface::elements::{ContainerState, Element, ElementCell, ElementState, ElementWrap, Expandable, Focus, PrototypeElement};
-use korangar_interface::event::{ChangeEvent, HoverInformation};
+use korangar_interface::elements::{
+    ButtonBuilder, ContainerState, Element, ElementCell, ElementState, ElementWrap, Expandable, Focus, PrototypeElement, Text,
+};
+use korangar_interface::event::{ChangeEvent, ClickAction, HoverInformation};
 use korangar_interface::layout::PlacementResolver;
 use korangar_interface::size_bound;
 use korangar_interface::state::{PlainRemote, Remote, RemoteClone};
-use ragnarok_bytes::{ByteReader, ByteWriter, ConversionError, ConversionResult, FromBytes};
-use ragnarok_packets::handler::PacketCallback;
-use ragnarok_packets::{Packet, PacketHeader};
+use ragnarok_bytes::encoding::UTF_8;
+use ragnarok_bytes::{ByteReader, ByteWriter, ConversionError, ConversionResult, FromBytes, RecordedField};
+use ragnarok_packets::handler::{PacketCallback, ServerType};
+use ragnarok_packets::{Packet, PacketExt, PacketHeader};
 
 use crate::input::MouseInputMode;
 use crate::interface::application::InterfaceSettings;
 use crate::interface::layout::{ScreenClip, ScreenPosition, ScreenSize};
 use crate::interface::linked::LinkedElement;
 use crate::interface::theme::InterfaceTheme;
+use crate::interface::windows::PacketReplayWindow;
 use crate::renderer::InterfaceRenderer;
 
+const EXPORT_DIRECTORY: &str = "client/packets";
+const HEX_LINE_LENGTH: usize = 16;
+
+/// Format bytes like a hex editor, with the offset, the hex values and the
+/// printable characters of every line.
+fn hex_lines(bytes: &[u8]) -> Vec<String> {
+    bytes
+        .chunks(HEX_LINE_LENGTH)
+        .enumerate()
+        .map(|(index, chunk)| {
+            let hex: Vec<String> = chunk.iter().map(|byte| format!("{byte:02x}")).collect();
+            // `^` is not printed since it starts a color code in the interface.
+            let characters: String = chunk
+                .iter()
+                .map(|&byte| match (byte.is_ascii_graphic() || byte == b' ') && byte != b'^' {
+                    true => byte as char,
+                    false => '.',
+                })
+                .collect();
+
+            format!(
+                "{:04x}  {:<width$}  {characters}",
+                index * HEX_LINE_LENGTH,
+                hex.join(" "),
+                width = HEX_LINE_LENGTH * 3 - 1
+            )
+        })
+        .collect()
+}
+
+fn hex_view<App: Application>(bytes: &[u8]) -> ElementCell<App> {
+    let lines = hex_lines(bytes)
+        .into_iter()
+        .map(|line| Text::default().with_text(line).wrap())
+        .collect();
+
+    Expandable::new("hex".to_owned(), lines, true).wrap()
+}
+
+/// Header of a packet that could not be decoded.
+fn raw_header(bytes: &[u8]) -> Option<PacketHeader> {
+    match bytes {
+        [low, high, ..] => Some(PacketHeader(u16::from_le_bytes([*low, *high]))),
+        _ => None,
+    }
+}
+
+/// A packet decoded from edited bytes.
+pub struct DecodedPacket {
+    /// Debug representation of the decoded packet.
+    pub details: String,
+    /// Fields of the packet and the bytes they were read from. The header is
+    /// not included.
+    pub fields: Vec<RecordedField>,
+}
+
+/// Decode bytes as a specific packet. Used to check edited packets before
+/// they are sent again.
+pub type DecodeFunction = fn(&[u8]) -> Result<DecodedPacket, String>;
+
+fn decode_packet<P: Packet>(bytes: &[u8]) -> Result<DecodedPacket, String> {
+    let mut byte_reader = ByteReader::without_metadata(bytes);
+    byte_reader.set_encoding(UTF_8);
+
+    let header = PacketHeader::from_bytes(&mut byte_reader).map_err(|error| format!("{error:?}"))?;
+
+    if header != P::HEADER {
+        return Err(format!("mismatched header 0x{:04X}", header.0));
+    }
+
+    byte_reader.record_fields();
+
+    let packet = P::payload_from_bytes(&mut byte_reader).map_err(|error| format!("{error:?}"))?;
+
+    match byte_reader.get_offset() == bytes.len() {
+        true => Ok(DecodedPacket {
+            details: format!("{packet:#?}"),
+            fields: byte_reader.take_recorded_fields(),
+        }),
+        false => Err(format!("{} trailing bytes", bytes.len() - byte_reader.get_offset())),
+    }
+}
+
+fn encode_packet<P: Packet>(packet: &P) -> Option<Vec<u8>> {
+    let mut byte_writer = ByteWriter::with_encoding(UTF_8);
+    packet.packet_to_bytes(&mut byte_writer).ok()?;
+    Some(byte_writer.into_inner())
+}
+
 #[derive(Debug, Clone)]
 struct UnknownPacket {
     pub bytes: Vec<u8>,
@@ -52,12 +149,11 @@ impl<App: Application> PrototypeElement<App> for UnknownPacket {
             true => {
                 let signature = PacketHeader::from_bytes(&mut byte_reader).unwrap();
                 let header = format!("0x{:0>4x}", signature.0);
-                let data = &self.bytes[byte_reader.get_offset()..];
 
-                vec![header.to_element("header".to_owned()), data.to_element("data".to_owned())]
+                vec![header.to_element("header".to_owned()), hex_view(&self.bytes)]
             }
             false => {
-                vec![self.bytes.to_element("data".to_owned())]
+                vec![hex_view(&self.bytes)]
             }
         };
 
@@ -98,16 +194,15 @@ impl<App: Application> PrototypeElement<App> for ErrorPacket {
             true => {
                 let signature = PacketHeader::from_bytes(&mut byte_reader).unwrap();
                 let header = format!("0x{:0>4x}", signature.0);
-                let data = &self.bytes[byte_reader.get_offset()..];
 
                 vec![
                     header.to_element("header".to_owned()),
                     error.to_element("error".to_owned()),
-                    data.to_element("data".to_owned()),
+                    hex_view(&self.bytes),
                 ]
             }
             false => {
-                vec![error.to_element("error".to_owned()), self.bytes.to_element("data".to_owned())]
+                vec![error.to_element("error".to_owned()), hex_view(&self.bytes)]
             }
         };
 
@@ -115,13 +210,23 @@ impl<App: Application> PrototypeElement<App> for ErrorPacket {
     }
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 enum Direction {
     Incoming,
     Outgoing,
 }
 
+impl Direction {
+    fn name(self) -> &'static str {
+        match self {
+            Direction::Incoming => "in",
+            Direction::Outgoing => "out",
+        }
+    }
+}
+
 impl Display for Direction {
-    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
         match self {
             Direction::Incoming => write!(f, "[^66FF44in^000000]"),
             Direction::Outgoing => write!(f, "[^FF7744out^000000]"),
@@ -132,26 +237,63 @@ impl Display for Direction {
 struct PacketEntry {
     element: Box<dyn PrototypeElement<InterfaceSettings> + Send>,
     name: &'static str,
+    /// Color code used to highlight packets that could not be decoded.
+    highlight: Option<&'static str>,
+    header: Option<PacketHeader>,
     is_ping: bool,
     direction: Direction,
+    server_type: Option<ServerType>,
+    /// Decoded fields of the packet, used for searching and exporting.
+    details: String,
+    /// Raw bytes of the packet. Only known for outgoing packets and packets
+    /// that could not be decoded.
+    bytes: Option<Vec<u8>>,
+    decode: Option<DecodeFunction>,
 }
 
 impl PacketEntry {
-    pub fn new_incoming(element: Box<dyn PrototypeElement<InterfaceSettings> + Send>, name: &'static str, is_ping: bool) -> Self {
+    fn decoded<P: Packet>(packet: &P, direction: Direction, server_type: Option<ServerType>) -> Self {
+        // Outgoing packets are always converted to bytes to send them, so this never
+        // hits an unimplemented conversion.
+        let (bytes, decode) = match direction {
+            Direction::Incoming => (None, None),
+            Direction::Outgoing => (encode_packet(packet), Some(decode_packet::<P> as DecodeFunction)),
+        };
+
         Self {
-            element,
-            name,
-            is_ping,
-            direction: Direction::Incoming,
+            element: packet.to_prototype_element(),
+            name: std::any::type_name::<P>(),
+            highlight: None,
+            header: Some(P::HEADER),
+            is_ping: P::IS_PING,
+            direction,
+            server_type,
+            details: format!("{packet:#?}"),
+            bytes,
+            decode,
         }
     }
 
-    pub fn new_outgoing(element: Box<dyn PrototypeElement<InterfaceSettings> + Send>, name: &'static str, is_ping: bool) -> Self {
+    fn raw(
+        element: Box<dyn PrototypeElement<InterfaceSettings> + Send>,
+        name: &'static str,
+        highlight: &'static str,
+        bytes: Vec<u8>,
+        direction: Direction,
+        server_type: Option<ServerType>,
+        details: String,
+    ) -> Self {
         Self {
             element,
             name,
-            is_ping,
-            direction: Direction::Outgoing,
+            highlight: Some(highlight),
+            header: raw_header(&bytes),
+            is_ping: false,
+            direction,
+            server_type,
+            details,
+            bytes: Some(bytes),
+            decode: None,
         }
     }
 
@@ -159,8 +301,129 @@ impl PacketEntry {
         self.is_ping
     }
 
+    fn display_name(&self) -> String {
+        let server = self
+            .server_type
+            .map(|server_type| format!("[{}] ", server_type.name()))
+            .unwrap_or_default();
+
+        match self.highlight {
+            Some(color) => format!("{} {server}^{color}{}^000000", self.direction, self.name),
+            None => format!("{} {server}{}", self.direction, self.name),
+        }
+    }
+
     fn to_element(&self) -> ElementCell<InterfaceSettings> {
-        self.element.to_element(format!("{} {}", self.direction, self.name))
+        let display = self.display_name();
+
+        match (self.direction, self.server_type, &self.bytes) {
+            (Direction::Outgoing, Some(server_type), Some(bytes)) => {
+                let replay_window = PacketReplayWindow::new(self.name, server_type, bytes.clone(), self.decode);
+
+                let elements = vec![
+                    ButtonBuilder::new()
+                        .with_text("Edit and resend")
+                        .with_event(Box::new(move || vec![ClickAction::OpenWindow(Box::new(replay_window.clone()))]))
+                        .build()
+                        .wrap(),
+                    self.element.to_element("fields".to_owned()),
+                ];
+
+                Expandable::new(display, elements, false).wrap()
+            }
+            _ => self.element.to_element(display),
+        }
+    }
+
+    fn write(&self, writer: &mut impl Write) -> io::Result<()> {
+        let server = self.server_type.map(ServerType::name).unwrap_or("unknown");
+        let header = self.header.map(|header| format!(" (0x{:0>4x})", header.0)).unwrap_or_default();
+
+        writeln!(writer, "[{}] [{server}] {}{header}", self.direction.name(), self.name)?;
+        writeln!(writer, "{}", self.details)?;
+
+        if let Some(bytes) = &self.bytes {
+            hex_lines(bytes).iter().try_for_each(|line| writeln!(writer, "{line}"))?;
+        }
+
+        writeln!(writer)
+    }
+}
+
+/// Settings of the packet view that can be toggled.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PacketFilter {
+    pub show_pings: bool,
+    pub show_incoming: bool,
+    pub show_outgoing: bool,
+    pub show_login_server: bool,
+    pub show_character_server: bool,
+    pub show_map_server: bool,
+}
+
+impl Default for PacketFilter {
+    fn default() -> Self {
+        Self {
+            show_pings: false,
+            show_incoming: true,
+            show_outgoing: true,
+            show_login_server: true,
+            show_character_server: true,
+            show_map_server: true,
+        }
+    }
+}
+
+/// Decides which packets are shown and exported.
+pub struct PacketQuery {
+    filter: PacketFilter,
+    packet_type: String,
+    search: String,
+}
+
+impl PacketQuery {
+    /// The packet type is either part of the packet name or a header like
+    /// `0x0089`. The search text is looked up in the decoded fields. Both are
+    /// case insensitive and ignored if empty.
+    pub fn new(filter: PacketFilter, packet_type: &str, search: &str) -> Self {
+        Self {
+            filter,
+            packet_type: packet_type.trim().to_lowercase(),
+            search: search.trim().to_lowercase(),
+        }
+    }
+
+    fn matches_packet_type(&self, entry: &PacketEntry) -> bool {
+        if self.packet_type.is_empty() {
+            return true;
+        }
+
+        match self.packet_type.strip_prefix("0x") {
+            Some(hex) => u16::from_str_radix(hex, 16).is_ok_and(|header| entry.header == Some(PacketHeader(header))),
+            None => entry.name.to_lowercase().contains(&self.packet_type),
+        }
+    }
+
+    fn matches(&self, entry: &PacketEntry) -> bool {
+        let filter = &self.filter;
+
+        let direction = match entry.direction {
+            Direction::Incoming => filter.show_incoming,
+            Direction::Outgoing => filter.show_outgoing,
+        };
+
+        let server = match entry.server_type {
+            Some(ServerType::Login) => filter.show_login_server,
+            Some(ServerType::Character) => filter.show_character_server,
+            Some(ServerType::Map) => filter.show_map_server,
+            None => true,
+        };
+
+        direction
+            && server
+            && (filter.show_pings || !entry.is_ping())
+            && self.matches_packet_type(entry)
+            && (self.search.is_empty() || entry.details.to_lowercase().contains(&self.search))
     }
 }
 
@@ -170,6 +433,7 @@ type PacketHistoryBufferPointer = &'static Mutex<(PacketHistoryBuffer, usize)>;
 #[derive(Clone)]
 pub struct PacketHistoryCallback {
     buffer_pointer: PacketHistoryBufferPointer,
+    server_type: Option<ServerType>,
 }
 
 static BUFFER_POINTER: LazyLock<PacketHistoryBufferPointer> = LazyLock::new(|| Box::leak(Box::new(Mutex::new((RingBuffer::default(), 0)))));
@@ -178,6 +442,7 @@ impl PacketHistoryCallback {
     pub fn get_static_instance() -> Self {
         Self {
             buffer_pointer: &BUFFER_POINTER,
+            server_type: None,
         }
     }
 
@@ -194,55 +459,97 @@ impl PacketHistoryCallback {
         lock.0.clear();
         lock.1 += 1;
     }
+
+    /// Write all packets that are shown by default. The history is not waited
+    /// for if it is locked, so this is safe to call from a panic hook.
+    pub fn write_history(&self, writer: &mut impl Write) -> io::Result<()> {
+        let lock = match self.buffer_pointer.try_lock() {
+            Ok(lock) => lock,
+            Err(TryLockError::Poisoned(error)) => error.into_inner(),
+            Err(TryLockError::WouldBlock) => return writeln!(writer, "packet history is locked"),
+        };
+        let query = PacketQuery::new(PacketFilter::default(), "", "");
+
+        lock.0
+            .iter()
+            .map(|(entry, _)| entry)
+            .filter(|entry| query.matches(entry))
+            .try_for_each(|entry| entry.write(writer))
+    }
+
+    fn push(&self, entry: PacketEntry) {
+        let mut lock = self.buffer_pointer.lock().unwrap();
+
+        lock.0.push((entry, LinkedElement::new()));
+        lock.1 += 1;
+    }
 }
 
 impl PacketCallback for PacketHistoryCallback {
+    fn for_server(&self, server_type: ServerType) -> Self {
+        Self {
+            buffer_pointer: self.buffer_pointer,
+            server_type: Some(server_type),
+        }
+    }
+
     fn incoming_packet<Packet>(&self, packet: &Packet)
     where
         Packet: ragnarok_packets::Packet,
     {
-        let mut lock = self.buffer_pointer.lock().unwrap();
-
-        let prototype_element = packet.to_prototype_element();
-        let entry = PacketEntry::new_incoming(prototype_element, std::any::type_name::<Packet>(), Packet::IS_PING);
-
-        lock.0.push((entry, LinkedElement::new()));
-        lock.1 += 1;
+        self.push(PacketEntry::decoded(packet, Direction::Incoming, self.server_type));
     }
 
     fn outgoing_packet<Packet>(&self, packet: &Packet)
     where
         Packet: ragnarok_packets::Packet,
     {
-        let mut lock = self.buffer_pointer.lock().unwrap();
+        self.push(PacketEntry::decoded(packet, Direction::Outgoing, self.server_type));
+    }
 
-        let prototype_element = packet.to_prototype_element();
-        let entry = PacketEntry::new_outgoing(prototype_element, std::any::type_name::<Packet>(), Packet::IS_PING);
+    fn outgoing_bytes(&self, bytes: &[u8]) {
+        let packet = UnknownPacket { bytes: bytes.to_vec() };
+        let details = format!("{packet:?}");
 
-        lock.0.push((entry, LinkedElement::new()));
-        lock.1 += 1;
+        self.push(PacketEntry::raw(
+            packet.to_prototype_element(),
+            "↻ Replayed ↻",
+            "44AAFF",
+            packet.bytes,
+            Direction::Outgoing,
+            self.server_type,
+            details,
+        ));
     }
 
     fn unknown_packet(&self, bytes: Vec<u8>) {
-        let mut lock = self.buffer_pointer.lock().unwrap();
-
         let packet = UnknownPacket { bytes };
-        let prototype_element = packet.to_prototype_element();
-        let entry = PacketEntry::new_incoming(prototype_element, "^FF8810� Unknown �^000000", false);
+        let details = format!("{packet:?}");
 
-        lock.0.push((entry, LinkedElement::new()));
-        lock.1 += 1;
+        self.push(PacketEntry::raw(
+            packet.to_prototype_element(),
+            "� Unknown �",
+            "FF8810",
+            packet.bytes,
+            Direction::Incoming,
+            self.server_type,
+            details,
+        ));
     }
 
     fn failed_packet(&self, bytes: Vec<u8>, error: Box<ConversionError>) {
-        let mut lock = self.buffer_pointer.lock().unwrap();
-
         let packet = ErrorPacket { bytes, error };
-        let prototype_element = packet.to_prototype_element();
-        let entry = PacketEntry::new_incoming(prototype_element, "^FF4444✖ Error ✖^000000", false);
-
-        lock.0.push((entry, LinkedElement::new()));
-        lock.1 += 1;
+        let details = format!("{:?}", packet.error);
+
+        self.push(PacketEntry::raw(
+            packet.to_prototype_element(),
+            "✖ Error ✖",
+            "FF4444",
+            packet.bytes,
+            Direction::Incoming,
+            self.server_type,
+            details,
+        ));
     }
 }
 
@@ -270,25 +577,56 @@ impl PacketHistoryRemote {
     pub fn is_empty(&self) -> bool {
         self.buffer_pointer.lock().unwrap().0.is_empty()
     }
+
+    /// Write all packets that match the query to a new file and return the
+    /// path of that file.
+    pub fn export(&self, query: &PacketQuery) -> io::Result<PathBuf> {
+        let timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|duration| duration.as_millis())
+            .unwrap_or_default();
+        let path = Path::new(EXPORT_DIRECTORY).join(format!("packets_{timestamp}.txt"));
+
+        std::fs::create_dir_all(EXPORT_DIRECTORY)?;
+
+        let mut writer = BufWriter::new(File::create(&path)?);
+        let lock = self.get();
+
+        lock.0
+            .iter()
+            .map(|(entry, _)| entry)
+            .filter(|entry| query.matches(entry))
+            .try_for_each(|entry| entry.write(&mut writer))?;
+
+        writer.flush()?;
+        Ok(path)
+    }
 }
 
 pub struct PacketView {
     packets: PacketHistoryRemote,
-    show_pings: PlainRemote<bool>,
+    filter: PlainRemote<PacketFilter>,
+    packet_type: PlainRemote<String>,
+    search: PlainRemote<String>,
     state: ContainerState<InterfaceSettings>,
 }
 
 impl PacketView {
-    pub fn new(packets: PacketHistoryRemote, show_pings: PlainRemote<bool>) -> Self {
+    pub fn new(
+        packets: PacketHistoryRemote,
+        filter: PlainRemote<PacketFilter>,
+        packet_type: PlainRemote<String>,
+        search: PlainRemote<String>,
+    ) -> Self {
         let elements = {
             let packets = packets.get();
-            let show_pings = show_pings.cloned();
+            let query = PacketQuery::new(filter.cloned(), &packet_type.get(), &search.get());
 
             packets
                 .0
                 .iter()
                 .filter_map(|(packet, linked_element)| {
-                    let show_packet = show_pings || !packet.is_ping();
+                    let show_packet = query.matches(packet);
 
                     match show_packet {
                         true => {
@@ -307,7 +645,9 @@ impl PacketView {
 
         Self {
             packets,
-            show_pings,
+            filter,
+            packet_type,
+            search,
             state: ContainerState::new(elements),
         }
     }
@@ -365,7 +705,11 @@ impl Element<InterfaceSettings> for PacketView {
     fn update(&mut self) -> Option<ChangeEvent> {
         let mut resolve = false;
 
-        if self.show_pings.consume_changed() | self.packets.consume_changed() {
+        if self.filter.consume_changed()
+            | self.packet_type.consume_changed()
+            | self.search.consume_changed()
+            | self.packets.consume_changed()
+        {
             // Remove elements of packets that are no longer in the list.
             if let Some(first_visible_packet) = self.packets.get().0.iter().find(|(_, linked_element)| !linked_element.is_hidden()) {
                 for _index in 0..self.state.elements.len() {
@@ -382,14 +726,14 @@ impl Element<InterfaceSettings> for PacketView {
                 resolve = true;
             }
 
-            let show_pings = self.show_pings.cloned();
+            let query = PacketQuery::new(self.filter.cloned(), &self.packet_type.get(), &self.search.get());
             let mut index = 0;
 
             // Add or remove elements that need to be shown/hidden based on filtering. Also
             // append new elements for packets that are new.
             self.packets.get().0.iter().for_each(|(packet, linked_element)| {
                 // Getting here means that the packet was already processed once.
-                let show_packet = show_pings || !packet.is_ping();
+                let show_packet = query.matches(packet);
 
                 if linked_element.is_linked() {
                     let was_hidden = linked_element.is_hidden();
@@ -479,3 +823,129 @@ impl Element<InterfaceSettings> for PacketView {
         );
     }
 }
+
+#[cfg(test)]
+mod inspector {
+    use ragnarok_packets::handler::ServerType;
+    use ragnarok_packets::{EntityId, Packet, PacketHeader, RequestDetailsPacket};
+
+    use super::{Direction, PacketEntry, PacketFilter, PacketQuery, UnknownPacket, hex_lines};
+
+    fn outgoing_entry() -> PacketEntry {
+        PacketEntry::decoded(
+            &RequestDetailsPacket { entity_id: EntityId(1234) },
+            Direction::Outgoing,
+            Some(ServerType::Map),
+        )
+    }
+
+    fn unknown_entry() -> PacketEntry {
+        let packet = UnknownPacket {
+            bytes: vec![0x34, 0x12, b'a', b'^'],
+        };
+
+        PacketEntry::raw(
+            packet.to_prototype_element(),
+            "Unknown",
+            "FF8810",
+            packet.bytes,
+            Direction::Incoming,
+            Some(ServerType::Character),
+            String::from("unknown"),
+        )
+    }
+
+    #[test]
+    fn format_hex_lines() {
+        let bytes: Vec<u8> = (0..20).map(|index| b'A' + index).collect();
+        let lines = hex_lines(&bytes);
+
+        assert_eq!(lines.len(), 2);
+        assert_eq!(
+            lines[0],
+            "0000  41 42 43 44 45 46 47 48 49 4a 4b 4c 4d 4e 4f 50  ABCDEFGHIJKLMNOP"
+        );
+        assert_eq!(lines[1], format!("0010  51 52 53 54{}  QRST", " ".repeat(36)));
+        assert_eq!(hex_lines(&[0x00, b'^']), [format!("0000  00 5e{}  ..", " ".repeat(42))]);
+    }
+
+    #[test]
+    fn filter_packets() {
+        let outgoing = outgoing_entry();
+        let unknown = unknown_entry();
+        let all = PacketQuery::new(PacketFilter::default(), "", "");
+
+        assert!(all.matches(&outgoing) && all.matches(&unknown));
+
+        let incoming_only = PacketQuery::new(
+            PacketFilter {
+                show_outgoing: false,
+                ..Default::default()
+            },
+            "",
+            "",
+        );
+        assert!(!incoming_only.matches(&outgoing) && incoming_only.matches(&unknown));
+
+        let no_character_server = PacketQuery::new(
+            PacketFilter {
+                show_character_server: false,
+                ..Default::default()
+            },
+            "",
+            "",
+        );
+        assert!(no_character_server.matches(&outgoing) && !no_character_server.matches(&unknown));
+
+        let header = format!("0x{:04X}", RequestDetailsPacket::HEADER.0);
+        assert!(PacketQuery::new(PacketFilter::default(), &header, "").matches(&outgoing));
+        assert!(PacketQuery::new(PacketFilter::default(), "0x1234", "").matches(&unknown));
+        assert!(PacketQuery::new(PacketFilter::default(), " requestdetails ", "").matches(&outgoing));
+        assert!(!PacketQuery::new(PacketFilter::default(), "requestdetails", "").matches(&unknown));
+    }
+
+    #[test]
+    fn search_decoded_fields() {
+        let outgoing = outgoing_entry();
+
+        assert!(PacketQuery::new(PacketFilter::default(), "", "1234").matches(&outgoing));
+        assert!(PacketQuery::new(PacketFilter::default(), "", "ENTITYID").matches(&outgoing));
+        assert!(!PacketQuery::new(PacketFilter::default(), "", "4321").matches(&outgoing));
+    }
+
+    #[test]
+    fn decode_outgoing_bytes() {
+        let outgoing = outgoing_entry();
+        let bytes = outgoing.bytes.clone().unwrap();
+        let decode = outgoing.decode.unwrap();
+
+        assert_eq!(bytes[..2], RequestDetailsPacket::HEADER.0.to_le_bytes());
+        assert_eq!(outgoing.header, Some(RequestDetailsPacket::HEADER));
+        let decoded = decode(&bytes).unwrap();
+        assert!(decoded.details.contains("1234"));
+        assert!(decoded.fields.iter().all(|field| field.range.start >= 2));
+        assert!(
+            decoded
+                .fields
+                .iter()
+                .any(|field| field.path == "entity_id._0" && field.type_name == "u32" && field.range == (2..6))
+        );
+        assert!(decode(&bytes[..3]).is_err());
+        assert!(decode(&[bytes.as_slice(), &[0]].concat()).unwrap_err().contains("trailing"));
+        assert_eq!(unknown_entry().header, Some(PacketHeader(0x1234)));
+    }
+
+    #[test]
+    fn export_entries() {
+        let mut output = Vec::new();
+        unknown_entry().write(&mut output).unwrap();
+
+        assert_eq!(
+            String::from_utf8(output).unwrap(),
+            format!(
+                "[in] [character] Unknown (0x1234)\nunknown\n0000  34 12 61 5e{}  4.a.\n\n",
+                " ".repeat(36)
+            )
+        );
+    }
+}
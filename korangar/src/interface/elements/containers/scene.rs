@@ -0,0 +1,304 @@
+use std::cell::RefCell;
+use std::rc::{Rc, Weak};
+
+use korangar_interface::elements::{
+    ButtonBuilder, Container, ContainerState, Element, ElementCell, ElementState, ElementWrap, Expandable, Focus, Headline, StaticLabel,
+};
+use korangar_interface::event::{ChangeEvent, HoverInformation};
+use korangar_interface::layout::PlacementResolver;
+use korangar_interface::state::{PlainRemote, Remote};
+use korangar_interface::{dimension_bound, size_bound};
+use ragnarok_packets::EntityId;
+
+use crate::input::{MouseInputMode, UserEvent};
+use crate::interface::application::InterfaceSettings;
+use crate::interface::elements::LiveValue;
+use crate::interface::layout::{ScreenClip, ScreenPosition, ScreenSize};
+use crate::interface::theme::InterfaceTheme;
+use crate::renderer::InterfaceRenderer;
+use crate::world::{AnimationActionType, AnimationControl, Inspection, SceneInspection};
+
+const ACTIONS: [AnimationActionType; 14] = [
+    AnimationActionType::Idle,
+    AnimationActionType::Walk,
+    AnimationActionType::Sit,
+    AnimationActionType::Pickup,
+    AnimationActionType::ReadyFight,
+    AnimationActionType::Attack1,
+    AnimationActionType::Attack2,
+    AnimationActionType::Attack3,
+    AnimationActionType::Skill,
+    AnimationActionType::Hurt,
+    AnimationActionType::Freeze1,
+    AnimationActionType::Freeze2,
+    AnimationActionType::Die,
+    AnimationActionType::Special,
+];
+
+/// Identifies the object shown by a row of the scene view. Effects and
+/// particles don't have an id, so they are identified by their index.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum SceneKey {
+    Entity(EntityId),
+    Effect(usize),
+    Particle(usize),
+}
+
+impl SceneKey {
+    fn select<'a>(&self, scene: &'a SceneInspection) -> Option<&'a Inspection> {
+        match *self {
+            SceneKey::Entity(entity_id) => scene.entity(entity_id),
+            SceneKey::Effect(index) => scene.effects.get(index),
+            SceneKey::Particle(index) => scene.particles.get(index),
+        }
+    }
+}
+
+/// Keys and titles of all rows. Since indices can be reused by a different
+/// kind of object, rows are only kept if their title is still the same.
+fn scene_rows(scene: &SceneInspection) -> Vec<(SceneKey, String)> {
+    let entities = scene
+        .entities
+        .iter()
+        .map(|(entity_id, inspection)| (SceneKey::Entity(*entity_id), inspection.title.clone()));
+    let effects = scene
+        .effects
+        .iter()
+        .enumerate()
+        .map(|(index, inspection)| (SceneKey::Effect(index), inspection.title.clone()));
+    let particles = scene
+        .particles
+        .iter()
+        .enumerate()
+        .map(|(index, inspection)| (SceneKey::Particle(index), inspection.title.clone()));
+
+    entities.chain(effects).chain(particles).collect()
+}
+
+fn animation_button(text: impl AsRef<str> + 'static, entity_id: EntityId, control: AnimationControl) -> ElementCell<InterfaceSettings> {
+    ButtonBuilder::new()
+        .with_text(text)
+        .with_event(UserEvent::ControlEntityAnimation { entity_id, control })
+        .with_width_bound(dimension_bound!(25%))
+        .build()
+        .wrap()
+}
+
+/// Lists all entities, effects and particles of the scene with their live
+/// values.
+pub struct SceneView {
+    scene: PlainRemote<SceneInspection>,
+    rows: Vec<(SceneKey, String, ElementCell<InterfaceSettings>)>,
+    state: ContainerState<InterfaceSettings>,
+}
+
+impl SceneView {
+    pub fn new(scene: PlainRemote<SceneInspection>) -> Self {
+        let rows = scene_rows(&scene.get())
+            .into_iter()
+            .map(|(key, title)| (key, title, Self::row_to_element(&scene, key)))
+            .collect();
+        let elements = Self::arrange(&scene.get(), &rows);
+
+        Self {
+            scene,
+            rows,
+            state: ContainerState::new(elements),
+        }
+    }
+
+    fn row_to_element(scene: &PlainRemote<SceneInspection>, key: SceneKey) -> ElementCell<InterfaceSettings> {
+        let (title, field_names) = match key.select(&scene.get()) {
+            Some(inspection) => (
+                inspection.title.clone(),
+                inspection.fields.iter().map(|(name, _)| *name).collect::<Vec<_>>(),
+            ),
+            None => (String::new(), Vec::new()),
+        };
+
+        let mut elements: Vec<ElementCell<InterfaceSettings>> = field_names
+            .into_iter()
+            .map(|name| {
+                let value = LiveValue::new(scene.clone(), move |scene: &SceneInspection| {
+                    key.select(scene).and_then(|inspection| inspection.field(name)).map(str::to_owned)
+                });
+
+                Container::new(vec![StaticLabel::new(name.to_owned()).wrap(), value.wrap()]).wrap()
+            })
+            .collect();
+
+        if let SceneKey::Entity(entity_id) = key {
+            elements.extend([
+                animation_button("Pause", entity_id, AnimationControl::Pause),
+                animation_button("Resume", entity_id, AnimationControl::Resume),
+                animation_button("Previous", entity_id, AnimationControl::Step(-1)),
+                animation_button("Next", entity_id, AnimationControl::Step(1)),
+            ]);
+
+            let actions = ACTIONS
+                .into_iter()
+                .map(|action_type| animation_button(format!("{action_type:?}"), entity_id, AnimationControl::Force(action_type)))
+                .collect();
+
+            elements.push(Expandable::new("force action".to_owned(), actions, false).wrap());
+        }
+
+        Expandable::new(title, elements, false).wrap()
+    }
+
+    fn arrange(
+        scene: &SceneInspection,
+        rows: &[(SceneKey, String, ElementCell<InterfaceSettings>)],
+    ) -> Vec<ElementCell<InterfaceSettings>> {
+        let headline = |name: &str, count: usize| Headline::new(format!("{name} ({count})"), size_bound!(100%, 12)).wrap();
+        let rows_of = |matches: fn(&SceneKey) -> bool| {
+            rows.iter()
+                .filter(move |(key, ..)| matches(key))
+                .map(|(.., element)| element.clone())
+        };
+
+        std::iter::once(headline("entities", scene.entities.len()))
+            .chain(rows_of(|key| matches!(key, SceneKey::Entity(_))))
+            .chain(std::iter::once(headline("effects", scene.effects.len())))
+            .chain(rows_of(|key| matches!(key, SceneKey::Effect(_))))
+            .chain(std::iter::once(headline("particles", scene.particles.len())))
+            .chain(rows_of(|key| matches!(key, SceneKey::Particle(_))))
+            .collect()
+    }
+}
+
+impl Element<InterfaceSettings> for SceneView {
+    fn get_state(&self) -> &ElementState<InterfaceSettings> {
+        &self.state.state
+    }
+
+    fn get_state_mut(&mut self) -> &mut ElementState<InterfaceSettings> {
+        &mut self.state.state
+    }
+
+    fn link_back(
+        &mut self,
+        weak_self: Weak<RefCell<dyn Element<InterfaceSettings>>>,
+        weak_parent: Option<Weak<RefCell<dyn Element<InterfaceSettings>>>>,
+    ) {
+        self.state.link_back(weak_self, weak_parent);
+    }
+
+    fn is_focusable(&self) -> bool {
+        self.state.is_focusable::<false>()
+    }
+
+    fn focus_next(
+        &self,
+        self_cell: ElementCell<InterfaceSettings>,
+        caller_cell: Option<ElementCell<InterfaceSettings>>,
+        focus: Focus,
+    ) -> Option<ElementCell<InterfaceSettings>> {
+        self.state.focus_next::<false>(self_cell, caller_cell, focus)
+    }
+
+    fn restore_focus(&self, self_cell: ElementCell<InterfaceSettings>) -> Option<ElementCell<InterfaceSettings>> {
+        self.state.restore_focus(self_cell)
+    }
+
+    fn resolve(
+        &mut self,
+        placement_resolver: &mut PlacementResolver<InterfaceSettings>,
+        application: &InterfaceSettings,
+        theme: &InterfaceTheme,
+    ) {
+        self.state.resolve(
+            placement_resolver,
+            application,
+            theme,
+            &size_bound!(100%, ?),
+            ScreenSize::default(),
+        );
+    }
+
+    fn update(&mut self) -> Option<ChangeEvent> {
+        let change_event = self.state.update();
+
+        if !self.scene.consume_changed() {
+            return change_event;
+        }
+
+        let new_rows = scene_rows(&self.scene.get());
+
+        // Values are updated by the rows themselves, so we only need to change
+        // the elements if objects were added, removed or replaced.
+        if self
+            .rows
+            .iter()
+            .map(|(key, title, _)| (key, title))
+            .eq(new_rows.iter().map(|(key, title)| (key, title)))
+        {
+            return change_event;
+        }
+
+        // Keep the rows of objects that still exist, so they stay expanded.
+        let mut old_rows = std::mem::take(&mut self.rows);
+        let weak_self = self.state.state.self_element.clone();
+
+        self.rows = new_rows
+            .into_iter()
+            .map(|(key, title)| {
+                match old_rows
+                    .iter()
+                    .position(|(old_key, old_title, _)| *old_key == key && *old_title == title)
+                {
+                    Some(index) => old_rows.swap_remove(index),
+                    None => {
+                        let element = Self::row_to_element(&self.scene, key);
+                        element.borrow_mut().link_back(Rc::downgrade(&element), weak_self.clone());
+                        (key, title, element)
+                    }
+                }
+            })
+            .collect();
+
+        let elements = Self::arrange(&self.scene.get(), &self.rows);
+        elements
+            .iter()
+            .filter(|element| !self.rows.iter().any(|(.., row)| Rc::ptr_eq(row, element)))
+            .for_each(|element| element.borrow_mut().link_back(Rc::downgrade(element), weak_self.clone()));
+        self.state.elements = elements;
+
+        Some(ChangeEvent::RESOLVE_WINDOW)
+    }
+
+    fn hovered_element(&self, mouse_position: ScreenPosition, mouse_mode: &MouseInputMode) -> HoverInformation<InterfaceSettings> {
+        match mouse_mode {
+            MouseInputMode::None => self.state.hovered_element(mouse_position, mouse_mode, false),
+            _ => HoverInformation::Missed,
+        }
+    }
+
+    fn render(
+        &self,
+        renderer: &InterfaceRenderer,
+        application: &InterfaceSettings,
+        theme: &InterfaceTheme,
+        parent_position: ScreenPosition,
+        screen_clip: ScreenClip,
+        hovered_element: Option<&dyn Element<InterfaceSettings>>,
+        focused_element: Option<&dyn Element<InterfaceSettings>>,
+        mouse_mode: &MouseInputMode,
+        second_theme: bool,
+    ) {
+        let mut renderer = self
+            .state
+            .state
+            .element_renderer(renderer, application, parent_position, screen_clip);
+
+        self.state.render(
+            &mut renderer,
+            application,
+            theme,
+            hovered_element,
+            focused_element,
+            mouse_mode,
+            second_theme,
+        );
+    }
+}
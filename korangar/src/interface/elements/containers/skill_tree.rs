@@ -1,10 +1,13 @@
-use korangar_interface::elements::{ContainerState, Element, ElementCell, ElementState, ElementWrap, Focus, WeakElementCell};
+use korangar_interface::elements::{
+    ButtonBuilder, ContainerState, Element, ElementCell, ElementState, ElementWrap, Focus, Text, WeakElementCell,
+};
 use korangar_interface::event::{ChangeEvent, HoverInformation};
 use korangar_interface::layout::PlacementResolver;
-use korangar_interface::size_bound;
 use korangar_interface::state::{PlainRemote, Remote};
+use korangar_interface::{dimension_bound, size_bound};
 
-use crate::input::MouseInputMode;
+use crate::graphics::Color;
+use crate::input::{MouseInputMode, UserEvent};
 use crate::interface::application::InterfaceSettings;
 use crate::interface::elements::SkillBox;
 use crate::interface::layout::{ScreenClip, ScreenPosition, ScreenSize};
@@ -13,28 +16,65 @@ use crate::interface::theme::InterfaceTheme;
 use crate::inventory::Skill;
 use crate::renderer::InterfaceRenderer;
 
+/// Lists all skills of the player's skill tree. Skills with unmet
+/// requirements are displayed as locked.
 pub struct SkillTreeContainer {
     skills: PlainRemote<Vec<Skill>>,
+    skill_points: PlainRemote<u32>,
     weak_self: Option<WeakElementCell<InterfaceSettings>>,
     state: ContainerState<InterfaceSettings>,
 }
 
 impl SkillTreeContainer {
-    pub fn new(skills: PlainRemote<Vec<Skill>>) -> Self {
+    pub fn new(skills: PlainRemote<Vec<Skill>>, skill_points: PlainRemote<u32>) -> Self {
         let elements = {
             let skills = skills.get();
-
-            skills
-                .iter()
-                .cloned()
-                .map(|skill| SkillBox::new(Some(skill), SkillSource::SkillTree, Box::new(|_| false)).wrap())
-                .collect()
+            let skill_points = *skill_points.get();
+
+            let mut elements = vec![Text::default().with_text(format!("Skill points: {skill_points}")).wrap()];
+
+            for skill in skills.iter() {
+                let is_locked = !skill.requirements_met(&skills);
+                let can_upgrade = skill.can_upgrade(skill_points);
+                let level_text = match skill.maximum_level {
+                    Some(maximum_level) => format!("{} / {}", skill.skill_level.0, maximum_level.0),
+                    None => skill.skill_level.0.to_string(),
+                };
+
+                elements.push(SkillBox::new(Some(skill.clone()), SkillSource::SkillTree, Box::new(|_| false)).wrap());
+                elements.push(
+                    Text::default()
+                        .with_text(format!("{} {level_text}", skill.skill_name))
+                        .with_foreground_color(move |_| match is_locked {
+                            true => Color::monochrome_u8(120),
+                            false => Color::monochrome_u8(255),
+                        })
+                        .with_width(dimension_bound!(60%))
+                        .wrap(),
+                );
+                elements.push(
+                    ButtonBuilder::new()
+                        .with_text("+")
+                        .with_event(UserEvent::UpgradeSkill(skill.skill_id))
+                        .with_disabled_selector(move || !can_upgrade)
+                        .with_width_bound(dimension_bound!(!))
+                        .build()
+                        .wrap(),
+                );
+            }
+
+            elements
         };
 
         let weak_self = None;
         let state = ContainerState::new(elements);
 
-        Self { skills, weak_self, state }
+        Self {
+            skills,
+            skill_points,
+            weak_self,
+            state,
+        }
     }
 }
 
@@ -81,11 +121,12 @@ impl Element<InterfaceSettings> for SkillTreeContainer {
     }
 
     fn update(&mut self) -> Option<ChangeEvent> {
-        if self.skills.consume_changed() {
+        // Both remotes need to be consumed, so we can't short circuit.
+        if self.skills.consume_changed() | self.skill_points.consume_changed() {
             let weak_parent = self.state.state.parent_element.take();
             let weak_self = self.weak_self.take().unwrap();
 
-            *self = Self::new(self.skills.clone());
+            *self = Self::new(self.skills.clone(), self.skill_points.clone());
             // important: link back after creating elements, otherwise focus navigation and
             // scrolling would break
             self.link_back(weak_self, weak_parent);
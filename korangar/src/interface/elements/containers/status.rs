@@ -0,0 +1,212 @@
+use korangar_interface::elements::{
+    ButtonBuilder, ContainerState, Element, ElementCell, ElementState, ElementWrap, Focus, Text, WeakElementCell,
+};
+use korangar_interface::event::{ChangeEvent, HoverInformation};
+use korangar_interface::layout::PlacementResolver;
+use korangar_interface::state::{PlainRemote, Remote};
+use korangar_interface::{dimension_bound, size_bound};
+use ragnarok_packets::StatType;
+
+use crate::graphics::Color;
+use crate::input::{MouseInputMode, UserEvent};
+use crate::interface::application::InterfaceSettings;
+use crate::interface::layout::{ScreenClip, ScreenPosition, ScreenSize};
+use crate::interface::theme::InterfaceTheme;
+use crate::renderer::InterfaceRenderer;
+use crate::world::PlayerStatus;
+
+const STATS: [(&str, StatType); 6] = [
+    ("Str", StatType::Strength),
+    ("Agi", StatType::Agility),
+    ("Vit", StatType::Vitality),
+    ("Int", StatType::Intelligence),
+    ("Dex", StatType::Dexterity),
+    ("Luk", StatType::Luck),
+];
+
+/// Displays the stats of the player and allows spending status points on the
+/// base stats.
+pub struct StatusView {
+    status: PlainRemote<PlayerStatus>,
+    weak_self: Option<WeakElementCell<InterfaceSettings>>,
+    state: ContainerState<InterfaceSettings>,
+}
+
+impl StatusView {
+    pub fn new(status: PlainRemote<PlayerStatus>) -> Self {
+        let elements = {
+            let status = status.get();
+            let mut elements = Vec::new();
+
+            let levels = [
+                (
+                    "Base level",
+                    status.base_level,
+                    status.base_experience,
+                    status.next_base_experience,
+                ),
+                ("Job level", status.job_level, status.job_experience, status.next_job_experience),
+            ];
+
+            for (label, level, experience, next_experience) in levels {
+                elements.push(Self::label(label));
+                elements.push(
+                    Text::default()
+                        .with_text(format!("{level} ({experience} / {next_experience})"))
+                        .with_width(dimension_bound!(!))
+                        .wrap(),
+                );
+            }
+
+            for (label, stat_type) in STATS {
+                let stat = status.get_stat(stat_type);
+                let can_raise = stat.required_points > 0 && status.status_points >= stat.required_points as u32;
+
+                elements.push(Self::label(label));
+                elements.push(
+                    Text::default()
+                        .with_text(format!("{} + {}", stat.base, stat.bonus))
+                        .with_width(dimension_bound!(30%))
+                        .wrap(),
+                );
+                elements.push(
+                    ButtonBuilder::new()
+                        .with_text(format!("+ ({})", stat.required_points))
+                        .with_event(UserEvent::RaiseStat(stat_type))
+                        .with_disabled_selector(move || !can_raise)
+                        .with_width_bound(dimension_bound!(!))
+                        .build()
+                        .wrap(),
+                );
+            }
+
+            let values = [
+                ("Status points", status.status_points.to_string()),
+                ("Atk", format!("{} + {}", status.attack.0, status.attack.1)),
+                ("Matk", format!("{} + {}", status.magic_attack.0, status.magic_attack.1)),
+                ("Def", format!("{} + {}", status.defense.0, status.defense.1)),
+                ("Mdef", format!("{} + {}", status.magic_defense.0, status.magic_defense.1)),
+                ("Hit", status.hit.to_string()),
+                ("Flee", format!("{} + {}", status.flee.0, status.flee.1)),
+                ("Critical", status.critical.to_string()),
+                ("Aspd", status.attack_speed.to_string()),
+                ("Weight", format!("{} / {}", status.weight / 10, status.maximum_weight / 10)),
+                ("Zeny", status.zeny.to_string()),
+            ];
+
+            for (label, value) in values {
+                elements.push(Self::label(label));
+                elements.push(Text::default().with_text(value).with_width(dimension_bound!(!)).wrap());
+            }
+
+            elements
+        };
+
+        let weak_self = None;
+        let state = ContainerState::new(elements);
+
+        Self { status, weak_self, state }
+    }
+
+    fn label(label: &str) -> ElementCell<InterfaceSettings> {
+        Text::default()
+            .with_text(label.to_owned())
+            .with_foreground_color(|_| Color::monochrome_u8(200))
+            .with_width(dimension_bound!(40%))
+            .wrap()
+    }
+}
+
+impl Element<InterfaceSettings> for StatusView {
+    fn get_state(&self) -> &ElementState<InterfaceSettings> {
+        &self.state.state
+    }
+
+    fn get_state_mut(&mut self) -> &mut ElementState<InterfaceSettings> {
+        &mut self.state.state
+    }
+
+    fn link_back(&mut self, weak_self: WeakElementCell<InterfaceSettings>, weak_parent: Option<WeakElementCell<InterfaceSettings>>) {
+        self.weak_self = Some(weak_self.clone());
+        self.state.link_back(weak_self, weak_parent);
+    }
+
+    fn is_focusable(&self) -> bool {
+        self.state.is_focusable::<false>()
+    }
+
+    fn focus_next(
+        &self,
+        self_cell: ElementCell<InterfaceSettings>,
+        caller_cell: Option<ElementCell<InterfaceSettings>>,
+        focus: Focus,
+    ) -> Option<ElementCell<InterfaceSettings>> {
+        self.state.focus_next::<false>(self_cell, caller_cell, focus)
+    }
+
+    fn restore_focus(&self, self_cell: ElementCell<InterfaceSettings>) -> Option<ElementCell<InterfaceSettings>> {
+        self.state.restore_focus(self_cell)
+    }
+
+    fn resolve(
+        &mut self,
+        placement_resolver: &mut PlacementResolver<InterfaceSettings>,
+        application: &InterfaceSettings,
+        theme: &InterfaceTheme,
+    ) {
+        let size_bound = &size_bound!(100%, ?);
+        self.state
+            .resolve(placement_resolver, application, theme, size_bound, ScreenSize::uniform(3.0));
+    }
+
+    fn update(&mut self) -> Option<ChangeEvent> {
+        if self.status.consume_changed() {
+            let weak_parent = self.state.state.parent_element.take();
+            let weak_self = self.weak_self.take().unwrap();
+
+            *self = Self::new(self.status.clone());
+            // important: link back after creating elements, otherwise focus navigation and
+            // scrolling would break
+            self.link_back(weak_self, weak_parent);
+
+            return Some(ChangeEvent::RESOLVE_WINDOW);
+        }
+
+        None
+    }
+
+    fn hovered_element(&self, mouse_position: ScreenPosition, mouse_mode: &MouseInputMode) -> HoverInformation<InterfaceSettings> {
+        match mouse_mode {
+            MouseInputMode::None => self.state.hovered_element(mouse_position, mouse_mode, false),
+            _ => HoverInformation::Missed,
+        }
+    }
+
+    fn render(
+        &self,
+        renderer: &InterfaceRenderer,
+        application: &InterfaceSettings,
+        theme: &InterfaceTheme,
+        parent_position: ScreenPosition,
+        screen_clip: ScreenClip,
+        hovered_element: Option<&dyn Element<InterfaceSettings>>,
+        focused_element: Option<&dyn Element<InterfaceSettings>>,
+        mouse_mode: &MouseInputMode,
+        second_theme: bool,
+    ) {
+        let mut renderer = self
+            .state
+            .state
+            .element_renderer(renderer, application, parent_position, screen_clip);
+
+        self.state.render(
+            &mut renderer,
+            application,
+            theme,
+            hovered_element,
+            focused_element,
+            mouse_mode,
+            second_theme,
+        );
+    }
+}
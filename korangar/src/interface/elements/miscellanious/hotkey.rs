@@ -0,0 +1,170 @@
+use korangar_interface::application::{FontSizeTrait, SizeTraitExt};
+use korangar_interface::elements::{Element, ElementState};
+use korangar_interface::event::{ClickAction, HoverInformation};
+use korangar_interface::layout::PlacementResolver;
+use korangar_interface::size_bound;
+use korangar_networking::InventoryItemDetails;
+use ragnarok_packets::HotbarSlot;
+
+use crate::graphics::Color;
+use crate::input::MouseInputMode;
+use crate::interface::application::InterfaceSettings;
+use crate::interface::layout::{CornerRadius, ScreenClip, ScreenPosition, ScreenSize};
+use crate::interface::resource::{ItemSource, Move, PartialMove, SkillSource};
+use crate::interface::theme::InterfaceTheme;
+use crate::inventory::HotbarEntry;
+use crate::loaders::{FontSize, Scaling};
+use crate::renderer::{InterfaceRenderer, SpriteRenderer};
+
+/// A slot of the hotbar that can hold either a skill or an item.
+pub struct HotkeyBox {
+    entry: Option<HotbarEntry>,
+    slot: HotbarSlot,
+    state: ElementState<InterfaceSettings>,
+}
+
+impl HotkeyBox {
+    pub fn new(entry: Option<HotbarEntry>, slot: HotbarSlot) -> Self {
+        Self {
+            entry,
+            slot,
+            state: ElementState::default(),
+        }
+    }
+
+    fn highlight(&self, mouse_mode: &MouseInputMode) -> bool {
+        match mouse_mode {
+            MouseInputMode::MoveSkill(source, _) => *source != SkillSource::Hotbar { slot: self.slot },
+            MouseInputMode::MoveItem(source, item) => {
+                *source != ItemSource::Hotbar { slot: self.slot } && matches!(item.details, InventoryItemDetails::Regular { .. })
+            }
+            _ => false,
+        }
+    }
+}
+
+impl Element<InterfaceSettings> for HotkeyBox {
+    fn get_state(&self) -> &ElementState<InterfaceSettings> {
+        &self.state
+    }
+
+    fn get_state_mut(&mut self) -> &mut ElementState<InterfaceSettings> {
+        &mut self.state
+    }
+
+    fn is_focusable(&self) -> bool {
+        self.entry.is_some()
+    }
+
+    fn resolve(
+        &mut self,
+        placement_resolver: &mut PlacementResolver<InterfaceSettings>,
+        _application: &InterfaceSettings,
+        _theme: &InterfaceTheme,
+    ) {
+        self.state.resolve(placement_resolver, &size_bound!(30, 30));
+    }
+
+    fn hovered_element(&self, mouse_position: ScreenPosition, mouse_mode: &MouseInputMode) -> HoverInformation<InterfaceSettings> {
+        match self.entry.is_some() || matches!(mouse_mode, MouseInputMode::MoveSkill(..) | MouseInputMode::MoveItem(..)) {
+            true => self.state.hovered_element(mouse_position),
+            false => HoverInformation::Missed,
+        }
+    }
+
+    fn left_click(&mut self, _force_update: &mut bool) -> Vec<ClickAction<InterfaceSettings>> {
+        match &self.entry {
+            Some(HotbarEntry::Skill(skill)) => vec![ClickAction::Move(PartialMove::Skill {
+                source: SkillSource::Hotbar { slot: self.slot },
+                skill: skill.clone(),
+            })],
+            Some(HotbarEntry::Item(item)) => vec![ClickAction::Move(PartialMove::Item {
+                source: ItemSource::Hotbar { slot: self.slot },
+                item: item.clone(),
+            })],
+            None => Vec::new(),
+        }
+    }
+
+    fn drop_resource(&mut self, drop_resource: PartialMove) -> Option<Move> {
+        match drop_resource {
+            PartialMove::Skill { source, skill } => {
+                let destination = SkillSource::Hotbar { slot: self.slot };
+
+                (source != destination).then_some(Move::Skill {
+                    source,
+                    destination,
+                    skill,
+                })
+            }
+            PartialMove::Item { source, item } => {
+                let destination = ItemSource::Hotbar { slot: self.slot };
+
+                (source != destination && matches!(item.details, InventoryItemDetails::Regular { .. })).then_some(Move::Item {
+                    source,
+                    destination,
+                    item,
+                })
+            }
+        }
+    }
+
+    fn render(
+        &self,
+        renderer: &InterfaceRenderer,
+        application: &InterfaceSettings,
+        theme: &InterfaceTheme,
+        parent_position: ScreenPosition,
+        screen_clip: ScreenClip,
+        hovered_element: Option<&dyn Element<InterfaceSettings>>,
+        focused_element: Option<&dyn Element<InterfaceSettings>>,
+        mouse_mode: &MouseInputMode,
+        _second_theme: bool,
+    ) {
+        let mut renderer = self.state.element_renderer(renderer, application, parent_position, screen_clip);
+
+        let highlight = self.highlight(mouse_mode);
+        let background_color = match self.is_element_self(hovered_element) || self.is_element_self(focused_element) {
+            true if highlight => Color::rgba_u8(60, 160, 160, 255),
+            true if matches!(mouse_mode, MouseInputMode::None) => theme.button.hovered_background_color.get(),
+            false if highlight => Color::rgba_u8(160, 160, 60, 255),
+            _ => theme.button.background_color.get(),
+        };
+
+        renderer.render_background(CornerRadius::uniform(5.0), background_color);
+
+        let (text, font_size) = match &self.entry {
+            Some(HotbarEntry::Skill(skill)) => {
+                skill.actions.render_sprite(
+                    renderer.renderer,
+                    &skill.sprite,
+                    &skill.animation_state,
+                    renderer.position + ScreenPosition::uniform(15.0 * application.get_scaling_factor()),
+                    0,
+                    Color::WHITE,
+                    application,
+                );
+
+                (format!("{}", skill.skill_level.0), FontSize::new(15.0))
+            }
+            Some(HotbarEntry::Item(item)) => {
+                if let Some(texture) = item.metadata.texture.as_ref() {
+                    renderer.renderer.render_sprite(
+                        texture.clone(),
+                        renderer.position,
+                        ScreenSize::uniform(30.0).scaled(Scaling::new(application.get_scaling_factor())),
+                        renderer.clip,
+                        Color::WHITE,
+                        false,
+                    );
+                }
+
+                (format!("{}", item.amount()), FontSize::new(12.0))
+            }
+            None => return,
+        };
+
+        renderer.render_text(&text, ScreenPosition::uniform(1.0), Color::BLACK, font_size);
+        renderer.render_text(&text, ScreenPosition::default(), Color::WHITE, font_size);
+    }
+}
@@ -4,10 +4,11 @@ use korangar_interface::elements::{Element, ElementState};
 use korangar_interface::event::{ClickAction, HoverInformation};
 use korangar_interface::layout::PlacementResolver;
 use korangar_interface::size_bound;
+use korangar_interface::tooltip::Tooltip;
 use korangar_networking::{InventoryItem, InventoryItemDetails};
 
 use crate::graphics::Color;
-use crate::input::MouseInputMode;
+use crate::input::{MouseInputMode, UserEvent};
 use crate::interface::application::InterfaceSettings;
 use crate::interface::layout::{CornerRadius, ScreenClip, ScreenPosition, ScreenSize};
 use crate::interface::resource::{ItemSource, Move, PartialMove};
@@ -65,6 +66,43 @@ impl Element<InterfaceSettings> for ItemBox {
         Vec::new()
     }
 
+    fn right_click(&mut self, _force_update: &mut bool) -> Vec<ClickAction<InterfaceSettings>> {
+        if self.source == ItemSource::Inventory
+            && let Some(item) = &self.item
+            && matches!(item.details, InventoryItemDetails::Regular { .. })
+        {
+            return vec![ClickAction::Custom(UserEvent::UseItem(item.index))];
+        }
+
+        Vec::new()
+    }
+
+    fn tooltip(&self) -> Option<Tooltip> {
+        let item = self.item.as_ref()?;
+        let metadata = &item.metadata;
+
+        let mut title = match &item.details {
+            InventoryItemDetails::Equippable { refinement_level, .. } if *refinement_level > 0 => {
+                format!("+{} {}", refinement_level, metadata.name)
+            }
+            _ => metadata.name.clone(),
+        };
+
+        if metadata.slot_count > 0 {
+            title = format!("{} [{}]", title, metadata.slot_count);
+        }
+
+        let mut tooltip = Tooltip::new(title).with_lines(metadata.description.iter().cloned());
+
+        if !metadata.cards.is_empty() {
+            tooltip = tooltip
+                .with_line("")
+                .with_lines(metadata.cards.iter().map(|card| format!("Card: {}", card)));
+        }
+
+        Some(tooltip)
+    }
+
     fn drop_resource(&mut self, drop_resource: PartialMove) -> Option<Move> {
         let PartialMove::Item { source, item } = drop_resource else {
             return None;
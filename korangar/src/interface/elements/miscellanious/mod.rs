@@ -1,7 +1,9 @@
 mod chat;
+mod hotkey;
 mod item;
 mod skill;
 
 pub use self::chat::ChatBuilder;
+pub use self::hotkey::HotkeyBox;
 pub use self::item::ItemBox;
 pub use self::skill::SkillBox;
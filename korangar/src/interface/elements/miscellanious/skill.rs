@@ -4,6 +4,7 @@ use korangar_interface::elements::{Element, ElementState};
 use korangar_interface::event::{ClickAction, HoverInformation};
 use korangar_interface::layout::PlacementResolver;
 use korangar_interface::size_bound;
+use korangar_interface::tooltip::Tooltip;
 
 use crate::graphics::Color;
 use crate::input::MouseInputMode;
@@ -54,7 +55,11 @@ impl Element<InterfaceSettings> for SkillBox {
     }
 
     fn left_click(&mut self, _force_update: &mut bool) -> Vec<ClickAction<InterfaceSettings>> {
-        if let Some(skill) = &self.skill {
+        // Skills that are not learned yet can't be used, so they can't be put on the
+        // hotbar either.
+        if let Some(skill) = &self.skill
+            && skill.is_learned()
+        {
             return vec![ClickAction::Move(PartialMove::Skill {
                 source: self.source,
                 skill: skill.clone(),
@@ -64,6 +69,25 @@ impl Element<InterfaceSettings> for SkillBox {
         Vec::new()
     }
 
+    fn tooltip(&self) -> Option<Tooltip> {
+        let skill = self.skill.as_ref()?;
+
+        let level = match skill.maximum_level {
+            Some(maximum_level) => format!("Level {} / {}", skill.skill_level.0, maximum_level.0),
+            None => format!("Level {}", skill.skill_level.0),
+        };
+
+        let mut tooltip = Tooltip::new(skill.skill_name.clone())
+            .with_line(level)
+            .with_line(format!("Type: {:?}", skill.skill_type));
+
+        if skill.upgradable {
+            tooltip = tooltip.with_line("Can be raised");
+        }
+
+        Some(tooltip)
+    }
+
     fn drop_resource(&mut self, drop_resource: PartialMove) -> Option<Move> {
         let PartialMove::Skill { source, skill } = drop_resource else {
             return None;
@@ -42,7 +42,7 @@ impl BuyContainer {
                     },
                     |item, cart, amount| {
                         cart.mutate(|cart| {
-                            if let Some(purchase) = cart.iter_mut().find(|purchase| purchase.item_id == item.item_id) {
+                            if let Some(purchase) = cart.iter_mut().find(|purchase| purchase.is_same_item(item)) {
                                 purchase.metadata.1 += amount;
                             } else {
                                 cart.push(ShopItem {
@@ -53,6 +53,7 @@ impl BuyContainer {
                                     quantity: item.quantity,
                                     weight: item.weight,
                                     location: item.location,
+                                    cart_index: item.cart_index,
                                 });
                             }
                         });
@@ -66,7 +67,7 @@ impl BuyContainer {
                         let cart_quantity = cart
                             .get()
                             .iter()
-                            .find(|cart_item| cart_item.item_id == item.item_id)
+                            .find(|cart_item| cart_item.is_same_item(item))
                             .map(|cart_item| cart_item.metadata.1)
                             .unwrap_or(0);
 
@@ -1,13 +1,14 @@
 use korangar_interface::application::SizeTraitExt;
 use korangar_interface::elements::{
-    ButtonBuilder, ContainerState, Element, ElementCell, ElementState, ElementWrap, Focus, WeakElementCell,
+    ButtonBuilder, ContainerState, Element, ElementCell, ElementState, ElementWrap, Focus, FocusMode, InputFieldBuilder, WeakElementCell,
 };
 use korangar_interface::event::{ChangeEvent, ClickAction, HoverInformation};
 use korangar_interface::layout::PlacementResolver;
-use korangar_interface::state::{PlainRemote, PlainTrackedState, Remote, TrackedState, TrackedStateExt};
+use korangar_interface::state::{PlainRemote, PlainTrackedState, Remote, TrackedState, TrackedStateClone, TrackedStateExt};
 use korangar_interface::{dimension_bound, size_bound};
 use korangar_networking::ShopItem;
 use num::Integer;
+use ragnarok_packets::{ItemId, OpenBuyingStoreItemInformation, Price};
 
 use super::CartSum;
 use crate::input::{MouseInputMode, UserEvent};
@@ -18,15 +19,205 @@ use crate::interface::theme::InterfaceTheme;
 use crate::renderer::InterfaceRenderer;
 use crate::world::ResourceMetadata;
 
+/// Highest price a buying store can pay for a single item.
+const MAXIMUM_BUYING_STORE_PRICE: u32 = 99_990_000;
+
+/// Highest amount of a single item a buying store can buy.
+const MAXIMUM_BUYING_STORE_AMOUNT: u32 = 9999;
+
+/// The price input of every item in the cart. The inputs are kept outside of
+/// the cart so they survive rebuilding the container.
+type PriceInputs = PlainTrackedState<Vec<(ItemId, PlainTrackedState<String>)>>;
+
+/// What happens to the items in the cart.
+#[derive(Clone)]
+pub enum BuyCartKind {
+    /// The items are bought from an NPC shop or a vending store.
+    Shop,
+    /// The items are requested in the player's buying store.
+    BuyingStore {
+        title: PlainTrackedState<String>,
+        zeny_limit: PlainTrackedState<String>,
+        prices: PriceInputs,
+        maximum_item_count: usize,
+    },
+}
+
+/// Parse the price of an item in a buying store.
+fn parse_buying_store_price(text: &str) -> Option<Price> {
+    text.trim()
+        .parse()
+        .ok()
+        .filter(|price| (1..=MAXIMUM_BUYING_STORE_PRICE).contains(price))
+        .map(Price)
+}
+
+/// Validate the store title, the zeny limit and the `(item id, amount,
+/// price)` of every item. Returns `None` if the store can't be opened like
+/// this.
+fn buying_store_items(
+    title: &str,
+    zeny_limit: &str,
+    items: &[(ItemId, u32, String)],
+    maximum_item_count: usize,
+) -> Option<(u32, Vec<OpenBuyingStoreItemInformation>)> {
+    if title.trim().is_empty() || items.is_empty() || items.len() > maximum_item_count {
+        return None;
+    }
+
+    let zeny_limit = zeny_limit.trim().parse().ok().filter(|zeny_limit| *zeny_limit > 0)?;
+    let items = items
+        .iter()
+        .map(|(item_id, amount, price)| {
+            Some(OpenBuyingStoreItemInformation {
+                item_id: *item_id,
+                amount: (1..=MAXIMUM_BUYING_STORE_AMOUNT).contains(amount).then_some(*amount as u16)?,
+                price: parse_buying_store_price(price)?,
+            })
+        })
+        .collect::<Option<_>>()?;
+
+    Some((zeny_limit, items))
+}
+
 pub struct BuyCartContainer {
     cart: PlainTrackedState<Vec<ShopItem<(ResourceMetadata, u32)>>>,
     cart_remote: PlainRemote<Vec<ShopItem<(ResourceMetadata, u32)>>>,
+    kind: BuyCartKind,
     state: ContainerState<InterfaceSettings>,
 }
 
 impl BuyCartContainer {
-    pub fn new(cart: PlainTrackedState<Vec<ShopItem<(ResourceMetadata, u32)>>>) -> Self {
-        let mut elements = cart
+    fn get_buying_store_items(
+        cart: &PlainTrackedState<Vec<ShopItem<(ResourceMetadata, u32)>>>,
+        title: &PlainTrackedState<String>,
+        zeny_limit: &PlainTrackedState<String>,
+        prices: &PriceInputs,
+        maximum_item_count: usize,
+    ) -> Option<(u32, Vec<OpenBuyingStoreItemInformation>)> {
+        let price_inputs = prices.get();
+        let items: Vec<(ItemId, u32, String)> = cart
+            .get()
+            .iter()
+            .map(|item| {
+                let price = price_inputs
+                    .iter()
+                    .find(|(item_id, _)| *item_id == item.item_id)
+                    .map(|(_, price)| price.cloned())
+                    .unwrap_or_default();
+
+                (item.item_id, item.metadata.1, price)
+            })
+            .collect();
+
+        buying_store_items(&title.get(), &zeny_limit.get(), &items, maximum_item_count)
+    }
+
+    fn shop_buttons(cart: &PlainTrackedState<Vec<ShopItem<(ResourceMetadata, u32)>>>) -> Vec<ElementCell<InterfaceSettings>> {
+        let cart = cart.clone();
+
+        vec![
+            CartSum::new(&cart, |item| item.price.0, |item| item.metadata.1).wrap(),
+            ButtonBuilder::new()
+                .with_text("purchase")
+                .with_event(move || {
+                    let items = cart
+                        .get()
+                        .iter()
+                        .map(|item| ShopItem {
+                            metadata: item.metadata.1,
+                            ..item.clone()
+                        })
+                        .collect();
+
+                    vec![ClickAction::Custom(UserEvent::BuyItems { items })]
+                })
+                .with_width_bound(dimension_bound!(50%))
+                .build()
+                .wrap(),
+            ButtonBuilder::new()
+                .with_text("cancel")
+                .with_event(move || vec![ClickAction::Custom(UserEvent::CloseShop)])
+                .with_width_bound(dimension_bound!(!))
+                .build()
+                .wrap(),
+        ]
+    }
+
+    fn buying_store_buttons(
+        cart: &PlainTrackedState<Vec<ShopItem<(ResourceMetadata, u32)>>>,
+        title: &PlainTrackedState<String>,
+        zeny_limit: &PlainTrackedState<String>,
+        prices: &PriceInputs,
+        maximum_item_count: usize,
+    ) -> Vec<ElementCell<InterfaceSettings>> {
+        let cart = cart.clone();
+        let disabled_cart = cart.clone();
+        let title = title.clone();
+        let disabled_title = title.clone();
+        let zeny_limit = zeny_limit.clone();
+        let disabled_zeny_limit = zeny_limit.clone();
+        let prices = prices.clone();
+        let disabled_prices = prices.clone();
+
+        vec![
+            ButtonBuilder::new()
+                .with_text("open store")
+                .with_event(move || {
+                    let Some((zeny_limit, items)) = Self::get_buying_store_items(&cart, &title, &zeny_limit, &prices, maximum_item_count)
+                    else {
+                        return Vec::new();
+                    };
+
+                    vec![ClickAction::Custom(UserEvent::StartBuyingStore {
+                        title: title.get().trim().to_owned(),
+                        zeny_limit,
+                        items,
+                    })]
+                })
+                .with_disabled_selector(move || {
+                    Self::get_buying_store_items(
+                        &disabled_cart,
+                        &disabled_title,
+                        &disabled_zeny_limit,
+                        &disabled_prices,
+                        maximum_item_count,
+                    )
+                    .is_none()
+                })
+                .with_width_bound(dimension_bound!(50%))
+                .build()
+                .wrap(),
+            ButtonBuilder::new()
+                .with_text("cancel")
+                .with_event(move || vec![ClickAction::Custom(UserEvent::CancelBuyingStore)])
+                .with_width_bound(dimension_bound!(!))
+                .build()
+                .wrap(),
+        ]
+    }
+
+    fn price_input(prices: &mut PriceInputs, item_id: ItemId) -> ElementCell<InterfaceSettings> {
+        let price = prices.mutate(|prices| match prices.iter().find(|(id, _)| *id == item_id) {
+            Some((_, price)) => price.clone(),
+            None => {
+                let price = PlainTrackedState::<String>::default();
+                prices.push((item_id, price.clone()));
+                price
+            }
+        });
+
+        InputFieldBuilder::new()
+            .with_state(price)
+            .with_ghost_text("Price")
+            .with_enter_action(|| vec![ClickAction::FocusNext(FocusMode::FocusNext)])
+            .with_length(10)
+            .build()
+            .wrap()
+    }
+
+    pub fn new(cart: PlainTrackedState<Vec<ShopItem<(ResourceMetadata, u32)>>>, mut kind: BuyCartKind) -> Self {
+        let entries = cart
             .get()
             .iter()
             .enumerate()
@@ -39,19 +230,19 @@ impl BuyCartContainer {
                     |item| Some(item.metadata.1 as usize),
                     |item, cart, amount| {
                         cart.mutate(|cart| {
-                            let purchase = cart.iter_mut().find(|purchase| purchase.item_id == item.item_id).unwrap();
+                            let purchase = cart.iter_mut().find(|purchase| purchase.is_same_item(item)).unwrap();
 
                             purchase.metadata.1 = purchase.metadata.1.saturating_sub(amount);
 
                             if purchase.metadata.1 == 0 {
-                                cart.retain(|purchase| purchase.item_id != item.item_id);
+                                cart.retain(|purchase| !purchase.is_same_item(item));
                             }
                         });
                     },
                     |item, cart, amount| {
                         cart.get()
                             .iter()
-                            .find(|cart_item| cart_item.item_id == item.item_id)
+                            .find(|cart_item| cart_item.is_same_item(item))
                             .map(|cart_item| amount.saturating_sub(cart_item.metadata.1) == 0)
                             .unwrap_or(true)
                     },
@@ -60,47 +251,60 @@ impl BuyCartContainer {
             .map(ElementWrap::wrap)
             .collect::<Vec<ElementCell<InterfaceSettings>>>();
 
-        {
-            let cart = cart.clone();
-
-            elements.insert(
-                0,
-                ButtonBuilder::new()
-                    .with_text("purchase")
-                    .with_event(move || {
-                        let items = cart
-                            .get()
-                            .iter()
-                            .map(|item| ShopItem {
-                                metadata: item.metadata.1,
-                                ..item.clone()
-                            })
-                            .collect();
-
-                        vec![ClickAction::Custom(UserEvent::BuyItems { items })]
-                    })
-                    .with_width_bound(dimension_bound!(50%))
+        let elements = match &mut kind {
+            BuyCartKind::Shop => Self::shop_buttons(&cart).into_iter().chain(entries).collect(),
+            BuyCartKind::BuyingStore {
+                title,
+                zeny_limit,
+                prices,
+                maximum_item_count,
+            } => {
+                let title_input = InputFieldBuilder::new()
+                    .with_state(title.clone())
+                    .with_ghost_text("Store title")
+                    .with_enter_action(|| vec![ClickAction::FocusNext(FocusMode::FocusNext)])
+                    .with_length(80)
                     .build()
-                    .wrap(),
-            );
-        }
+                    .wrap();
+                let zeny_limit_input = InputFieldBuilder::new()
+                    .with_state(zeny_limit.clone())
+                    .with_ghost_text("Zeny limit")
+                    .with_enter_action(|| vec![ClickAction::FocusNext(FocusMode::FocusNext)])
+                    .with_length(10)
+                    .build()
+                    .wrap();
 
-        elements.insert(
-            1,
-            ButtonBuilder::new()
-                .with_text("cancel")
-                .with_event(move || vec![ClickAction::Custom(UserEvent::CloseShop)])
-                .with_width_bound(dimension_bound!(!))
-                .build()
-                .wrap(),
-        );
+                // Every entry is followed by the input for its price.
+                let entries_with_prices = cart
+                    .get()
+                    .iter()
+                    .zip(entries)
+                    .flat_map(|(item, entry)| [entry, Self::price_input(prices, item.item_id)])
+                    .collect::<Vec<_>>();
 
-        elements.insert(0, CartSum::new(&cart, |item| item.price.0, |item| item.metadata.1).wrap());
+                [title_input, zeny_limit_input]
+                    .into_iter()
+                    .chain(entries_with_prices)
+                    .chain(Self::buying_store_buttons(
+                        &cart,
+                        title,
+                        zeny_limit,
+                        prices,
+                        *maximum_item_count,
+                    ))
+                    .collect()
+            }
+        };
 
         let cart_remote = cart.new_remote();
         let state = ContainerState::new(elements);
 
-        Self { cart, cart_remote, state }
+        Self {
+            cart,
+            cart_remote,
+            kind,
+            state,
+        }
     }
 }
 
@@ -150,7 +354,7 @@ impl Element<InterfaceSettings> for BuyCartContainer {
             let weak_parent = self.state.state.parent_element.take();
             let weak_self = self.state.state.self_element.take().unwrap();
 
-            *self = Self::new(self.cart.clone());
+            *self = Self::new(self.cart.clone(), self.kind.clone());
             // important: link back after creating elements, otherwise focus navigation and
             // scrolling would break
             self.link_back(weak_self, weak_parent);
@@ -197,3 +401,56 @@ impl Element<InterfaceSettings> for BuyCartContainer {
         );
     }
 }
+
+#[cfg(test)]
+mod buying_store {
+    use ragnarok_packets::{ItemId, Price};
+
+    use super::{MAXIMUM_BUYING_STORE_PRICE, buying_store_items, parse_buying_store_price};
+
+    fn item(item_id: u32, amount: u32, price: &str) -> (ItemId, u32, String) {
+        (ItemId(item_id), amount, price.to_owned())
+    }
+
+    #[test]
+    fn parse_prices() {
+        assert_eq!(parse_buying_store_price("1500"), Some(Price(1500)));
+        assert_eq!(parse_buying_store_price(" 20 "), Some(Price(20)));
+        assert_eq!(
+            parse_buying_store_price(&MAXIMUM_BUYING_STORE_PRICE.to_string()),
+            Some(Price(MAXIMUM_BUYING_STORE_PRICE))
+        );
+        assert_eq!(parse_buying_store_price("0"), None);
+        assert_eq!(parse_buying_store_price("99990001"), None);
+        assert_eq!(parse_buying_store_price("cheap"), None);
+    }
+
+    #[test]
+    fn accept_valid_store() {
+        let (zeny_limit, items) = buying_store_items("Jellopy", "50000", &[item(909, 100, "10"), item(501, 5, "40")], 2).unwrap();
+
+        assert_eq!(zeny_limit, 50000);
+        assert_eq!(items.len(), 2);
+        assert_eq!(items[0].item_id, ItemId(909));
+        assert_eq!(items[0].amount, 100);
+        assert_eq!(items[0].price, Price(10));
+        assert_eq!(items[1].price, Price(40));
+    }
+
+    #[test]
+    fn reject_invalid_store() {
+        // Missing title.
+        assert!(buying_store_items(" ", "50000", &[item(909, 100, "10")], 2).is_none());
+        // Missing or invalid zeny limit.
+        assert!(buying_store_items("Jellopy", "", &[item(909, 100, "10")], 2).is_none());
+        assert!(buying_store_items("Jellopy", "0", &[item(909, 100, "10")], 2).is_none());
+        // No items.
+        assert!(buying_store_items("Jellopy", "50000", &[], 2).is_none());
+        // More items than the skill level allows.
+        assert!(buying_store_items("Jellopy", "50000", &[item(909, 1, "10"), item(501, 1, "40")], 1).is_none());
+        // Missing price.
+        assert!(buying_store_items("Jellopy", "50000", &[item(909, 100, "")], 2).is_none());
+        // Amount out of range.
+        assert!(buying_store_items("Jellopy", "50000", &[item(909, 10000, "10")], 2).is_none());
+    }
+}
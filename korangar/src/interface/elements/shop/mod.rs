@@ -7,9 +7,9 @@ mod sell_cart;
 mod sum;
 
 pub use self::buy::BuyContainer;
-pub use self::buy_cart::BuyCartContainer;
+pub use self::buy_cart::{BuyCartContainer, BuyCartKind};
 pub use self::display::{ItemDisplay, ItemResourceProvider};
 pub use self::entry::{ShopEntry, ShopEntryOperation};
 pub use self::sell::SellContainer;
-pub use self::sell_cart::SellCartContainer;
+pub use self::sell_cart::{SellCartContainer, SellCartKind};
 pub use self::sum::CartSum;
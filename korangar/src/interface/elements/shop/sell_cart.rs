@@ -1,14 +1,14 @@
 use korangar_interface::application::SizeTraitExt;
 use korangar_interface::elements::{
-    ButtonBuilder, ContainerState, Element, ElementCell, ElementState, ElementWrap, Focus, WeakElementCell,
+    ButtonBuilder, ContainerState, Element, ElementCell, ElementState, ElementWrap, Focus, FocusMode, InputFieldBuilder, WeakElementCell,
 };
 use korangar_interface::event::{ChangeEvent, ClickAction, HoverInformation};
 use korangar_interface::layout::PlacementResolver;
-use korangar_interface::state::{PlainRemote, PlainTrackedState, Remote, TrackedState, TrackedStateExt};
+use korangar_interface::state::{PlainRemote, PlainTrackedState, Remote, TrackedState, TrackedStateClone, TrackedStateExt};
 use korangar_interface::{dimension_bound, size_bound};
 use korangar_networking::SellItem;
 use num::Integer;
-use ragnarok_packets::SoldItemInformation;
+use ragnarok_packets::{InventoryIndex, OpenVendingStoreItemInformation, Price, SoldItemInformation};
 
 use super::CartSum;
 use crate::input::{MouseInputMode, UserEvent};
@@ -19,15 +19,183 @@ use crate::interface::theme::InterfaceTheme;
 use crate::renderer::InterfaceRenderer;
 use crate::world::ResourceMetadata;
 
+/// Highest price of a single item in a vending store.
+const MAXIMUM_VENDING_PRICE: u32 = 1_000_000_000;
+
+/// The price input of every item in the cart. The inputs are kept outside of
+/// the cart so they survive rebuilding the container.
+type PriceInputs = PlainTrackedState<Vec<(InventoryIndex, PlainTrackedState<String>)>>;
+
+/// What happens to the items in the cart.
+#[derive(Clone)]
+pub enum SellCartKind {
+    /// The items are sold to an NPC shop.
+    Shop,
+    /// The items are put up for sale in the player's vending store.
+    Vending {
+        title: PlainTrackedState<String>,
+        prices: PriceInputs,
+        maximum_item_count: usize,
+    },
+}
+
+/// Parse the price of an item in a vending store.
+fn parse_vending_price(text: &str) -> Option<Price> {
+    text.trim()
+        .parse()
+        .ok()
+        .filter(|price| (1..=MAXIMUM_VENDING_PRICE).contains(price))
+        .map(Price)
+}
+
+/// Validate the store title and the `(cart index, amount, price)` of every
+/// item. Returns `None` if the store can't be opened like this.
+fn vending_items(
+    title: &str,
+    items: &[(InventoryIndex, u16, String)],
+    maximum_item_count: usize,
+) -> Option<Vec<OpenVendingStoreItemInformation>> {
+    if title.trim().is_empty() || items.is_empty() || items.len() > maximum_item_count {
+        return None;
+    }
+
+    items
+        .iter()
+        .map(|(cart_index, amount, price)| {
+            Some(OpenVendingStoreItemInformation {
+                cart_index: *cart_index,
+                amount: (*amount > 0).then_some(*amount)?,
+                price: parse_vending_price(price)?,
+            })
+        })
+        .collect()
+}
+
 pub struct SellCartContainer {
     cart: PlainTrackedState<Vec<SellItem<(ResourceMetadata, u16)>>>,
     cart_remote: PlainRemote<Vec<SellItem<(ResourceMetadata, u16)>>>,
+    kind: SellCartKind,
     state: ContainerState<InterfaceSettings>,
 }
 
 impl SellCartContainer {
-    pub fn new(cart: PlainTrackedState<Vec<SellItem<(ResourceMetadata, u16)>>>) -> Self {
-        let mut elements = cart
+    fn get_vending_items(
+        cart: &PlainTrackedState<Vec<SellItem<(ResourceMetadata, u16)>>>,
+        title: &PlainTrackedState<String>,
+        prices: &PriceInputs,
+        maximum_item_count: usize,
+    ) -> Option<Vec<OpenVendingStoreItemInformation>> {
+        let price_inputs = prices.get();
+        let items: Vec<(InventoryIndex, u16, String)> = cart
+            .get()
+            .iter()
+            .map(|item| {
+                let price = price_inputs
+                    .iter()
+                    .find(|(index, _)| *index == item.inventory_index)
+                    .map(|(_, price)| price.cloned())
+                    .unwrap_or_default();
+
+                (item.inventory_index, item.metadata.1, price)
+            })
+            .collect();
+
+        vending_items(&title.get(), &items, maximum_item_count)
+    }
+
+    fn shop_buttons(cart: &PlainTrackedState<Vec<SellItem<(ResourceMetadata, u16)>>>) -> Vec<ElementCell<InterfaceSettings>> {
+        let cart = cart.clone();
+
+        vec![
+            CartSum::new(&cart, |item| item.price.0, |item| item.metadata.1 as u32).wrap(),
+            ButtonBuilder::new()
+                .with_text("sell")
+                .with_event(move || {
+                    let items = cart
+                        .get()
+                        .iter()
+                        .map(|item| SoldItemInformation {
+                            inventory_index: item.inventory_index,
+                            amount: item.metadata.1,
+                        })
+                        .collect();
+
+                    vec![ClickAction::Custom(UserEvent::SellItems { items })]
+                })
+                .with_width_bound(dimension_bound!(50%))
+                .build()
+                .wrap(),
+            ButtonBuilder::new()
+                .with_text("cancel")
+                .with_event(move || vec![ClickAction::Custom(UserEvent::CloseShop)])
+                .with_width_bound(dimension_bound!(!))
+                .build()
+                .wrap(),
+        ]
+    }
+
+    fn vending_buttons(
+        cart: &PlainTrackedState<Vec<SellItem<(ResourceMetadata, u16)>>>,
+        title: &PlainTrackedState<String>,
+        prices: &PriceInputs,
+        maximum_item_count: usize,
+    ) -> Vec<ElementCell<InterfaceSettings>> {
+        let cart = cart.clone();
+        let disabled_cart = cart.clone();
+        let title = title.clone();
+        let disabled_title = title.clone();
+        let prices = prices.clone();
+        let disabled_prices = prices.clone();
+
+        vec![
+            ButtonBuilder::new()
+                .with_text("open store")
+                .with_event(move || {
+                    let Some(items) = Self::get_vending_items(&cart, &title, &prices, maximum_item_count) else {
+                        return Vec::new();
+                    };
+
+                    vec![ClickAction::Custom(UserEvent::StartVending {
+                        title: title.get().trim().to_owned(),
+                        items,
+                    })]
+                })
+                .with_disabled_selector(move || {
+                    Self::get_vending_items(&disabled_cart, &disabled_title, &disabled_prices, maximum_item_count).is_none()
+                })
+                .with_width_bound(dimension_bound!(50%))
+                .build()
+                .wrap(),
+            ButtonBuilder::new()
+                .with_text("cancel")
+                .with_event(move || vec![ClickAction::Custom(UserEvent::CancelVending)])
+                .with_width_bound(dimension_bound!(!))
+                .build()
+                .wrap(),
+        ]
+    }
+
+    fn price_input(prices: &mut PriceInputs, inventory_index: InventoryIndex) -> ElementCell<InterfaceSettings> {
+        let price = prices.mutate(|prices| match prices.iter().find(|(index, _)| *index == inventory_index) {
+            Some((_, price)) => price.clone(),
+            None => {
+                let price = PlainTrackedState::<String>::default();
+                prices.push((inventory_index, price.clone()));
+                price
+            }
+        });
+
+        InputFieldBuilder::new()
+            .with_state(price)
+            .with_ghost_text("Price")
+            .with_enter_action(|| vec![ClickAction::FocusNext(FocusMode::FocusNext)])
+            .with_length(10)
+            .build()
+            .wrap()
+    }
+
+    pub fn new(cart: PlainTrackedState<Vec<SellItem<(ResourceMetadata, u16)>>>, mut kind: SellCartKind) -> Self {
+        let entries = cart
             .get()
             .iter()
             .enumerate()
@@ -64,50 +232,45 @@ impl SellCartContainer {
             .map(ElementWrap::wrap)
             .collect::<Vec<ElementCell<InterfaceSettings>>>();
 
-        {
-            let cart = cart.clone();
-
-            elements.insert(
-                0,
-                ButtonBuilder::new()
-                    .with_text("sell")
-                    .with_event(move || {
-                        let items = cart
-                            .get()
-                            .iter()
-                            .map(|item| SoldItemInformation {
-                                inventory_index: item.inventory_index,
-                                amount: item.metadata.1,
-                            })
-                            .collect();
-
-                        vec![ClickAction::Custom(UserEvent::SellItems { items })]
-                    })
-                    .with_width_bound(dimension_bound!(50%))
+        let elements = match &mut kind {
+            SellCartKind::Shop => Self::shop_buttons(&cart).into_iter().chain(entries).collect(),
+            SellCartKind::Vending {
+                title,
+                prices,
+                maximum_item_count,
+            } => {
+                let title_input = InputFieldBuilder::new()
+                    .with_state(title.clone())
+                    .with_ghost_text("Store title")
+                    .with_enter_action(|| vec![ClickAction::FocusNext(FocusMode::FocusNext)])
+                    .with_length(80)
                     .build()
-                    .wrap(),
-            );
-        }
+                    .wrap();
 
-        elements.insert(
-            1,
-            ButtonBuilder::new()
-                .with_text("cancel")
-                .with_event(move || vec![ClickAction::Custom(UserEvent::CloseShop)])
-                .with_width_bound(dimension_bound!(!))
-                .build()
-                .wrap(),
-        );
+                // Every entry is followed by the input for its price.
+                let entries_with_prices = cart
+                    .get()
+                    .iter()
+                    .zip(entries)
+                    .flat_map(|(item, entry)| [entry, Self::price_input(prices, item.inventory_index)])
+                    .collect::<Vec<_>>();
 
-        elements.insert(
-            0,
-            CartSum::new(&cart, |item| item.price.0, |item| item.metadata.1 as u32).wrap(),
-        );
+                std::iter::once(title_input)
+                    .chain(entries_with_prices)
+                    .chain(Self::vending_buttons(&cart, title, prices, *maximum_item_count))
+                    .collect()
+            }
+        };
 
         let cart_remote = cart.new_remote();
         let state = ContainerState::new(elements);
 
-        Self { cart, cart_remote, state }
+        Self {
+            cart,
+            cart_remote,
+            kind,
+            state,
+        }
     }
 }
 
@@ -157,7 +320,7 @@ impl Element<InterfaceSettings> for SellCartContainer {
             let weak_parent = self.state.state.parent_element.take();
             let weak_self = self.state.state.self_element.take().unwrap();
 
-            *self = Self::new(self.cart.clone());
+            *self = Self::new(self.cart.clone(), self.kind.clone());
             // important: link back after creating elements, otherwise focus navigation and
             // scrolling would break
             self.link_back(weak_self, weak_parent);
@@ -204,3 +367,55 @@ impl Element<InterfaceSettings> for SellCartContainer {
         );
     }
 }
+
+#[cfg(test)]
+mod vending {
+    use ragnarok_packets::{InventoryIndex, Price};
+
+    use super::{MAXIMUM_VENDING_PRICE, parse_vending_price, vending_items};
+
+    fn item(index: u16, amount: u16, price: &str) -> (InventoryIndex, u16, String) {
+        (InventoryIndex(index), amount, price.to_owned())
+    }
+
+    #[test]
+    fn parse_prices() {
+        assert_eq!(parse_vending_price("1500"), Some(Price(1500)));
+        assert_eq!(parse_vending_price(" 20 "), Some(Price(20)));
+        assert_eq!(
+            parse_vending_price(&MAXIMUM_VENDING_PRICE.to_string()),
+            Some(Price(MAXIMUM_VENDING_PRICE))
+        );
+        assert_eq!(parse_vending_price("0"), None);
+        assert_eq!(parse_vending_price("-5"), None);
+        assert_eq!(parse_vending_price("1000000001"), None);
+        assert_eq!(parse_vending_price("cheap"), None);
+        assert_eq!(parse_vending_price(""), None);
+    }
+
+    #[test]
+    fn accept_valid_store() {
+        let items = vending_items("Potions", &[item(2, 10, "50"), item(5, 1, "20000")], 3).unwrap();
+
+        assert_eq!(items.len(), 2);
+        assert_eq!(items[0].cart_index, InventoryIndex(2));
+        assert_eq!(items[0].amount, 10);
+        assert_eq!(items[0].price, Price(50));
+        assert_eq!(items[1].price, Price(20000));
+    }
+
+    #[test]
+    fn reject_invalid_store() {
+        // Missing title.
+        assert!(vending_items(" ", &[item(2, 10, "50")], 3).is_none());
+        // No items.
+        assert!(vending_items("Potions", &[], 3).is_none());
+        // More items than the skill level allows.
+        assert!(vending_items("Potions", &[item(1, 1, "5"), item(2, 1, "5")], 1).is_none());
+        // Missing or invalid price.
+        assert!(vending_items("Potions", &[item(2, 10, "")], 3).is_none());
+        assert!(vending_items("Potions", &[item(2, 10, "0")], 3).is_none());
+        // No amount.
+        assert!(vending_items("Potions", &[item(2, 0, "50")], 3).is_none());
+    }
+}
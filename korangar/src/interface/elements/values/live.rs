@@ -0,0 +1,95 @@
+use korangar_interface::elements::{Element, ElementState};
+use korangar_interface::event::ChangeEvent;
+use korangar_interface::layout::PlacementResolver;
+use korangar_interface::state::{PlainRemote, Remote};
+
+use crate::input::MouseInputMode;
+use crate::interface::application::InterfaceSettings;
+use crate::interface::layout::{ScreenClip, ScreenPosition};
+use crate::interface::theme::InterfaceTheme;
+use crate::renderer::InterfaceRenderer;
+
+/// Text that is selected from a remote and updated whenever the remote
+/// changes.
+pub struct LiveValue<T: 'static> {
+    remote: PlainRemote<T>,
+    selector: Box<dyn Fn(&T) -> Option<String>>,
+    cached_value: String,
+    state: ElementState<InterfaceSettings>,
+}
+
+impl<T: 'static> LiveValue<T> {
+    /// The selector returns `None` if the value does not exist anymore.
+    pub fn new(remote: PlainRemote<T>, selector: impl Fn(&T) -> Option<String> + 'static) -> Self {
+        let cached_value = Self::select(&remote, &selector);
+
+        Self {
+            remote,
+            selector: Box::new(selector),
+            cached_value,
+            state: ElementState::default(),
+        }
+    }
+
+    fn select(remote: &PlainRemote<T>, selector: &dyn Fn(&T) -> Option<String>) -> String {
+        selector(&remote.get()).unwrap_or_else(|| "-".to_owned())
+    }
+}
+
+impl<T: 'static> Element<InterfaceSettings> for LiveValue<T> {
+    fn get_state(&self) -> &ElementState<InterfaceSettings> {
+        &self.state
+    }
+
+    fn get_state_mut(&mut self) -> &mut ElementState<InterfaceSettings> {
+        &mut self.state
+    }
+
+    fn resolve(
+        &mut self,
+        placement_resolver: &mut PlacementResolver<InterfaceSettings>,
+        _application: &InterfaceSettings,
+        theme: &InterfaceTheme,
+    ) {
+        self.state.resolve(placement_resolver, &theme.value.size_bound);
+    }
+
+    fn update(&mut self) -> Option<ChangeEvent> {
+        if !self.remote.consume_changed() {
+            return None;
+        }
+
+        let value = Self::select(&self.remote, &self.selector);
+
+        if value != self.cached_value {
+            self.cached_value = value;
+            return Some(ChangeEvent::RENDER_WINDOW);
+        }
+
+        None
+    }
+
+    fn render(
+        &self,
+        renderer: &InterfaceRenderer,
+        application: &InterfaceSettings,
+        theme: &InterfaceTheme,
+        parent_position: ScreenPosition,
+        screen_clip: ScreenClip,
+        _hovered_element: Option<&dyn Element<InterfaceSettings>>,
+        _focused_element: Option<&dyn Element<InterfaceSettings>>,
+        _mouse_mode: &MouseInputMode,
+        _second_theme: bool,
+    ) {
+        let mut renderer = self.state.element_renderer(renderer, application, parent_position, screen_clip);
+
+        renderer.render_background(theme.value.corner_radius.get(), theme.value.background_color.get());
+
+        renderer.render_text(
+            &self.cached_value,
+            theme.value.text_offset.get(),
+            theme.value.foreground_color.get(),
+            theme.value.font_size.get(),
+        );
+    }
+}
@@ -1,7 +1,11 @@
 mod array;
 mod color;
+#[cfg(feature = "debug")]
+mod live;
 mod number;
 
 pub use self::array::MutableArrayValue;
 pub use self::color::MutableColorValue;
+#[cfg(feature = "debug")]
+pub use self::live::LiveValue;
 pub use self::number::MutableNumberValue;
@@ -7,7 +7,10 @@ use crate::world::ResourceMetadata;
 #[derive(Clone, Copy, Debug, PartialEq)]
 pub enum ItemSource {
     Inventory,
+    Cart,
+    Mail,
     Equipment { position: EquipPosition },
+    Hotbar { slot: HotbarSlot },
 }
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
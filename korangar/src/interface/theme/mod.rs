@@ -752,6 +752,7 @@ pub struct InputTheme {
     pub text_color: Mutable<Color, Render>,
     pub ghost_text_color: Mutable<Color, Render>,
     pub focused_text_color: Mutable<Color, Render>,
+    pub selection_color: Mutable<Color, Render>,
     pub corner_radius: MutableRange<CornerRadius, Render>,
     pub font_size: MutableRange<FontSize, Render>,
     pub text_offset: MutableRange<ScreenPosition, Render>,
@@ -769,6 +770,7 @@ impl ThemeDefault<DefaultMenu> for InputTheme {
             text_color: Mutable::new(Color::monochrome_u8(200)),
             ghost_text_color: Mutable::new(Color::monochrome_u8(100)),
             focused_text_color: Mutable::new(Color::monochrome_u8(200)),
+            selection_color: Mutable::new(Color::rgb_u8(90, 80, 140)),
             corner_radius: MutableRange::new(
                 CornerRadius::uniform(26.0),
                 CornerRadius::default(),
@@ -796,6 +798,7 @@ impl ThemeDefault<DefaultMain> for InputTheme {
             text_color: Mutable::new(Color::monochrome_u8(200)),
             ghost_text_color: Mutable::new(Color::monochrome_u8(100)),
             focused_text_color: Mutable::new(Color::monochrome_u8(200)),
+            selection_color: Mutable::new(Color::rgb_u8(70, 90, 130)),
             corner_radius: MutableRange::new(CornerRadius::uniform(6.0), CornerRadius::default(), CornerRadius::uniform(30.0)),
             font_size: MutableRange::new(FontSize::new(14.0), FontSize::new(6.0), FontSize::new(50.0)),
             text_offset: MutableRange::new(
@@ -835,6 +838,10 @@ impl korangar_interface::theme::InputTheme<InterfaceSettings> for InputTheme {
         self.focused_text_color.get()
     }
 
+    fn selection_color(&self) -> Color {
+        self.selection_color.get()
+    }
+
     fn corner_radius(&self) -> CornerRadius {
         self.corner_radius.get()
     }
@@ -922,6 +929,322 @@ impl korangar_interface::theme::ChatTheme<InterfaceSettings> for ChatTheme {
     }
 }
 
+#[derive(Serialize, Deserialize, PrototypeElement)]
+pub struct TooltipTheme {
+    pub background_color: Mutable<Color, Nothing>,
+    pub title_color: Mutable<Color, Nothing>,
+    pub foreground_color: Mutable<Color, Nothing>,
+    pub corner_radius: MutableRange<CornerRadius, Nothing>,
+    pub text_offset: MutableRange<ScreenPosition, Nothing>,
+    pub mouse_offset: MutableRange<ScreenPosition, Nothing>,
+    pub title_font_size: MutableRange<FontSize, Nothing>,
+    pub font_size: MutableRange<FontSize, Nothing>,
+    pub maximum_width: MutableRange<f32, Nothing>,
+    pub delay: MutableRange<f32, Nothing>,
+}
+
+impl ThemeDefault<DefaultMenu> for TooltipTheme {
+    fn default() -> Self {
+        Self {
+            background_color: Mutable::new(Color::rgba_u8(245, 242, 235, 240)),
+            title_color: Mutable::new(Color::rgb_u8(60, 40, 110)),
+            foreground_color: Mutable::new(Color::monochrome_u8(30)),
+            corner_radius: MutableRange::new(CornerRadius::uniform(6.0), CornerRadius::default(), CornerRadius::uniform(30.0)),
+            text_offset: MutableRange::new(
+                ScreenPosition { left: 8.0, top: 6.0 },
+                ScreenPosition::default(),
+                ScreenPosition::uniform(50.0),
+            ),
+            mouse_offset: MutableRange::new(
+                ScreenPosition { left: 16.0, top: 16.0 },
+                ScreenPosition::default(),
+                ScreenPosition::uniform(50.0),
+            ),
+            title_font_size: MutableRange::new(FontSize::new(15.0), FontSize::new(6.0), FontSize::new(50.0)),
+            font_size: MutableRange::new(FontSize::new(13.0), FontSize::new(6.0), FontSize::new(50.0)),
+            maximum_width: MutableRange::new(300.0, 100.0, 800.0),
+            delay: MutableRange::new(0.4, 0.0, 3.0),
+        }
+    }
+}
+
+impl ThemeDefault<DefaultMain> for TooltipTheme {
+    fn default() -> Self {
+        Self {
+            background_color: Mutable::new(Color::rgba_u8(240, 240, 240, 240)),
+            title_color: Mutable::new(Color::rgb_u8(40, 50, 110)),
+            foreground_color: Mutable::new(Color::monochrome_u8(30)),
+            corner_radius: MutableRange::new(CornerRadius::uniform(4.0), CornerRadius::default(), CornerRadius::uniform(30.0)),
+            text_offset: MutableRange::new(
+                ScreenPosition { left: 6.0, top: 4.0 },
+                ScreenPosition::default(),
+                ScreenPosition::uniform(50.0),
+            ),
+            mouse_offset: MutableRange::new(
+                ScreenPosition { left: 16.0, top: 16.0 },
+                ScreenPosition::default(),
+                ScreenPosition::uniform(50.0),
+            ),
+            title_font_size: MutableRange::new(FontSize::new(14.0), FontSize::new(6.0), FontSize::new(50.0)),
+            font_size: MutableRange::new(FontSize::new(12.0), FontSize::new(6.0), FontSize::new(50.0)),
+            maximum_width: MutableRange::new(280.0, 100.0, 800.0),
+            delay: MutableRange::new(0.4, 0.0, 3.0),
+        }
+    }
+}
+
+impl korangar_interface::theme::TooltipTheme<InterfaceSettings> for TooltipTheme {
+    fn background_color(&self) -> Color {
+        self.background_color.get()
+    }
+
+    fn title_color(&self) -> Color {
+        self.title_color.get()
+    }
+
+    fn foreground_color(&self) -> Color {
+        self.foreground_color.get()
+    }
+
+    fn corner_radius(&self) -> CornerRadius {
+        self.corner_radius.get()
+    }
+
+    fn text_offset(&self) -> ScreenPosition {
+        self.text_offset.get()
+    }
+
+    fn mouse_offset(&self) -> ScreenPosition {
+        self.mouse_offset.get()
+    }
+
+    fn title_font_size(&self) -> FontSize {
+        self.title_font_size.get()
+    }
+
+    fn font_size(&self) -> FontSize {
+        self.font_size.get()
+    }
+
+    fn maximum_width(&self) -> f32 {
+        self.maximum_width.get()
+    }
+
+    fn delay(&self) -> f32 {
+        self.delay.get()
+    }
+}
+
+#[derive(Serialize, Deserialize, PrototypeElement)]
+pub struct TabTheme {
+    pub background_color: Mutable<Color, Render>,
+    pub hovered_background_color: Mutable<Color, Render>,
+    pub selected_background_color: Mutable<Color, Render>,
+    pub foreground_color: Mutable<Color, Render>,
+    pub selected_foreground_color: Mutable<Color, Render>,
+    pub corner_radius: MutableRange<CornerRadius, Render>,
+    pub text_offset: MutableRange<ScreenPosition, Render>,
+    pub font_size: MutableRange<FontSize, Render>,
+    pub height_bound: DimensionBound,
+}
+
+impl ThemeDefault<DefaultMenu> for TabTheme {
+    fn default() -> Self {
+        Self {
+            background_color: Mutable::new(Color::rgb_u8(90, 50, 150)),
+            hovered_background_color: Mutable::new(Color::rgb_u8(200, 70, 255)),
+            selected_background_color: Mutable::new(Color::rgb_u8(150, 70, 255)),
+            foreground_color: Mutable::new(Color::monochrome_u8(170)),
+            selected_foreground_color: Mutable::new(Color::monochrome_u8(230)),
+            corner_radius: MutableRange::new(CornerRadius::uniform(8.0), CornerRadius::default(), CornerRadius::uniform(30.0)),
+            text_offset: MutableRange::new(
+                ScreenPosition { left: 10.0, top: 4.0 },
+                ScreenPosition::default(),
+                ScreenPosition { left: 100.0, top: 20.0 },
+            ),
+            font_size: MutableRange::new(FontSize::new(14.0), FontSize::new(6.0), FontSize::new(30.0)),
+            height_bound: dimension_bound!(22),
+        }
+    }
+}
+
+impl ThemeDefault<DefaultMain> for TabTheme {
+    fn default() -> Self {
+        Self {
+            background_color: Mutable::new(Color::monochrome_u8(70)),
+            hovered_background_color: Mutable::new(Color::rgb_u8(140, 120, 140)),
+            selected_background_color: Mutable::new(Color::monochrome_u8(110)),
+            foreground_color: Mutable::new(Color::monochrome_u8(160)),
+            selected_foreground_color: Mutable::new(Color::monochrome_u8(220)),
+            corner_radius: MutableRange::new(CornerRadius::uniform(4.0), CornerRadius::default(), CornerRadius::uniform(30.0)),
+            text_offset: MutableRange::new(
+                ScreenPosition { left: 5.0, top: 1.0 },
+                ScreenPosition::default(),
+                ScreenPosition { left: 100.0, top: 20.0 },
+            ),
+            font_size: MutableRange::new(FontSize::new(14.0), FontSize::new(6.0), FontSize::new(30.0)),
+            height_bound: dimension_bound!(16),
+        }
+    }
+}
+
+impl korangar_interface::theme::TabTheme<InterfaceSettings> for TabTheme {
+    fn background_color(&self) -> Color {
+        self.background_color.get()
+    }
+
+    fn hovered_background_color(&self) -> Color {
+        self.hovered_background_color.get()
+    }
+
+    fn selected_background_color(&self) -> Color {
+        self.selected_background_color.get()
+    }
+
+    fn foreground_color(&self) -> Color {
+        self.foreground_color.get()
+    }
+
+    fn selected_foreground_color(&self) -> Color {
+        self.selected_foreground_color.get()
+    }
+
+    fn corner_radius(&self) -> CornerRadius {
+        self.corner_radius.get()
+    }
+
+    fn text_offset(&self) -> ScreenPosition {
+        self.text_offset.get()
+    }
+
+    fn font_size(&self) -> FontSize {
+        self.font_size.get()
+    }
+
+    fn height_bound(&self) -> DimensionBound {
+        self.height_bound
+    }
+}
+
+#[derive(Serialize, Deserialize, PrototypeElement)]
+pub struct TableTheme {
+    pub header_background_color: Mutable<Color, Render>,
+    pub hovered_header_background_color: Mutable<Color, Render>,
+    pub header_foreground_color: Mutable<Color, Render>,
+    pub row_background_color: Mutable<Color, Render>,
+    pub second_row_background_color: Mutable<Color, Render>,
+    pub row_foreground_color: Mutable<Color, Render>,
+    pub resize_handle_color: Mutable<Color, Render>,
+    pub corner_radius: MutableRange<CornerRadius, Render>,
+    pub text_offset: MutableRange<ScreenPosition, Render>,
+    pub font_size: MutableRange<FontSize, Render>,
+    pub header_height_bound: DimensionBound,
+    pub row_height: MutableRange<f32, Resolve>,
+    pub minimum_column_width: MutableRange<f32, Resolve>,
+}
+
+impl ThemeDefault<DefaultMenu> for TableTheme {
+    fn default() -> Self {
+        Self {
+            header_background_color: Mutable::new(Color::rgb_u8(150, 70, 255)),
+            hovered_header_background_color: Mutable::new(Color::rgb_u8(200, 70, 255)),
+            header_foreground_color: Mutable::new(Color::monochrome_u8(220)),
+            row_background_color: Mutable::new(Color::rgba_u8(60, 40, 90, 160)),
+            second_row_background_color: Mutable::new(Color::rgba_u8(80, 55, 115, 160)),
+            row_foreground_color: Mutable::new(Color::monochrome_u8(200)),
+            resize_handle_color: Mutable::new(Color::rgb_u8(220, 170, 215)),
+            corner_radius: MutableRange::new(CornerRadius::uniform(6.0), CornerRadius::default(), CornerRadius::uniform(30.0)),
+            text_offset: MutableRange::new(
+                ScreenPosition { left: 6.0, top: 3.0 },
+                ScreenPosition::default(),
+                ScreenPosition { left: 100.0, top: 20.0 },
+            ),
+            font_size: MutableRange::new(FontSize::new(13.0), FontSize::new(6.0), FontSize::new(30.0)),
+            header_height_bound: dimension_bound!(20),
+            row_height: MutableRange::new(20.0, 8.0, 60.0),
+            minimum_column_width: MutableRange::new(8.0, 1.0, 50.0),
+        }
+    }
+}
+
+impl ThemeDefault<DefaultMain> for TableTheme {
+    fn default() -> Self {
+        Self {
+            header_background_color: Mutable::new(Color::monochrome_u8(100)),
+            hovered_header_background_color: Mutable::new(Color::rgb_u8(140, 120, 140)),
+            header_foreground_color: Mutable::new(Color::monochrome_u8(210)),
+            row_background_color: Mutable::new(Color::monochrome_u8(45)),
+            second_row_background_color: Mutable::new(Color::monochrome_u8(55)),
+            row_foreground_color: Mutable::new(Color::monochrome_u8(190)),
+            resize_handle_color: Mutable::new(Color::rgb_u8(220, 170, 215)),
+            corner_radius: MutableRange::new(CornerRadius::uniform(3.0), CornerRadius::default(), CornerRadius::uniform(30.0)),
+            text_offset: MutableRange::new(
+                ScreenPosition { left: 4.0, top: 1.0 },
+                ScreenPosition::default(),
+                ScreenPosition { left: 100.0, top: 20.0 },
+            ),
+            font_size: MutableRange::new(FontSize::new(12.0), FontSize::new(6.0), FontSize::new(30.0)),
+            header_height_bound: dimension_bound!(16),
+            row_height: MutableRange::new(16.0, 8.0, 60.0),
+            minimum_column_width: MutableRange::new(8.0, 1.0, 50.0),
+        }
+    }
+}
+
+impl korangar_interface::theme::TableTheme<InterfaceSettings> for TableTheme {
+    fn header_background_color(&self) -> Color {
+        self.header_background_color.get()
+    }
+
+    fn hovered_header_background_color(&self) -> Color {
+        self.hovered_header_background_color.get()
+    }
+
+    fn header_foreground_color(&self) -> Color {
+        self.header_foreground_color.get()
+    }
+
+    fn row_background_color(&self) -> Color {
+        self.row_background_color.get()
+    }
+
+    fn second_row_background_color(&self) -> Color {
+        self.second_row_background_color.get()
+    }
+
+    fn row_foreground_color(&self) -> Color {
+        self.row_foreground_color.get()
+    }
+
+    fn resize_handle_color(&self) -> Color {
+        self.resize_handle_color.get()
+    }
+
+    fn corner_radius(&self) -> CornerRadius {
+        self.corner_radius.get()
+    }
+
+    fn text_offset(&self) -> ScreenPosition {
+        self.text_offset.get()
+    }
+
+    fn font_size(&self) -> FontSize {
+        self.font_size.get()
+    }
+
+    fn header_height_bound(&self) -> DimensionBound {
+        self.header_height_bound
+    }
+
+    fn row_height(&self) -> f32 {
+        self.row_height.get()
+    }
+
+    fn minimum_column_width(&self) -> f32 {
+        self.minimum_column_width.get()
+    }
+}
+
 #[derive(Serialize, Deserialize, PrototypeElement)]
 pub struct CursorTheme {
     pub color: Mutable<Color, Nothing>,
@@ -1119,6 +1442,9 @@ pub struct InterfaceTheme {
     pub input: InputTheme,
     pub profiler: ProfilerTheme,
     pub chat: ChatTheme,
+    pub tooltip: TooltipTheme,
+    pub tab: TabTheme,
+    pub table: TableTheme,
 }
 
 impl<T: ThemeKindMarker> ThemeDefault<T> for InterfaceTheme
@@ -1133,6 +1459,9 @@ where
     InputTheme: ThemeDefault<T>,
     ProfilerTheme: ThemeDefault<T>,
     ChatTheme: ThemeDefault<T>,
+    TooltipTheme: ThemeDefault<T>,
+    TabTheme: ThemeDefault<T>,
+    TableTheme: ThemeDefault<T>,
 {
     fn default() -> Self {
         Self {
@@ -1146,6 +1475,9 @@ where
             input: ThemeDefault::<T>::default(),
             profiler: ThemeDefault::<T>::default(),
             chat: ThemeDefault::<T>::default(),
+            tooltip: ThemeDefault::<T>::default(),
+            tab: ThemeDefault::<T>::default(),
+            table: ThemeDefault::<T>::default(),
         }
     }
 }
@@ -1160,6 +1492,9 @@ impl korangar_interface::theme::InterfaceTheme for InterfaceTheme {
     type Profiler = ProfilerTheme;
     type Settings = InterfaceSettings;
     type Slider = SliderTheme;
+    type Tab = TabTheme;
+    type Table = TableTheme;
+    type Tooltip = TooltipTheme;
     type Value = ValueTheme;
     type Window = WindowTheme;
 
@@ -1202,6 +1537,18 @@ impl korangar_interface::theme::InterfaceTheme for InterfaceTheme {
     fn chat(&self) -> &Self::Chat {
         &self.chat
     }
+
+    fn tooltip(&self) -> &Self::Tooltip {
+        &self.tooltip
+    }
+
+    fn tab(&self) -> &Self::Tab {
+        &self.tab
+    }
+
+    fn table(&self) -> &Self::Table {
+        &self.table
+    }
 }
 
 #[derive(Default, Serialize, Deserialize, PrototypeElement)]
@@ -0,0 +1,56 @@
+use derive_new::new;
+use korangar_interface::elements::{ButtonBuilder, ElementWrap};
+use korangar_interface::size_bound;
+use korangar_interface::state::PlainRemote;
+use korangar_interface::windows::{PrototypeWindow, Window, WindowBuilder};
+use korangar_networking::InventoryItem;
+
+use crate::input::UserEvent;
+use crate::interface::application::InterfaceSettings;
+use crate::interface::elements::{CartStatus, InventoryContainer};
+use crate::interface::layout::ScreenSize;
+use crate::interface::resource::ItemSource;
+use crate::interface::windows::WindowCache;
+use crate::inventory::CartCapacity;
+use crate::world::ResourceMetadata;
+
+#[derive(new)]
+pub struct CartWindow {
+    items: PlainRemote<Vec<InventoryItem<ResourceMetadata>>>,
+    capacity: PlainRemote<CartCapacity>,
+}
+
+impl CartWindow {
+    pub const WINDOW_CLASS: &'static str = "cart";
+}
+
+impl PrototypeWindow<InterfaceSettings> for CartWindow {
+    fn window_class(&self) -> Option<&str> {
+        Self::WINDOW_CLASS.into()
+    }
+
+    fn to_window(
+        &self,
+        window_cache: &WindowCache,
+        application: &InterfaceSettings,
+        available_space: ScreenSize,
+    ) -> Window<InterfaceSettings> {
+        let elements = vec![
+            CartStatus::new(self.capacity.clone()).wrap(),
+            InventoryContainer::new(self.items.clone(), ItemSource::Cart, 100).wrap(),
+            ButtonBuilder::new()
+                .with_text("Remove cart")
+                .with_event(UserEvent::RemoveCart)
+                .build()
+                .wrap(),
+        ];
+
+        WindowBuilder::new()
+            .with_title("Cart".to_string())
+            .with_class(Self::WINDOW_CLASS.to_string())
+            .with_size_bound(size_bound!(300 > 400 < 500, ? < 80%))
+            .with_elements(elements)
+            .closable()
+            .build(window_cache, application, available_space)
+    }
+}
@@ -0,0 +1,93 @@
+use derive_new::new;
+use korangar_interface::elements::{ButtonBuilder, ElementWrap, InputFieldBuilder, Text};
+use korangar_interface::event::ClickAction;
+use korangar_interface::state::{PlainTrackedState, TrackedState};
+use korangar_interface::windows::{PrototypeWindow, Window, WindowBuilder};
+use korangar_interface::{dimension_bound, size_bound};
+use ragnarok_packets::InventoryIndex;
+
+use crate::input::UserEvent;
+use crate::interface::application::InterfaceSettings;
+use crate::interface::layout::ScreenSize;
+use crate::interface::windows::WindowCache;
+
+/// Asks the player how many items of a stack should be dropped on the ground.
+#[derive(new)]
+pub struct DropItemWindow {
+    index: InventoryIndex,
+    name: String,
+    maximum_amount: u16,
+}
+
+impl DropItemWindow {
+    pub const WINDOW_CLASS: &'static str = "drop_item";
+
+    fn parse_amount(text: &str, maximum_amount: u16) -> Option<u16> {
+        text.trim().parse().ok().filter(|amount| (1..=maximum_amount).contains(amount))
+    }
+}
+
+impl PrototypeWindow<InterfaceSettings> for DropItemWindow {
+    fn window_class(&self) -> Option<&str> {
+        Self::WINDOW_CLASS.into()
+    }
+
+    fn to_window(
+        &self,
+        window_cache: &WindowCache,
+        application: &InterfaceSettings,
+        available_space: ScreenSize,
+    ) -> Window<InterfaceSettings> {
+        let amount = PlainTrackedState::new(self.maximum_amount.to_string());
+        let index = self.index;
+        let maximum_amount = self.maximum_amount;
+
+        let drop_action = {
+            let amount = amount.clone();
+
+            move || {
+                Self::parse_amount(&amount.get(), maximum_amount)
+                    .map(|amount| vec![ClickAction::Custom(UserEvent::DropItem { index, amount })])
+                    .unwrap_or_default()
+            }
+        };
+
+        let drop_disabled_selector = {
+            let amount = amount.clone();
+
+            move || Self::parse_amount(&amount.get(), maximum_amount).is_none()
+        };
+
+        let elements = vec![
+            Text::default().with_text(self.name.clone()).wrap(),
+            InputFieldBuilder::new()
+                .with_state(amount)
+                .with_ghost_text("Amount")
+                .with_enter_action(drop_action.clone())
+                .with_length(5)
+                .build()
+                .wrap(),
+            ButtonBuilder::new()
+                .with_text("Drop")
+                .with_disabled_selector(drop_disabled_selector)
+                .with_event(drop_action)
+                .with_width_bound(dimension_bound!(50%))
+                .build()
+                .wrap(),
+            ButtonBuilder::new()
+                .with_text("Cancel")
+                .with_event(|| vec![ClickAction::CloseWindow])
+                .with_width_bound(dimension_bound!(!))
+                .build()
+                .wrap(),
+        ];
+
+        WindowBuilder::new()
+            .with_title("Drop item".to_string())
+            .with_class(Self::WINDOW_CLASS.to_string())
+            .with_size_bound(size_bound!(200 > 250 < 300, ?))
+            .with_elements(elements)
+            .closable()
+            .build(window_cache, application, available_space)
+    }
+}
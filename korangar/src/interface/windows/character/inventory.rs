@@ -8,6 +8,7 @@ use korangar_networking::InventoryItem;
 use crate::interface::application::InterfaceSettings;
 use crate::interface::elements::InventoryContainer;
 use crate::interface::layout::ScreenSize;
+use crate::interface::resource::ItemSource;
 use crate::interface::windows::WindowCache;
 use crate::world::ResourceMetadata;
 
@@ -31,7 +32,7 @@ impl PrototypeWindow<InterfaceSettings> for InventoryWindow {
         application: &InterfaceSettings,
         available_space: ScreenSize,
     ) -> Window<InterfaceSettings> {
-        let elements = vec![InventoryContainer::new(self.items.clone()).wrap()];
+        let elements = vec![InventoryContainer::new(self.items.clone(), ItemSource::Inventory, 40).wrap()];
 
         WindowBuilder::new()
             .with_title("Inventory".to_string())
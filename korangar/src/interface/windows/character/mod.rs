@@ -1,4 +1,6 @@
+mod cart;
 mod creation;
+mod drop;
 mod equipment;
 mod hotbar;
 mod inventory;
@@ -6,8 +8,11 @@ mod overview;
 mod respawn;
 mod selection;
 mod skill_tree;
+mod status;
 
+pub use self::cart::CartWindow;
 pub use self::creation::CharacterCreationWindow;
+pub use self::drop::DropItemWindow;
 pub use self::equipment::EquipmentWindow;
 pub use self::hotbar::HotbarWindow;
 pub use self::inventory::InventoryWindow;
@@ -15,3 +20,4 @@ pub use self::overview::CharacterOverviewWindow;
 pub use self::respawn::RespawnWindow;
 pub use self::selection::CharacterSelectionWindow;
 pub use self::skill_tree::SkillTreeWindow;
+pub use self::status::StatusWindow;
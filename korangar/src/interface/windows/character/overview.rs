@@ -33,11 +33,21 @@ impl PrototypeWindow<InterfaceSettings> for CharacterOverviewWindow {
             Text::default()
                 .with_text(|| format!("job level: {}", player.get_job_level()))
                 .wrap(),*/
+            ButtonBuilder::new()
+                .with_text("Status")
+                .with_event(UserEvent::OpenStatusWindow)
+                .build()
+                .wrap(),
             ButtonBuilder::new()
                 .with_text("Inventory")
                 .with_event(UserEvent::OpenInventoryWindow)
                 .build()
                 .wrap(),
+            ButtonBuilder::new()
+                .with_text("Cart")
+                .with_event(UserEvent::OpenCartWindow)
+                .build()
+                .wrap(),
             ButtonBuilder::new()
                 .with_text("Equipment")
                 .with_event(UserEvent::OpenEquipmentWindow)
@@ -53,6 +63,26 @@ impl PrototypeWindow<InterfaceSettings> for CharacterOverviewWindow {
                 .with_event(UserEvent::OpenFriendsWindow)
                 .build()
                 .wrap(),
+            ButtonBuilder::new()
+                .with_text("Homunculus")
+                .with_event(UserEvent::OpenHomunculusWindow)
+                .build()
+                .wrap(),
+            ButtonBuilder::new()
+                .with_text("Mercenary")
+                .with_event(UserEvent::OpenMercenaryWindow)
+                .build()
+                .wrap(),
+            ButtonBuilder::new()
+                .with_text("Pet")
+                .with_event(UserEvent::OpenPetWindow)
+                .build()
+                .wrap(),
+            ButtonBuilder::new()
+                .with_text("Mailbox")
+                .with_event(UserEvent::OpenMailboxWindow)
+                .build()
+                .wrap(),
             ButtonBuilder::new()
                 .with_text("Menu")
                 .with_event(UserEvent::OpenMenuWindow)
@@ -11,11 +11,12 @@ use crate::inventory::Skill;
 
 pub struct SkillTreeWindow {
     skills: PlainRemote<Vec<Skill>>,
+    skill_points: PlainRemote<u32>,
 }
 
 impl SkillTreeWindow {
-    pub fn new(skills: PlainRemote<Vec<Skill>>) -> Self {
-        Self { skills }
+    pub fn new(skills: PlainRemote<Vec<Skill>>, skill_points: PlainRemote<u32>) -> Self {
+        Self { skills, skill_points }
     }
 }
 
@@ -34,7 +35,7 @@ impl PrototypeWindow<InterfaceSettings> for SkillTreeWindow {
         application: &InterfaceSettings,
         available_space: ScreenSize,
     ) -> Window<InterfaceSettings> {
-        let elements = vec![SkillTreeContainer::new(self.skills.clone()).wrap()];
+        let elements = vec![SkillTreeContainer::new(self.skills.clone(), self.skill_points.clone()).wrap()];
 
         WindowBuilder::new()
             .with_title("Skill tree".to_string())
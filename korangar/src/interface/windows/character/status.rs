@@ -0,0 +1,43 @@
+use derive_new::new;
+use korangar_interface::elements::ElementWrap;
+use korangar_interface::size_bound;
+use korangar_interface::state::PlainRemote;
+use korangar_interface::windows::{PrototypeWindow, Window, WindowBuilder};
+
+use crate::interface::application::InterfaceSettings;
+use crate::interface::elements::StatusView;
+use crate::interface::layout::ScreenSize;
+use crate::interface::windows::WindowCache;
+use crate::world::PlayerStatus;
+
+#[derive(new)]
+pub struct StatusWindow {
+    status: PlainRemote<PlayerStatus>,
+}
+
+impl StatusWindow {
+    pub const WINDOW_CLASS: &'static str = "status";
+}
+
+impl PrototypeWindow<InterfaceSettings> for StatusWindow {
+    fn window_class(&self) -> Option<&str> {
+        Self::WINDOW_CLASS.into()
+    }
+
+    fn to_window(
+        &self,
+        window_cache: &WindowCache,
+        application: &InterfaceSettings,
+        available_space: ScreenSize,
+    ) -> Window<InterfaceSettings> {
+        let elements = vec![StatusView::new(self.status.clone()).wrap()];
+
+        WindowBuilder::new()
+            .with_title("Status".to_string())
+            .with_class(Self::WINDOW_CLASS.to_string())
+            .with_size_bound(size_bound!(250 > 300 < 400, ?))
+            .with_elements(elements)
+            .closable()
+            .build(window_cache, application, available_space)
+    }
+}
@@ -0,0 +1,119 @@
+use derive_new::new;
+use korangar_interface::elements::{ButtonBuilder, ElementWrap, InputFieldBuilder};
+use korangar_interface::event::ClickAction;
+use korangar_interface::state::{PlainRemote, PlainTrackedState, TrackedStateTake};
+use korangar_interface::windows::{PrototypeWindow, Window, WindowBuilder};
+use korangar_interface::{dimension_bound, size_bound};
+use korangar_networking::CompanionType;
+use ragnarok_packets::{HomunculusCommand, HomunculusInformationPacket};
+
+use crate::input::UserEvent;
+use crate::interface::application::InterfaceSettings;
+use crate::interface::elements::CompanionStatus;
+use crate::interface::layout::ScreenSize;
+use crate::interface::windows::WindowCache;
+use crate::inventory::Companion;
+
+#[derive(new)]
+pub struct HomunculusWindow {
+    homunculus: PlainRemote<Companion<HomunculusInformationPacket>>,
+}
+
+impl HomunculusWindow {
+    pub const WINDOW_CLASS: &'static str = "homunculus";
+
+    fn get_values(information: &HomunculusInformationPacket) -> Vec<(&'static str, String)> {
+        vec![
+            ("Name", information.name.clone()),
+            ("Level", information.level.to_string()),
+            (
+                "Health points",
+                format!("{} / {}", information.health_points, information.maximum_health_points),
+            ),
+            (
+                "Spell points",
+                format!("{} / {}", information.spell_points, information.maximum_spell_points),
+            ),
+            (
+                "Experience",
+                format!("{} / {}", information.experience, information.next_experience),
+            ),
+            ("Hunger", information.hunger.to_string()),
+            ("Intimacy", information.intimacy.to_string()),
+            ("Attack", information.attack.to_string()),
+            ("Magic attack", information.magic_attack.to_string()),
+            ("Hit", information.hit.to_string()),
+            ("Critical", information.critical.to_string()),
+            ("Defense", information.defense.to_string()),
+            ("Magic defense", information.magic_defense.to_string()),
+            ("Flee", information.flee.to_string()),
+            ("Attack speed", information.attack_speed.to_string()),
+            ("Skill points", information.skill_points.to_string()),
+        ]
+    }
+}
+
+impl PrototypeWindow<InterfaceSettings> for HomunculusWindow {
+    fn window_class(&self) -> Option<&str> {
+        Self::WINDOW_CLASS.into()
+    }
+
+    fn to_window(
+        &self,
+        window_cache: &WindowCache,
+        application: &InterfaceSettings,
+        available_space: ScreenSize,
+    ) -> Window<InterfaceSettings> {
+        let name = PlainTrackedState::<String>::default();
+
+        let rename_action = {
+            let mut name = name.clone();
+
+            Box::new(move || {
+                let taken_string = name.take();
+
+                (!taken_string.is_empty())
+                    .then_some(vec![ClickAction::Custom(UserEvent::RenameHomunculus(taken_string))])
+                    .unwrap_or_default()
+            })
+        };
+
+        let elements = vec![
+            CompanionStatus::new(self.homunculus.clone(), Self::get_values, "No homunculus").wrap(),
+            InputFieldBuilder::new()
+                .with_state(name)
+                .with_ghost_text("New name")
+                .with_enter_action(rename_action.clone())
+                .with_length(24)
+                .with_width_bound(dimension_bound!(70%))
+                .build()
+                .wrap(),
+            ButtonBuilder::new()
+                .with_text("Rename")
+                .with_event(rename_action)
+                .with_width_bound(dimension_bound!(!))
+                .build()
+                .wrap(),
+            ButtonBuilder::new()
+                .with_text("Feed")
+                .with_event(UserEvent::HomunculusCommand(HomunculusCommand::Feed))
+                .with_width_bound(dimension_bound!(50%))
+                .build()
+                .wrap(),
+            ButtonBuilder::new()
+                .with_text("Recall")
+                .with_event(UserEvent::RecallCompanion(CompanionType::Homunculus))
+                .with_width_bound(dimension_bound!(!))
+                .build()
+                .wrap(),
+        ];
+
+        WindowBuilder::new()
+            .with_title("Homunculus".to_string())
+            .with_class(Self::WINDOW_CLASS.to_owned())
+            .with_size_bound(size_bound!(200 > 300 < 400, ?))
+            .with_elements(elements)
+            .closable()
+            .build(window_cache, application, available_space)
+    }
+}
@@ -0,0 +1,86 @@
+use derive_new::new;
+use korangar_interface::elements::{ButtonBuilder, ElementWrap};
+use korangar_interface::state::PlainRemote;
+use korangar_interface::windows::{PrototypeWindow, Window, WindowBuilder};
+use korangar_interface::{dimension_bound, size_bound};
+use korangar_networking::CompanionType;
+use ragnarok_packets::{MercenaryCommand, MercenaryInformationPacket};
+
+use crate::input::UserEvent;
+use crate::interface::application::InterfaceSettings;
+use crate::interface::elements::CompanionStatus;
+use crate::interface::layout::ScreenSize;
+use crate::interface::windows::WindowCache;
+use crate::inventory::Companion;
+
+#[derive(new)]
+pub struct MercenaryWindow {
+    mercenary: PlainRemote<Companion<MercenaryInformationPacket>>,
+}
+
+impl MercenaryWindow {
+    pub const WINDOW_CLASS: &'static str = "mercenary";
+
+    fn get_values(information: &MercenaryInformationPacket) -> Vec<(&'static str, String)> {
+        vec![
+            ("Name", information.name.clone()),
+            ("Level", information.level.to_string()),
+            (
+                "Health points",
+                format!("{} / {}", information.health_points, information.maximum_health_points),
+            ),
+            (
+                "Spell points",
+                format!("{} / {}", information.spell_points, information.maximum_spell_points),
+            ),
+            ("Loyalty", information.loyalty.to_string()),
+            ("Summons", information.summon_count.to_string()),
+            ("Kills", information.kill_count.to_string()),
+            ("Attack", information.attack.to_string()),
+            ("Magic attack", information.magic_attack.to_string()),
+            ("Hit", information.hit.to_string()),
+            ("Critical", information.critical.to_string()),
+            ("Defense", information.defense.to_string()),
+            ("Magic defense", information.magic_defense.to_string()),
+            ("Flee", information.flee.to_string()),
+            ("Attack speed", information.attack_speed.to_string()),
+        ]
+    }
+}
+
+impl PrototypeWindow<InterfaceSettings> for MercenaryWindow {
+    fn window_class(&self) -> Option<&str> {
+        Self::WINDOW_CLASS.into()
+    }
+
+    fn to_window(
+        &self,
+        window_cache: &WindowCache,
+        application: &InterfaceSettings,
+        available_space: ScreenSize,
+    ) -> Window<InterfaceSettings> {
+        let elements = vec![
+            CompanionStatus::new(self.mercenary.clone(), Self::get_values, "No mercenary").wrap(),
+            ButtonBuilder::new()
+                .with_text("Recall")
+                .with_event(UserEvent::RecallCompanion(CompanionType::Mercenary))
+                .with_width_bound(dimension_bound!(50%))
+                .build()
+                .wrap(),
+            ButtonBuilder::new()
+                .with_text("Dismiss")
+                .with_event(UserEvent::MercenaryCommand(MercenaryCommand::Dismiss))
+                .with_width_bound(dimension_bound!(!))
+                .build()
+                .wrap(),
+        ];
+
+        WindowBuilder::new()
+            .with_title("Mercenary".to_string())
+            .with_class(Self::WINDOW_CLASS.to_owned())
+            .with_size_bound(size_bound!(200 > 300 < 400, ?))
+            .with_elements(elements)
+            .closable()
+            .build(window_cache, application, available_space)
+    }
+}
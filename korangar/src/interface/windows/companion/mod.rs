@@ -0,0 +1,7 @@
+mod homunculus;
+mod mercenary;
+mod pet;
+
+pub use self::homunculus::HomunculusWindow;
+pub use self::mercenary::MercenaryWindow;
+pub use self::pet::PetWindow;
@@ -0,0 +1,109 @@
+use derive_new::new;
+use korangar_interface::elements::{ButtonBuilder, ElementWrap, InputFieldBuilder};
+use korangar_interface::event::ClickAction;
+use korangar_interface::state::{PlainRemote, PlainTrackedState, TrackedStateTake};
+use korangar_interface::windows::{PrototypeWindow, Window, WindowBuilder};
+use korangar_interface::{dimension_bound, size_bound};
+use ragnarok_packets::{PetCommand, PetInformationPacket};
+
+use crate::input::UserEvent;
+use crate::interface::application::InterfaceSettings;
+use crate::interface::elements::CompanionStatus;
+use crate::interface::layout::ScreenSize;
+use crate::interface::windows::WindowCache;
+use crate::inventory::Companion;
+
+#[derive(new)]
+pub struct PetWindow {
+    pet: PlainRemote<Companion<PetInformationPacket>>,
+}
+
+impl PetWindow {
+    pub const WINDOW_CLASS: &'static str = "pet";
+
+    fn get_values(information: &PetInformationPacket) -> Vec<(&'static str, String)> {
+        vec![
+            ("Name", information.name.clone()),
+            ("Level", information.level.to_string()),
+            ("Hunger", information.hunger.to_string()),
+            ("Intimacy", information.intimacy.to_string()),
+        ]
+    }
+}
+
+impl PrototypeWindow<InterfaceSettings> for PetWindow {
+    fn window_class(&self) -> Option<&str> {
+        Self::WINDOW_CLASS.into()
+    }
+
+    fn to_window(
+        &self,
+        window_cache: &WindowCache,
+        application: &InterfaceSettings,
+        available_space: ScreenSize,
+    ) -> Window<InterfaceSettings> {
+        let name = PlainTrackedState::<String>::default();
+
+        let rename_action = {
+            let mut name = name.clone();
+
+            Box::new(move || {
+                let taken_string = name.take();
+
+                (!taken_string.is_empty())
+                    .then_some(vec![ClickAction::Custom(UserEvent::RenamePet(taken_string))])
+                    .unwrap_or_default()
+            })
+        };
+
+        let elements = vec![
+            CompanionStatus::new(self.pet.clone(), Self::get_values, "No pet").wrap(),
+            InputFieldBuilder::new()
+                .with_state(name)
+                .with_ghost_text("New name")
+                .with_enter_action(rename_action.clone())
+                .with_length(24)
+                .with_width_bound(dimension_bound!(70%))
+                .build()
+                .wrap(),
+            ButtonBuilder::new()
+                .with_text("Rename")
+                .with_event(rename_action)
+                .with_width_bound(dimension_bound!(!))
+                .build()
+                .wrap(),
+            ButtonBuilder::new()
+                .with_text("Feed")
+                .with_event(UserEvent::PetCommand(PetCommand::Feed))
+                .with_width_bound(dimension_bound!(50%))
+                .build()
+                .wrap(),
+            ButtonBuilder::new()
+                .with_text("Performance")
+                .with_event(UserEvent::PetCommand(PetCommand::Performance))
+                .with_width_bound(dimension_bound!(!))
+                .build()
+                .wrap(),
+            ButtonBuilder::new()
+                .with_text("Unequip accessory")
+                .with_event(UserEvent::PetCommand(PetCommand::UnequipAccessory))
+                .with_width_bound(dimension_bound!(50%))
+                .build()
+                .wrap(),
+            ButtonBuilder::new()
+                .with_text("Return to egg")
+                .with_event(UserEvent::PetCommand(PetCommand::ReturnToEgg))
+                .with_width_bound(dimension_bound!(!))
+                .build()
+                .wrap(),
+        ];
+
+        WindowBuilder::new()
+            .with_title("Pet".to_string())
+            .with_class(Self::WINDOW_CLASS.to_owned())
+            .with_size_bound(size_bound!(200 > 300 < 400, ?))
+            .with_elements(elements)
+            .closable()
+            .build(window_cache, application, available_space)
+    }
+}
@@ -0,0 +1,145 @@
+use std::rc::Rc;
+use std::sync::Arc;
+
+use korangar_interface::elements::{ElementWrap, InputFieldBuilder, ScrollView};
+use korangar_interface::event::ClickAction;
+use korangar_interface::state::{PlainTrackedState, TrackedState, TrackedStateTake, TrackedStateVec};
+use korangar_interface::windows::{PrototypeWindow, Window, WindowBuilder};
+use korangar_interface::{dimension_bound, size_bound};
+use korangar_networking::MessageColor;
+
+use crate::console::{CommandRegistry, ConsoleOutput};
+use crate::interface::application::InterfaceSettings;
+use crate::interface::elements::ChatBuilder;
+use crate::interface::layout::ScreenSize;
+use crate::interface::theme::InterfaceTheme;
+use crate::interface::windows::{ChatMessage, WindowCache};
+use crate::loaders::FontLoader;
+
+pub struct ConsoleWindow {
+    registry: Rc<CommandRegistry>,
+    output: PlainTrackedState<Vec<ChatMessage>>,
+    history: PlainTrackedState<Vec<String>>,
+    font_loader: Arc<FontLoader>,
+}
+
+impl ConsoleWindow {
+    pub const WINDOW_CLASS: &'static str = "console";
+
+    pub fn new(
+        registry: Rc<CommandRegistry>,
+        output: PlainTrackedState<Vec<ChatMessage>>,
+        history: PlainTrackedState<Vec<String>>,
+        font_loader: Arc<FontLoader>,
+    ) -> Self {
+        Self {
+            registry,
+            output,
+            history,
+            font_loader,
+        }
+    }
+}
+
+fn print_line(output: &mut PlainTrackedState<Vec<ChatMessage>>, text: String, color: MessageColor) {
+    output.push(ChatMessage { text, color });
+}
+
+impl PrototypeWindow<InterfaceSettings> for ConsoleWindow {
+    fn window_class(&self) -> Option<&str> {
+        Self::WINDOW_CLASS.into()
+    }
+
+    fn to_window(
+        &self,
+        window_cache: &WindowCache,
+        application: &InterfaceSettings,
+        available_space: ScreenSize,
+    ) -> Window<InterfaceSettings> {
+        let input_text = PlainTrackedState::<String>::default();
+
+        let enter_action = {
+            let registry = self.registry.clone();
+            let mut output = self.output.clone();
+            let mut input_text = input_text.clone();
+
+            move || {
+                let line = input_text.take();
+
+                if line.trim().is_empty() {
+                    return Vec::new();
+                }
+
+                print_line(&mut output, format!("> {line}"), MessageColor::Broadcast);
+
+                match registry.execute(&line) {
+                    Ok(ConsoleOutput::Events(events)) => events.into_iter().map(ClickAction::Custom).collect(),
+                    Ok(ConsoleOutput::Text(lines)) => {
+                        lines
+                            .into_iter()
+                            .for_each(|line| print_line(&mut output, line, MessageColor::Information));
+                        Vec::new()
+                    }
+                    Ok(ConsoleOutput::Clear) => {
+                        output.clear();
+                        Vec::new()
+                    }
+                    Err(message) => {
+                        print_line(&mut output, message, MessageColor::Error);
+                        Vec::new()
+                    }
+                }
+            }
+        };
+
+        let completion = {
+            let registry = self.registry.clone();
+            let output = self.output.clone();
+
+            move |line: &str| {
+                let completion = registry.complete(line);
+
+                // Show all candidates if the completion is ambiguous.
+                if completion.candidates.len() > 1 {
+                    let mut output = output.clone();
+                    print_line(&mut output, completion.candidates.join("  "), MessageColor::Information);
+                }
+
+                completion.text
+            }
+        };
+
+        let elements = vec![
+            ScrollView::new(
+                vec![
+                    ChatBuilder::new()
+                        .with_messages(self.output.new_remote())
+                        .with_font_loader(self.font_loader.clone())
+                        .build()
+                        .wrap(),
+                ],
+                size_bound!(100%, !),
+            )
+            .wrap(),
+            InputFieldBuilder::new()
+                .with_state(input_text)
+                .with_ghost_text("Command, help or @server command")
+                .with_enter_action(enter_action)
+                .with_length(200)
+                .with_history(self.history.clone())
+                .with_completion(completion)
+                .with_width_bound(dimension_bound!(100%))
+                .build()
+                .wrap(),
+        ];
+
+        WindowBuilder::new()
+            .with_title("Console".to_string())
+            .with_class(Self::WINDOW_CLASS.to_string())
+            .with_size_bound(size_bound!(300 > 600 < 900, 150 > 300 < 800))
+            .with_background_color(Box::new(|theme: &InterfaceTheme| theme.chat.background_color.get()))
+            .with_elements(elements)
+            .closable()
+            .build(window_cache, application, available_space)
+    }
+}
@@ -1,13 +1,19 @@
 mod commands;
+mod console;
 mod inspector;
 mod maps;
 mod packet;
 mod profiler;
+mod replay;
+mod scene;
 mod time;
 
 pub use self::commands::CommandsWindow;
+pub use self::console::ConsoleWindow;
 pub use self::inspector::FrameInspectorWindow;
 pub use self::maps::MapsWindow;
 pub use self::packet::PacketWindow;
 pub use self::profiler::ProfilerWindow;
+pub use self::replay::PacketReplayWindow;
+pub use self::scene::SceneInspectorWindow;
 pub use self::time::TimeWindow;
@@ -1,4 +1,5 @@
-use korangar_interface::elements::{ButtonBuilder, ElementWrap, ScrollView, StateButtonBuilder};
+use korangar_debug::logging::{log_error, log_info};
+use korangar_interface::elements::{ButtonBuilder, ElementCell, ElementWrap, InputFieldBuilder, ScrollView, StateButtonBuilder};
 use korangar_interface::event::ClickAction;
 use korangar_interface::state::{PlainTrackedState, TrackedState, TrackedStateBinary};
 use korangar_interface::windows::{PrototypeWindow, Window, WindowBuilder};
@@ -6,13 +7,25 @@ use korangar_interface::{dimension_bound, size_bound};
 
 use crate::input::UserEvent;
 use crate::interface::application::InterfaceSettings;
-use crate::interface::elements::{PacketHistoryRemote, PacketView};
+use crate::interface::elements::{PacketFilter, PacketHistoryRemote, PacketQuery, PacketView};
 use crate::interface::layout::ScreenSize;
 use crate::interface::windows::WindowCache;
 
+fn filter_button(text: &'static str, state: impl TrackedStateBinary<bool>) -> ElementCell<InterfaceSettings> {
+    StateButtonBuilder::new()
+        .with_text(text)
+        .with_remote(state.new_remote())
+        .with_event(state.toggle_action())
+        .with_width_bound(dimension_bound!(33.33%))
+        .build()
+        .wrap()
+}
+
 pub struct PacketWindow {
     packets: PacketHistoryRemote,
-    show_pings: PlainTrackedState<bool>,
+    filter: PlainTrackedState<PacketFilter>,
+    packet_type: PlainTrackedState<String>,
+    search: PlainTrackedState<String>,
     update: PlainTrackedState<bool>,
 }
 
@@ -20,11 +33,11 @@ impl PacketWindow {
     pub const WINDOW_CLASS: &'static str = "network";
 
     pub fn new(packets: PacketHistoryRemote, update: PlainTrackedState<bool>) -> Self {
-        let show_pings = PlainTrackedState::default();
-
         Self {
             packets,
-            show_pings,
+            filter: PlainTrackedState::default(),
+            packet_type: PlainTrackedState::default(),
+            search: PlainTrackedState::default(),
             update,
         }
     }
@@ -41,7 +54,15 @@ impl PrototypeWindow<InterfaceSettings> for PacketWindow {
         application: &InterfaceSettings,
         available_space: ScreenSize,
     ) -> Window<InterfaceSettings> {
-        let elements = vec![PacketView::new(self.packets.clone(), self.show_pings.new_remote()).wrap()];
+        let elements = vec![
+            PacketView::new(
+                self.packets.clone(),
+                self.filter.new_remote(),
+                self.packet_type.new_remote(),
+                self.search.new_remote(),
+            )
+            .wrap(),
+        ];
 
         let clear_selector = {
             let packets = self.packets.clone();
@@ -50,6 +71,25 @@ impl PrototypeWindow<InterfaceSettings> for PacketWindow {
 
         let clear_action = { move || vec![ClickAction::Custom(UserEvent::ClearPacketHistory)] };
 
+        // Export all packets that are currently shown.
+        let export_action = {
+            let packets = self.packets.clone();
+            let filter = self.filter.clone();
+            let packet_type = self.packet_type.clone();
+            let search = self.search.clone();
+
+            move || {
+                let query = PacketQuery::new(filter.get().clone(), &packet_type.get(), &search.get());
+
+                match packets.export(&query) {
+                    Ok(path) => log_info!("exported packets to {}", path.display()),
+                    Err(error) => log_error!("failed to export packets: {}", error),
+                }
+
+                Vec::new()
+            }
+        };
+
         let elements = vec![
             ButtonBuilder::new()
                 .with_text("Clear")
@@ -58,10 +98,9 @@ impl PrototypeWindow<InterfaceSettings> for PacketWindow {
                 .with_width_bound(dimension_bound!(33.33%))
                 .build()
                 .wrap(),
-            StateButtonBuilder::new()
-                .with_text("Show pings")
-                .with_remote(self.show_pings.new_remote())
-                .with_event(self.show_pings.toggle_action())
+            ButtonBuilder::new()
+                .with_text("Export")
+                .with_event(Box::new(export_action))
                 .with_width_bound(dimension_bound!(33.33%))
                 .build()
                 .wrap(),
@@ -72,6 +111,28 @@ impl PrototypeWindow<InterfaceSettings> for PacketWindow {
                 .with_width_bound(dimension_bound!(!))
                 .build()
                 .wrap(),
+            filter_button("Incoming", self.filter.mapped(|filter| &filter.show_incoming)),
+            filter_button("Outgoing", self.filter.mapped(|filter| &filter.show_outgoing)),
+            filter_button("Pings", self.filter.mapped(|filter| &filter.show_pings)),
+            filter_button("Login", self.filter.mapped(|filter| &filter.show_login_server)),
+            filter_button("Character", self.filter.mapped(|filter| &filter.show_character_server)),
+            filter_button("Map", self.filter.mapped(|filter| &filter.show_map_server)),
+            InputFieldBuilder::new()
+                .with_state(self.packet_type.clone())
+                .with_ghost_text("Packet name or 0x header")
+                .with_enter_action(Vec::new)
+                .with_length(64)
+                .with_width_bound(dimension_bound!(50%))
+                .build()
+                .wrap(),
+            InputFieldBuilder::new()
+                .with_state(self.search.clone())
+                .with_ghost_text("Search fields")
+                .with_enter_action(Vec::new)
+                .with_length(64)
+                .with_width_bound(dimension_bound!(!))
+                .build()
+                .wrap(),
             ScrollView::new(elements, size_bound!(100%, ? < super)).wrap(),
         ];
 
@@ -1,5 +1,9 @@
-use korangar_debug::profiling::Profiler;
-use korangar_interface::elements::{ElementWrap, PickList, StateButtonBuilder};
+use std::time::Duration;
+
+use korangar_debug::logging::{Colorize, log_error, print_debug};
+use korangar_debug::profiling::{Profiler, export_chrome_trace, start_trace_recording};
+use korangar_interface::elements::{ButtonBuilder, ElementWrap, InputFieldBuilder, PickList, StateButtonBuilder};
+use korangar_interface::event::ClickAction;
 use korangar_interface::state::{PlainTrackedState, Remote, TrackedState, TrackedStateBinary, ValueState};
 use korangar_interface::windows::{PrototypeWindow, Window, WindowBuilder};
 use korangar_interface::{dimension_bound, size_bound};
@@ -84,9 +88,73 @@ impl Remote<bool> for ProfilerHaltedRemote {
     }
 }
 
+const TRACE_DIRECTORY: &str = "client/traces";
+/// Longest trace recording in seconds.
+const MAXIMUM_TRACE_RECORDING_SECONDS: u64 = 600;
+
+/// Parse the duration of a trace recording in seconds.
+fn parse_trace_duration(text: &str) -> Option<Duration> {
+    text.trim()
+        .parse()
+        .ok()
+        .filter(|seconds| (1..=MAXIMUM_TRACE_RECORDING_SECONDS).contains(seconds))
+        .map(Duration::from_secs)
+}
+
+/// Create the trace directory and return a new, timestamped file path in it.
+fn trace_path(kind: &str) -> Option<String> {
+    if let Err(error) = std::fs::create_dir_all(TRACE_DIRECTORY) {
+        log_error!("failed to create trace directory: {}", error.to_string().red());
+        return None;
+    }
+
+    let timestamp = chrono::Local::now().format("%Y-%m-%d_%H-%M-%S");
+    Some(format!("{TRACE_DIRECTORY}/{kind}_{timestamp}.json"))
+}
+
+fn export_trace() -> Vec<ClickAction<InterfaceSettings>> {
+    let Some(path) = trace_path("frames") else {
+        return Vec::new();
+    };
+
+    match export_chrome_trace(crate::threads::Enum::ALL.iter().copied(), &path) {
+        Ok(frame_count) => print_debug!("exported {} frames to {}", frame_count.yellow(), path.magenta()),
+        Err(error) => log_error!("failed to export trace to {}: {}", path.magenta(), error.to_string().red()),
+    }
+
+    Vec::new()
+}
+
+fn record_trace(duration: &PlainTrackedState<String>) -> Vec<ClickAction<InterfaceSettings>> {
+    let Some(duration) = parse_trace_duration(&duration.get()) else {
+        return Vec::new();
+    };
+
+    let Some(path) = trace_path("recording") else {
+        return Vec::new();
+    };
+
+    match start_trace_recording(&path, duration) {
+        Ok(()) => print_debug!(
+            "recording trace for {} seconds to {}",
+            duration.as_secs().yellow(),
+            path.magenta()
+        ),
+        Err(error) => log_error!(
+            "failed to start recording trace to {}: {}",
+            path.magenta(),
+            error.to_string().red()
+        ),
+    }
+
+    Vec::new()
+}
+
 pub struct ProfilerWindow {
     always_update: PlainTrackedState<bool>,
     visible_thread: PlainTrackedState<crate::threads::Enum>,
+    /// Duration of a trace recording in seconds.
+    trace_duration: PlainTrackedState<String>,
 }
 
 impl ProfilerWindow {
@@ -96,6 +164,7 @@ impl ProfilerWindow {
         Self {
             always_update: PlainTrackedState::new(true),
             visible_thread: PlainTrackedState::new(crate::threads::Enum::Main),
+            trace_duration: PlainTrackedState::new("10".to_owned()),
         }
     }
 }
@@ -112,6 +181,9 @@ impl PrototypeWindow<InterfaceSettings> for ProfilerWindow {
         available_space: ScreenSize,
     ) -> Window<InterfaceSettings> {
         let profiler_halted_state = TrackedProfilerHaltedState::default();
+        let enter_duration = self.trace_duration.clone();
+        let record_duration = self.trace_duration.clone();
+        let disabled_duration = self.trace_duration.clone();
 
         let elements = vec![
             PickList::default()
@@ -137,6 +209,27 @@ impl PrototypeWindow<InterfaceSettings> for ProfilerWindow {
                 .with_width_bound(dimension_bound!(150))
                 .build()
                 .wrap(),
+            ButtonBuilder::new()
+                .with_text("Export trace")
+                .with_event(Box::new(export_trace))
+                .with_width_bound(dimension_bound!(150))
+                .build()
+                .wrap(),
+            InputFieldBuilder::new()
+                .with_state(self.trace_duration.clone())
+                .with_ghost_text("Seconds")
+                .with_enter_action(move || record_trace(&enter_duration))
+                .with_length(3)
+                .with_width_bound(dimension_bound!(150))
+                .build()
+                .wrap(),
+            ButtonBuilder::new()
+                .with_text("Record trace")
+                .with_event(move || record_trace(&record_duration))
+                .with_disabled_selector(move || parse_trace_duration(&disabled_duration.get()).is_none())
+                .with_width_bound(dimension_bound!(150))
+                .build()
+                .wrap(),
             ElementWrap::wrap(FrameView::new(
                 self.always_update.new_remote(),
                 self.visible_thread.new_remote(),
@@ -152,3 +245,23 @@ impl PrototypeWindow<InterfaceSettings> for ProfilerWindow {
             .build(window_cache, application, available_space)
     }
 }
+
+#[cfg(test)]
+mod trace_duration {
+    use std::time::Duration;
+
+    use super::{MAXIMUM_TRACE_RECORDING_SECONDS, parse_trace_duration};
+
+    #[test]
+    fn parse_durations() {
+        assert_eq!(parse_trace_duration("10"), Some(Duration::from_secs(10)));
+        assert_eq!(parse_trace_duration(" 1 "), Some(Duration::from_secs(1)));
+        assert_eq!(
+            parse_trace_duration(&MAXIMUM_TRACE_RECORDING_SECONDS.to_string()),
+            Some(Duration::from_secs(MAXIMUM_TRACE_RECORDING_SECONDS))
+        );
+        assert_eq!(parse_trace_duration("0"), None);
+        assert_eq!(parse_trace_duration("601"), None);
+        assert_eq!(parse_trace_duration("long"), None);
+    }
+}
@@ -0,0 +1,403 @@
+use std::ops::Range;
+
+use korangar_debug::logging::{log_error, log_info, log_warning};
+use korangar_interface::elements::{ButtonBuilder, ElementWrap, InputFieldBuilder, Text};
+use korangar_interface::event::ClickAction;
+use korangar_interface::state::{PlainTrackedState, TrackedState};
+use korangar_interface::windows::{PrototypeWindow, Window, WindowBuilder};
+use korangar_interface::{dimension_bound, size_bound};
+use ragnarok_bytes::RecordedField;
+use ragnarok_packets::handler::ServerType;
+
+use crate::input::UserEvent;
+use crate::interface::application::InterfaceSettings;
+use crate::interface::elements::DecodeFunction;
+use crate::interface::layout::ScreenSize;
+use crate::interface::windows::WindowCache;
+
+/// Maximum number of bytes that can be edited.
+const MAXIMUM_PACKET_SIZE: usize = 2048;
+
+/// Format bytes as space separated hex pairs.
+fn format_hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|byte| format!("{byte:02x}")).collect::<Vec<_>>().join(" ")
+}
+
+/// Parse hex pairs, ignoring any whitespace between them.
+fn parse_hex(text: &str) -> Result<Vec<u8>, String> {
+    let digits: Vec<char> = text.chars().filter(|character| !character.is_whitespace()).collect();
+
+    if digits.len() % 2 != 0 {
+        return Err("odd number of hex digits".to_owned());
+    }
+
+    digits
+        .chunks(2)
+        .map(|pair| {
+            let pair: String = pair.iter().collect();
+            u8::from_str_radix(&pair, 16).map_err(|_| format!("invalid hex value `{pair}`"))
+        })
+        .collect()
+}
+
+/// Defines how numbers of the given primitive types are shown and edited.
+macro_rules! number_conversions {
+    ($($number:ident),*) => {
+        fn is_number(type_name: &str) -> bool {
+            matches!(type_name, $(stringify!($number))|*)
+        }
+
+        fn format_number(type_name: &str, bytes: &[u8]) -> Option<String> {
+            match type_name {
+                $(stringify!($number) => Some(<$number>::from_le_bytes(bytes.try_into().ok()?).to_string()),)*
+                _ => None,
+            }
+        }
+
+        fn encode_number(type_name: &str, text: &str) -> Result<Vec<u8>, String> {
+            match type_name {
+                $(stringify!($number) => text
+                    .trim()
+                    .parse::<$number>()
+                    .map(|number| number.to_le_bytes().to_vec())
+                    .map_err(|_| format!("`{}` is not a valid {type_name}", text.trim())),)*
+                _ => Err(format!("{type_name} is not a number")),
+            }
+        }
+    };
+}
+
+number_conversions!(u8, u16, u32, u64, i8, i16, i32, i64, f32, f64);
+
+/// How a field of a decoded packet is edited.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum FieldKind {
+    Number(&'static str),
+    Text,
+    Bytes,
+}
+
+impl FieldKind {
+    fn from_type_name(type_name: &'static str) -> Self {
+        match type_name {
+            "alloc::string::String" => Self::Text,
+            _ if is_number(type_name) => Self::Number(type_name),
+            _ => Self::Bytes,
+        }
+    }
+
+    fn name(self) -> &'static str {
+        match self {
+            Self::Number(type_name) => type_name,
+            Self::Text => "text",
+            Self::Bytes => "hex",
+        }
+    }
+
+    /// Format the bytes of the field for editing.
+    fn format(self, bytes: &[u8]) -> String {
+        match self {
+            Self::Number(type_name) => format_number(type_name, bytes).unwrap_or_else(|| format_hex(bytes)),
+            Self::Text => String::from_utf8_lossy(bytes.split(|byte| *byte == 0).next().unwrap_or_default()).into_owned(),
+            Self::Bytes => format_hex(bytes),
+        }
+    }
+
+    /// Encode an edited value into exactly `size` bytes.
+    fn encode(self, text: &str, size: usize) -> Result<Vec<u8>, String> {
+        let mut bytes = match self {
+            Self::Number(type_name) => encode_number(type_name, text)?,
+            Self::Text => text.as_bytes().to_vec(),
+            Self::Bytes => parse_hex(text)?,
+        };
+
+        match self {
+            Self::Text if bytes.len() <= size => bytes.resize(size, 0),
+            Self::Text => return Err(format!("text is longer than {size} bytes")),
+            _ if bytes.len() != size => return Err(format!("expected {size} bytes but got {}", bytes.len())),
+            _ => {}
+        }
+
+        Ok(bytes)
+    }
+}
+
+/// Editor for a single field of a decoded packet.
+#[derive(Clone)]
+struct FieldEditor {
+    path: String,
+    kind: FieldKind,
+    range: Range<usize>,
+    original_text: String,
+    text: PlainTrackedState<String>,
+}
+
+impl FieldEditor {
+    /// Create editors for all fields that don't consist of other fields.
+    fn from_fields(bytes: &[u8], fields: Vec<RecordedField>) -> Vec<Self> {
+        fields
+            .into_iter()
+            .filter(|field| field.is_leaf && !field.range.is_empty())
+            .map(|field| {
+                let kind = FieldKind::from_type_name(field.type_name);
+                let original_text = kind.format(&bytes[field.range.clone()]);
+
+                Self {
+                    path: field.path,
+                    kind,
+                    range: field.range,
+                    text: PlainTrackedState::new(original_text.clone()),
+                    original_text,
+                }
+            })
+            .collect()
+    }
+}
+
+/// Encode all edited fields into a copy of the original bytes. Fields that
+/// were not changed keep their original bytes.
+fn apply_fields(bytes: &[u8], field_editors: &[FieldEditor]) -> Result<Vec<u8>, String> {
+    let mut bytes = bytes.to_vec();
+
+    for editor in field_editors {
+        let text = editor.text.get();
+
+        if *text != editor.original_text {
+            let encoded = editor
+                .kind
+                .encode(&text, editor.range.len())
+                .map_err(|error| format!("{}: {error}", editor.path))?;
+            bytes[editor.range.clone()].copy_from_slice(&encoded);
+        }
+    }
+
+    Ok(bytes)
+}
+
+/// Edit an outgoing packet and send it again. Packets that can be decoded are
+/// edited field by field, other packets as raw bytes.
+#[derive(Clone)]
+pub struct PacketReplayWindow {
+    name: &'static str,
+    server_type: ServerType,
+    bytes: Vec<u8>,
+    decode: Option<DecodeFunction>,
+}
+
+impl PacketReplayWindow {
+    pub const WINDOW_CLASS: &'static str = "packet_replay";
+
+    pub fn new(name: &'static str, server_type: ServerType, bytes: Vec<u8>, decode: Option<DecodeFunction>) -> Self {
+        Self {
+            name,
+            server_type,
+            bytes,
+            decode,
+        }
+    }
+}
+
+impl PrototypeWindow<InterfaceSettings> for PacketReplayWindow {
+    fn window_class(&self) -> Option<&str> {
+        Self::WINDOW_CLASS.into()
+    }
+
+    fn to_window(
+        &self,
+        window_cache: &WindowCache,
+        application: &InterfaceSettings,
+        available_space: ScreenSize,
+    ) -> Window<InterfaceSettings> {
+        // Packets that can be decoded are edited field by field, anything else is
+        // edited as raw bytes.
+        let field_editors = self
+            .decode
+            .and_then(|decode| decode(&self.bytes).ok())
+            .map(|decoded| FieldEditor::from_fields(&self.bytes, decoded.fields))
+            .unwrap_or_default();
+        let input_text = PlainTrackedState::new(format_hex(&self.bytes));
+
+        let send_action = {
+            let field_editors = field_editors.clone();
+            let input_text = input_text.clone();
+            let original_bytes = self.bytes.clone();
+            let name = self.name;
+            let server_type = self.server_type;
+            let decode = self.decode;
+
+            move || {
+                let bytes = match field_editors.is_empty() {
+                    true => parse_hex(&input_text.get()),
+                    false => apply_fields(&original_bytes, &field_editors),
+                };
+
+                let bytes = match bytes {
+                    Ok(bytes) => bytes,
+                    Err(error) => {
+                        log_error!("failed to encode packet bytes: {}", error);
+                        return Vec::new();
+                    }
+                };
+
+                // Edited packets are sent even if they are invalid, since sending invalid
+                // packets can be useful for testing the server.
+                match decode.map(|decode| decode(&bytes)) {
+                    Some(Ok(decoded)) => log_info!("resending {}: {}", name, decoded.details),
+                    Some(Err(error)) => log_warning!("edited bytes are not a valid {}: {}", name, error),
+                    None => {}
+                }
+
+                vec![ClickAction::Custom(UserEvent::SendRawPacket { server_type, bytes })]
+            }
+        };
+
+        let mut elements = vec![
+            Text::default()
+                .with_text(format!("{} ({} server)", self.name, self.server_type.name()))
+                .wrap(),
+        ];
+
+        match field_editors.is_empty() {
+            true => elements.push(
+                InputFieldBuilder::new()
+                    .with_state(input_text)
+                    .with_ghost_text("Packet bytes in hex")
+                    .with_enter_action(send_action.clone())
+                    .with_length(MAXIMUM_PACKET_SIZE * 3)
+                    .build()
+                    .wrap(),
+            ),
+            false => {
+                for editor in field_editors {
+                    let length = match editor.kind {
+                        FieldKind::Number(_) => 32,
+                        FieldKind::Text => editor.range.len(),
+                        FieldKind::Bytes => editor.range.len() * 3,
+                    };
+
+                    elements.push(
+                        Text::default()
+                            .with_text(format!("{} ({})", editor.path, editor.kind.name()))
+                            .wrap(),
+                    );
+                    elements.push(
+                        InputFieldBuilder::new()
+                            .with_state(editor.text)
+                            .with_ghost_text(editor.kind.name())
+                            .with_enter_action(send_action.clone())
+                            .with_length(length)
+                            .build()
+                            .wrap(),
+                    );
+                }
+            }
+        }
+
+        elements.push(
+            ButtonBuilder::new()
+                .with_text("Send")
+                .with_event(Box::new(send_action))
+                .with_width_bound(dimension_bound!(100%))
+                .build()
+                .wrap(),
+        );
+
+        WindowBuilder::new()
+            .with_title("Replay packet".to_string())
+            .with_class(Self::WINDOW_CLASS.to_string())
+            .with_size_bound(size_bound!(300 > 500 < 800, ?))
+            .with_elements(elements)
+            .closable()
+            .build(window_cache, application, available_space)
+    }
+}
+
+#[cfg(test)]
+mod hex {
+    use super::{format_hex, parse_hex};
+
+    #[test]
+    fn round_trip() {
+        let bytes = [0x68, 0x03, 0x00, 0xFF, 0x12];
+
+        assert_eq!(format_hex(&bytes), "68 03 00 ff 12");
+        assert_eq!(parse_hex(&format_hex(&bytes)).unwrap(), bytes);
+        assert_eq!(parse_hex(" 6803\n00FF 12 ").unwrap(), bytes);
+        assert_eq!(parse_hex("").unwrap(), []);
+    }
+
+    #[test]
+    fn invalid_hex() {
+        assert_eq!(parse_hex("680").unwrap_err(), "odd number of hex digits");
+        assert_eq!(parse_hex("68 0g").unwrap_err(), "invalid hex value `0g`");
+    }
+}
+
+#[cfg(test)]
+mod fields {
+    use korangar_interface::state::TrackedState;
+    use ragnarok_bytes::RecordedField;
+
+    use super::{FieldEditor, FieldKind, apply_fields};
+
+    fn field(path: &str, type_name: &'static str, range: std::ops::Range<usize>, is_leaf: bool) -> RecordedField {
+        RecordedField {
+            path: path.to_owned(),
+            type_name,
+            range,
+            is_leaf,
+        }
+    }
+
+    #[test]
+    fn format_and_encode() {
+        assert_eq!(FieldKind::from_type_name("u16"), FieldKind::Number("u16"));
+        assert_eq!(FieldKind::from_type_name("alloc::string::String"), FieldKind::Text);
+        assert_eq!(FieldKind::from_type_name("[u8; 4]"), FieldKind::Bytes);
+
+        assert_eq!(FieldKind::Number("i16").format(&[0xFE, 0xFF]), "-2");
+        assert_eq!(FieldKind::Number("i16").encode(" -2 ", 2).unwrap(), [0xFE, 0xFF]);
+        assert!(FieldKind::Number("u8").encode("256", 1).is_err());
+
+        assert_eq!(FieldKind::Text.format(b"abc\0\0x"), "abc");
+        assert_eq!(FieldKind::Text.encode("ab", 4).unwrap(), b"ab\0\0");
+        assert!(FieldKind::Text.encode("abcde", 4).is_err());
+
+        assert_eq!(FieldKind::Bytes.format(&[1, 2]), "01 02");
+        assert_eq!(FieldKind::Bytes.encode("0a0b", 2).unwrap(), [0x0A, 0x0B]);
+        assert!(FieldKind::Bytes.encode("0a", 2).is_err());
+    }
+
+    #[test]
+    fn apply_edited_fields() {
+        let bytes = [0x68, 0x03, 0x10, 0x00, b'a', b'b', 0, 0];
+        let field_editors = FieldEditor::from_fields(&bytes, vec![
+            field("count", "u16", 2..4, true),
+            field("name", "alloc::string::String", 4..8, true),
+            field("inner", "Inner", 2..8, false),
+        ]);
+
+        assert_eq!(field_editors.len(), 2);
+        assert_eq!(field_editors[0].text.get().as_str(), "16");
+        assert_eq!(field_editors[1].text.get().as_str(), "ab");
+        assert_eq!(apply_fields(&bytes, &field_editors).unwrap(), bytes);
+
+        let mut count = field_editors[0].text.clone();
+        count.set("258".to_owned());
+        assert_eq!(apply_fields(&bytes, &field_editors).unwrap(), [
+            0x68, 0x03, 0x02, 0x01, b'a', b'b', 0, 0
+        ]);
+
+        let mut name = field_editors[1].text.clone();
+        name.set("xyz".to_owned());
+        assert_eq!(apply_fields(&bytes, &field_editors).unwrap(), [
+            0x68, 0x03, 0x02, 0x01, b'x', b'y', b'z', 0
+        ]);
+
+        name.set("vwxyz".to_owned());
+        assert_eq!(
+            apply_fields(&bytes, &field_editors).unwrap_err(),
+            "name: text is longer than 4 bytes"
+        );
+    }
+}
@@ -0,0 +1,171 @@
+use cgmath::Vector2;
+use korangar_debug::logging::log_error;
+use korangar_interface::elements::{ButtonBuilder, ElementWrap, InputFieldBuilder, ScrollView};
+use korangar_interface::event::ClickAction;
+use korangar_interface::state::{PlainRemote, PlainTrackedState, TrackedState};
+use korangar_interface::windows::{PrototypeWindow, Window, WindowBuilder};
+use korangar_interface::{dimension_bound, size_bound};
+
+use crate::input::UserEvent;
+use crate::interface::application::InterfaceSettings;
+use crate::interface::elements::SceneView;
+use crate::interface::layout::ScreenSize;
+use crate::interface::windows::WindowCache;
+use crate::world::{EffectId, SceneInspection};
+
+fn find_effect(name: &str) -> Option<EffectId> {
+    EffectId::all()
+        .into_iter()
+        .find(|effect_id| format!("{effect_id:?}").eq_ignore_ascii_case(name.trim()))
+}
+
+/// Complete the effect name as far as it is unambiguous.
+fn complete_effect(text: &str) -> Option<String> {
+    let prefix = text.trim().to_lowercase();
+    let candidates: Vec<String> = EffectId::all()
+        .iter()
+        .map(|effect_id| format!("{effect_id:?}"))
+        .filter(|name| name.to_lowercase().starts_with(&prefix))
+        .collect();
+
+    let first = candidates.first()?;
+    let common_length = candidates.iter().skip(1).fold(first.len(), |length, name| {
+        first
+            .chars()
+            .zip(name.chars())
+            .take(length)
+            .take_while(|(left, right)| left.eq_ignore_ascii_case(right))
+            .count()
+    });
+
+    (common_length > prefix.len()).then(|| first[..common_length].to_owned())
+}
+
+/// Parse a tile position in the form `x y`. An empty text means no position.
+fn parse_position(text: &str) -> Result<Option<Vector2<usize>>, String> {
+    let mut coordinates = text.split_whitespace().map(str::parse::<usize>);
+
+    match (coordinates.next(), coordinates.next(), coordinates.next()) {
+        (None, ..) => Ok(None),
+        (Some(Ok(x)), Some(Ok(y)), None) => Ok(Some(Vector2::new(x, y))),
+        _ => Err(format!("invalid tile position `{}`, expected `x y`", text.trim())),
+    }
+}
+
+pub struct SceneInspectorWindow {
+    scene: PlainRemote<SceneInspection>,
+}
+
+impl SceneInspectorWindow {
+    pub const WINDOW_CLASS: &'static str = "scene_inspector";
+
+    pub fn new(scene: PlainRemote<SceneInspection>) -> Self {
+        Self { scene }
+    }
+}
+
+impl PrototypeWindow<InterfaceSettings> for SceneInspectorWindow {
+    fn window_class(&self) -> Option<&str> {
+        Self::WINDOW_CLASS.into()
+    }
+
+    fn to_window(
+        &self,
+        window_cache: &WindowCache,
+        application: &InterfaceSettings,
+        available_space: ScreenSize,
+    ) -> Window<InterfaceSettings> {
+        let effect_text = PlainTrackedState::default();
+        let position_text = PlainTrackedState::default();
+
+        let spawn_action = {
+            let effect_text = effect_text.clone();
+            let position_text = position_text.clone();
+
+            move || {
+                let Some(effect_id) = find_effect(&effect_text.get()) else {
+                    log_error!("unknown effect `{}`", effect_text.get().trim());
+                    return Vec::new();
+                };
+
+                let position = match parse_position(&position_text.get()) {
+                    Ok(position) => position,
+                    Err(error) => {
+                        log_error!("{}", error);
+                        return Vec::new();
+                    }
+                };
+
+                vec![ClickAction::Custom(UserEvent::SpawnEffect {
+                    file: effect_id.file_name(),
+                    position,
+                })]
+            }
+        };
+
+        let elements = vec![
+            InputFieldBuilder::new()
+                .with_state(effect_text)
+                .with_ghost_text("Effect")
+                .with_enter_action(spawn_action.clone())
+                .with_length(50)
+                .with_completion(complete_effect)
+                .with_width_bound(dimension_bound!(50%))
+                .build()
+                .wrap(),
+            InputFieldBuilder::new()
+                .with_state(position_text)
+                .with_ghost_text("Tile (x y)")
+                .with_enter_action(spawn_action.clone())
+                .with_length(20)
+                .with_width_bound(dimension_bound!(25%))
+                .build()
+                .wrap(),
+            ButtonBuilder::new()
+                .with_text("Spawn")
+                .with_event(Box::new(spawn_action))
+                .with_width_bound(dimension_bound!(25%))
+                .build()
+                .wrap(),
+            ScrollView::new(vec![SceneView::new(self.scene.clone()).wrap()], size_bound!(100%, ? < super)).wrap(),
+        ];
+
+        WindowBuilder::new()
+            .with_title("Scene inspector".to_string())
+            .with_class(Self::WINDOW_CLASS.to_string())
+            .with_size_bound(size_bound!(300 > 400 < 600, ? < 80%))
+            .with_elements(elements)
+            .closable()
+            .build(window_cache, application, available_space)
+    }
+}
+
+#[cfg(test)]
+mod spawn {
+    use cgmath::Vector2;
+
+    use super::{complete_effect, find_effect, parse_position};
+
+    #[test]
+    fn find_effect_ignores_case() {
+        assert!(find_effect("firewall").is_some());
+        assert!(find_effect(" FireWall ").is_some());
+        assert!(find_effect("not_an_effect").is_none());
+    }
+
+    #[test]
+    fn complete_effect_names() {
+        assert_eq!(complete_effect("firewal").as_deref(), Some("Firewall"));
+        assert_eq!(complete_effect("Firewall"), None);
+        assert_eq!(complete_effect("zzz"), None);
+    }
+
+    #[test]
+    fn parse_positions() {
+        assert_eq!(parse_position(""), Ok(None));
+        assert_eq!(parse_position(" 12 34 "), Ok(Some(Vector2::new(12, 34))));
+        assert!(parse_position("12").is_err());
+        assert!(parse_position("12 34 56").is_err());
+        assert!(parse_position("x y").is_err());
+    }
+}
@@ -25,26 +25,25 @@ impl PrototypeWindow<InterfaceSettings> for TimeWindow {
         application: &InterfaceSettings,
         available_space: ScreenSize,
     ) -> Window<InterfaceSettings> {
-        // TODO: Unify Set* events into one that takes a specific time
         let elements = vec![
             ButtonBuilder::new()
                 .with_text("Set dawn")
-                .with_event(UserEvent::SetDawn)
+                .with_event(UserEvent::SetTimeOfDay(5.0))
                 .build()
                 .wrap(),
             ButtonBuilder::new()
                 .with_text("Set noon")
-                .with_event(UserEvent::SetNoon)
+                .with_event(UserEvent::SetTimeOfDay(12.0))
                 .build()
                 .wrap(),
             ButtonBuilder::new()
                 .with_text("Set dusk")
-                .with_event(UserEvent::SetDusk)
+                .with_event(UserEvent::SetTimeOfDay(17.0))
                 .build()
                 .wrap(),
             ButtonBuilder::new()
                 .with_text("Set midnight")
-                .with_event(UserEvent::SetMidnight)
+                .with_event(UserEvent::SetTimeOfDay(24.0))
                 .build()
                 .wrap(),
         ];
@@ -25,6 +25,7 @@ pub struct ChatMessage {
 #[derive(new)]
 pub struct ChatWindow {
     messages: PlainRemote<Vec<ChatMessage>>,
+    history: PlainTrackedState<Vec<String>>,
     font_loader: Arc<FontLoader>,
 }
 
@@ -77,6 +78,7 @@ impl PrototypeWindow<InterfaceSettings> for ChatWindow {
                 .with_ghost_text("Write message or command")
                 .with_enter_action(input_action)
                 .with_length(80)
+                .with_history(self.history.clone())
                 .with_width_bound(dimension_bound!(75%))
                 .build()
                 .wrap(),
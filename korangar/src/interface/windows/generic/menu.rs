@@ -38,6 +38,11 @@ impl PrototypeWindow<InterfaceSettings> for MenuWindow {
                 .with_event(UserEvent::OpenAudioSettingsWindow)
                 .build()
                 .wrap(),
+            ButtonBuilder::new()
+                .with_text("Key bindings")
+                .with_event(UserEvent::OpenKeyBindingsWindow)
+                .build()
+                .wrap(),
             #[cfg(feature = "debug")]
             ButtonBuilder::new()
                 .with_text("Render settings")
@@ -60,6 +65,13 @@ impl PrototypeWindow<InterfaceSettings> for MenuWindow {
                 .build()
                 .wrap(),
             #[cfg(feature = "debug")]
+            ButtonBuilder::new()
+                .with_text("Scene inspector")
+                .with_event(UserEvent::OpenSceneInspectorWindow)
+                .with_foreground_color(|theme: &InterfaceTheme| theme.button.debug_foreground_color.get())
+                .build()
+                .wrap(),
+            #[cfg(feature = "debug")]
             ButtonBuilder::new()
                 .with_text("Commands")
                 .with_event(UserEvent::OpenCommandsWindow)
@@ -67,6 +79,13 @@ impl PrototypeWindow<InterfaceSettings> for MenuWindow {
                 .build()
                 .wrap(),
             #[cfg(feature = "debug")]
+            ButtonBuilder::new()
+                .with_text("Console")
+                .with_event(UserEvent::OpenConsoleWindow)
+                .with_foreground_color(|theme: &InterfaceTheme| theme.button.debug_foreground_color.get())
+                .build()
+                .wrap(),
+            #[cfg(feature = "debug")]
             ButtonBuilder::new()
                 .with_text("Time")
                 .with_event(UserEvent::OpenTimeWindow)
@@ -0,0 +1,62 @@
+use derive_new::new;
+use korangar_interface::elements::{ButtonBuilder, ElementWrap, ScrollView};
+use korangar_interface::state::PlainRemote;
+use korangar_interface::windows::{PrototypeWindow, Window, WindowBuilder};
+use korangar_interface::{dimension_bound, size_bound};
+use ragnarok_packets::MailInformation;
+
+use crate::input::UserEvent;
+use crate::interface::application::InterfaceSettings;
+use crate::interface::elements::MailView;
+use crate::interface::layout::ScreenSize;
+use crate::interface::windows::WindowCache;
+
+#[derive(new)]
+pub struct MailboxWindow {
+    mails: PlainRemote<Vec<MailInformation>>,
+}
+
+impl MailboxWindow {
+    pub const WINDOW_CLASS: &'static str = "mailbox";
+}
+
+impl PrototypeWindow<InterfaceSettings> for MailboxWindow {
+    fn window_class(&self) -> Option<&str> {
+        Self::WINDOW_CLASS.into()
+    }
+
+    fn to_window(
+        &self,
+        window_cache: &WindowCache,
+        application: &InterfaceSettings,
+        available_space: ScreenSize,
+    ) -> Window<InterfaceSettings> {
+        let elements = vec![
+            ScrollView::new(vec![MailView::new(self.mails.clone()).wrap()], size_bound!(100%, ? < 400)).wrap(),
+            ButtonBuilder::new()
+                .with_text("Load more")
+                .with_event(UserEvent::RequestMoreMails)
+                .with_width_bound(dimension_bound!(50%))
+                .build()
+                .wrap(),
+            ButtonBuilder::new()
+                .with_text("Write mail")
+                .with_event(UserEvent::OpenWriteMailWindow)
+                .with_width_bound(dimension_bound!(!))
+                .build()
+                .wrap(),
+            ButtonBuilder::new()
+                .with_text("Close")
+                .with_event(UserEvent::CloseMailbox)
+                .build()
+                .wrap(),
+        ];
+
+        WindowBuilder::new()
+            .with_title("Mailbox".to_string())
+            .with_class(Self::WINDOW_CLASS.to_string())
+            .with_size_bound(size_bound!(250 > 300 < 400, ?))
+            .with_elements(elements)
+            .build(window_cache, application, available_space)
+    }
+}
@@ -0,0 +1,7 @@
+mod mailbox;
+mod read;
+mod write;
+
+pub use self::mailbox::MailboxWindow;
+pub use self::read::MailWindow;
+pub use self::write::WriteMailWindow;
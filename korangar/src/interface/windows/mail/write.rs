@@ -0,0 +1,150 @@
+use derive_new::new;
+use korangar_interface::elements::{ButtonBuilder, ElementWrap, InputFieldBuilder};
+use korangar_interface::event::ClickAction;
+use korangar_interface::state::{PlainRemote, PlainTrackedState, Remote, TrackedState, TrackedStateClone};
+use korangar_interface::windows::{PrototypeWindow, Window, WindowBuilder};
+use korangar_interface::{dimension_bound, size_bound};
+use korangar_networking::{InventoryItem, MAXIMUM_MAIL_TEXT_LENGTH, MAXIMUM_MAIL_TITLE_LENGTH};
+
+use crate::input::UserEvent;
+use crate::interface::application::InterfaceSettings;
+use crate::interface::elements::InventoryContainer;
+use crate::interface::layout::ScreenSize;
+use crate::interface::resource::ItemSource;
+use crate::interface::windows::WindowCache;
+use crate::inventory::MailRecipient;
+use crate::world::ResourceMetadata;
+
+/// Number of items that can be attached to a single mail.
+const ATTACHMENT_SLOTS: usize = 5;
+
+#[derive(new)]
+pub struct WriteMailWindow {
+    attachments: PlainRemote<Vec<InventoryItem<ResourceMetadata>>>,
+    recipient: PlainRemote<Option<MailRecipient>>,
+}
+
+impl WriteMailWindow {
+    pub const WINDOW_CLASS: &'static str = "write_mail";
+}
+
+impl PrototypeWindow<InterfaceSettings> for WriteMailWindow {
+    fn window_class(&self) -> Option<&str> {
+        Self::WINDOW_CLASS.into()
+    }
+
+    fn to_window(
+        &self,
+        window_cache: &WindowCache,
+        application: &InterfaceSettings,
+        available_space: ScreenSize,
+    ) -> Window<InterfaceSettings> {
+        let recipient_name = PlainTrackedState::<String>::default();
+        let title = PlainTrackedState::<String>::default();
+        let text = PlainTrackedState::<String>::default();
+        let zeny = PlainTrackedState::<String>::default();
+
+        let check_action = {
+            let recipient_name = recipient_name.clone();
+
+            Box::new(move || {
+                let name = recipient_name.cloned();
+
+                (!name.is_empty())
+                    .then_some(vec![ClickAction::Custom(UserEvent::CheckMailRecipient(name))])
+                    .unwrap_or_default()
+            })
+        };
+
+        let send_disabled_selector = {
+            let recipient_name = recipient_name.clone();
+            let title = title.clone();
+            let zeny = zeny.clone();
+            let recipient = self.recipient.clone_state();
+
+            move || {
+                let zeny = zeny.get();
+                let is_zeny_valid = zeny.is_empty() || zeny.parse::<u64>().is_ok();
+                let is_recipient_valid = recipient
+                    .get()
+                    .as_ref()
+                    .is_some_and(|recipient| recipient.name == *recipient_name.get());
+
+                !is_recipient_valid || !is_zeny_valid || title.get().is_empty()
+            }
+        };
+
+        let send_action = {
+            let title = title.clone();
+            let text = text.clone();
+            let zeny = zeny.clone();
+
+            move || {
+                let zeny = zeny.get().parse().unwrap_or_default();
+
+                vec![ClickAction::Custom(UserEvent::SendMail {
+                    title: title.cloned(),
+                    text: text.cloned(),
+                    zeny,
+                })]
+            }
+        };
+
+        let elements = vec![
+            InputFieldBuilder::new()
+                .with_state(recipient_name)
+                .with_ghost_text("Recipient")
+                .with_enter_action(check_action.clone())
+                .with_length(24)
+                .with_width_bound(dimension_bound!(70%))
+                .build()
+                .wrap(),
+            ButtonBuilder::new()
+                .with_text("Check")
+                .with_event(check_action)
+                .with_width_bound(dimension_bound!(!))
+                .build()
+                .wrap(),
+            InputFieldBuilder::new()
+                .with_state(title)
+                .with_ghost_text("Title")
+                // The maximum length includes the null terminator.
+                .with_length(MAXIMUM_MAIL_TITLE_LENGTH - 1)
+                .build()
+                .wrap(),
+            InputFieldBuilder::new()
+                .with_state(text)
+                .with_ghost_text("Text")
+                .with_length(MAXIMUM_MAIL_TEXT_LENGTH - 1)
+                .build()
+                .wrap(),
+            InputFieldBuilder::new()
+                .with_state(zeny)
+                .with_ghost_text("Zeny")
+                .with_length(10)
+                .build()
+                .wrap(),
+            InventoryContainer::new(self.attachments.clone(), ItemSource::Mail, ATTACHMENT_SLOTS).wrap(),
+            ButtonBuilder::new()
+                .with_text("Send")
+                .with_disabled_selector(send_disabled_selector)
+                .with_event(Box::new(send_action))
+                .with_width_bound(dimension_bound!(50%))
+                .build()
+                .wrap(),
+            ButtonBuilder::new()
+                .with_text("Cancel")
+                .with_event(UserEvent::CancelWriteMail)
+                .with_width_bound(dimension_bound!(!))
+                .build()
+                .wrap(),
+        ];
+
+        WindowBuilder::new()
+            .with_title("Write mail".to_string())
+            .with_class(Self::WINDOW_CLASS.to_string())
+            .with_size_bound(size_bound!(250 > 300 < 400, ?))
+            .with_elements(elements)
+            .build(window_cache, application, available_space)
+    }
+}
@@ -1,10 +1,12 @@
 mod account;
 mod cache;
 mod character;
+mod companion;
 #[cfg(feature = "debug")]
 mod debug;
 mod friends;
 mod generic;
+mod mail;
 mod mutable;
 mod settings;
 mod shop;
@@ -12,10 +14,12 @@ mod shop;
 pub use self::account::*;
 pub use self::cache::WindowCache;
 pub use self::character::*;
+pub use self::companion::*;
 #[cfg(feature = "debug")]
 pub use self::debug::*;
 pub use self::friends::*;
 pub use self::generic::*;
+pub use self::mail::*;
 pub use self::mutable::*;
 pub use self::settings::*;
 pub use self::shop::*;
@@ -1,6 +1,7 @@
 use korangar_interface::elements::{ElementWrap, StateButtonBuilder};
 use korangar_interface::size_bound;
 use korangar_interface::state::TrackedStateBinary;
+use korangar_interface::tooltip::Tooltip;
 use korangar_interface::windows::{PrototypeWindow, Window, WindowBuilder};
 
 use crate::interface::application::InterfaceSettings;
@@ -45,6 +46,9 @@ where
                 .with_text("Mute audio on focus loss")
                 .with_event(self.mute_on_focus_loss.toggle_action())
                 .with_remote(self.mute_on_focus_loss.new_remote())
+                .with_tooltip(
+                    Tooltip::new("Mute audio on focus loss").with_line("Mutes all audio while the game window is in the background."),
+                )
                 .build()
                 .wrap(),
         ];
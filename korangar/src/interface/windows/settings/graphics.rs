@@ -1,5 +1,6 @@
 use korangar_interface::elements::{ElementWrap, PickList, PrototypeElement, StateButtonBuilder, Text};
 use korangar_interface::state::{TrackedState, TrackedStateBinary};
+use korangar_interface::tooltip::Tooltip;
 use korangar_interface::windows::{PrototypeWindow, Window, WindowBuilder};
 use korangar_interface::{dimension_bound, size_bound};
 
@@ -180,12 +181,20 @@ where
                 .with_options(vec![("Classic", LightingMode::Classic), ("Enhanced", LightingMode::Enhanced)])
                 .with_selected(self.lighting_mode.clone())
                 .with_event(Box::new(Vec::new))
+                .with_tooltip(
+                    Tooltip::new("Lighting mode")
+                        .with_line("Enhanced lighting uses physically based shading instead of the lighting of the original client."),
+                )
                 .with_width(dimension_bound!(!))
                 .wrap(),
             StateButtonBuilder::new()
                 .with_text("Triple buffering")
                 .with_event(self.triple_buffering.toggle_action())
                 .with_remote(self.triple_buffering.new_remote())
+                .with_tooltip(
+                    Tooltip::new("Triple buffering")
+                        .with_line("Reduces stuttering when VSYNC is enabled, at the cost of slightly more input latency."),
+                )
                 .build()
                 .wrap(),
             Text::default()
@@ -202,6 +211,10 @@ where
                 ])
                 .with_selected(self.texture_filtering.clone())
                 .with_event(Box::new(Vec::new))
+                .with_tooltip(
+                    Tooltip::new("Texture filtering")
+                        .with_line("How textures are sampled when they are displayed larger or smaller than their original size."),
+                )
                 .with_width(dimension_bound!(!))
                 .wrap(),
             Text::default().with_text("Multisampling").with_width(dimension_bound!(50%)).wrap(),
@@ -209,6 +222,9 @@ where
                 .with_options(self.supported_msaa.clone())
                 .with_selected(self.msaa.clone())
                 .with_event(Box::new(Vec::new))
+                .with_tooltip(
+                    Tooltip::new("Multisampling").with_line("Smooths the edges of geometry by taking multiple samples per pixel."),
+                )
                 .with_width(dimension_bound!(!))
                 .wrap(),
             Text::default().with_text("Supersampling").with_width(dimension_bound!(50%)).wrap(),
@@ -216,6 +232,10 @@ where
                 .with_options(vec![("Off", Ssaa::Off), ("x2", Ssaa::X2), ("x3", Ssaa::X3), ("x4", Ssaa::X4)])
                 .with_selected(self.ssaa.clone())
                 .with_event(Box::new(Vec::new))
+                .with_tooltip(
+                    Tooltip::new("Supersampling")
+                        .with_line("Renders the scene at a higher resolution and scales it down. Has a large impact on performance."),
+                )
                 .with_width(dimension_bound!(!))
                 .wrap(),
             Text::default()
@@ -229,6 +249,10 @@ where
                 ])
                 .with_selected(self.screen_space_anti_aliasing.clone())
                 .with_event(Box::new(Vec::new))
+                .with_tooltip(
+                    Tooltip::new("Screen space AA")
+                        .with_line("Smooths edges as a post processing step. Cheap, but slightly blurs the image."),
+                )
                 .with_width(dimension_bound!(!))
                 .wrap(),
             Text::default().with_text("Shadow quality").with_width(dimension_bound!(50%)).wrap(),
@@ -243,6 +267,7 @@ where
                 ])
                 .with_selected(self.shadow_quality.clone())
                 .with_event(Box::new(Vec::new))
+                .with_tooltip(Tooltip::new("Shadow quality").with_line("Soft shadows look more natural, but take more samples per pixel."))
                 .with_width(dimension_bound!(!))
                 .wrap(),
             Text::default().with_text("Shadow detail").with_width(dimension_bound!(50%)).wrap(),
@@ -254,19 +279,23 @@ where
                 ])
                 .with_selected(self.shadow_detail.clone())
                 .with_event(Box::new(Vec::new))
+                .with_tooltip(Tooltip::new("Shadow detail").with_line("Resolution of the shadow maps."))
                 .with_width(dimension_bound!(!))
                 .wrap(),
             StateButtonBuilder::new()
                 .with_text("High Quality Interface")
                 .with_event(self.high_quality_interface.toggle_action())
                 .with_remote(self.high_quality_interface.new_remote())
+                .with_tooltip(
+                    Tooltip::new("High quality interface").with_line("Renders the interface at twice the resolution for sharper text."),
+                )
                 .build()
                 .wrap(),
             application.to_element("Interface settings".to_string()),
         ];
 
-        // TODO: Instead of not showing these options, disable the checkboxes and add a
-        //       tooltip
+        // TODO: Instead of not showing these options, disable the checkboxes and
+        //       explain why in the tooltip
         if self.present_mode_info.supports_immediate || self.present_mode_info.supports_mailbox {
             elements.insert(
                 2,
@@ -274,6 +303,10 @@ where
                     .with_text("Enable VSYNC")
                     .with_event(self.vsync.toggle_action())
                     .with_remote(self.vsync.new_remote())
+                    .with_tooltip(
+                        Tooltip::new("Enable VSYNC")
+                            .with_line("Synchronizes the framerate with the refresh rate of the monitor to prevent tearing."),
+                    )
                     .build()
                     .wrap(),
             );
@@ -297,6 +330,7 @@ where
                     ])
                     .with_selected(self.limit_framerate.clone())
                     .with_event(Box::new(Vec::new))
+                    .with_tooltip(Tooltip::new("Limit framerate").with_line("Maximum number of frames rendered per second."))
                     .with_width(dimension_bound!(!))
                     .wrap(),
             );
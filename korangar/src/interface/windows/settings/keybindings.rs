@@ -0,0 +1,56 @@
+use derive_new::new;
+use korangar_interface::elements::{ButtonBuilder, ElementWrap, ScrollView};
+use korangar_interface::size_bound;
+use korangar_interface::state::PlainRemote;
+use korangar_interface::windows::{PrototypeWindow, Window, WindowBuilder};
+
+use crate::input::UserEvent;
+use crate::interface::application::InterfaceSettings;
+use crate::interface::elements::KeyBindingsView;
+use crate::interface::layout::ScreenSize;
+use crate::interface::windows::WindowCache;
+use crate::settings::{InputAction, KeyBindings};
+
+#[derive(new)]
+pub struct KeyBindingsWindow {
+    key_bindings: PlainRemote<KeyBindings>,
+    capture: PlainRemote<Option<InputAction>>,
+}
+
+impl KeyBindingsWindow {
+    pub const WINDOW_CLASS: &'static str = "key_bindings";
+}
+
+impl PrototypeWindow<InterfaceSettings> for KeyBindingsWindow {
+    fn window_class(&self) -> Option<&str> {
+        Self::WINDOW_CLASS.into()
+    }
+
+    fn to_window(
+        &self,
+        window_cache: &WindowCache,
+        application: &InterfaceSettings,
+        available_space: ScreenSize,
+    ) -> Window<InterfaceSettings> {
+        let elements = vec![
+            ButtonBuilder::new()
+                .with_text("Reset to defaults")
+                .with_event(UserEvent::ResetKeyBindings)
+                .build()
+                .wrap(),
+            ScrollView::new(
+                vec![KeyBindingsView::new(self.key_bindings.clone(), self.capture.clone()).wrap()],
+                size_bound!(100%, ? < super),
+            )
+            .wrap(),
+        ];
+
+        WindowBuilder::new()
+            .with_title("Key Bindings".to_string())
+            .with_class(Self::WINDOW_CLASS.to_string())
+            .with_size_bound(size_bound!(400 > 450 < 600, ? < 80%))
+            .with_elements(elements)
+            .closable()
+            .build(window_cache, application, available_space)
+    }
+}
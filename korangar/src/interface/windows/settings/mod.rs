@@ -1,9 +1,11 @@
 mod audio;
 mod graphics;
+mod keybindings;
 #[cfg(feature = "debug")]
 mod render;
 
 pub use self::audio::AudioSettingsWindow;
 pub use self::graphics::GraphicsSettingsWindow;
+pub use self::keybindings::KeyBindingsWindow;
 #[cfg(feature = "debug")]
 pub use self::render::RenderSettingsWindow;
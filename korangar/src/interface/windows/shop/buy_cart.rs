@@ -6,7 +6,7 @@ use korangar_interface::windows::{PrototypeWindow, Window, WindowBuilder};
 use korangar_networking::ShopItem;
 
 use crate::interface::application::InterfaceSettings;
-use crate::interface::elements::BuyCartContainer;
+use crate::interface::elements::{BuyCartContainer, BuyCartKind};
 use crate::interface::layout::ScreenSize;
 use crate::interface::windows::WindowCache;
 use crate::world::ResourceMetadata;
@@ -31,7 +31,7 @@ impl PrototypeWindow<InterfaceSettings> for BuyCartWindow {
         application: &InterfaceSettings,
         available_space: ScreenSize,
     ) -> Window<InterfaceSettings> {
-        let elements = vec![BuyCartContainer::new(self.cart.clone()).wrap()];
+        let elements = vec![BuyCartContainer::new(self.cart.clone(), BuyCartKind::Shop).wrap()];
         let elements = vec![ScrollView::new(elements, size_bound!(100%, ? < super)).wrap()];
 
         WindowBuilder::new()
@@ -0,0 +1,53 @@
+use derive_new::new;
+use korangar_interface::elements::{ElementWrap, ScrollView};
+use korangar_interface::size_bound;
+use korangar_interface::state::PlainTrackedState;
+use korangar_interface::windows::{PrototypeWindow, Window, WindowBuilder};
+use korangar_networking::ShopItem;
+
+use crate::interface::application::InterfaceSettings;
+use crate::interface::elements::{BuyCartContainer, BuyCartKind};
+use crate::interface::layout::ScreenSize;
+use crate::interface::windows::WindowCache;
+use crate::world::ResourceMetadata;
+
+#[derive(new)]
+pub struct BuyingStoreCartWindow {
+    cart: PlainTrackedState<Vec<ShopItem<(ResourceMetadata, u32)>>>,
+    maximum_item_count: usize,
+}
+
+impl BuyingStoreCartWindow {
+    pub const WINDOW_CLASS: &'static str = "buying_store_cart";
+}
+
+impl PrototypeWindow<InterfaceSettings> for BuyingStoreCartWindow {
+    fn window_class(&self) -> Option<&str> {
+        Self::WINDOW_CLASS.into()
+    }
+
+    fn to_window(
+        &self,
+        window_cache: &WindowCache,
+        application: &InterfaceSettings,
+        available_space: ScreenSize,
+    ) -> Window<InterfaceSettings> {
+        let elements = vec![
+            BuyCartContainer::new(self.cart.clone(), BuyCartKind::BuyingStore {
+                title: PlainTrackedState::default(),
+                zeny_limit: PlainTrackedState::default(),
+                prices: PlainTrackedState::default(),
+                maximum_item_count: self.maximum_item_count,
+            })
+            .wrap(),
+        ];
+        let elements = vec![ScrollView::new(elements, size_bound!(100%, ? < super)).wrap()];
+
+        WindowBuilder::new()
+            .with_title(format!("Wanted (max. {})", self.maximum_item_count))
+            .with_class(Self::WINDOW_CLASS.to_string())
+            .with_size_bound(size_bound!(300 > 400 < 500, ? < 60%))
+            .with_elements(elements)
+            .build(window_cache, application, available_space)
+    }
+}
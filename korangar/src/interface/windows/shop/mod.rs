@@ -1,11 +1,23 @@
 mod buy;
 mod buy_cart;
 mod buy_or_sell;
+mod buying_store;
+mod buying_store_cart;
+mod own_buying_store;
 mod sell;
 mod sell_cart;
+mod vending;
+mod vending_cart;
+mod vending_store;
 
 pub use self::buy::*;
 pub use self::buy_cart::*;
 pub use self::buy_or_sell::*;
+pub use self::buying_store::*;
+pub use self::buying_store_cart::*;
+pub use self::own_buying_store::*;
 pub use self::sell::*;
 pub use self::sell_cart::*;
+pub use self::vending::*;
+pub use self::vending_cart::*;
+pub use self::vending_store::*;
@@ -6,7 +6,7 @@ use korangar_interface::windows::{PrototypeWindow, Window, WindowBuilder};
 use korangar_networking::SellItem;
 
 use crate::interface::application::InterfaceSettings;
-use crate::interface::elements::SellCartContainer;
+use crate::interface::elements::{SellCartContainer, SellCartKind};
 use crate::interface::layout::ScreenSize;
 use crate::interface::windows::WindowCache;
 use crate::world::ResourceMetadata;
@@ -31,7 +31,7 @@ impl PrototypeWindow<InterfaceSettings> for SellCartWindow {
         application: &InterfaceSettings,
         available_space: ScreenSize,
     ) -> Window<InterfaceSettings> {
-        let elements = vec![SellCartContainer::new(self.cart.clone()).wrap()];
+        let elements = vec![SellCartContainer::new(self.cart.clone(), SellCartKind::Shop).wrap()];
         let elements = vec![ScrollView::new(elements, size_bound!(100%, ? < super)).wrap()];
 
         WindowBuilder::new()
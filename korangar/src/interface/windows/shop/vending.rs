@@ -0,0 +1,46 @@
+use derive_new::new;
+use korangar_interface::elements::{ElementWrap, ScrollView};
+use korangar_interface::size_bound;
+use korangar_interface::state::{PlainRemote, PlainTrackedState};
+use korangar_interface::windows::{PrototypeWindow, Window, WindowBuilder};
+use korangar_networking::SellItem;
+
+use crate::interface::application::InterfaceSettings;
+use crate::interface::elements::SellContainer;
+use crate::interface::layout::ScreenSize;
+use crate::interface::windows::WindowCache;
+use crate::world::ResourceMetadata;
+
+/// Lists the items in the player's cart that can be put up for sale.
+#[derive(new)]
+pub struct VendingWindow {
+    cart_items: PlainRemote<Vec<SellItem<(ResourceMetadata, u16)>>>,
+    items_for_sale: PlainTrackedState<Vec<SellItem<(ResourceMetadata, u16)>>>,
+}
+
+impl VendingWindow {
+    pub const WINDOW_CLASS: &'static str = "vending";
+}
+
+impl PrototypeWindow<InterfaceSettings> for VendingWindow {
+    fn window_class(&self) -> Option<&str> {
+        Self::WINDOW_CLASS.into()
+    }
+
+    fn to_window(
+        &self,
+        window_cache: &WindowCache,
+        application: &InterfaceSettings,
+        available_space: ScreenSize,
+    ) -> Window<InterfaceSettings> {
+        let elements = vec![SellContainer::new(self.cart_items.clone(), self.items_for_sale.clone()).wrap()];
+        let elements = vec![ScrollView::new(elements, size_bound!(100%, ? < super)).wrap()];
+
+        WindowBuilder::new()
+            .with_title("Vending".to_string())
+            .with_class(Self::WINDOW_CLASS.to_string())
+            .with_size_bound(size_bound!(300 > 400 < 500, ? < 60%))
+            .with_elements(elements)
+            .build(window_cache, application, available_space)
+    }
+}
@@ -0,0 +1,48 @@
+use derive_new::new;
+use korangar_interface::elements::{ButtonBuilder, ElementWrap, Text};
+use korangar_interface::size_bound;
+use korangar_interface::windows::{PrototypeWindow, Window, WindowBuilder};
+
+use crate::input::UserEvent;
+use crate::interface::application::InterfaceSettings;
+use crate::interface::layout::ScreenSize;
+use crate::interface::windows::WindowCache;
+
+/// Shown while the player's own vending store is open.
+#[derive(new)]
+pub struct VendingStoreWindow {
+    title: String,
+}
+
+impl VendingStoreWindow {
+    pub const WINDOW_CLASS: &'static str = "vending_store";
+}
+
+impl PrototypeWindow<InterfaceSettings> for VendingStoreWindow {
+    fn window_class(&self) -> Option<&str> {
+        Self::WINDOW_CLASS.into()
+    }
+
+    fn to_window(
+        &self,
+        window_cache: &WindowCache,
+        application: &InterfaceSettings,
+        available_space: ScreenSize,
+    ) -> Window<InterfaceSettings> {
+        let elements = vec![
+            Text::default().with_text(self.title.clone()).wrap(),
+            ButtonBuilder::new()
+                .with_text("Close store")
+                .with_event(UserEvent::CloseVendingStore)
+                .build()
+                .wrap(),
+        ];
+
+        WindowBuilder::new()
+            .with_title("Vending store".to_string())
+            .with_class(Self::WINDOW_CLASS.to_string())
+            .with_size_bound(size_bound!(200 > 250 < 300, ?))
+            .with_elements(elements)
+            .build(window_cache, application, available_space)
+    }
+}
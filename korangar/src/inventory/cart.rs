@@ -0,0 +1,163 @@
+use std::cell::Ref;
+use std::sync::Arc;
+
+use korangar_interface::state::{PlainRemote, PlainTrackedState, TrackedState};
+use korangar_networking::{InventoryItem, InventoryItemDetails, NoMetadata, SellItem};
+use ragnarok_packets::{InventoryIndex, ItemId, Price};
+
+use super::Inventory;
+use crate::graphics::Texture;
+use crate::loaders::AsyncLoader;
+use crate::world::{Library, ResourceMetadata};
+
+/// Number of items and weight of the items in the player's cart.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct CartCapacity {
+    pub item_count: u16,
+    pub maximum_item_count: u16,
+    /// Weight in tenths, the same unit the map server uses.
+    pub weight: u32,
+    pub maximum_weight: u32,
+}
+
+/// The push cart of a merchant. The items are stored just like the items in
+/// the inventory, but the map server also tells us how full the cart is.
+#[derive(Default)]
+pub struct Cart {
+    items: Inventory,
+    capacity: PlainTrackedState<CartCapacity>,
+}
+
+impl Cart {
+    pub fn fill(&mut self, async_loader: &AsyncLoader, library: &Library, items: Vec<InventoryItem<NoMetadata>>) {
+        self.items.fill(async_loader, library, items);
+    }
+
+    pub fn add_item(&mut self, async_loader: &AsyncLoader, library: &Library, item: InventoryItem<NoMetadata>) {
+        self.items.add_item(async_loader, library, item);
+    }
+
+    pub fn update_item_sprite(&mut self, item_id: ItemId, texture: Arc<Texture>) {
+        self.items.update_item_sprite(item_id, texture);
+    }
+
+    pub fn remove_item(&mut self, index: InventoryIndex, remove_amount: u16) {
+        self.items.remove_item(index, remove_amount);
+    }
+
+    /// All items in the cart with their full amount, ready to be put up for
+    /// sale in a vending store. The prices are set by the player later.
+    pub fn items_for_sale(&self) -> Vec<SellItem<(ResourceMetadata, u16)>> {
+        self.items
+            .get_items()
+            .iter()
+            .map(|item| {
+                let amount = match &item.details {
+                    InventoryItemDetails::Regular { amount, .. } => *amount,
+                    InventoryItemDetails::Equippable { .. } => 1,
+                };
+
+                SellItem {
+                    metadata: (item.metadata.clone(), amount),
+                    inventory_index: item.index,
+                    price: Price(0),
+                    overcharge_price: Price(0),
+                }
+            })
+            .collect()
+    }
+
+    pub fn set_capacity(&mut self, capacity: CartCapacity) {
+        self.capacity.set(capacity);
+    }
+
+    /// Remove all items, for example when the cart is taken off.
+    pub fn clear(&mut self) {
+        self.items.clear();
+        self.capacity.set(CartCapacity::default());
+    }
+
+    pub fn get_items(&self) -> Ref<'_, Vec<InventoryItem<ResourceMetadata>>> {
+        self.items.get_items()
+    }
+
+    pub fn item_remote(&self) -> PlainRemote<Vec<InventoryItem<ResourceMetadata>>> {
+        self.items.item_remote()
+    }
+
+    pub fn capacity_remote(&self) -> PlainRemote<CartCapacity> {
+        self.capacity.new_remote()
+    }
+}
+
+#[cfg(test)]
+mod items {
+    use korangar_interface::state::{PlainTrackedState, Remote};
+    use korangar_networking::{InventoryItem, InventoryItemDetails};
+    use ragnarok_packets::{EquipPosition, InventoryIndex, ItemId, RegularItemFlags};
+
+    use super::{Cart, CartCapacity};
+    use crate::inventory::Inventory;
+    use crate::world::ResourceMetadata;
+
+    fn item(index: u16, name: &str, amount: u16) -> InventoryItem<ResourceMetadata> {
+        InventoryItem {
+            metadata: ResourceMetadata {
+                texture: None,
+                name: name.to_owned(),
+                description: Vec::new(),
+                slot_count: 0,
+                cards: Vec::new(),
+            },
+            index: InventoryIndex(index),
+            item_id: ItemId(500 + index as u32),
+            item_type: 0,
+            slot: [0; 4],
+            hire_expiration_date: 0,
+            details: InventoryItemDetails::Regular {
+                amount,
+                equipped_position: EquipPosition::NONE,
+                flags: RegularItemFlags::empty(),
+            },
+        }
+    }
+
+    fn cart(items: Vec<InventoryItem<ResourceMetadata>>) -> Cart {
+        Cart {
+            items: Inventory {
+                items: PlainTrackedState::new(items),
+            },
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn items_for_sale() {
+        let cart = cart(vec![item(2, "Red Potion", 30), item(3, "Jellopy", 1)]);
+        let items = cart.items_for_sale();
+
+        assert_eq!(items.len(), 2);
+        assert_eq!(items[0].inventory_index, InventoryIndex(2));
+        assert_eq!(items[0].metadata.1, 30);
+        assert_eq!(items[1].metadata.0.name, "Jellopy");
+        assert_eq!(items[1].metadata.1, 1);
+    }
+
+    #[test]
+    fn clear_resets_capacity() {
+        let mut cart = cart(vec![item(2, "Red Potion", 30)]);
+        let mut capacity = cart.capacity_remote();
+
+        cart.set_capacity(CartCapacity {
+            item_count: 1,
+            maximum_item_count: 100,
+            weight: 300,
+            maximum_weight: 80000,
+        });
+        cart.clear();
+
+        assert!(cart.get_items().is_empty());
+        assert_eq!(capacity.get().item_count, 0);
+        assert_eq!(capacity.get().maximum_weight, 0);
+    }
+}
@@ -0,0 +1,139 @@
+use korangar_interface::state::{PlainRemote, PlainTrackedState, TrackedState, TrackedStateExt};
+use korangar_networking::{CompanionType, CompanionUpdate, MercenaryParameter};
+use ragnarok_packets::{EntityId, HomunculusInformationPacket, MercenaryInformationPacket, PetInformationPacket};
+
+/// A companion owned by the player. The entity id and the properties are sent
+/// in different packets, so either of them might be missing.
+#[derive(Clone, Debug)]
+pub struct Companion<T> {
+    pub entity_id: Option<EntityId>,
+    pub information: Option<T>,
+}
+
+impl<T> Default for Companion<T> {
+    fn default() -> Self {
+        Self {
+            entity_id: None,
+            information: None,
+        }
+    }
+}
+
+#[derive(Default)]
+pub struct Companions {
+    homunculus: PlainTrackedState<Companion<HomunculusInformationPacket>>,
+    mercenary: PlainTrackedState<Companion<MercenaryInformationPacket>>,
+    pet: PlainTrackedState<Companion<PetInformationPacket>>,
+}
+
+impl Companions {
+    pub fn set_homunculus_information(&mut self, information: HomunculusInformationPacket) {
+        self.homunculus.mutate(|homunculus| homunculus.information = Some(information));
+    }
+
+    pub fn set_mercenary_information(&mut self, information: MercenaryInformationPacket) {
+        self.mercenary.mutate(|mercenary| {
+            mercenary.entity_id = Some(information.entity_id);
+            mercenary.information = Some(information);
+        });
+    }
+
+    pub fn set_pet_information(&mut self, information: PetInformationPacket) {
+        self.pet.mutate(|pet| pet.information = Some(information));
+    }
+
+    pub fn update(&mut self, companion_type: CompanionType, entity_id: EntityId, update: CompanionUpdate) {
+        match companion_type {
+            CompanionType::Homunculus => self.homunculus.mutate(|homunculus| {
+                homunculus.entity_id = Some(entity_id);
+
+                if let Some(information) = homunculus.information.as_mut() {
+                    match update {
+                        CompanionUpdate::Intimacy(intimacy) => information.intimacy = intimacy as u16,
+                        CompanionUpdate::Hunger(hunger) => information.hunger = hunger as u16,
+                        _ => {}
+                    }
+                }
+            }),
+            CompanionType::Pet => {
+                // Accessory and performance updates are broadcast for all pets in sight,
+                // so only the spawn packet tells us which pet belongs to the player.
+                if update != CompanionUpdate::Spawned && self.pet.get().entity_id != Some(entity_id) {
+                    return;
+                }
+
+                self.pet.mutate(|pet| {
+                    pet.entity_id = Some(entity_id);
+
+                    if let Some(information) = pet.information.as_mut() {
+                        match update {
+                            CompanionUpdate::Intimacy(intimacy) => information.intimacy = intimacy as u16,
+                            CompanionUpdate::Hunger(hunger) => information.hunger = hunger as u16,
+                            CompanionUpdate::Accessory(accessory_id) => information.accessory_id = accessory_id as u16,
+                            _ => {}
+                        }
+                    }
+                });
+            }
+            CompanionType::Mercenary => self.mercenary.mutate(|mercenary| mercenary.entity_id = Some(entity_id)),
+        }
+    }
+
+    pub fn update_mercenary(&mut self, parameter: MercenaryParameter) {
+        self.mercenary.mutate(|mercenary| {
+            let Some(information) = mercenary.information.as_mut() else {
+                return;
+            };
+
+            match parameter {
+                MercenaryParameter::HealthPoints(value) => information.health_points = value,
+                MercenaryParameter::MaximumHealthPoints(value) => information.maximum_health_points = value,
+                MercenaryParameter::SpellPoints(value) => information.spell_points = value,
+                MercenaryParameter::MaximumSpellPoints(value) => information.maximum_spell_points = value,
+                MercenaryParameter::Attack(value) => information.attack = value as u16,
+                MercenaryParameter::MagicAttack(value) => information.magic_attack = value as u16,
+                MercenaryParameter::Defense(value) => information.defense = value as u16,
+                MercenaryParameter::MagicDefense(value) => information.magic_defense = value as u16,
+                MercenaryParameter::Hit(value) => information.hit = value as u16,
+                MercenaryParameter::Critical(value) => information.critical = value as u16,
+                MercenaryParameter::AttackSpeed(value) => information.attack_speed = value as u16,
+                MercenaryParameter::Flee(value) => information.flee = value as u16,
+                MercenaryParameter::KillCount(value) => information.kill_count = value,
+                MercenaryParameter::Loyalty(value) => information.loyalty = value as u16,
+            }
+        });
+    }
+
+    /// Check if an entity is one of the player's companions.
+    pub fn is_owned(&self, entity_id: EntityId) -> bool {
+        self.get_entity_id(CompanionType::Homunculus) == Some(entity_id)
+            || self.get_entity_id(CompanionType::Mercenary) == Some(entity_id)
+            || self.get_entity_id(CompanionType::Pet) == Some(entity_id)
+    }
+
+    pub fn get_entity_id(&self, companion_type: CompanionType) -> Option<EntityId> {
+        match companion_type {
+            CompanionType::Homunculus => self.homunculus.get().entity_id,
+            CompanionType::Mercenary => self.mercenary.get().entity_id,
+            CompanionType::Pet => self.pet.get().entity_id,
+        }
+    }
+
+    pub fn clear(&mut self) {
+        self.homunculus.set(Companion::default());
+        self.mercenary.set(Companion::default());
+        self.pet.set(Companion::default());
+    }
+
+    pub fn homunculus_remote(&self) -> PlainRemote<Companion<HomunculusInformationPacket>> {
+        self.homunculus.new_remote()
+    }
+
+    pub fn mercenary_remote(&self) -> PlainRemote<Companion<MercenaryInformationPacket>> {
+        self.mercenary.new_remote()
+    }
+
+    pub fn pet_remote(&self) -> PlainRemote<Companion<PetInformationPacket>> {
+        self.pet.new_remote()
+    }
+}
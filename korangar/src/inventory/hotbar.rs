@@ -1,42 +1,99 @@
 use std::cell::Ref;
 
-use korangar_interface::state::{PlainRemote, PlainTrackedState, TrackedState, TrackedStateExt};
-use korangar_networking::NetworkingSystem;
+use korangar_interface::state::{PlainRemote, PlainTrackedState, TrackedState, TrackedStateExt, ValueState};
+use korangar_networking::{InventoryItem, NetworkingSystem};
 use ragnarok_packets::handler::PacketCallback;
-use ragnarok_packets::{HotbarSlot, HotbarTab, HotkeyData};
+use ragnarok_packets::{HotbarSlot, HotbarTab, HotkeyData, ItemId, SkillLevel};
 
 use super::Skill;
+use crate::world::ResourceMetadata;
+
+/// Number of slots in a single row of the hotbar.
+pub const HOTBAR_SLOT_COUNT: usize = 10;
+/// Number of hotbar rows the player can switch between.
+pub const HOTBAR_ROW_COUNT: usize = 4;
+/// Number of hotkeys the map server stores per tab. The rows are laid out
+/// consecutively, so a row can span two tabs.
+const HOTKEYS_PER_TAB: usize = 38;
+
+pub type HotbarRows = [[Option<HotbarEntry>; HOTBAR_SLOT_COUNT]; HOTBAR_ROW_COUNT];
+
+#[derive(Clone, Debug)]
+pub enum HotbarEntry {
+    Skill(Skill),
+    Item(InventoryItem<ResourceMetadata>),
+}
+
+impl HotbarEntry {
+    fn hotkey_data(&self) -> HotkeyData {
+        match self {
+            HotbarEntry::Skill(skill) => HotkeyData {
+                is_skill: true as u8,
+                skill_id: skill.skill_id.0 as u32,
+                quantity_or_skill_level: skill.skill_level,
+            },
+            HotbarEntry::Item(item) => HotkeyData {
+                is_skill: false as u8,
+                skill_id: item.item_id.0,
+                quantity_or_skill_level: SkillLevel(item.amount()),
+            },
+        }
+    }
+}
+
+/// Location of a hotbar slot in the hotkey storage of the map server.
+fn hotkey_location(row: usize, slot: HotbarSlot) -> (HotbarTab, HotbarSlot) {
+    let index = row * HOTBAR_SLOT_COUNT + slot.0 as usize;
+    let tab = HotbarTab((index / HOTKEYS_PER_TAB) as u16);
+    let slot = HotbarSlot((index % HOTKEYS_PER_TAB) as u16);
+
+    (tab, slot)
+}
+
+/// Row and slot of a hotkey sent by the map server. Returns `None` if the
+/// hotkey is outside of the rows shown by the client.
+pub fn hotbar_location(tab: HotbarTab, index: usize) -> Option<(usize, HotbarSlot)> {
+    let index = tab.0 as usize * HOTKEYS_PER_TAB + index;
+    let row = index / HOTBAR_SLOT_COUNT;
+    let slot = HotbarSlot((index % HOTBAR_SLOT_COUNT) as u16);
+
+    (row < HOTBAR_ROW_COUNT).then_some((row, slot))
+}
 
 #[derive(Default)]
 pub struct Hotbar {
-    skills: PlainTrackedState<[Option<Skill>; 10]>,
+    rows: PlainTrackedState<HotbarRows>,
+    current_row: PlainTrackedState<usize>,
+    /// Item hotkeys sent by the map server for items that are not in the
+    /// inventory yet. They are resolved once the items arrive.
+    pending_items: [[Option<ItemId>; HOTBAR_SLOT_COUNT]; HOTBAR_ROW_COUNT],
 }
 
 impl Hotbar {
-    /// Set the slot without notifying the map server.
-    pub fn set_slot(&mut self, slot: HotbarSlot, skill: Skill) {
-        self.skills.mutate(|skills| {
-            skills[slot.0 as usize] = Some(skill);
+    /// Set a slot of any row without notifying the map server.
+    pub fn set_slot(&mut self, row: usize, slot: HotbarSlot, entry: HotbarEntry) {
+        self.pending_items[row][slot.0 as usize] = None;
+        self.rows.mutate(|rows| {
+            rows[row][slot.0 as usize] = Some(entry);
         });
     }
 
-    /// Update the slot and notify the map server.
-    pub fn update_slot<Callback>(&mut self, networking_system: &mut NetworkingSystem<Callback>, slot: HotbarSlot, skill: Skill)
+    /// Update a slot of the current row and notify the map server.
+    pub fn update_slot<Callback>(&mut self, networking_system: &mut NetworkingSystem<Callback>, slot: HotbarSlot, entry: HotbarEntry)
     where
         Callback: PacketCallback + Send,
     {
-        let _ = networking_system.set_hotkey_data(HotbarTab(0), slot, HotkeyData {
-            is_skill: true as u8,
-            skill_id: skill.skill_id.0 as u32,
-            quantity_or_skill_level: skill.skill_level,
-        });
+        let row = *self.current_row.get();
+        let (tab, server_slot) = hotkey_location(row, slot);
+        let _ = networking_system.set_hotkey_data(tab, server_slot, entry.hotkey_data());
+        self.pending_items[row][slot.0 as usize] = None;
 
-        self.skills.mutate(|skills| {
-            skills[slot.0 as usize] = Some(skill);
+        self.rows.mutate(|rows| {
+            rows[row][slot.0 as usize] = Some(entry);
         });
     }
 
-    /// Swap two slots in the hotbar and notify the map server.
+    /// Swap two slots of the current row and notify the map server.
     pub fn swap_slot<Callback>(
         &mut self,
         networking_system: &mut NetworkingSystem<Callback>,
@@ -46,61 +103,237 @@ impl Hotbar {
         Callback: PacketCallback + Send,
     {
         if source_slot != destination_slot {
-            self.skills.mutate(|skills| {
-                let first = skills[source_slot.0 as usize].take();
-                let second = skills[destination_slot.0 as usize].take();
-
-                let first_data = first
-                    .as_ref()
-                    .map(|skill| HotkeyData {
-                        is_skill: true as u8,
-                        skill_id: skill.skill_id.0 as u32,
-                        quantity_or_skill_level: skill.skill_level,
-                    })
-                    .unwrap_or(HotkeyData::UNBOUND);
-
-                let second_data = second
-                    .as_ref()
-                    .map(|skill| HotkeyData {
-                        is_skill: true as u8,
-                        skill_id: skill.skill_id.0 as u32,
-                        quantity_or_skill_level: skill.skill_level,
-                    })
-                    .unwrap_or(HotkeyData::UNBOUND);
-
-                let _ = networking_system.set_hotkey_data(HotbarTab(0), destination_slot, first_data);
-                let _ = networking_system.set_hotkey_data(HotbarTab(0), source_slot, second_data);
-
-                skills[source_slot.0 as usize] = second;
-                skills[destination_slot.0 as usize] = first;
+            let row = *self.current_row.get();
+            self.pending_items[row][source_slot.0 as usize] = None;
+            self.pending_items[row][destination_slot.0 as usize] = None;
+
+            self.rows.mutate(|rows| {
+                let first = rows[row][source_slot.0 as usize].take();
+                let second = rows[row][destination_slot.0 as usize].take();
+
+                let first_data = first.as_ref().map(HotbarEntry::hotkey_data).unwrap_or(HotkeyData::UNBOUND);
+                let second_data = second.as_ref().map(HotbarEntry::hotkey_data).unwrap_or(HotkeyData::UNBOUND);
+
+                let (tab, slot) = hotkey_location(row, destination_slot);
+                let _ = networking_system.set_hotkey_data(tab, slot, first_data);
+                let (tab, slot) = hotkey_location(row, source_slot);
+                let _ = networking_system.set_hotkey_data(tab, slot, second_data);
+
+                rows[row][source_slot.0 as usize] = second;
+                rows[row][destination_slot.0 as usize] = first;
             })
         }
     }
 
-    /// Clear the slot without notifying the map server.
-    pub fn unset_slot(&mut self, slot: HotbarSlot) {
-        self.skills.mutate(|skills| {
-            skills[slot.0 as usize] = None;
+    /// Clear a slot of any row without notifying the map server.
+    pub fn unset_slot(&mut self, row: usize, slot: HotbarSlot) {
+        self.pending_items[row][slot.0 as usize] = None;
+        self.rows.mutate(|rows| {
+            rows[row][slot.0 as usize] = None;
         });
     }
 
-    /// Clear the slot and notify the map server.
-    pub fn clear_slot<Callback>(&mut self, networking_system: &mut NetworkingSystem<Callback>, slot: HotbarSlot)
+    /// Remember an item hotkey for an item that is not in the inventory yet.
+    /// The slot stays empty until [`Self::refresh_items`] finds the item.
+    pub fn set_pending_item(&mut self, row: usize, slot: HotbarSlot, item_id: ItemId) {
+        self.unset_slot(row, slot);
+        self.pending_items[row][slot.0 as usize] = Some(item_id);
+    }
+
+    /// Clear a slot of any row and notify the map server.
+    pub fn clear_slot<Callback>(&mut self, networking_system: &mut NetworkingSystem<Callback>, row: usize, slot: HotbarSlot)
     where
         Callback: PacketCallback + Send,
     {
-        let _ = networking_system.set_hotkey_data(HotbarTab(0), slot, HotkeyData::UNBOUND);
+        let (tab, server_slot) = hotkey_location(row, slot);
+        let _ = networking_system.set_hotkey_data(tab, server_slot, HotkeyData::UNBOUND);
+        self.pending_items[row][slot.0 as usize] = None;
+
+        self.rows.mutate(|rows| {
+            rows[row][slot.0 as usize] = None;
+        });
+    }
+
+    /// Update the items in the hotbar after the inventory changed. Pending
+    /// item hotkeys are put in their slots once the item is in the inventory,
+    /// and items that are no longer in the inventory are removed from the
+    /// hotbar.
+    pub fn refresh_items<Callback>(
+        &mut self,
+        networking_system: &mut NetworkingSystem<Callback>,
+        inventory_items: &[InventoryItem<ResourceMetadata>],
+    ) where
+        Callback: PacketCallback + Send,
+    {
+        let pending_items = &mut self.pending_items;
+
+        self.rows.with_mut(|rows| {
+            let mut changed = false;
+
+            for (row, pending_row) in pending_items.iter_mut().enumerate() {
+                for (slot, pending_item) in pending_row.iter_mut().enumerate() {
+                    let Some(item_id) = *pending_item else {
+                        continue;
+                    };
+
+                    if let Some(inventory_item) = inventory_items.iter().find(|inventory_item| inventory_item.item_id == item_id) {
+                        rows[row][slot] = Some(HotbarEntry::Item(inventory_item.clone()));
+                        *pending_item = None;
+                        changed = true;
+                    }
+                }
+            }
+
+            for (row, entries) in rows.iter_mut().enumerate() {
+                for (slot, entry) in entries.iter_mut().enumerate() {
+                    let Some(HotbarEntry::Item(item)) = entry else {
+                        continue;
+                    };
+
+                    match inventory_items.iter().find(|inventory_item| inventory_item.item_id == item.item_id) {
+                        Some(inventory_item) if inventory_item.amount() == item.amount() && inventory_item.index == item.index => {}
+                        Some(inventory_item) => {
+                            *item = inventory_item.clone();
+                            changed = true;
+                        }
+                        None => {
+                            let (tab, slot) = hotkey_location(row, HotbarSlot(slot as u16));
+                            let _ = networking_system.set_hotkey_data(tab, slot, HotkeyData::UNBOUND);
+
+                            *entry = None;
+                            changed = true;
+                        }
+                    }
+                }
+            }
 
-        self.skills.mutate(|skills| {
-            skills[slot.0 as usize] = None;
+            match changed {
+                true => ValueState::Mutated(()),
+                false => ValueState::Unchanged(()),
+            }
         });
     }
 
-    pub fn get_skill_in_slot(&self, slot: HotbarSlot) -> Ref<Option<Skill>> {
-        Ref::map(self.skills.get(), |skills| &skills[slot.0 as usize])
+    pub fn previous_row(&mut self) {
+        self.current_row
+            .mutate(|row| *row = (*row + HOTBAR_ROW_COUNT - 1) % HOTBAR_ROW_COUNT);
+    }
+
+    pub fn next_row(&mut self) {
+        self.current_row.mutate(|row| *row = (*row + 1) % HOTBAR_ROW_COUNT);
+    }
+
+    /// Get the entry in a slot of the current row.
+    pub fn get_entry_in_slot(&self, slot: HotbarSlot) -> Ref<Option<HotbarEntry>> {
+        let row = *self.current_row.get();
+        Ref::map(self.rows.get(), |rows| &rows[row][slot.0 as usize])
+    }
+
+    pub fn get_rows(&self) -> PlainRemote<HotbarRows> {
+        self.rows.new_remote()
+    }
+
+    pub fn current_row_remote(&self) -> PlainRemote<usize> {
+        self.current_row.new_remote()
+    }
+}
+
+#[cfg(test)]
+mod pending_items {
+    use korangar_interface::state::Remote;
+    use korangar_networking::{InventoryItem, InventoryItemDetails, NetworkingSystem};
+    use ragnarok_packets::{EquipPosition, HotbarSlot, InventoryIndex, ItemId, RegularItemFlags};
+
+    use super::{Hotbar, HotbarEntry};
+    use crate::world::ResourceMetadata;
+
+    fn item(index: u16, item_id: u32) -> InventoryItem<ResourceMetadata> {
+        InventoryItem {
+            metadata: ResourceMetadata {
+                texture: None,
+                name: String::new(),
+                description: Vec::new(),
+                slot_count: 0,
+                cards: Vec::new(),
+            },
+            index: InventoryIndex(index),
+            item_id: ItemId(item_id),
+            item_type: 0,
+            slot: [0; 4],
+            hire_expiration_date: 0,
+            details: InventoryItemDetails::Regular {
+                amount: 10,
+                equipped_position: EquipPosition::NONE,
+                flags: RegularItemFlags::empty(),
+            },
+        }
+    }
+
+    fn item_in_slot(hotbar: &Hotbar, row: usize, slot: usize) -> Option<ItemId> {
+        match &hotbar.get_rows().get()[row][slot] {
+            Some(HotbarEntry::Item(item)) => Some(item.item_id),
+            _ => None,
+        }
+    }
+
+    #[test]
+    fn hotkeys_before_inventory() {
+        let (mut networking_system, _) = NetworkingSystem::spawn();
+        let mut hotbar = Hotbar::default();
+
+        hotbar.set_pending_item(0, HotbarSlot(2), ItemId(501));
+        hotbar.set_pending_item(1, HotbarSlot(0), ItemId(502));
+        assert_eq!(item_in_slot(&hotbar, 0, 2), None);
+
+        hotbar.refresh_items(&mut networking_system, &[item(2, 501)]);
+        assert_eq!(item_in_slot(&hotbar, 0, 2), Some(ItemId(501)));
+        assert_eq!(item_in_slot(&hotbar, 1, 0), None);
+
+        // The second item is still pending until it arrives.
+        hotbar.refresh_items(&mut networking_system, &[item(2, 501), item(3, 502)]);
+        assert_eq!(item_in_slot(&hotbar, 1, 0), Some(ItemId(502)));
+    }
+
+    #[test]
+    fn unset_slot_drops_pending_item() {
+        let (mut networking_system, _) = NetworkingSystem::spawn();
+        let mut hotbar = Hotbar::default();
+
+        hotbar.set_pending_item(0, HotbarSlot(2), ItemId(501));
+        hotbar.unset_slot(0, HotbarSlot(2));
+        hotbar.refresh_items(&mut networking_system, &[item(2, 501)]);
+
+        assert_eq!(item_in_slot(&hotbar, 0, 2), None);
+    }
+}
+
+#[cfg(test)]
+mod location {
+    use ragnarok_packets::{HotbarSlot, HotbarTab};
+
+    use super::{HOTBAR_ROW_COUNT, HOTBAR_SLOT_COUNT, hotbar_location, hotkey_location};
+
+    #[test]
+    fn round_trip() {
+        for row in 0..HOTBAR_ROW_COUNT {
+            for slot in 0..HOTBAR_SLOT_COUNT {
+                let slot = HotbarSlot(slot as u16);
+                let (tab, index) = hotkey_location(row, slot);
+
+                assert_eq!(hotbar_location(tab, index.0 as usize), Some((row, slot)));
+            }
+        }
+    }
+
+    #[test]
+    fn row_spans_tabs() {
+        assert_eq!(hotkey_location(3, HotbarSlot(7)), (HotbarTab(0), HotbarSlot(37)));
+        assert_eq!(hotkey_location(3, HotbarSlot(8)), (HotbarTab(1), HotbarSlot(0)));
     }
 
-    pub fn get_skills(&self) -> PlainRemote<[Option<Skill>; 10]> {
-        self.skills.new_remote()
+    #[test]
+    fn outside_of_rows() {
+        assert_eq!(hotbar_location(HotbarTab(1), 2), None);
+        assert_eq!(hotbar_location(HotbarTab(2), 0), None);
     }
 }
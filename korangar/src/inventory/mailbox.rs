@@ -0,0 +1,326 @@
+use korangar_interface::state::{PlainRemote, PlainTrackedState, TrackedState, TrackedStateVec, ValueState};
+use korangar_networking::{InventoryItem, InventoryItemDetails};
+use ragnarok_packets::{CharacterId, InventoryIndex, MailId, MailInformation, MailType};
+
+use crate::world::ResourceMetadata;
+
+/// A mail that was opened by the player, including its attachments.
+#[derive(Clone, Debug)]
+pub struct OpenedMail {
+    pub mail_type: MailType,
+    pub mail_id: MailId,
+    pub title: String,
+    pub sender: String,
+    pub text: String,
+    pub zeny: u64,
+    pub items: Vec<MailItem>,
+}
+
+/// An item attached to a received mail.
+#[derive(Clone, Debug)]
+pub struct MailItem {
+    pub name: String,
+    pub amount: u16,
+}
+
+/// The recipient of a mail that is currently being written. The map server
+/// only accepts mails for recipients that were checked beforehand.
+#[derive(Clone, Debug)]
+pub struct MailRecipient {
+    pub character_id: CharacterId,
+    pub name: String,
+}
+
+/// State of the Rodex mailbox. The map server sends the mails in pages, so
+/// the list grows as the player requests more mails.
+#[derive(Default)]
+pub struct Mailbox {
+    mails: PlainTrackedState<Vec<MailInformation>>,
+    is_end: PlainTrackedState<bool>,
+    append_next_page: bool,
+    opened_mail: PlainTrackedState<Option<OpenedMail>>,
+    attachments: PlainTrackedState<Vec<InventoryItem<ResourceMetadata>>>,
+    recipient: PlainTrackedState<Option<MailRecipient>>,
+    has_new_mail: bool,
+}
+
+impl Mailbox {
+    /// Mark the next page of mails to be appended to the list instead of
+    /// replacing it.
+    pub fn expect_next_page(&mut self) {
+        self.append_next_page = true;
+    }
+
+    /// Add a page of mails. Unless [`Self::expect_next_page`] was called
+    /// before, the current list is replaced.
+    pub fn add_mails(&mut self, mails: Vec<MailInformation>, is_end: bool) {
+        let append = std::mem::take(&mut self.append_next_page);
+
+        self.mails.with_mut(|current_mails| {
+            if !append {
+                current_mails.clear();
+            }
+
+            current_mails.extend(mails);
+            ValueState::Mutated(())
+        });
+        self.is_end.set(is_end);
+    }
+
+    pub fn last_mail_id(&self) -> Option<MailId> {
+        self.mails.get().last().map(|mail| mail.mail_id)
+    }
+
+    pub fn is_end(&self) -> bool {
+        *self.is_end.get()
+    }
+
+    pub fn open_mail(&mut self, mail: OpenedMail) {
+        let mail_id = mail.mail_id;
+
+        self.mails
+            .with_mut(|mails| match mails.iter_mut().find(|mail| mail.mail_id == mail_id) {
+                Some(mail) if mail.is_read == 0 => {
+                    mail.is_read = 1;
+                    ValueState::Mutated(())
+                }
+                _ => ValueState::Unchanged(()),
+            });
+        self.opened_mail.set(Some(mail));
+    }
+
+    /// Find the title and sender of a mail in the list.
+    pub fn get_title_and_sender(&self, mail_id: MailId) -> Option<(String, String)> {
+        self.mails
+            .get()
+            .iter()
+            .find(|mail| mail.mail_id == mail_id)
+            .map(|mail| (mail.title.clone(), mail.sender.clone()))
+    }
+
+    pub fn remove_mail(&mut self, mail_id: MailId) {
+        self.mails.retain(|mail| mail.mail_id != mail_id);
+
+        if self.opened_mail.get().as_ref().is_some_and(|mail| mail.mail_id == mail_id) {
+            self.opened_mail.set(None);
+        }
+    }
+
+    pub fn take_zeny(&mut self, mail_id: MailId) {
+        self.opened_mail.with_mut(|mail| match mail {
+            Some(mail) if mail.mail_id == mail_id => {
+                mail.zeny = 0;
+                ValueState::Mutated(())
+            }
+            _ => ValueState::Unchanged(()),
+        });
+    }
+
+    pub fn take_items(&mut self, mail_id: MailId) {
+        self.opened_mail.with_mut(|mail| match mail {
+            Some(mail) if mail.mail_id == mail_id => {
+                mail.items.clear();
+                ValueState::Mutated(())
+            }
+            _ => ValueState::Unchanged(()),
+        });
+    }
+
+    /// Add an item from the inventory to the mail that is being written.
+    pub fn attach_item(&mut self, mut item: InventoryItem<ResourceMetadata>, attached_amount: u16) {
+        self.attachments.with_mut(|attachments| {
+            if let Some(attached_item) = attachments.iter_mut().find(|attached_item| attached_item.index == item.index) {
+                if let InventoryItemDetails::Regular { amount, .. } = &mut attached_item.details {
+                    *amount += attached_amount;
+                }
+            } else {
+                if let InventoryItemDetails::Regular { amount, .. } = &mut item.details {
+                    *amount = attached_amount;
+                }
+
+                attachments.push(item);
+            }
+
+            ValueState::Mutated(())
+        });
+    }
+
+    pub fn detach_item(&mut self, index: InventoryIndex, detached_amount: u16) {
+        self.attachments.with_mut(|attachments| {
+            let Some(position) = attachments.iter().position(|item| item.index == index) else {
+                return ValueState::Unchanged(());
+            };
+
+            if let InventoryItemDetails::Regular { amount, .. } = &mut attachments[position].details {
+                if *amount > detached_amount {
+                    *amount -= detached_amount;
+                    return ValueState::Mutated(());
+                }
+            }
+
+            attachments.remove(position);
+            ValueState::Mutated(())
+        });
+    }
+
+    pub fn set_recipient(&mut self, recipient: Option<MailRecipient>) {
+        self.recipient.set(recipient);
+    }
+
+    pub fn get_recipient(&self) -> Option<MailRecipient> {
+        self.recipient.get().clone()
+    }
+
+    /// Reset the state of the mail that is being written.
+    pub fn clear_draft(&mut self) {
+        self.attachments.clear();
+        self.recipient.set(None);
+    }
+
+    pub fn set_has_new_mail(&mut self, has_new_mail: bool) {
+        self.has_new_mail = has_new_mail;
+    }
+
+    pub fn has_new_mail(&self) -> bool {
+        self.has_new_mail
+    }
+
+    pub fn mail_remote(&self) -> PlainRemote<Vec<MailInformation>> {
+        self.mails.new_remote()
+    }
+
+    pub fn opened_mail_remote(&self) -> PlainRemote<Option<OpenedMail>> {
+        self.opened_mail.new_remote()
+    }
+
+    pub fn attachment_remote(&self) -> PlainRemote<Vec<InventoryItem<ResourceMetadata>>> {
+        self.attachments.new_remote()
+    }
+
+    pub fn recipient_remote(&self) -> PlainRemote<Option<MailRecipient>> {
+        self.recipient.new_remote()
+    }
+}
+
+#[cfg(test)]
+mod state {
+    use korangar_interface::state::Remote;
+    use ragnarok_packets::{MailFlags, MailId, MailInformation, MailType};
+
+    use super::{MailItem, Mailbox, OpenedMail};
+
+    fn mail(id: u64) -> MailInformation {
+        MailInformation {
+            mail_type: MailType::Character,
+            mail_id: MailId(id),
+            is_read: 0,
+            flags: MailFlags::TEXT,
+            sender: "Sender".to_owned(),
+            sent_time: 0,
+            expiration_time: 0,
+            title_length: 6,
+            title: "Title".to_owned(),
+        }
+    }
+
+    fn opened_mail(id: u64) -> OpenedMail {
+        OpenedMail {
+            mail_type: MailType::Character,
+            mail_id: MailId(id),
+            title: "Title".to_owned(),
+            sender: "Sender".to_owned(),
+            text: "Text".to_owned(),
+            zeny: 500,
+            items: vec![MailItem {
+                name: "Red Potion".to_owned(),
+                amount: 5,
+            }],
+        }
+    }
+
+    fn mail_ids(mailbox: &Mailbox) -> Vec<u64> {
+        mailbox.mail_remote().get().iter().map(|mail| mail.mail_id.0).collect()
+    }
+
+    #[test]
+    fn replace_pages() {
+        let mut mailbox = Mailbox::default();
+
+        mailbox.add_mails(vec![mail(1), mail(2)], false);
+        mailbox.add_mails(vec![mail(3)], true);
+
+        assert_eq!(mail_ids(&mailbox), [3]);
+        assert!(mailbox.is_end());
+    }
+
+    #[test]
+    fn append_next_page() {
+        let mut mailbox = Mailbox::default();
+
+        mailbox.add_mails(vec![mail(1), mail(2)], false);
+        mailbox.expect_next_page();
+        mailbox.add_mails(vec![mail(3)], true);
+
+        assert_eq!(mail_ids(&mailbox), [1, 2, 3]);
+        assert_eq!(mailbox.last_mail_id(), Some(MailId(3)));
+
+        // Only the next page is appended.
+        mailbox.add_mails(vec![mail(4)], true);
+        assert_eq!(mail_ids(&mailbox), [4]);
+    }
+
+    #[test]
+    fn open_marks_read() {
+        let mut mailbox = Mailbox::default();
+
+        mailbox.add_mails(vec![mail(1), mail(2)], true);
+        mailbox.open_mail(opened_mail(2));
+
+        let mails = mailbox.mail_remote();
+        assert_eq!(mails.get()[0].is_read, 0);
+        assert_eq!(mails.get()[1].is_read, 1);
+        assert!(mailbox.opened_mail_remote().get().is_some());
+    }
+
+    #[test]
+    fn remove_opened_mail() {
+        let mut mailbox = Mailbox::default();
+
+        mailbox.add_mails(vec![mail(1), mail(2)], true);
+        mailbox.open_mail(opened_mail(1));
+        mailbox.remove_mail(MailId(1));
+
+        assert_eq!(mail_ids(&mailbox), [2]);
+        assert!(mailbox.opened_mail_remote().get().is_none());
+    }
+
+    #[test]
+    fn take_attachments() {
+        let mut mailbox = Mailbox::default();
+
+        mailbox.open_mail(opened_mail(1));
+        // Taking from another mail doesn't change the opened one.
+        mailbox.take_zeny(MailId(2));
+        mailbox.take_zeny(MailId(1));
+        mailbox.take_items(MailId(1));
+
+        let opened_mail = mailbox.opened_mail_remote();
+        let opened_mail = opened_mail.get();
+        let opened_mail = opened_mail.as_ref().unwrap();
+        assert_eq!(opened_mail.zeny, 0);
+        assert!(opened_mail.items.is_empty());
+    }
+
+    #[test]
+    fn title_and_sender() {
+        let mut mailbox = Mailbox::default();
+
+        mailbox.add_mails(vec![mail(1)], true);
+
+        assert_eq!(
+            mailbox.get_title_and_sender(MailId(1)),
+            Some(("Title".to_owned(), "Sender".to_owned()))
+        );
+        assert_eq!(mailbox.get_title_and_sender(MailId(2)), None);
+    }
+}
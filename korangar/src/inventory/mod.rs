@@ -1,4 +1,7 @@
+mod cart;
+mod companions;
 mod hotbar;
+mod mailbox;
 mod skills;
 
 use std::cell::Ref;
@@ -8,7 +11,10 @@ use korangar_interface::state::{PlainRemote, PlainTrackedState, TrackedState, Tr
 use korangar_networking::{InventoryItem, InventoryItemDetails, NoMetadata};
 use ragnarok_packets::{EquipPosition, InventoryIndex, ItemId};
 
-pub use self::hotbar::Hotbar;
+pub use self::cart::{Cart, CartCapacity};
+pub use self::companions::{Companion, Companions};
+pub use self::hotbar::{HOTBAR_ROW_COUNT, HOTBAR_SLOT_COUNT, Hotbar, HotbarEntry, HotbarRows, hotbar_location};
+pub use self::mailbox::{MailItem, MailRecipient, Mailbox, OpenedMail};
 pub use self::skills::{Skill, SkillTree};
 use crate::graphics::Texture;
 use crate::loaders::AsyncLoader;
@@ -51,6 +57,10 @@ impl Inventory {
         });
     }
 
+    pub fn clear(&mut self) {
+        self.items.set(Vec::new());
+    }
+
     pub fn update_item_sprite(&mut self, item_id: ItemId, texture: Arc<Texture>) {
         self.items.with_mut(|items| {
             items.iter_mut().filter(|item| item.item_id == item_id).for_each(|item| {
@@ -78,6 +88,23 @@ impl Inventory {
         });
     }
 
+    /// Set the amount of a regular item, removing it if there are none left.
+    pub fn set_item_amount(&mut self, index: InventoryIndex, new_amount: u16) {
+        self.items.with_mut(|items| {
+            let Some(position) = items.iter().position(|item| item.index == index) else {
+                return ValueState::Unchanged(());
+            };
+
+            if new_amount == 0 {
+                items.remove(position);
+            } else if let InventoryItemDetails::Regular { amount, .. } = &mut items[position].details {
+                *amount = new_amount;
+            }
+
+            ValueState::Mutated(())
+        });
+    }
+
     pub fn update_equipped_position(&mut self, index: InventoryIndex, new_equipped_position: EquipPosition) {
         self.items.mutate(|items| {
             let item = items.iter_mut().find(|item| item.index == index).unwrap();
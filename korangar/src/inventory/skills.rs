@@ -1,10 +1,10 @@
 use std::sync::Arc;
 
-use korangar_interface::state::{PlainRemote, PlainTrackedState, TrackedState};
+use korangar_interface::state::{PlainRemote, PlainTrackedState, TrackedState, ValueState};
 use ragnarok_packets::{ClientTick, SkillId, SkillInformation, SkillLevel, SkillType};
 
 use crate::loaders::{ActionLoader, Sprite, SpriteLoader};
-use crate::world::{Actions, SpriteAnimationState};
+use crate::world::{Actions, Library, SpriteAnimationState};
 
 #[derive(Clone, Debug)]
 pub struct Skill {
@@ -12,14 +12,99 @@ pub struct Skill {
     pub skill_level: SkillLevel,
     pub skill_type: SkillType,
     pub skill_name: String,
+    /// `None` if the client files don't contain any information about the
+    /// skill.
+    pub maximum_level: Option<SkillLevel>,
+    /// Skills that need to be learned to the given level before this skill
+    /// can be learned.
+    pub required_skills: Vec<(SkillId, SkillLevel)>,
+    /// Set by the map server if the player can raise the level of the skill.
+    pub upgradable: bool,
     pub sprite: Arc<Sprite>,
     pub actions: Arc<Actions>,
     pub animation_state: SpriteAnimationState,
 }
 
+/// Whether every required skill is learned to at least the required level.
+fn requirements_met(required_skills: &[(SkillId, SkillLevel)], learned_level: impl Fn(SkillId) -> Option<SkillLevel>) -> bool {
+    required_skills
+        .iter()
+        .all(|(skill_id, skill_level)| learned_level(*skill_id).is_some_and(|level| level.0 >= skill_level.0))
+}
+
+/// The map server only marks skills as upgradable if the player can learn
+/// them and they are below their maximum level, so its flag is authoritative.
+fn can_upgrade(upgradable: bool, skill_points: u32) -> bool {
+    upgradable && skill_points > 0
+}
+
+impl Skill {
+    /// Skills that are part of the skill tree but not learned yet have a
+    /// level of zero.
+    pub fn is_learned(&self) -> bool {
+        self.skill_level.0 > 0
+    }
+
+    pub fn requirements_met(&self, skills: &[Skill]) -> bool {
+        requirements_met(&self.required_skills, |skill_id| {
+            skills
+                .iter()
+                .find(|skill| skill.skill_id == skill_id)
+                .map(|skill| skill.skill_level)
+        })
+    }
+
+    pub fn can_upgrade(&self, skill_points: u32) -> bool {
+        can_upgrade(self.upgradable, skill_points)
+    }
+}
+
+fn load_skill(
+    sprite_loader: &SpriteLoader,
+    action_loader: &ActionLoader,
+    library: &Library,
+    job_id: usize,
+    skill_information: SkillInformation,
+    client_tick: ClientTick,
+) -> Option<Skill> {
+    let file_path = format!("아이템\\{}", skill_information.skill_name);
+    let sprite = sprite_loader.get_or_load(&format!("{file_path}.spr")).ok()?;
+    let actions = action_loader.get_or_load(&format!("{file_path}.act")).ok()?;
+
+    Some(Skill {
+        skill_id: skill_information.skill_id,
+        skill_level: skill_information.skill_level,
+        skill_type: skill_information.skill_type,
+        skill_name: skill_information.skill_name,
+        maximum_level: library.get_skill_maximum_level(skill_information.skill_id),
+        required_skills: library.get_skill_requirements(skill_information.skill_id, job_id).to_vec(),
+        upgradable: skill_information.upgraded != 0,
+        sprite,
+        actions,
+        animation_state: SpriteAnimationState::new(client_tick),
+    })
+}
+
+/// Placeholder for a skill of the skill tree that the map server didn't send
+/// yet, because its requirements are not met.
+fn locked_skill_information(library: &Library, skill_id: SkillId) -> Option<SkillInformation> {
+    let skill_name = library.get_skill_identifier(skill_id)?.to_owned();
+
+    Some(SkillInformation {
+        skill_id,
+        skill_type: SkillType::Passive,
+        skill_level: SkillLevel(0),
+        spell_point_cost: 0,
+        attack_range: 0,
+        skill_name,
+        upgraded: 0,
+    })
+}
+
 #[derive(Default)]
 pub struct SkillTree {
     skills: PlainTrackedState<Vec<Skill>>,
+    skill_points: PlainTrackedState<u32>,
 }
 
 impl SkillTree {
@@ -27,36 +112,133 @@ impl SkillTree {
         &mut self,
         sprite_loader: &SpriteLoader,
         action_loader: &ActionLoader,
-        skill_data: Vec<SkillInformation>,
+        library: &Library,
+        job_id: usize,
+        mut skill_data: Vec<SkillInformation>,
         client_tick: ClientTick,
     ) {
-        let skills = skill_data
+        // Keep the order of the skill tree in the client files, so the skills of the
+        // base jobs come first.
+        let mut skill_information: Vec<SkillInformation> = library
+            .get_job_skill_tree(job_id)
             .into_iter()
-            .map(|skill_data| {
-                let file_path = format!("아이템\\{}", skill_data.skill_name);
-                let sprite = sprite_loader.get_or_load(&format!("{file_path}.spr")).unwrap();
-                let actions = action_loader.get_or_load(&format!("{file_path}.act")).unwrap();
-
-                Skill {
-                    skill_id: skill_data.skill_id,
-                    skill_level: skill_data.skill_level,
-                    skill_type: skill_data.skill_type,
-                    skill_name: skill_data.skill_name,
-                    sprite,
-                    actions,
-                    animation_state: SpriteAnimationState::new(client_tick),
-                }
-            })
+            .filter_map(
+                |skill_id| match skill_data.iter().position(|skill| skill.skill_id == skill_id) {
+                    Some(position) => Some(skill_data.remove(position)),
+                    None => locked_skill_information(library, skill_id),
+                },
+            )
+            .collect();
+
+        skill_information.extend(skill_data);
+
+        let skills = skill_information
+            .into_iter()
+            .filter_map(|skill_information| load_skill(sprite_loader, action_loader, library, job_id, skill_information, client_tick))
             .collect();
 
         self.skills.set(skills);
     }
 
+    pub fn add_skill(
+        &mut self,
+        sprite_loader: &SpriteLoader,
+        action_loader: &ActionLoader,
+        library: &Library,
+        job_id: usize,
+        skill_information: SkillInformation,
+        client_tick: ClientTick,
+    ) {
+        let Some(skill) = load_skill(sprite_loader, action_loader, library, job_id, skill_information, client_tick) else {
+            return;
+        };
+
+        self.skills.with_mut(|skills| {
+            match skills.iter_mut().find(|known_skill| known_skill.skill_id == skill.skill_id) {
+                Some(known_skill) => *known_skill = skill,
+                None => skills.push(skill),
+            }
+
+            ValueState::Mutated(())
+        });
+    }
+
+    pub fn update_skill(&mut self, skill_id: SkillId, skill_level: SkillLevel, upgradable: bool) {
+        self.skills
+            .with_mut(|skills| match skills.iter_mut().find(|skill| skill.skill_id == skill_id) {
+                Some(skill) => {
+                    skill.skill_level = skill_level;
+                    skill.upgradable = upgradable;
+                    ValueState::Mutated(())
+                }
+                None => ValueState::Unchanged(()),
+            });
+    }
+
+    pub fn set_skill_points(&mut self, skill_points: u32) {
+        self.skill_points.set(skill_points);
+    }
+
     pub fn get_skills(&self) -> PlainRemote<Vec<Skill>> {
         self.skills.new_remote()
     }
 
+    pub fn skill_points_remote(&self) -> PlainRemote<u32> {
+        self.skill_points.new_remote()
+    }
+
     pub fn find_skill(&self, skill_id: SkillId) -> Option<Skill> {
         self.skills.get().iter().find(|skill| skill.skill_id == skill_id).cloned()
     }
 }
+
+#[cfg(test)]
+mod upgrade {
+    use ragnarok_packets::{SkillId, SkillLevel};
+
+    use super::{can_upgrade, requirements_met};
+
+    const BASH: SkillId = SkillId(5);
+    const PROVOKE: SkillId = SkillId(6);
+    const MAGNUM_BREAK: SkillId = SkillId(7);
+
+    fn learned_level(skill_id: SkillId) -> Option<SkillLevel> {
+        match skill_id {
+            BASH => Some(SkillLevel(5)),
+            PROVOKE => Some(SkillLevel(0)),
+            _ => None,
+        }
+    }
+
+    #[test]
+    fn no_requirements() {
+        assert!(requirements_met(&[], learned_level));
+    }
+
+    #[test]
+    fn requirements_met_at_level() {
+        assert!(requirements_met(&[(BASH, SkillLevel(5))], learned_level));
+        assert!(requirements_met(&[(BASH, SkillLevel(3))], learned_level));
+    }
+
+    #[test]
+    fn requirement_level_too_low() {
+        assert!(!requirements_met(&[(BASH, SkillLevel(6))], learned_level));
+        assert!(!requirements_met(
+            &[(BASH, SkillLevel(1)), (PROVOKE, SkillLevel(1))],
+            learned_level
+        ));
+    }
+
+    #[test]
+    fn requirement_missing() {
+        assert!(!requirements_met(&[(MAGNUM_BREAK, SkillLevel(1))], learned_level));
+    }
+
+    #[test]
+    fn upgrade_needs_flag_and_points() {
+        assert!(can_upgrade(true, 1));
+        assert!(!can_upgrade(true, 0));
+        assert!(!can_upgrade(false, 10));
+    }
+}
@@ -21,6 +21,7 @@ use crate::world::{AnimationData, EntityType, Library, Map};
 pub enum ItemLocation {
     Inventory,
     Shop,
+    Ground,
 }
 
 #[derive(Debug, Clone, Hash, Eq, PartialEq)]
@@ -3,7 +3,7 @@ use std::sync::{Arc, Mutex};
 
 use cgmath::Deg;
 #[cfg(feature = "debug")]
-use korangar_debug::logging::{Colorize, Timer, print_debug};
+use korangar_debug::logging::{Colorize, Timer, log_error};
 use korangar_util::FileLoader;
 use korangar_util::container::SimpleCache;
 use ragnarok_bytes::{ByteReader, FromBytes};
@@ -189,7 +189,7 @@ fn parse_blend_factor(value: i32, previous: Option<BlendFactor>, is_source: bool
         12 if !is_source => BlendFactor::OneMinusSrcAlpha,
         _ => {
             #[cfg(feature = "debug")]
-            print_debug!("[{}] unknown blend factor found in frame data: {value}", "error".red());
+            log_error!("unknown blend factor found in frame data: {value}");
             BlendFactor::Zero
         }
     }
@@ -203,7 +203,7 @@ fn parse_animation_type(value: i32) -> AnimationType {
         3 => AnimationType::Type3,
         _ => {
             #[cfg(feature = "debug")]
-            print_debug!("[{}] unknown animation type found in frame data: {value}", "error".red());
+            log_error!("unknown animation type found in frame data: {value}");
             AnimationType::Type1
         }
     }
@@ -215,7 +215,7 @@ fn parse_frame_type(value: i32) -> FrameType {
         1 => FrameType::Morphing,
         _ => {
             #[cfg(feature = "debug")]
-            print_debug!("[{}] unknown frame type found in frame data: {value}", "error".red());
+            log_error!("unknown frame type found in frame data: {value}");
             FrameType::Basic
         }
     }
@@ -226,7 +226,7 @@ fn parse_mt_present(value: i32) -> MultiTexturePresent {
         0 => MultiTexturePresent::None,
         _ => {
             #[cfg(feature = "debug")]
-            print_debug!("[{}] unknown multi texture present found in frame data: {value}", "error".red());
+            log_error!("unknown multi texture present found in frame data: {value}");
             MultiTexturePresent::None
         }
     }
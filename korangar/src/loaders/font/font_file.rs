@@ -6,7 +6,7 @@ use cosmic_text::fontdb::{ID, Source};
 use hashbrown::HashMap;
 use image::{ImageFormat, ImageReader, RgbaImage};
 #[cfg(feature = "debug")]
-use korangar_debug::logging::{Colorize, Timer, print_debug};
+use korangar_debug::logging::{Colorize, Timer, log_error};
 use korangar_util::FileLoader;
 
 use crate::loaders::GameFileLoader;
@@ -33,7 +33,7 @@ impl FontFile {
 
         let Ok(font_data) = game_file_loader.get(&ttf_file_path) else {
             #[cfg(feature = "debug")]
-            print_debug!("[{}] failed to load font file '{}'", "error".red(), ttf_file_path.magenta());
+            log_error!("failed to load font file '{}'", ttf_file_path.magenta());
             return None;
         };
 
@@ -41,7 +41,7 @@ impl FontFile {
 
         let Ok(font_map_data) = game_file_loader.get(&map_file_path) else {
             #[cfg(feature = "debug")]
-            print_debug!("[{}] failed to load font map file '{}'", "error".red(), map_file_path.magenta());
+            log_error!("failed to load font map file '{}'", map_file_path.magenta());
             return None;
         };
 
@@ -49,7 +49,7 @@ impl FontFile {
 
         let Ok(font_map_decoder) = font_map_reader.decode() else {
             #[cfg(feature = "debug")]
-            print_debug!("[{}] failed to decode font map '{}'", "error".red(), map_file_path.magenta());
+            log_error!("failed to decode font map '{}'", map_file_path.magenta());
             return None;
         };
 
@@ -59,9 +59,8 @@ impl FontFile {
 
         let Ok(font_description_data) = game_file_loader.get(&map_description_file_path) else {
             #[cfg(feature = "debug")]
-            print_debug!(
-                "[{}] failed to load font map description file '{}'",
-                "error".red(),
+            log_error!(
+                "failed to load font map description file '{}'",
                 map_description_file_path.magenta()
             );
             return None;
@@ -69,9 +68,8 @@ impl FontFile {
 
         let Ok(font_description_content) = String::from_utf8(font_description_data) else {
             #[cfg(feature = "debug")]
-            print_debug!(
-                "[{}] invalid UTF-8 text data found in font map description file '{}'",
-                "error".red(),
+            log_error!(
+                "invalid UTF-8 text data found in font map description file '{}'",
                 map_description_file_path.magenta()
             );
             return None;
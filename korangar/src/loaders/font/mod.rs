@@ -10,9 +10,7 @@ use cosmic_text::{Attrs, Buffer, Family, FontSystem, Metrics, Shaping, fontdb};
 use hashbrown::HashMap;
 use image::{ImageBuffer, Rgba, RgbaImage, imageops};
 #[cfg(feature = "debug")]
-use korangar_debug::logging::Colorize;
-#[cfg(feature = "debug")]
-use korangar_debug::logging::print_debug;
+use korangar_debug::logging::log_warning;
 use korangar_interface::application::FontSizeTrait;
 use korangar_interface::elements::ElementDisplay;
 use korangar_util::Rectangle;
@@ -153,7 +151,7 @@ impl FontLoader {
     fn system_locale() -> String {
         sys_locale::get_locale().unwrap_or_else(|| {
             #[cfg(feature = "debug")]
-            print_debug!("[{}] failed to get system locale, falling back to en-US", "warning".yellow());
+            log_warning!("failed to get system locale, falling back to en-US");
             "en-US".to_string()
         })
     }
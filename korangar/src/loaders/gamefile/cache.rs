@@ -26,6 +26,7 @@ const PNG_FILE_EXTENSION: &str = ".png";
 
 const DDS_FILE_EXTENSION: &str = ".dds";
 const IVF_FILE_EXTENSION: &str = ".ivf";
+const WAV_FILE_EXTENSION: &str = ".wav";
 const TEXTURE_PREFIX: &str = "data\\texture\\";
 const VIDEO_PREFIX: &str = "data\\video\\";
 
@@ -193,6 +194,13 @@ fn analyze_files(
                 return true;
             }
 
+            // Caches created before audio was extracted need to be updated.
+            if let MediaType::Video = media_type
+                && !current_archive.file_exists(&video_file_wav_name(source_file))
+            {
+                return true;
+            }
+
             // Check if file is outdated
             match current_archive.get_file_by_path(&target_name) {
                 Some(cached_file) if cached_file.len() >= blake3::OUT_LEN => {
@@ -263,6 +271,10 @@ fn copy_existing_files(
 
             println!("Copying existing {} `{}`", file_type, target_file);
             builder.copy_file_from_archive(current_archive, &target_file);
+
+            if let MediaType::Video = media_type {
+                builder.copy_file_from_archive(current_archive, &video_file_wav_name(source_file));
+            }
         }
     }
 }
@@ -400,63 +412,112 @@ fn process_video(
 ) {
     println!("Encoding video for `{bik_file_name}`");
     let hash = blake3::hash(&bik_data);
+    let bik_data = Arc::new(bik_data);
+
+    let video_arguments = ["-c:v", "libsvtav1", "-crf", "32", "-preset", "4", "-f", "ivf"];
+
+    let mut ivf_data = match run_ffmpeg(&video_arguments, bik_data.clone()) {
+        Ok(ivf_data) => ivf_data,
+        Err(error) => {
+            println!("FFmpeg failed to encode `{bik_file_name}`: {error}");
+            return;
+        }
+    };
+
+    // Videos without an audio track get an empty audio file, so that we don't try
+    // to extract the audio again on the next sync.
+    let audio_arguments = ["-vn", "-c:a", "pcm_s16le", "-f", "wav"];
+
+    let wav_data = match run_ffmpeg(&audio_arguments, bik_data) {
+        Ok(mut wav_data) if finalize_wav_header(&mut wav_data) => wav_data,
+        Ok(_) => {
+            println!("FFmpeg created an invalid audio file for `{bik_file_name}`");
+            Vec::new()
+        }
+        Err(_) => {
+            println!("No audio track found in `{bik_file_name}`");
+            Vec::new()
+        }
+    };
+
+    ivf_data.extend_from_slice(hash.as_bytes());
+
+    builder.add_file(ivf_file_name, ivf_data, Compression::Off);
+    builder.add_file(&video_file_wav_name(bik_file_name), wav_data, Compression::Off);
+
+    *created_count += 1;
+}
 
-    let cmd = Command::new("ffmpeg")
+/// Run FFmpeg with the given data as input and return the output. The input
+/// and output arguments are added by this function.
+fn run_ffmpeg(arguments: &[&str], input_data: Arc<Vec<u8>>) -> Result<Vec<u8>, String> {
+    let mut child = Command::new("ffmpeg")
         .arg("-i")
         .arg("pipe:0")
-        .arg("-c:v")
-        .arg("libsvtav1")
-        .arg("-crf")
-        .arg("32")
-        .arg("-preset")
-        .arg("4")
-        .arg("-f")
-        .arg("ivf")
+        .args(arguments)
         .arg("pipe:1")
         .stdin(Stdio::piped())
         .stdout(Stdio::piped())
         .stderr(Stdio::piped())
-        .spawn();
-
-    match cmd {
-        Ok(mut child) => {
-            if let Some(mut stdin) = child.stdin.take() {
-                std::thread::spawn(move || {
-                    if let Err(error) = stdin.write_all(&bik_data) {
-                        println!("Failed to write to FFmpeg stdin: {error:?}");
-                        return;
-                    }
+        .spawn()
+        .map_err(|error| format!("failed to start FFmpeg: {error:?}"))?;
+
+    if let Some(mut stdin) = child.stdin.take() {
+        std::thread::spawn(move || {
+            if let Err(error) = stdin.write_all(&input_data) {
+                println!("Failed to write to FFmpeg stdin: {error:?}");
+                return;
+            }
 
-                    if let Err(error) = stdin.flush() {
-                        println!("Failed to flush FFmpeg stdin: {error:?}");
-                    }
-                });
+            if let Err(error) = stdin.flush() {
+                println!("Failed to flush FFmpeg stdin: {error:?}");
             }
+        });
+    }
 
-            match child.wait_with_output() {
-                Ok(output) => {
-                    if output.status.success() {
-                        let mut ivf_data = output.stdout;
+    let output = child
+        .wait_with_output()
+        .map_err(|error| format!("failed to get FFmpeg output: {error:?}"))?;
 
-                        ivf_data.extend_from_slice(hash.as_bytes());
+    match output.status.success() {
+        true => Ok(output.stdout),
+        false => Err(String::from_utf8_lossy(&output.stderr).into_owned()),
+    }
+}
 
-                        builder.add_file(ivf_file_name, ivf_data, Compression::Off);
+/// FFmpeg can't seek back when writing to a pipe, so the sizes in the WAV
+/// header are left as placeholders. This sets the sizes of the RIFF and data
+/// chunks to the actual sizes. Returns `false` if the data is not a valid WAV
+/// file.
+fn finalize_wav_header(wav_data: &mut [u8]) -> bool {
+    if wav_data.len() < 12 || &wav_data[0..4] != b"RIFF" || &wav_data[8..12] != b"WAVE" {
+        return false;
+    }
 
-                        *created_count += 1;
-                    } else {
-                        let error = String::from_utf8_lossy(&output.stderr);
-                        println!("FFmpeg failed to encode `{bik_file_name}`: {error}");
-                    }
-                }
-                Err(error) => {
-                    println!("Failed to get FFmpeg output: {error:?}");
-                }
-            }
-        }
-        Err(error) => {
-            println!("Failed to start FFmpeg: {error:?}");
+    let Ok(riff_size) = u32::try_from(wav_data.len() - 8) else {
+        return false;
+    };
+    wav_data[4..8].copy_from_slice(&riff_size.to_le_bytes());
+
+    let mut offset = 12;
+
+    while offset + 8 <= wav_data.len() {
+        let chunk_id = &wav_data[offset..offset + 4];
+        let chunk_size = u32::from_le_bytes(wav_data[offset + 4..offset + 8].try_into().unwrap()) as usize;
+
+        if chunk_id == b"data" {
+            // The data chunk is the last chunk written by FFmpeg, so it spans the rest of
+            // the file.
+            let data_size = (wav_data.len() - offset - 8) as u32;
+            wav_data[offset + 4..offset + 8].copy_from_slice(&data_size.to_le_bytes());
+            return true;
         }
+
+        // Chunks are padded to an even size.
+        offset += 8 + chunk_size + (chunk_size & 1);
     }
+
+    false
 }
 
 fn crop_to_multiple_of_four(mut image: RgbaImage) -> RgbaImage {
@@ -491,3 +552,56 @@ pub fn texture_file_dds_name(image_file_name: &str) -> String {
 pub fn video_file_ivf_name(bik_file_name: &str) -> String {
     format!("{bik_file_name}{IVF_FILE_EXTENSION}")
 }
+
+pub fn video_file_wav_name(bik_file_name: &str) -> String {
+    format!("{bik_file_name}{WAV_FILE_EXTENSION}")
+}
+
+#[cfg(test)]
+mod wav_header {
+    use super::finalize_wav_header;
+
+    fn wav_file(chunks: &[(&[u8; 4], &[u8])], placeholder: u32) -> Vec<u8> {
+        let mut data = b"RIFF".to_vec();
+        data.extend_from_slice(&placeholder.to_le_bytes());
+        data.extend_from_slice(b"WAVE");
+
+        for (chunk_id, content) in chunks {
+            data.extend_from_slice(*chunk_id);
+            let size = match *chunk_id == b"data" {
+                true => placeholder,
+                false => content.len() as u32,
+            };
+            data.extend_from_slice(&size.to_le_bytes());
+            data.extend_from_slice(content);
+
+            if content.len() % 2 == 1 && *chunk_id != b"data" {
+                data.push(0);
+            }
+        }
+
+        data
+    }
+
+    #[test]
+    fn fix_placeholder_sizes() {
+        let mut data = wav_file(&[(b"fmt ", &[1; 16]), (b"LIST", &[2; 5]), (b"data", &[3; 10])], u32::MAX);
+
+        assert!(finalize_wav_header(&mut data));
+        assert_eq!(u32::from_le_bytes(data[4..8].try_into().unwrap()) as usize, data.len() - 8);
+
+        let data_offset = data.len() - 10 - 8;
+        assert_eq!(&data[data_offset..data_offset + 4], b"data");
+        assert_eq!(
+            u32::from_le_bytes(data[data_offset + 4..data_offset + 8].try_into().unwrap()),
+            10
+        );
+    }
+
+    #[test]
+    fn reject_invalid_files() {
+        assert!(!finalize_wav_header(&mut Vec::new()));
+        assert!(!finalize_wav_header(&mut b"RIFF\0\0\0\0AVI ".to_vec()));
+        assert!(!finalize_wav_header(&mut wav_file(&[(b"fmt ", &[1; 16])], 0)));
+    }
+}
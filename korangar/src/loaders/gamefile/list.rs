@@ -1,5 +1,5 @@
 #[cfg(feature = "debug")]
-use korangar_debug::logging::{Colorize, print_debug};
+use korangar_debug::logging::{Colorize, log_warning, print_debug};
 use korangar_interface::elements::PrototypeElement;
 use serde::{Deserialize, Serialize};
 
@@ -29,9 +29,8 @@ impl GameArchiveList {
             .and_then(|data| ron::from_str(&data).ok())
             .unwrap_or_else(|| {
                 #[cfg(feature = "debug")]
-                print_debug!(
-                    "[{}] failed to load game archive list from {}; trying with default",
-                    "warning".yellow(),
+                log_warning!(
+                    "failed to load game archive list from {}; trying with default",
                     Self::FILE_NAME.magenta(),
                 );
 
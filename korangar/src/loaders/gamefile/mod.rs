@@ -11,10 +11,10 @@ use std::sync::RwLock;
 
 use blake3::Hash;
 #[cfg(feature = "debug")]
-use korangar_debug::logging::{Colorize, Timer, print_debug};
+use korangar_debug::logging::{Colorize, Timer, log_error, log_warning, print_debug};
 use korangar_util::{FileLoader, FileNotFoundError};
 
-pub use self::cache::{sync_cache_archive, texture_file_dds_name, video_file_ivf_name};
+pub use self::cache::{sync_cache_archive, texture_file_dds_name, video_file_ivf_name, video_file_wav_name};
 use self::list::GameArchiveList;
 use super::archive::folder::FolderArchive;
 use super::archive::native::{NativeArchive, NativeArchiveBuilder};
@@ -182,12 +182,7 @@ impl GameFileLoader {
                 Err(_error) => {
                     #[cfg(feature = "debug")]
                     {
-                        print_debug!(
-                            "[{}] failed to extract file {} from the grf: {:?}",
-                            "warning".yellow(),
-                            file_name.magenta(),
-                            _error
-                        );
+                        log_warning!("failed to extract file {} from the grf: {:?}", file_name.magenta(), _error);
                         failed_count += 1;
                     }
 
@@ -205,7 +200,7 @@ impl GameFileLoader {
                 Err(_error) => {
                     #[cfg(feature = "debug")]
                     {
-                        print_debug!("[{}] error upcasting {}: {:?}", "warning".yellow(), file_name.magenta(), _error,);
+                        log_warning!("error upcasting {}: {:?}", file_name.magenta(), _error,);
                         failed_count += 1;
                     }
                 }
@@ -246,7 +241,7 @@ impl GameFileLoader {
 
         #[cfg(feature = "debug")]
         if _hash != game_file_hash {
-            print_debug!("[{}] Cache is out of sync. Please re-sync or delete the cache", "error".red());
+            log_error!("Cache is out of sync. Please re-sync or delete the cache");
         }
 
         self.add_archive(archive, false);
@@ -1,5 +1,6 @@
 use std::hash::{DefaultHasher, Hash, Hasher};
 
+use encoding_rs::Encoding;
 use serde::de::Error;
 use serde::{Deserialize, Deserializer};
 
@@ -67,6 +68,11 @@ pub struct ClientInfo {
     /// Defines each available connection on the Service Select screen.
     #[serde(default, alias = "connection")]
     pub services: Vec<Service>,
+
+    /// Encoding declared by the file. Text exchanged with the servers uses
+    /// the same encoding.
+    #[serde(skip)]
+    pub encoding: Option<&'static Encoding>,
 }
 
 /// The ClientInfo's Service structure
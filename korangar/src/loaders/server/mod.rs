@@ -24,7 +24,8 @@ pub fn load_client_info(game_file_loader: &GameFileLoader) -> ClientInfo {
         .or_else(|_| game_file_loader.get("data\\clientinfo.xml"))
         .expect("failed to find clientinfo");
 
-    let content = match get_xml_encoding(&client_info) {
+    let encoding = get_xml_encoding(&client_info);
+    let content = match encoding {
         Some(encoding) => {
             let (cow, _) = encoding.decode_without_bom_handling(&client_info);
             cow
@@ -32,7 +33,10 @@ pub fn load_client_info(game_file_loader: &GameFileLoader) -> ClientInfo {
         None => String::from_utf8_lossy(client_info.as_slice()),
     };
 
-    let client_info: ClientInfo = from_str(&content).unwrap();
+    let client_info = ClientInfo {
+        encoding,
+        ..from_str(&content).unwrap()
+    };
 
     #[cfg(feature = "debug")]
     timer.stop();
@@ -1,15 +1,17 @@
-use std::iter;
+use std::io::Cursor;
 use std::sync::Arc;
 
+use bytemuck::Zeroable;
 use derive_new::new;
+use korangar_audio::AudioEngine;
 #[cfg(feature = "debug")]
 use korangar_debug::logging::{Colorize, Timer, print_debug};
 use korangar_util::FileLoader;
-use korangar_video::ivf::Ivf;
+use korangar_video::ivf::{Ivf, IvfHeader, IvfIndex};
 use wgpu::TextureFormat;
 
-use crate::loaders::{FALLBACK_PNG_FILE, GameFileLoader, TextureLoader, video_file_ivf_name};
-use crate::world::{Video, VideoFrame};
+use crate::loaders::{FALLBACK_PNG_FILE, GameFileLoader, TextureLoader, video_file_ivf_name, video_file_wav_name};
+use crate::world::{Video, VideoAudio, VideoFrame};
 
 const BIK_FILE_ENDING: &str = ".bik";
 
@@ -17,6 +19,7 @@ const BIK_FILE_ENDING: &str = ".bik";
 pub struct VideoLoader {
     game_file_loader: Arc<GameFileLoader>,
     texture_loader: Arc<TextureLoader>,
+    audio_engine: Arc<AudioEngine<GameFileLoader>>,
 }
 
 impl VideoLoader {
@@ -24,27 +27,41 @@ impl VideoLoader {
         path.ends_with(BIK_FILE_ENDING)
     }
 
+    /// Videos without an audio track have an empty audio file in the cache.
+    fn load_audio(&self, path: &str) -> Option<VideoAudio> {
+        let audio_path = format!("data\\video\\{}", video_file_wav_name(path));
+        let audio_data = self
+            .game_file_loader
+            .get(&audio_path)
+            .ok()
+            .filter(|audio_data| !audio_data.is_empty())?;
+
+        self.audio_engine
+            .load_video_audio(audio_data)
+            .map(|video_audio_key| VideoAudio::new(self.audio_engine.clone(), video_audio_key))
+    }
+
     fn load_video(&self, path: &str) -> Option<Video> {
         let video_file_name = video_file_ivf_name(path);
-        let path = format!("data\\video\\{video_file_name}");
+        let video_path = format!("data\\video\\{video_file_name}");
 
         #[cfg(feature = "debug")]
-        let timer = Timer::new_dynamic(format!("load video data from {}", path.magenta()));
+        let timer = Timer::new_dynamic(format!("load video data from {}", video_path.magenta()));
 
-        let Ok(mut file_data) = self.game_file_loader.get(&path) else {
+        let Ok(mut file_data) = self.game_file_loader.get(&video_path) else {
             #[cfg(feature = "debug")]
-            print_debug!("Could not find IVF video file `{}`", path);
+            print_debug!("Could not find IVF video file `{}`", video_path);
             return None;
         };
 
         // The IVF file contains a blake3 hash value at the end, which we cut off.
         file_data.truncate(file_data.len() - blake3::OUT_LEN);
 
-        let mut ivf = match Ivf::new(file_data.as_slice()) {
+        let mut ivf = match Ivf::new(Cursor::new(file_data.as_slice())) {
             Ok(ivf_file) => ivf_file,
             Err(_error) => {
                 #[cfg(feature = "debug")]
-                print_debug!("Can't open IVF video file `{}`: {}", path, _error);
+                print_debug!("Can't open IVF video file `{}`: {}", video_path, _error);
                 return None;
             }
         };
@@ -57,20 +74,31 @@ impl VideoLoader {
 
         let width = ivf.width() as u32;
         let height = ivf.height() as u32;
-        let timescale = ivf.header().timebase_numerator as f64 / ivf.header().timebase_denominator as f64;
+        let header = *ivf.header();
 
         let texture = self
             .texture_loader
             .create_raw(&video_file_name, width, height, 1, TextureFormat::Rgba8UnormSrgb, false);
 
-        let frames = Vec::from_iter(iter::from_fn(|| {
+        // The index tells us where decoding can start when seeking.
+        let index = match ivf.build_index() {
+            Ok(index) => index,
+            Err(_error) => {
+                #[cfg(feature = "debug")]
+                print_debug!("Can't index IVF video file `{}`: {}", video_path, _error);
+                return None;
+            }
+        };
+
+        let frames = Vec::from_iter(index.entries().iter().map_while(|_| {
             ivf.read_frame().ok().flatten().map(|frame| VideoFrame {
-                timestamp: frame.timestamp as i64,
+                timestamp: header.timestamp_in_milliseconds(frame.timestamp),
                 packet: frame.packet.into(),
             })
         }));
 
-        let video = Video::new(width, height, timescale, frames, texture);
+        let audio = self.load_audio(path);
+        let video = Video::new(width, height, frames, index, header, audio, texture);
 
         #[cfg(feature = "debug")]
         timer.stop();
@@ -92,7 +120,15 @@ impl VideoLoader {
                 let (width, height) = image.dimensions();
                 let texture = self.texture_loader.create_color(path, image, false);
 
-                Video::new(width, height, 1.0, Vec::new(), texture)
+                Video::new(
+                    width,
+                    height,
+                    Vec::new(),
+                    IvfIndex::default(),
+                    IvfHeader::zeroed(),
+                    None,
+                    texture,
+                )
             }
         }
     }
@@ -25,6 +25,8 @@ macro_rules! time_phase {
     }
 }
 
+#[cfg(feature = "debug")]
+mod console;
 mod graphics;
 mod input;
 #[macro_use]
@@ -38,35 +40,45 @@ mod world;
 
 use std::io::Cursor;
 use std::net::{SocketAddr, ToSocketAddrs};
+#[cfg(feature = "debug")]
+use std::rc::Rc;
 use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
 use std::sync::{Arc, LazyLock};
+use std::time::Duration;
 
 use cgmath::{Point3, Vector2, Vector3};
+use encoding_rs::EUC_KR;
 #[cfg(feature = "debug")]
 use graphics::RenderSettings;
 use image::{EncodableLayout, ImageFormat, ImageReader};
 use korangar_audio::{AudioEngine, SoundEffectKey};
 #[cfg(feature = "debug")]
-use korangar_debug::logging::{Colorize, print_debug};
+use korangar_debug::logging::{Colorize, log_error, print_debug};
+use korangar_debug::logging::{LogFilter, set_log_filter, start_file_logging};
 #[cfg(feature = "debug")]
 use korangar_debug::profile_block;
+use korangar_debug::profiling::FrameStatistics;
 #[cfg(feature = "debug")]
-use korangar_debug::profiling::Profiler;
+use korangar_debug::profiling::{Profiler, stop_trace_recording};
 use korangar_interface::Interface;
 use korangar_interface::application::{Application, FocusState, FontSizeTrait, PositionTraitExt};
+use korangar_interface::elements::Element;
 use korangar_interface::state::{
-    MappedRemote, PlainTrackedState, Remote, TrackedState, TrackedStateExt, TrackedStateTake, TrackedStateVec, ValueState,
+    MappedRemote, PlainRemote, PlainTrackedState, Remote, TrackedState, TrackedStateExt, TrackedStateTake, TrackedStateVec, ValueState,
 };
+use korangar_interface::tooltip::TooltipState;
 use korangar_networking::{
-    DisconnectReason, HotkeyState, LoginServerLoginData, MessageColor, NetworkEvent, NetworkEventBuffer, NetworkingSystem, SellItem,
-    ShopItem,
+    CompanionType, CompanionUpdate, DisconnectReason, HotkeyState, ItemQuantity, LoginServerLoginData, MessageColor, NetworkEvent,
+    NetworkEventBuffer, NetworkingSystem, SellItem, SendMailError, ShopItem, StoreType,
 };
 use korangar_util::pathing::PathFinder;
 #[cfg(not(feature = "debug"))]
 use ragnarok_packets::handler::NoPacketCallback;
 use ragnarok_packets::{
-    BuyShopItemsResult, CharacterId, CharacterInformation, CharacterServerInformation, Direction, DisappearanceReason, Friend, HotbarSlot,
-    SellItemsResult, SkillId, SkillType, TilePosition, UnitId, WorldPosition,
+    AddItemToCartResult, AddItemToMailResult, BuyShopItemsResult, BuyVendingItemsResult, CharacterId, CharacterInformation,
+    CharacterServerInformation, Direction, DisappearanceReason, EntityId, FeedResult, Friend, ItemId, ItemPickupResult,
+    MailAttachmentResult, OpenVendingStoreResult, OwnVendingItemInformation, Price, SellItemsResult, SellToBuyingStoreItemInformation,
+    SendMailResult, SkillId, SkillType, StatusType, TilePosition, UnitId, WorldPosition,
 };
 use renderer::InterfaceRenderer;
 use settings::AudioSettings;
@@ -78,14 +90,16 @@ use wgpu::{
     Instance, InstanceDescriptor, InstanceFlags, MemoryHints, NoopBackendOptions, Queue, Trace,
 };
 use winit::application::ApplicationHandler;
-use winit::dpi::{LogicalSize, PhysicalSize};
+use winit::dpi::{LogicalSize, PhysicalPosition, PhysicalSize};
 use winit::event::WindowEvent;
 use winit::event_loop::{ActiveEventLoop, ControlFlow, EventLoop};
 use winit::keyboard::PhysicalKey;
 use winit::window::{Icon, Window, WindowId};
 
+#[cfg(feature = "debug")]
+use crate::console::{CommandRegistry, ConsoleHistory, register_client_commands};
 use crate::graphics::*;
-use crate::input::{InputSystem, UserEvent};
+use crate::input::{GamepadBackend, InputSystem, UserEvent, movement_target, stick_to_grid_direction};
 use crate::interface::application::InterfaceSettings;
 use crate::interface::cursor::{MouseCursor, MouseCursorState};
 use crate::interface::dialog::DialogSystem;
@@ -94,14 +108,20 @@ use crate::interface::elements::PacketHistoryCallback;
 use crate::interface::layout::{ScreenPosition, ScreenSize};
 use crate::interface::linked::LinkedElement;
 use crate::interface::resource::{ItemSource, Move, SkillSource};
+use crate::interface::theme::InterfaceThemeKind;
 use crate::interface::windows::*;
-use crate::inventory::{Hotbar, Inventory, SkillTree};
+use crate::inventory::{
+    Cart, CartCapacity, Companions, Hotbar, HotbarEntry, Inventory, MailItem, MailRecipient, Mailbox, OpenedMail, SkillTree,
+    hotbar_location,
+};
 use crate::loaders::*;
 #[cfg(feature = "debug")]
 use crate::renderer::DebugMarkerRenderer;
 use crate::renderer::{AlignHorizontal, EffectRenderer, GameInterfaceRenderer};
-use crate::settings::{GraphicsSettings, LightingMode};
-use crate::system::GameTimer;
+use crate::settings::{GraphicsSettings, InputAction, KeyBindings, LightingMode};
+use crate::system::{
+    GameTimer, install_crash_reporter, set_crash_adapter, set_crash_graphics_settings, set_crash_map, set_crash_player_position,
+};
 use crate::world::*;
 
 const CLIENT_NAME: &str = "Korangar";
@@ -110,6 +130,10 @@ const DEFAULT_MAP: &str = "geffen";
 const START_CAMERA_FOCUS_POINT: Point3<f32> = Point3::new(600.0, 0.0, 240.0);
 const DEFAULT_BACKGROUND_MUSIC: Option<&str> = Some("bgm\\01.mp3");
 const MAIN_MENU_CLICK_SOUND_EFFECT: &str = "버튼소리.wav";
+const LOG_DIRECTORY: &str = "client/logs";
+/// Time in seconds between two updates of the scene inspector.
+#[cfg(feature = "debug")]
+const SCENE_INSPECTION_INTERVAL: f64 = 0.1;
 // TODO: The number of point lights that can cast shadows should be configurable
 // through the graphics settings. For now I just chose an arbitrary smaller
 // number that should be playable on most devices.
@@ -146,6 +170,12 @@ fn main() {
     #[cfg(feature = "debug")]
     let _measurement = threads::Main::start_frame();
 
+    let args: Vec<String> = std::env::args().collect();
+
+    initialize_logging(&args);
+
+    install_crash_reporter();
+
     initialize_shutdown_signal();
 
     time_phase!("create global thread pool", {
@@ -160,16 +190,49 @@ fn main() {
         init_tls_rand();
     });
 
-    let args: Vec<String> = std::env::args().collect();
     let sync_cache = args.len() > 1 && &args[1] == "sync-cache";
 
-    let Some(mut client) = Client::init(sync_cache) else {
+    // Frame statistics are collected when a frame budget in milliseconds is given,
+    // e.g. `--frame-budget=16.6`.
+    let frame_budget = args
+        .iter()
+        .find_map(|argument| argument.strip_prefix("--frame-budget="))
+        .and_then(|budget| budget.parse::<f64>().ok())
+        .map(|budget| Duration::from_secs_f64(budget / 1000.0));
+
+    let Some(mut client) = Client::init(sync_cache, frame_budget) else {
         return;
     };
 
     let event_loop = EventLoop::new().unwrap();
     event_loop.set_control_flow(ControlFlow::Poll);
     let _ = event_loop.run_app(&mut client);
+
+    // A running trace recording is only finished once its duration has passed, so
+    // we finish it here to not leave an incomplete trace behind.
+    #[cfg(feature = "debug")]
+    match stop_trace_recording() {
+        Ok(Some(path)) => print_debug!("finished recording trace to {}", path.display().magenta()),
+        Ok(None) => {}
+        Err(error) => log_error!("failed to finish recording trace: {}", error.to_string().red()),
+    }
+}
+
+/// Log messages can be filtered per module with `--log-filter`, e.g.
+/// `--log-filter=warning,korangar::loaders=debug`. All messages that pass the
+/// filter are also written to a log file, so they can be attached to bug
+/// reports.
+fn initialize_logging(args: &[String]) {
+    if let Some(filter) = args.iter().find_map(|argument| argument.strip_prefix("--log-filter=")) {
+        match filter.parse::<LogFilter>() {
+            Ok(filter) => set_log_filter(filter),
+            Err(error) => println!("invalid log filter: {error}"),
+        }
+    }
+
+    if let Err(error) = start_file_logging(LOG_DIRECTORY) {
+        println!("failed to create log file in {LOG_DIRECTORY}: {error}");
+    }
 }
 
 fn initialize_shutdown_signal() {
@@ -215,6 +278,10 @@ struct Client {
     point_light_instructions: Vec<PointLightInstruction>,
 
     input_system: InputSystem,
+    gamepad_backend: GamepadBackend,
+    ime_allowed: bool,
+    /// Area of the text cursor that the candidate window was last placed at.
+    ime_cursor_area: Option<(ScreenPosition, ScreenSize)>,
     lighting_mode: MappedRemote<GraphicsSettings, LightingMode>,
     vsync: MappedRemote<GraphicsSettings, bool>,
     limit_framerate: MappedRemote<GraphicsSettings, LimitFramerate>,
@@ -226,17 +293,22 @@ struct Client {
     ssaa: MappedRemote<GraphicsSettings, Ssaa>,
     screen_space_anti_aliasing: MappedRemote<GraphicsSettings, ScreenSpaceAntiAliasing>,
     high_quality_interface: MappedRemote<GraphicsSettings, bool>,
+    /// Only used to keep the graphics settings of crash reports up to date.
+    graphics_settings: PlainRemote<GraphicsSettings>,
     #[cfg(feature = "debug")]
     render_settings: PlainTrackedState<RenderSettings>,
     mute_on_focus_loss: MappedRemote<AudioSettings, bool>,
+    key_bindings: PlainTrackedState<KeyBindings>,
 
     application: InterfaceSettings,
     interface: Interface<InterfaceSettings>,
     focus_state: FocusState<InterfaceSettings>,
     mouse_cursor: MouseCursor,
+    tooltip_state: TooltipState<InterfaceSettings>,
     dialog_system: DialogSystem,
     show_interface: bool,
     game_timer: GameTimer,
+    frame_statistics: FrameStatistics,
 
     #[cfg(feature = "debug")]
     debug_camera: DebugCamera,
@@ -253,6 +325,11 @@ struct Client {
     saved_characters: PlainTrackedState<Vec<CharacterInformation>>,
     shop_items: PlainTrackedState<Vec<ShopItem<ResourceMetadata>>>,
     sell_items: PlainTrackedState<Vec<SellItem<(ResourceMetadata, u16)>>>,
+    vending_store: Option<(EntityId, u32)>,
+    buying_store: Option<(EntityId, u32)>,
+    store_title: String,
+    own_vending_items: Vec<OwnVendingItemInformation>,
+    store_title_areas: Vec<(EntityId, StoreType, ScreenPosition, ScreenSize)>,
     currently_deleting: Option<CharacterId>,
     saved_player_name: String,
     move_request: PlainTrackedState<Option<usize>>,
@@ -266,8 +343,13 @@ struct Client {
     effect_holder: EffectHolder,
     entities: Vec<Entity>,
     player_inventory: Inventory,
+    player_cart: Cart,
+    mailbox: Mailbox,
+    ground_items: Vec<GroundItem>,
+    pending_pickup: Option<EntityId>,
     player_skill_tree: SkillTree,
     hotbar: Hotbar,
+    companions: Companions,
     path_finder: PathFinder,
 
     point_light_set_buffer: ResourceSetBuffer<LightSourceKey>,
@@ -283,8 +365,22 @@ struct Client {
     tile_texture_set: Arc<TextureSet>,
 
     chat_messages: PlainTrackedState<Vec<ChatMessage>>,
+    /// Previously sent messages, recalled with the arrow keys.
+    chat_history: PlainTrackedState<Vec<String>>,
     main_menu_click_sound_effect: SoundEffectKey,
 
+    #[cfg(feature = "debug")]
+    console_registry: Rc<CommandRegistry>,
+    #[cfg(feature = "debug")]
+    console_output: PlainTrackedState<Vec<ChatMessage>>,
+    #[cfg(feature = "debug")]
+    console_history: ConsoleHistory,
+    #[cfg(feature = "debug")]
+    scene_inspection: PlainTrackedState<SceneInspection>,
+    /// Time in seconds since the scene inspection was last updated.
+    #[cfg(feature = "debug")]
+    scene_inspection_timer: f64,
+
     map: Option<Box<Map>>,
 
     #[cfg(feature = "debug")]
@@ -302,12 +398,16 @@ struct Client {
 }
 
 impl Client {
-    fn init(sync_cache: bool) -> Option<Self> {
+    fn init(sync_cache: bool, frame_budget: Option<Duration>) -> Option<Self> {
         time_phase!("load settings", {
             let picker_value = Arc::new(AtomicU64::new(0));
             let input_system = InputSystem::new(picker_value.clone());
+            let gamepad_backend = GamepadBackend::new();
+            let ime_allowed = false;
+            let ime_cursor_area = None;
+            let key_bindings = PlainTrackedState::new(KeyBindings::new());
             let graphics_settings = PlainTrackedState::new(GraphicsSettings::new());
-            let application = InterfaceSettings::load_or_default();
+            let mut application = InterfaceSettings::load_or_default();
 
             let lighting_mode = graphics_settings.mapped(|settings| &settings.lighting_mode).new_remote();
             let vsync = graphics_settings.mapped(|settings| &settings.vsync).new_remote();
@@ -323,6 +423,9 @@ impl Client {
                 .new_remote();
             let high_quality_interface = graphics_settings.mapped(|settings| &settings.high_quality_interface).new_remote();
 
+            set_crash_graphics_settings(&graphics_settings.get());
+            let graphics_settings = graphics_settings.new_remote();
+
             #[cfg(feature = "debug")]
             let render_settings = PlainTrackedState::new(RenderSettings::new());
         });
@@ -357,6 +460,7 @@ impl Client {
 
         time_phase!("create device", {
             let capabilities = Capabilities::from_adapter(&adapter);
+            set_crash_adapter(&adapter.get_info(), &capabilities);
 
             let (device, queue) = pollster::block_on(async {
                 adapter
@@ -411,7 +515,11 @@ impl Client {
                 &capabilities,
                 game_file_loader.clone(),
             ));
-            let video_loader = Arc::new(VideoLoader::new(game_file_loader.clone(), texture_loader.clone()));
+            let video_loader = Arc::new(VideoLoader::new(
+                game_file_loader.clone(),
+                texture_loader.clone(),
+                audio_engine.clone(),
+            ));
             let font_loader = Arc::new(FontLoader::new(application.get_fonts(), &game_file_loader, &texture_loader));
             let map_loader = Arc::new(MapLoader::new(
                 device.clone(),
@@ -432,10 +540,7 @@ impl Client {
                 // we can try is generating it again.
 
                 #[cfg(feature = "debug")]
-                print_debug!(
-                    "[{}] failed to execute lua files; attempting to fix it by re-patching",
-                    "error".red()
-                );
+                log_error!("failed to execute lua files; attempting to fix it by re-patching");
 
                 game_file_loader.remove_patched_lua_files();
                 game_file_loader.load_patched_lua_files();
@@ -515,12 +620,18 @@ impl Client {
             let mut interface = Interface::new(INITIAL_SCREEN_SIZE);
             let mut focus_state = FocusState::default();
             let mouse_cursor = MouseCursor::new(&sprite_loader, &action_loader);
+            let tooltip_state = TooltipState::default();
             let dialog_system = DialogSystem::default();
             let show_interface = true;
         });
 
         time_phase!("initialize timer", {
             let game_timer = GameTimer::new();
+            let mut frame_statistics = FrameStatistics::default();
+
+            if let Some(frame_budget) = frame_budget {
+                frame_statistics.enable(frame_budget);
+            }
         });
 
         time_phase!("initialize camera", {
@@ -537,13 +648,17 @@ impl Client {
 
         time_phase!("initialize networking", {
             let client_info = load_client_info(&game_file_loader);
+            let encoding = client_info.encoding.unwrap_or(EUC_KR);
 
             #[cfg(not(feature = "debug"))]
-            let (networking_system, network_event_buffer) = NetworkingSystem::spawn();
+            let (mut networking_system, network_event_buffer) = NetworkingSystem::spawn();
             #[cfg(feature = "debug")]
             let packet_history_callback = PacketHistoryCallback::get_static_instance();
             #[cfg(feature = "debug")]
-            let (networking_system, network_event_buffer) = NetworkingSystem::spawn_with_callback(packet_history_callback.clone());
+            let (mut networking_system, network_event_buffer) = NetworkingSystem::spawn_with_callback(packet_history_callback.clone());
+
+            networking_system.set_encoding(encoding);
+            application.set_encoding(encoding);
 
             let friend_list: PlainTrackedState<Vec<(Friend, LinkedElement)>> = PlainTrackedState::default();
             let saved_login_data: Option<LoginServerLoginData> = None;
@@ -551,6 +666,11 @@ impl Client {
             let saved_characters: PlainTrackedState<Vec<CharacterInformation>> = PlainTrackedState::default();
             let shop_items: PlainTrackedState<Vec<ShopItem<ResourceMetadata>>> = PlainTrackedState::default();
             let sell_items: PlainTrackedState<Vec<SellItem<(ResourceMetadata, u16)>>> = PlainTrackedState::default();
+            let vending_store = None;
+            let buying_store = None;
+            let store_title = String::new();
+            let own_vending_items = Vec::new();
+            let store_title_areas = Vec::new();
             let currently_deleting: Option<CharacterId> = None;
             let saved_player_name = String::new();
             let move_request: PlainTrackedState<Option<usize>> = PlainTrackedState::default();
@@ -568,8 +688,13 @@ impl Client {
             let effect_holder = EffectHolder::default();
             let entities = Vec::<Entity>::new();
             let player_inventory = Inventory::default();
+            let player_cart = Cart::default();
+            let mailbox = Mailbox::default();
+            let ground_items = Vec::new();
+            let pending_pickup = None;
             let player_skill_tree = SkillTree::default();
             let hotbar = Hotbar::default();
+            let companions = Companions::default();
             let path_finder = PathFinder::default();
 
             let point_light_set_buffer = ResourceSetBuffer::default();
@@ -609,11 +734,27 @@ impl Client {
                 text: welcome_string,
                 color: MessageColor::Server,
             }]);
+            let chat_history = PlainTrackedState::default();
 
             let main_menu_click_sound_effect = audio_engine.load(MAIN_MENU_CLICK_SOUND_EFFECT);
+
+            #[cfg(feature = "debug")]
+            let console_registry = {
+                let mut console_registry = CommandRegistry::default();
+                register_client_commands(&mut console_registry);
+                Rc::new(console_registry)
+            };
+            #[cfg(feature = "debug")]
+            let console_output = PlainTrackedState::default();
+            #[cfg(feature = "debug")]
+            let console_history = ConsoleHistory::new();
+            #[cfg(feature = "debug")]
+            let scene_inspection = PlainTrackedState::default();
         });
 
         time_phase!("load default map", {
+            set_crash_map(DEFAULT_MAP);
+
             let map = map_loader
                 .load(
                     DEFAULT_MAP.to_string(),
@@ -661,6 +802,9 @@ impl Client {
             point_light_with_shadow_instructions,
             point_light_instructions,
             input_system,
+            gamepad_backend,
+            ime_allowed,
+            ime_cursor_area,
             lighting_mode,
             vsync,
             limit_framerate,
@@ -672,16 +816,20 @@ impl Client {
             ssaa,
             screen_space_anti_aliasing,
             high_quality_interface,
+            graphics_settings,
             #[cfg(feature = "debug")]
             render_settings,
             mute_on_focus_loss,
+            key_bindings,
             application,
             interface,
             focus_state,
             mouse_cursor,
+            tooltip_state,
             dialog_system,
             show_interface,
             game_timer,
+            frame_statistics,
             #[cfg(feature = "debug")]
             debug_camera,
             start_camera,
@@ -696,6 +844,11 @@ impl Client {
             saved_characters,
             shop_items,
             sell_items,
+            vending_store,
+            buying_store,
+            store_title,
+            own_vending_items,
+            store_title_areas,
             currently_deleting,
             saved_player_name,
             move_request,
@@ -708,8 +861,13 @@ impl Client {
             effect_holder,
             entities,
             player_inventory,
+            player_cart,
+            mailbox,
+            ground_items,
+            pending_pickup,
             player_skill_tree,
             hotbar,
+            companions,
             path_finder,
             point_light_set_buffer,
             directional_shadow_object_set_buffer,
@@ -722,7 +880,18 @@ impl Client {
             #[cfg(feature = "debug")]
             tile_texture_set,
             chat_messages,
+            chat_history,
             main_menu_click_sound_effect,
+            #[cfg(feature = "debug")]
+            console_registry,
+            #[cfg(feature = "debug")]
+            console_output,
+            #[cfg(feature = "debug")]
+            console_history,
+            #[cfg(feature = "debug")]
+            scene_inspection,
+            #[cfg(feature = "debug")]
+            scene_inspection_timer: 0.0,
             map: Some(map),
             #[cfg(feature = "debug")]
             packet_history_callback,
@@ -745,6 +914,8 @@ impl Client {
         #[cfg(feature = "debug")]
         let _measurement = threads::Main::start_frame();
 
+        self.frame_statistics.start_frame();
+
         #[cfg(feature = "debug")]
         let clear_measurement = Profiler::start_measurement("clear instructions");
 
@@ -789,11 +960,17 @@ impl Client {
         self.middle_interface_renderer.update_scaling(scaling);
         self.top_interface_renderer.update_scaling(scaling);
 
+        let wait_phase = self.frame_statistics.start_phase();
         let frame = self.graphics_engine.wait_for_next_frame();
+        self.frame_statistics.stop_phase("wait for frame", wait_phase);
 
         #[cfg(feature = "debug")]
         let timer_measurement = Profiler::start_measurement("update timers");
 
+        for event in self.gamepad_backend.poll() {
+            self.input_system.update_gamepad(event);
+        }
+
         self.input_system.update_delta();
 
         let delta_time = self.game_timer.update();
@@ -811,11 +988,37 @@ impl Client {
             &self.application,
             &mut self.focus_state,
             &mut self.mouse_cursor,
+            &self.key_bindings.get(),
             #[cfg(feature = "debug")]
             &self.render_settings,
             client_tick,
         );
 
+        // Tooltips are hidden while dragging or moving something.
+        let tooltip_element = hovered_element.as_ref().filter(|_| self.input_system.get_mouse_mode().is_none());
+        self.tooltip_state.update(tooltip_element, delta_time as f32);
+
+        // Input methods are only enabled while an element that accepts text is focused.
+        let text_cursor_area = focused_element.as_ref().and_then(|element| element.borrow().text_cursor_area());
+
+        if let Some(window) = self.window.as_ref() {
+            if self.ime_allowed != text_cursor_area.is_some() {
+                self.ime_allowed = text_cursor_area.is_some();
+                window.set_ime_allowed(self.ime_allowed);
+            }
+
+            if let Some((position, size)) = text_cursor_area
+                && self.ime_cursor_area != text_cursor_area
+            {
+                window.set_ime_cursor_area(
+                    PhysicalPosition::new(position.left, position.top),
+                    PhysicalSize::new(size.width, size.height),
+                );
+            }
+        }
+
+        self.ime_cursor_area = text_cursor_area;
+
         #[cfg(feature = "debug")]
         let picker_measurement = Profiler::start_measurement("update picker target");
 
@@ -839,6 +1042,7 @@ impl Client {
 
         #[cfg(feature = "debug")]
         let network_event_measurement = Profiler::start_measurement("process network events");
+        let network_event_phase = self.frame_statistics.start_phase();
 
         for event in self.network_event_buffer.drain() {
             match event {
@@ -917,6 +1121,7 @@ impl Client {
 
                     self.interface.close_all_windows_except(&mut self.focus_state);
 
+                    set_crash_map(DEFAULT_MAP);
                     self.async_loader
                         .request_map_load(DEFAULT_MAP.to_string(), Some(TilePosition::new(0, 0)));
                 }
@@ -996,6 +1201,10 @@ impl Client {
                     }
 
                     self.entities.push(player);
+                    self.companions.clear();
+                    self.player_cart.clear();
+                    self.ground_items.clear();
+                    self.pending_pickup = None;
 
                     // TODO: This will do one unnecessary restore_focus. Check if
                     //       that will be problematic.
@@ -1006,12 +1215,16 @@ impl Client {
                     self.interface.open_window(
                         &self.application,
                         &mut self.focus_state,
-                        &ChatWindow::new(self.chat_messages.new_remote(), self.font_loader.clone()),
+                        &ChatWindow::new(
+                            self.chat_messages.new_remote(),
+                            self.chat_history.clone(),
+                            self.font_loader.clone(),
+                        ),
                     );
                     self.interface.open_window(
                         &self.application,
                         &mut self.focus_state,
-                        &HotbarWindow::new(self.hotbar.get_skills()),
+                        &HotbarWindow::new(self.hotbar.get_rows(), self.hotbar.current_row_remote()),
                     );
 
                     // Put the dialog system in a well-defined state.
@@ -1048,6 +1261,10 @@ impl Client {
                         let entity_type = npc.get_entity_type();
                         let entity_part_files = npc.get_entity_part_files(&self.library);
 
+                        if self.companions.is_owned(entity_id) {
+                            npc.set_owner(self.entities.first().map(Entity::get_entity_id));
+                        }
+
                         // Sometimes (like after a job change) the server will tell the client
                         // that a new entity appeared, even though it was already on screen. So
                         // to prevent the entity existing twice, we remove the old one.
@@ -1122,7 +1339,10 @@ impl Client {
 
                     // Only the player must stay alive between map changes.
                     self.entities.truncate(1);
+                    self.ground_items.clear();
+                    self.pending_pickup = None;
 
+                    set_crash_map(&map_name);
                     self.async_loader.request_map_load(map_name, Some(player_position));
                 }
                 NetworkEvent::UpdateClientTick { client_tick, received_at } => {
@@ -1170,8 +1390,25 @@ impl Client {
                         panic!();
                     };
 
+                    if let StatusType::SkillPoint(skill_points) = status_type {
+                        self.player_skill_tree.set_skill_points(skill_points);
+                    }
+
                     player.update_status(status_type);
                 }
+                NetworkEvent::StatUpResult { stat_type, success, value } => {
+                    let Entity::Player(player) = &mut self.entities[0] else {
+                        panic!();
+                    };
+
+                    match success {
+                        true => player.set_stat_base(stat_type, value as u32),
+                        false => self.chat_messages.push(ChatMessage {
+                            text: "Not enough status points".to_owned(),
+                            color: MessageColor::Error,
+                        }),
+                    }
+                }
                 NetworkEvent::OpenDialog(text, npc_id) => {
                     if let Some(dialog_window) = self.dialog_system.open_dialog_window(text, npc_id) {
                         self.interface.open_window(&self.application, &mut self.focus_state, &dialog_window);
@@ -1188,9 +1425,215 @@ impl Client {
                 NetworkEvent::RemoveQuestEffect(entity_id) => self.particle_holder.remove_quest_icon(entity_id),
                 NetworkEvent::SetInventory { items } => {
                     self.player_inventory.fill(&self.async_loader, &self.library, items);
+                    self.hotbar
+                        .refresh_items(&mut self.networking_system, &self.player_inventory.get_items());
+                }
+                NetworkEvent::SetCartItems { items } => {
+                    self.player_cart.fill(&self.async_loader, &self.library, items);
+                }
+                NetworkEvent::UpdateCartCapacity {
+                    item_count,
+                    maximum_item_count,
+                    weight,
+                    maximum_weight,
+                } => {
+                    self.player_cart.set_capacity(CartCapacity {
+                        item_count,
+                        maximum_item_count,
+                        weight,
+                        maximum_weight,
+                    });
+                }
+                NetworkEvent::CartItemAdded { item } => {
+                    self.player_cart.add_item(&self.async_loader, &self.library, item);
+                }
+                NetworkEvent::CartItemRemoved { index, amount } => {
+                    self.player_cart.remove_item(index, amount);
+                }
+                NetworkEvent::AddItemToCartFailed { result } => {
+                    let text = match result {
+                        AddItemToCartResult::Overweight => "The cart is too heavy",
+                        AddItemToCartResult::TooManyItems => "The cart is full",
+                    };
+
+                    self.chat_messages.push(ChatMessage {
+                        text: text.to_owned(),
+                        color: MessageColor::Error,
+                    });
+                }
+                NetworkEvent::CartRemoved => {
+                    self.player_cart.clear();
+                    self.interface
+                        .close_window_with_class(&mut self.focus_state, CartWindow::WINDOW_CLASS);
+                }
+                NetworkEvent::ChangeCart { entity_id, cart_type } => {
+                    if let Some(entity) = self.entities.iter_mut().find(|entity| entity.get_entity_id() == entity_id) {
+                        entity.set_cart(cart_type);
+
+                        if let Some(animation_data) = self.async_loader.request_animation_data_load(
+                            entity.get_entity_id(),
+                            entity.get_entity_type(),
+                            entity.get_entity_part_files(&self.library),
+                        ) {
+                            entity.set_animation_data(animation_data);
+                        }
+                    }
+                }
+                NetworkEvent::MailNotification { has_new_mail } => {
+                    if has_new_mail && !self.mailbox.has_new_mail() {
+                        self.chat_messages.push(ChatMessage {
+                            text: "You have new mail".to_owned(),
+                            color: MessageColor::Information,
+                        });
+                    }
+
+                    self.mailbox.set_has_new_mail(has_new_mail);
+                }
+                NetworkEvent::MailList { mails, is_end } => {
+                    self.mailbox.add_mails(mails, is_end);
+                }
+                NetworkEvent::MailContent {
+                    mail_type,
+                    mail_id,
+                    text,
+                    zeny,
+                    items,
+                } => {
+                    let (title, sender) = self.mailbox.get_title_and_sender(mail_id).unwrap_or_default();
+                    let items = items
+                        .into_iter()
+                        .map(|item| MailItem {
+                            name: self.library.get_item_name_from_id(item.item_id, item.is_identified != 0).to_owned(),
+                            amount: item.amount,
+                        })
+                        .collect();
+
+                    self.mailbox.open_mail(OpenedMail {
+                        mail_type,
+                        mail_id,
+                        title,
+                        sender,
+                        text,
+                        zeny,
+                        items,
+                    });
+                }
+                NetworkEvent::MailDeleted { mail_id } => {
+                    self.mailbox.remove_mail(mail_id);
+                }
+                NetworkEvent::MailZenyReceived { mail_id, result } => match result {
+                    MailAttachmentResult::Success => self.mailbox.take_zeny(mail_id),
+                    MailAttachmentResult::Failed | MailAttachmentResult::Overweight => {
+                        self.chat_messages.push(ChatMessage {
+                            text: "Failed to take the zeny from the mail".to_owned(),
+                            color: MessageColor::Error,
+                        });
+                    }
+                },
+                NetworkEvent::MailItemsReceived { mail_id, result } => {
+                    let text = match result {
+                        MailAttachmentResult::Success => {
+                            self.mailbox.take_items(mail_id);
+                            continue;
+                        }
+                        MailAttachmentResult::Failed => "Failed to take the items from the mail",
+                        MailAttachmentResult::Overweight => "You are carrying too much to take the items",
+                    };
+
+                    self.chat_messages.push(ChatMessage {
+                        text: text.to_owned(),
+                        color: MessageColor::Error,
+                    });
+                }
+                NetworkEvent::WriteMailOpened { success, .. } => match success {
+                    true => {
+                        self.mailbox.clear_draft();
+                        self.interface.open_window(
+                            &self.application,
+                            &mut self.focus_state,
+                            &WriteMailWindow::new(self.mailbox.attachment_remote(), self.mailbox.recipient_remote()),
+                        );
+                    }
+                    false => {
+                        self.chat_messages.push(ChatMessage {
+                            text: "Can't write a mail right now".to_owned(),
+                            color: MessageColor::Error,
+                        });
+                    }
+                },
+                NetworkEvent::MailRecipientChecked {
+                    name,
+                    character_id,
+                    base_level,
+                } => match character_id {
+                    Some(character_id) => {
+                        self.chat_messages.push(ChatMessage {
+                            text: format!("Found {name} (level {base_level})"),
+                            color: MessageColor::Information,
+                        });
+                        self.mailbox.set_recipient(Some(MailRecipient { character_id, name }));
+                    }
+                    None => {
+                        self.chat_messages.push(ChatMessage {
+                            text: format!("No character with the name {name} exists"),
+                            color: MessageColor::Error,
+                        });
+                        self.mailbox.set_recipient(None);
+                    }
+                },
+                NetworkEvent::MailItemAttached { result, index, amount } => {
+                    let text = match result {
+                        AddItemToMailResult::Success => {
+                            let item = self.player_inventory.get_items().iter().find(|item| item.index == index).cloned();
+
+                            if let Some(item) = item {
+                                self.mailbox.attach_item(item, amount);
+                            }
+
+                            continue;
+                        }
+                        AddItemToMailResult::Overweight => "The mail is too heavy",
+                        AddItemToMailResult::Failed => "Failed to attach the item",
+                        AddItemToMailResult::TooManyItems => "No more items can be attached",
+                        AddItemToMailResult::TradeRestricted => "This item can't be traded",
+                    };
+
+                    self.chat_messages.push(ChatMessage {
+                        text: text.to_owned(),
+                        color: MessageColor::Error,
+                    });
+                }
+                NetworkEvent::MailItemDetached { success, index, amount } => {
+                    if success {
+                        self.mailbox.detach_item(index, amount);
+                    }
+                }
+                NetworkEvent::MailSent { result } => {
+                    let text = match result {
+                        SendMailResult::Success => {
+                            self.mailbox.clear_draft();
+                            self.interface
+                                .close_window_with_class(&mut self.focus_state, WriteMailWindow::WINDOW_CLASS);
+                            self.chat_messages.push(ChatMessage {
+                                text: "The mail was sent".to_owned(),
+                                color: MessageColor::Information,
+                            });
+                            continue;
+                        }
+                        SendMailResult::Failed => "Failed to send the mail",
+                        SendMailResult::RecipientNotFound => "The recipient does not exist",
+                        SendMailResult::NotEnoughZeny => "You don't have enough zeny",
+                    };
+
+                    self.chat_messages.push(ChatMessage {
+                        text: text.to_owned(),
+                        color: MessageColor::Error,
+                    });
                 }
                 NetworkEvent::IventoryItemAdded { item } => {
                     self.player_inventory.add_item(&self.async_loader, &self.library, item);
+                    self.hotbar
+                        .refresh_items(&mut self.networking_system, &self.player_inventory.get_items());
 
                     // TODO: Update the selling items. If you pick up an item
                     // that you already have the sell window
@@ -1203,10 +1646,96 @@ impl Client {
                     amount,
                 } => {
                     self.player_inventory.remove_item(index, amount);
+                    self.hotbar
+                        .refresh_items(&mut self.networking_system, &self.player_inventory.get_items());
+                }
+                NetworkEvent::ItemPickupFailed { result } => {
+                    let text = match result {
+                        ItemPickupResult::Overweight => "You are carrying too much weight",
+                        ItemPickupResult::NoSpace | ItemPickupResult::MaximumOfItem | ItemPickupResult::StackLimitation => {
+                            "You can not carry any more items"
+                        }
+                        _ => "Failed to pick up the item",
+                    };
+
+                    self.chat_messages.push(ChatMessage {
+                        text: text.to_owned(),
+                        color: MessageColor::Error,
+                    });
+                }
+                NetworkEvent::ItemUsed {
+                    index,
+                    entity_id,
+                    amount,
+                    success,
+                    ..
+                } => {
+                    if self.entities.first().is_none_or(|player| player.get_entity_id() != entity_id) {
+                        continue;
+                    }
+
+                    match success {
+                        true => {
+                            self.player_inventory.set_item_amount(index, amount);
+                            self.hotbar
+                                .refresh_items(&mut self.networking_system, &self.player_inventory.get_items());
+                        }
+                        false => self.chat_messages.push(ChatMessage {
+                            text: "The item can not be used right now".to_owned(),
+                            color: MessageColor::Error,
+                        }),
+                    }
+                }
+                NetworkEvent::GroundItemAppeared {
+                    entity_id,
+                    item_id,
+                    is_identified,
+                    amount,
+                    position,
+                    sub_position,
+                    ..
+                } => {
+                    if let Some(map) = self.map.as_ref() {
+                        let metadata = self.library.load_ground_item_metadata(&self.async_loader, item_id, is_identified);
+                        let ground_item = GroundItem::new(map, entity_id, item_id, amount, position, sub_position, metadata);
+
+                        self.ground_items.retain(|item| item.get_entity_id() != entity_id);
+                        self.ground_items.push(ground_item);
+                    }
+                }
+                NetworkEvent::GroundItemDisappeared { entity_id } => {
+                    self.ground_items.retain(|item| item.get_entity_id() != entity_id);
+
+                    if self.pending_pickup == Some(entity_id) {
+                        self.pending_pickup = None;
+                    }
                 }
                 NetworkEvent::SkillTree(skill_information) => {
-                    self.player_skill_tree
-                        .fill(&self.sprite_loader, &self.action_loader, skill_information, client_tick);
+                    self.player_skill_tree.fill(
+                        &self.sprite_loader,
+                        &self.action_loader,
+                        &self.library,
+                        self.entities[0].get_job(),
+                        skill_information,
+                        client_tick,
+                    );
+                }
+                NetworkEvent::SkillUpdated {
+                    skill_id,
+                    skill_level,
+                    upgradable,
+                } => {
+                    self.player_skill_tree.update_skill(skill_id, skill_level, upgradable);
+                }
+                NetworkEvent::SkillAdded(skill_information) => {
+                    self.player_skill_tree.add_skill(
+                        &self.sprite_loader,
+                        &self.action_loader,
+                        &self.library,
+                        self.entities[0].get_job(),
+                        skill_information,
+                        client_tick,
+                    );
                 }
                 NetworkEvent::UpdateEquippedPosition { index, equipped_position } => {
                     self.player_inventory.update_equipped_position(index, equipped_position);
@@ -1342,24 +1871,37 @@ impl Client {
                     });
                 }
                 NetworkEvent::SetHotkeyData { tab, hotkeys } => {
-                    // FIX: Since we only have one hotbar at the moment, we ignore
-                    // everything but 0.
-                    if tab.0 != 0 {
-                        continue;
-                    }
+                    for (index, hotkey) in hotkeys.into_iter().enumerate() {
+                        let Some((row, slot)) = hotbar_location(tab, index) else {
+                            continue;
+                        };
 
-                    for (index, hotkey) in hotkeys.into_iter().take(10).enumerate() {
                         match hotkey {
-                            HotkeyState::Bound(hotkey) => {
+                            HotkeyState::Bound(hotkey) if hotkey.is_skill != 0 => {
                                 let Some(mut skill) = self.player_skill_tree.find_skill(SkillId(hotkey.skill_id as u16)) else {
-                                    self.hotbar.clear_slot(&mut self.networking_system, HotbarSlot(index as u16));
+                                    self.hotbar.clear_slot(&mut self.networking_system, row, slot);
                                     continue;
                                 };
 
                                 skill.skill_level = hotkey.quantity_or_skill_level;
-                                self.hotbar.set_slot(HotbarSlot(index as u16), skill);
+                                self.hotbar.set_slot(row, slot, HotbarEntry::Skill(skill));
                             }
-                            HotkeyState::Unbound => self.hotbar.unset_slot(HotbarSlot(index as u16)),
+                            HotkeyState::Bound(hotkey) => {
+                                // Items that are not in the inventory are kept as pending instead
+                                // of being cleared, in case the hotkeys arrive before the inventory.
+                                let item = self
+                                    .player_inventory
+                                    .get_items()
+                                    .iter()
+                                    .find(|item| item.item_id == ItemId(hotkey.skill_id))
+                                    .cloned();
+
+                                match item {
+                                    Some(item) => self.hotbar.set_slot(row, slot, HotbarEntry::Item(item)),
+                                    None => self.hotbar.set_pending_item(row, slot, ItemId(hotkey.skill_id)),
+                                }
+                            }
+                            HotkeyState::Unbound => self.hotbar.unset_slot(row, slot),
                         }
                     }
                 }
@@ -1413,15 +1955,14 @@ impl Client {
                                     .find(|inventory_item| inventory_item.index == item.inventory_index)
                                     .expect("item not in inventory");
 
-                                let name = inventory_item.metadata.name.clone();
-                                let texture = inventory_item.metadata.texture.clone();
+                                let metadata = inventory_item.metadata.clone();
                                 let quantity = match &inventory_item.details {
                                     korangar_networking::InventoryItemDetails::Regular { amount, .. } => *amount,
                                     korangar_networking::InventoryItemDetails::Equippable { .. } => 1,
                                 };
 
                                 SellItem {
-                                    metadata: (ResourceMetadata { name, texture }, quantity),
+                                    metadata: (metadata, quantity),
                                     inventory_index: item.inventory_index,
                                     price: item.price,
                                     overcharge_price: item.overcharge_price,
@@ -1454,69 +1995,453 @@ impl Client {
                         });
                     }
                 },
-            }
-        }
-
-        #[cfg(feature = "debug")]
-        network_event_measurement.stop();
+                NetworkEvent::VendingAvailable { maximum_item_count } => {
+                    self.sell_items.set(self.player_cart.items_for_sale());
 
-        #[cfg(feature = "debug")]
-        let user_event_measurement = Profiler::start_measurement("process user events");
+                    let items_for_sale = PlainTrackedState::default();
 
-        for event in user_events {
-            match event {
-                UserEvent::LogIn {
-                    service_id,
-                    username,
-                    password,
-                } => {
-                    let service = self
-                        .client_info
-                        .services
-                        .iter()
-                        .find(|service| service.service_id() == service_id)
-                        .unwrap();
-                    let address = format!("{}:{}", service.address, service.port);
-                    let socket_address = address
-                        .to_socket_addrs()
-                        .expect("Failed to resolve IP")
-                        .next()
-                        .expect("ill formatted service IP");
+                    self.interface.open_window(
+                        &self.application,
+                        &mut self.focus_state,
+                        &VendingWindow::new(self.sell_items.new_remote(), items_for_sale.clone()),
+                    );
+                    self.interface.open_window(
+                        &self.application,
+                        &mut self.focus_state,
+                        &VendingCartWindow::new(items_for_sale, maximum_item_count as usize),
+                    );
+                }
+                NetworkEvent::VendingStoreOpened { result } => match result {
+                    OpenVendingStoreResult::Success => {
+                        self.interface
+                            .close_window_with_class(&mut self.focus_state, VendingWindow::WINDOW_CLASS);
+                        self.interface
+                            .close_window_with_class(&mut self.focus_state, VendingCartWindow::WINDOW_CLASS);
 
-                    self.saved_login_server_address = Some(socket_address);
-                    self.saved_username = username.clone();
-                    self.saved_password = password.clone();
+                        let title = self.store_title.clone();
 
-                    self.networking_system.connect_to_login_server(socket_address, username, password);
-                }
-                UserEvent::SelectServer(server) => {
-                    self.saved_character_server = Some(server.clone());
+                        // The store entry is only sent to other players, so we set it for the
+                        // player ourselves.
+                        if let Some(player) = self.entities.first_mut() {
+                            player.set_store(Some((StoreType::Vending, title.clone())));
+                        }
 
-                    self.networking_system.disconnect_from_login_server();
+                        self.interface
+                            .open_window(&self.application, &mut self.focus_state, &VendingStoreWindow::new(title));
+                    }
+                    OpenVendingStoreResult::Failed => {
+                        self.chat_messages.push(ChatMessage {
+                            text: "Failed to open vending store".to_owned(),
+                            color: MessageColor::Error,
+                        });
+                    }
+                },
+                NetworkEvent::OwnVendingItems { items } => self.own_vending_items = items,
+                NetworkEvent::VendingItemSold { cart_index, amount, zeny } => {
+                    // The map server removes the item from the cart with a separate packet, so
+                    // we can't rely on the cart to find the name.
+                    let name = self
+                        .own_vending_items
+                        .iter()
+                        .find(|item| item.cart_index == cart_index)
+                        .map(|item| self.library.get_item_name_from_id(item.item_id, item.identified != 0))
+                        .unwrap_or_default();
 
-                    // Korangar should never attempt to connect to the character
-                    // server before it logged in to the login server, so it's fine to
-                    // unwrap here.
-                    let login_data = self.saved_login_data.as_ref().unwrap();
-                    self.networking_system.connect_to_character_server(login_data, server);
-                }
-                UserEvent::Respawn => {
-                    let _ = self.networking_system.respawn();
-                    self.interface
-                        .close_window_with_class(&mut self.focus_state, RespawnWindow::WINDOW_CLASS);
+                    self.chat_messages.push(ChatMessage {
+                        text: format!("Sold {amount}x {name} for {zeny} zeny"),
+                        color: MessageColor::Information,
+                    });
                 }
-                UserEvent::LogOut => {
-                    let _ = self.networking_system.log_out();
+                NetworkEvent::StoreEntryAdded {
+                    entity_id,
+                    store_type,
+                    title,
+                } => {
+                    if let Some(entity) = self.entities.iter_mut().find(|entity| entity.get_entity_id() == entity_id) {
+                        entity.set_store(Some((store_type, title)));
+                    }
                 }
-                UserEvent::Exit => event_loop.exit(),
-                UserEvent::CameraZoom(factor) => self.player_camera.soft_zoom(factor),
-                UserEvent::CameraRotate(factor) => self.player_camera.soft_rotate(factor),
-                UserEvent::CameraResetRotation => self.player_camera.reset_rotation(),
-                UserEvent::OpenMenuWindow => {
+                NetworkEvent::StoreEntryRemoved { entity_id } => {
+                    if let Some(entity) = self.entities.iter_mut().find(|entity| entity.get_entity_id() == entity_id) {
+                        entity.set_store(None);
+                    }
+
+                    let is_vending_store = self.vending_store.is_some_and(|(vendor_id, _)| vendor_id == entity_id);
+                    let is_buying_store = self.buying_store.is_some_and(|(buyer_id, _)| buyer_id == entity_id);
+
+                    if is_vending_store {
+                        self.vending_store = None;
+                        self.interface
+                            .close_window_with_class(&mut self.focus_state, BuyWindow::WINDOW_CLASS);
+                        self.interface
+                            .close_window_with_class(&mut self.focus_state, BuyCartWindow::WINDOW_CLASS);
+                    }
+
+                    if is_buying_store {
+                        self.buying_store = None;
+                        self.interface
+                            .close_window_with_class(&mut self.focus_state, SellWindow::WINDOW_CLASS);
+                        self.interface
+                            .close_window_with_class(&mut self.focus_state, SellCartWindow::WINDOW_CLASS);
+                    }
+                }
+                NetworkEvent::OpenVendingStore {
+                    entity_id,
+                    store_id,
+                    items,
+                } => {
+                    self.vending_store = Some((entity_id, store_id));
+                    self.shop_items.mutate(|shop_items| {
+                        *shop_items = items
+                            .into_iter()
+                            .map(|item| self.library.load_shop_item_metadata(&self.async_loader, item))
+                            .collect()
+                    });
+
+                    let cart = PlainTrackedState::default();
+
+                    self.interface.open_window(
+                        &self.application,
+                        &mut self.focus_state,
+                        &BuyWindow::new(self.shop_items.new_remote(), cart.clone()),
+                    );
+                    self.interface
+                        .open_window(&self.application, &mut self.focus_state, &BuyCartWindow::new(cart));
+                }
+                NetworkEvent::BuyingVendingItemsFailed { result } => {
+                    let text = match result {
+                        BuyVendingItemsResult::Success => continue,
+                        BuyVendingItemsResult::NotEnoughZeny => "Not enough zeny",
+                        BuyVendingItemsResult::WeightLimitExceeded => "Weight limit exceeded",
+                        BuyVendingItemsResult::OutOfStock => "The vendor does not have enough items left",
+                        BuyVendingItemsResult::VendorIsTrading => "The vendor is currently trading",
+                        BuyVendingItemsResult::StoreChanged => "The store has changed",
+                        BuyVendingItemsResult::TooManyItems => "You can not carry any more items",
+                    };
+
+                    self.chat_messages.push(ChatMessage {
+                        text: text.to_owned(),
+                        color: MessageColor::Error,
+                    });
+                }
+                NetworkEvent::OpenBuyingStore {
+                    entity_id,
+                    store_id,
+                    zeny_limit: _zeny_limit,
+                    items,
+                } => {
+                    let inventory_items = self.player_inventory.get_items();
+
+                    self.sell_items.mutate(|sell_items| {
+                        // Buying stores don't tell us which items to sell, so we offer every
+                        // matching item in the inventory.
+                        *sell_items = inventory_items
+                            .iter()
+                            .filter_map(|inventory_item| {
+                                let wanted_item = items.iter().find(|item| item.item_id == inventory_item.item_id)?;
+                                let quantity = match &inventory_item.details {
+                                    korangar_networking::InventoryItemDetails::Regular { amount, .. } => *amount,
+                                    korangar_networking::InventoryItemDetails::Equippable { .. } => 1,
+                                };
+
+                                Some(SellItem {
+                                    metadata: (inventory_item.metadata.clone(), quantity.min(wanted_item.amount)),
+                                    inventory_index: inventory_item.index,
+                                    price: wanted_item.price,
+                                    overcharge_price: wanted_item.price,
+                                })
+                            })
+                            .collect()
+                    });
+
+                    drop(inventory_items);
+                    self.buying_store = Some((entity_id, store_id));
+
+                    let cart = PlainTrackedState::default();
+
+                    self.interface.open_window(
+                        &self.application,
+                        &mut self.focus_state,
+                        &SellWindow::new(self.sell_items.new_remote(), cart.clone()),
+                    );
+                    self.interface
+                        .open_window(&self.application, &mut self.focus_state, &SellCartWindow::new(cart));
+                }
+                NetworkEvent::BuyingStoreItemSold {
+                    inventory_index,
+                    amount,
+                    zeny,
+                } => {
+                    let name = self
+                        .player_inventory
+                        .get_items()
+                        .iter()
+                        .find(|item| item.index == inventory_index)
+                        .map(|item| item.metadata.name.clone())
+                        .unwrap_or_default();
+
+                    // The map server does not send a separate packet to remove the item.
+                    self.player_inventory.remove_item(inventory_index, amount);
+                    self.hotbar
+                        .refresh_items(&mut self.networking_system, &self.player_inventory.get_items());
+                    self.chat_messages.push(ChatMessage {
+                        text: format!("Sold {amount}x {name} for {zeny} zeny"),
+                        color: MessageColor::Information,
+                    });
+                }
+                NetworkEvent::SellingToBuyingStoreFailed { item_id } => {
+                    let name = self.library.get_item_name_from_id(item_id, true);
+
+                    self.chat_messages.push(ChatMessage {
+                        text: format!("Failed to sell {name}"),
+                        color: MessageColor::Error,
+                    });
+                }
+                NetworkEvent::BuyingStoreAvailable { maximum_item_count } => {
+                    let inventory_items = self.player_inventory.get_items();
+                    let mut candidates: Vec<ShopItem<ResourceMetadata>> = Vec::new();
+
+                    // Buying stores can only request stackable items that the player owns at
+                    // least one of.
+                    for item in inventory_items
+                        .iter()
+                        .filter(|item| matches!(item.details, korangar_networking::InventoryItemDetails::Regular { .. }))
+                    {
+                        if !candidates.iter().any(|candidate| candidate.item_id == item.item_id) {
+                            candidates.push(ShopItem {
+                                metadata: item.metadata.clone(),
+                                item_id: item.item_id,
+                                item_type: item.item_type,
+                                price: Price(0),
+                                quantity: ItemQuantity::Infinite,
+                                weight: 0,
+                                location: 0,
+                                cart_index: None,
+                            });
+                        }
+                    }
+
+                    drop(inventory_items);
+                    self.shop_items.set(candidates);
+
+                    let cart = PlainTrackedState::default();
+
+                    self.interface.open_window(
+                        &self.application,
+                        &mut self.focus_state,
+                        &BuyingStoreWindow::new(self.shop_items.new_remote(), cart.clone()),
+                    );
+                    self.interface.open_window(
+                        &self.application,
+                        &mut self.focus_state,
+                        &BuyingStoreCartWindow::new(cart, maximum_item_count as usize),
+                    );
+                }
+                NetworkEvent::OpeningBuyingStoreFailed { result, total_weight } => {
+                    let text = match result {
+                        2 => format!("Failed to open buying store, the weight limit would be exceeded by {total_weight}"),
+                        _ => "Failed to open buying store".to_owned(),
+                    };
+
+                    self.chat_messages.push(ChatMessage {
+                        text,
+                        color: MessageColor::Error,
+                    });
+                }
+                NetworkEvent::BuyingStoreOpened { zeny_limit } => {
+                    self.interface
+                        .close_window_with_class(&mut self.focus_state, BuyingStoreWindow::WINDOW_CLASS);
+                    self.interface
+                        .close_window_with_class(&mut self.focus_state, BuyingStoreCartWindow::WINDOW_CLASS);
+
+                    let title = self.store_title.clone();
+
+                    // The store entry is only sent to other players, so we set it for the
+                    // player ourselves.
+                    if let Some(player) = self.entities.first_mut() {
+                        player.set_store(Some((StoreType::BuyingStore, title.clone())));
+                    }
+
+                    self.interface
+                        .open_window(&self.application, &mut self.focus_state, &OwnBuyingStoreWindow::new(title));
+                    self.chat_messages.push(ChatMessage {
+                        text: format!("Opened buying store with a zeny limit of {zeny_limit}"),
+                        color: MessageColor::Information,
+                    });
+                }
+                NetworkEvent::BuyingStoreItemBought {
+                    item_id,
+                    amount,
+                    zeny_limit,
+                } => {
+                    let name = self.library.get_item_name_from_id(item_id, true);
+
+                    self.chat_messages.push(ChatMessage {
+                        text: format!("Bought {amount}x {name}, {zeny_limit} zeny left to spend"),
+                        color: MessageColor::Information,
+                    });
+                }
+                NetworkEvent::BuyingStoreTradeFailed { result } => match result {
+                    // The map server closes the store in both cases.
+                    3 | 4 => {
+                        let text = match result {
+                            3 => "Buying store closed, the zeny limit was reached",
+                            _ => "Buying store closed, all items were bought",
+                        };
+
+                        if let Some(player) = self.entities.first_mut() {
+                            player.set_store(None);
+                        }
+
+                        self.interface
+                            .close_window_with_class(&mut self.focus_state, OwnBuyingStoreWindow::WINDOW_CLASS);
+                        self.chat_messages.push(ChatMessage {
+                            text: text.to_owned(),
+                            color: MessageColor::Information,
+                        });
+                    }
+                    _ => {
+                        self.chat_messages.push(ChatMessage {
+                            text: "A trade with the buying store failed".to_owned(),
+                            color: MessageColor::Error,
+                        });
+                    }
+                },
+                NetworkEvent::HomunculusInformation(information) => self.companions.set_homunculus_information(information),
+                NetworkEvent::MercenaryInformation(information) => {
+                    let entity_id = information.entity_id;
+                    let owner = self.entities.first().map(Entity::get_entity_id);
+
+                    self.companions.set_mercenary_information(information);
+
+                    if let Some(entity) = self.entities.iter_mut().find(|entity| entity.get_entity_id() == entity_id) {
+                        entity.set_owner(owner);
+                    }
+                }
+                NetworkEvent::MercenaryParameterChanged(parameter) => self.companions.update_mercenary(parameter),
+                NetworkEvent::PetInformation(information) => self.companions.set_pet_information(information),
+                NetworkEvent::CompanionUpdated {
+                    companion_type,
+                    entity_id,
+                    update,
+                } => {
+                    self.companions.update(companion_type, entity_id, update);
+
+                    if update == CompanionUpdate::Spawned {
+                        let owner = self.entities.first().map(Entity::get_entity_id);
+
+                        if let Some(entity) = self.entities.iter_mut().find(|entity| entity.get_entity_id() == entity_id) {
+                            entity.set_owner(owner);
+                        }
+                    }
+                }
+                NetworkEvent::CompanionFed {
+                    companion_type,
+                    result,
+                    item_id,
+                } => {
+                    let companion_name = match companion_type {
+                        CompanionType::Homunculus => "homunculus",
+                        CompanionType::Mercenary => "mercenary",
+                        CompanionType::Pet => "pet",
+                    };
+
+                    let chat_message = match result {
+                        FeedResult::Success => ChatMessage {
+                            text: format!(
+                                "Fed your {companion_name} with {}",
+                                self.library.get_item_name_from_id(item_id, true)
+                            ),
+                            color: MessageColor::Information,
+                        },
+                        FeedResult::Failed => ChatMessage {
+                            text: format!("Failed to feed your {companion_name}"),
+                            color: MessageColor::Error,
+                        },
+                    };
+
+                    self.chat_messages.push(chat_message);
+                }
+            }
+        }
+
+        #[cfg(feature = "debug")]
+        network_event_measurement.stop();
+        self.frame_statistics.stop_phase("network events", network_event_phase);
+
+        #[cfg(feature = "debug")]
+        let user_event_measurement = Profiler::start_measurement("process user events");
+        let user_event_phase = self.frame_statistics.start_phase();
+
+        for event in user_events {
+            match event {
+                UserEvent::LogIn {
+                    service_id,
+                    username,
+                    password,
+                } => {
+                    let service = self
+                        .client_info
+                        .services
+                        .iter()
+                        .find(|service| service.service_id() == service_id)
+                        .unwrap();
+                    let address = format!("{}:{}", service.address, service.port);
+                    let socket_address = address
+                        .to_socket_addrs()
+                        .expect("Failed to resolve IP")
+                        .next()
+                        .expect("ill formatted service IP");
+
+                    self.saved_login_server_address = Some(socket_address);
+                    self.saved_username = username.clone();
+                    self.saved_password = password.clone();
+
+                    self.networking_system.connect_to_login_server(socket_address, username, password);
+                }
+                UserEvent::SelectServer(server) => {
+                    self.saved_character_server = Some(server.clone());
+
+                    self.networking_system.disconnect_from_login_server();
+
+                    // Korangar should never attempt to connect to the character
+                    // server before it logged in to the login server, so it's fine to
+                    // unwrap here.
+                    let login_data = self.saved_login_data.as_ref().unwrap();
+                    self.networking_system.connect_to_character_server(login_data, server);
+                }
+                UserEvent::Respawn => {
+                    let _ = self.networking_system.respawn();
+                    self.interface
+                        .close_window_with_class(&mut self.focus_state, RespawnWindow::WINDOW_CLASS);
+                }
+                UserEvent::LogOut => {
+                    let _ = self.networking_system.log_out();
+                }
+                UserEvent::Exit => event_loop.exit(),
+                UserEvent::CameraZoom(factor) => self.player_camera.soft_zoom(factor),
+                UserEvent::CameraRotate(factor) => self.player_camera.soft_rotate(factor),
+                UserEvent::CameraResetRotation => self.player_camera.reset_rotation(),
+                UserEvent::OpenMenuWindow => {
                     if !self.entities.is_empty() {
                         self.interface.open_window(&self.application, &mut self.focus_state, &MenuWindow)
                     }
                 }
+                UserEvent::OpenStatusWindow => {
+                    if let Some(Entity::Player(player)) = self.entities.first() {
+                        self.interface.open_window(
+                            &self.application,
+                            &mut self.focus_state,
+                            &StatusWindow::new(player.status_remote()),
+                        )
+                    }
+                }
+                UserEvent::RaiseStat(stat_type) => {
+                    let _ = self.networking_system.raise_stat(stat_type, 1);
+                }
+                UserEvent::UpgradeSkill(skill_id) => {
+                    let _ = self.networking_system.upgrade_skill(skill_id);
+                }
                 UserEvent::OpenInventoryWindow => {
                     if !self.entities.is_empty() {
                         self.interface.open_window(
@@ -1526,6 +2451,15 @@ impl Client {
                         )
                     }
                 }
+                UserEvent::OpenCartWindow => {
+                    if !self.entities.is_empty() {
+                        self.interface.open_window(
+                            &self.application,
+                            &mut self.focus_state,
+                            &CartWindow::new(self.player_cart.item_remote(), self.player_cart.capacity_remote()),
+                        )
+                    }
+                }
                 UserEvent::OpenEquipmentWindow => {
                     if !self.entities.is_empty() {
                         self.interface.open_window(
@@ -1540,7 +2474,10 @@ impl Client {
                         self.interface.open_window(
                             &self.application,
                             &mut self.focus_state,
-                            &SkillTreeWindow::new(self.player_skill_tree.get_skills()),
+                            &SkillTreeWindow::new(
+                                self.player_skill_tree.get_skills(),
+                                self.player_skill_tree.skill_points_remote(),
+                            ),
                         )
                     }
                 }
@@ -1568,6 +2505,36 @@ impl Client {
                     &mut self.focus_state,
                     &AudioSettingsWindow::new(self.mute_on_focus_loss.clone_state()),
                 ),
+                UserEvent::OpenKeyBindingsWindow => self.interface.open_window(
+                    &self.application,
+                    &mut self.focus_state,
+                    &KeyBindingsWindow::new(self.key_bindings.new_remote(), self.input_system.key_binding_capture_remote()),
+                ),
+                UserEvent::CaptureKeyBinding(action) => self.input_system.capture_key_binding(action),
+                UserEvent::SetKeyBinding { action, chord } => {
+                    let conflicts = self.key_bindings.mutate(|key_bindings| {
+                        let conflicts = key_bindings.add_chord(action, chord);
+                        key_bindings.save();
+                        conflicts
+                    });
+
+                    if !conflicts.is_empty() {
+                        let actions = conflicts.iter().map(InputAction::to_string).collect::<Vec<_>>().join(", ");
+
+                        self.chat_messages.push(ChatMessage {
+                            text: format!("{chord} is also bound to {actions}"),
+                            color: MessageColor::Error,
+                        });
+                    }
+                }
+                UserEvent::ClearKeyBinding(action) => self.key_bindings.mutate(|key_bindings| {
+                    key_bindings.clear_chords(action);
+                    key_bindings.save();
+                }),
+                UserEvent::ResetKeyBindings => self.key_bindings.mutate(|key_bindings| {
+                    *key_bindings = KeyBindings::default();
+                    key_bindings.save();
+                }),
                 UserEvent::OpenFriendsWindow => {
                     self.interface.open_window(
                         &self.application,
@@ -1575,6 +2542,39 @@ impl Client {
                         &FriendsWindow::new(self.friend_list.new_remote()),
                     );
                 }
+                UserEvent::OpenHomunculusWindow => {
+                    self.interface.open_window(
+                        &self.application,
+                        &mut self.focus_state,
+                        &HomunculusWindow::new(self.companions.homunculus_remote()),
+                    );
+                }
+                UserEvent::OpenMercenaryWindow => {
+                    self.interface.open_window(
+                        &self.application,
+                        &mut self.focus_state,
+                        &MercenaryWindow::new(self.companions.mercenary_remote()),
+                    );
+                }
+                UserEvent::OpenPetWindow => {
+                    self.interface.open_window(
+                        &self.application,
+                        &mut self.focus_state,
+                        &PetWindow::new(self.companions.pet_remote()),
+                    );
+                }
+                UserEvent::OpenMailboxWindow => {
+                    if !self.entities.is_empty() {
+                        let _ = self.networking_system.open_mailbox();
+
+                        self.mailbox.set_has_new_mail(false);
+                        self.interface.open_window(
+                            &self.application,
+                            &mut self.focus_state,
+                            &MailboxWindow::new(self.mailbox.mail_remote()),
+                        );
+                    }
+                }
                 UserEvent::ToggleShowInterface => self.show_interface = !self.show_interface,
                 UserEvent::SetThemeFile { theme_file, theme_kind } => self.application.set_theme_file(theme_file, theme_kind),
                 UserEvent::SaveTheme { theme_kind } => self.application.save_theme(theme_kind),
@@ -1604,7 +2604,14 @@ impl Client {
                         .switch_character_slot(self.move_request.take().unwrap(), destination_slot);
                 }
                 UserEvent::RequestPlayerMove(destination) => {
+                    if let Some((entity_id, store_type)) = self.get_hovered_store_title(mouse_position) {
+                        let _ = self.networking_system.request_store_item_list(entity_id, store_type);
+                        continue;
+                    }
+
                     if !self.entities.is_empty() {
+                        self.pending_pickup = None;
+
                         let _ = self.networking_system.player_move(WorldPosition {
                             x: destination.x,
                             y: destination.y,
@@ -1612,13 +2619,96 @@ impl Client {
                         });
                     }
                 }
+                UserEvent::MoveInDirection(direction) => {
+                    if let Some(map) = self.map.as_ref()
+                        && !self.entities.is_empty()
+                    {
+                        let direction = stick_to_grid_direction(direction, self.player_camera.view_direction());
+                        let start = self.entities[0].get_grid_position();
+
+                        if let Some(destination) = movement_target(map, &mut self.path_finder, start, direction) {
+                            self.pending_pickup = None;
+
+                            let _ = self.networking_system.player_move(WorldPosition {
+                                x: destination.x,
+                                y: destination.y,
+                                direction: Direction::N,
+                            });
+                        }
+                    }
+                }
+                UserEvent::RequestPickUpItem(entity_id) => {
+                    let Some(ground_item) = self.ground_items.iter().find(|item| item.get_entity_id() == entity_id) else {
+                        continue;
+                    };
+
+                    if self.entities.is_empty() {
+                        continue;
+                    }
+
+                    let item_position = ground_item.get_grid_position();
+                    let player_position = self.entities[0].get_grid_position();
+
+                    // The map server rejects the request if the player is too far away, so we walk
+                    // to the item first and pick it up once we are in range.
+                    match ground_item.is_in_pickup_range(player_position) {
+                        true => {
+                            let _ = self.networking_system.pick_up_item(entity_id);
+                        }
+                        false => {
+                            self.pending_pickup = Some(entity_id);
+
+                            let _ = self.networking_system.player_move(WorldPosition {
+                                x: item_position.x,
+                                y: item_position.y,
+                                direction: Direction::N,
+                            });
+                        }
+                    }
+                }
+                UserEvent::UseItem(index) => {
+                    if let Some(saved_login_data) = self.saved_login_data.as_ref() {
+                        let _ = self.networking_system.use_item(index, saved_login_data.account_id);
+                    }
+                }
+                UserEvent::RequestDropItem(item) => {
+                    if item.is_equipped() {
+                        self.chat_messages.push(ChatMessage {
+                            text: "Equipped items can't be dropped".to_owned(),
+                            color: MessageColor::Error,
+                        });
+                    } else if item.amount() > 1 {
+                        self.interface.open_window(
+                            &self.application,
+                            &mut self.focus_state,
+                            &DropItemWindow::new(item.index, item.metadata.name.clone(), item.amount()),
+                        );
+                    } else {
+                        let _ = self.networking_system.drop_item(item.index, 1);
+                    }
+                }
+                UserEvent::DropItem { index, amount } => {
+                    let _ = self.networking_system.drop_item(index, amount);
+
+                    self.interface
+                        .close_window_with_class(&mut self.focus_state, DropItemWindow::WINDOW_CLASS);
+                }
                 UserEvent::RequestPlayerInteract(entity_id) => {
+                    if let Some((entity_id, store_type)) = self.get_hovered_store_title(mouse_position) {
+                        let _ = self.networking_system.request_store_item_list(entity_id, store_type);
+                        continue;
+                    }
+
                     let entity = self.entities.iter_mut().find(|entity| entity.get_entity_id() == entity_id);
 
                     if let Some(entity) = entity {
                         let _ = match entity.get_entity_type() {
                             EntityType::Npc => self.networking_system.start_dialog(entity_id),
                             EntityType::Monster => self.networking_system.player_attack(entity_id),
+                            EntityType::Player => match entity.get_store() {
+                                Some((store_type, _)) => self.networking_system.request_store_item_list(entity_id, *store_type),
+                                None => Ok(()),
+                            },
                             EntityType::Warp => self.networking_system.player_move({
                                 let position = entity.get_grid_position();
                                 WorldPosition {
@@ -1666,6 +2756,24 @@ impl Client {
                         (ItemSource::Equipment { .. }, ItemSource::Inventory) => {
                             let _ = self.networking_system.request_item_unequip(item.index);
                         }
+                        (ItemSource::Inventory, ItemSource::Cart) => {
+                            let _ = self.networking_system.move_item_to_cart(item.index, item.amount());
+                        }
+                        (ItemSource::Cart, ItemSource::Inventory) => {
+                            let _ = self.networking_system.move_item_from_cart(item.index, item.amount());
+                        }
+                        (ItemSource::Inventory, ItemSource::Mail) => {
+                            let _ = self.networking_system.add_item_to_mail(item.index, item.amount());
+                        }
+                        (ItemSource::Mail, ItemSource::Inventory) => {
+                            let _ = self.networking_system.remove_item_from_mail(item.index, item.amount());
+                        }
+                        (ItemSource::Inventory, ItemSource::Hotbar { slot }) => {
+                            self.hotbar.update_slot(&mut self.networking_system, slot, HotbarEntry::Item(item));
+                        }
+                        (ItemSource::Hotbar { slot: source_slot }, ItemSource::Hotbar { slot: destination_slot }) => {
+                            self.hotbar.swap_slot(&mut self.networking_system, source_slot, destination_slot);
+                        }
                         _ => {}
                     },
                     Move::Skill {
@@ -1674,7 +2782,8 @@ impl Client {
                         skill,
                     } => match (source, destination) {
                         (SkillSource::SkillTree, SkillSource::Hotbar { slot }) => {
-                            self.hotbar.update_slot(&mut self.networking_system, slot, skill);
+                            self.hotbar
+                                .update_slot(&mut self.networking_system, slot, HotbarEntry::Skill(skill));
                         }
                         (SkillSource::Hotbar { slot: source_slot }, SkillSource::Hotbar { slot: destination_slot }) => {
                             self.hotbar.swap_slot(&mut self.networking_system, source_slot, destination_slot);
@@ -1682,63 +2791,65 @@ impl Client {
                         _ => {}
                     },
                 },
-                UserEvent::CastSkill(slot) => {
-                    if let Some(skill) = self.hotbar.get_skill_in_slot(slot).as_ref() {
-                        match skill.skill_type {
-                            SkillType::Passive => {}
-                            SkillType::Attack => {
-                                if let Some(PickerTarget::Entity(entity_id)) = mouse_target {
-                                    let _ = self.networking_system.cast_skill(skill.skill_id, skill.skill_level, entity_id);
-                                }
+                UserEvent::CastSkill(slot) => match self.hotbar.get_entry_in_slot(slot).as_ref() {
+                    Some(HotbarEntry::Skill(skill)) => match skill.skill_type {
+                        SkillType::Passive => {}
+                        SkillType::Attack => {
+                            if let Some(PickerTarget::Entity(entity_id)) = mouse_target {
+                                let _ = self.networking_system.cast_skill(skill.skill_id, skill.skill_level, entity_id);
                             }
-                            SkillType::Ground | SkillType::Trap => {
-                                if let Some(PickerTarget::Tile { x, y }) = mouse_target {
-                                    let _ = self
-                                        .networking_system
-                                        .cast_ground_skill(skill.skill_id, skill.skill_level, TilePosition { x, y });
-                                }
+                        }
+                        SkillType::Ground | SkillType::Trap => {
+                            if let Some(PickerTarget::Tile { x, y }) = mouse_target {
+                                let _ = self
+                                    .networking_system
+                                    .cast_ground_skill(skill.skill_id, skill.skill_level, TilePosition { x, y });
                             }
-                            SkillType::SelfCast => match skill.skill_id == ROLLING_CUTTER_ID {
-                                true => {
-                                    let _ = self.networking_system.cast_channeling_skill(
-                                        skill.skill_id,
-                                        skill.skill_level,
-                                        self.entities[0].get_entity_id(),
-                                    );
-                                }
-                                false => {
-                                    let _ = self.networking_system.cast_skill(
-                                        skill.skill_id,
-                                        skill.skill_level,
-                                        self.entities[0].get_entity_id(),
-                                    );
-                                }
-                            },
-                            SkillType::Support => {
-                                if let Some(PickerTarget::Entity(entity_id)) = mouse_target {
-                                    let _ = self.networking_system.cast_skill(skill.skill_id, skill.skill_level, entity_id);
-                                } else {
-                                    let _ = self.networking_system.cast_skill(
-                                        skill.skill_id,
-                                        skill.skill_level,
-                                        self.entities[0].get_entity_id(),
-                                    );
-                                }
+                        }
+                        SkillType::SelfCast => match skill.skill_id == ROLLING_CUTTER_ID {
+                            true => {
+                                let _ = self.networking_system.cast_channeling_skill(
+                                    skill.skill_id,
+                                    skill.skill_level,
+                                    self.entities[0].get_entity_id(),
+                                );
+                            }
+                            false => {
+                                let _ =
+                                    self.networking_system
+                                        .cast_skill(skill.skill_id, skill.skill_level, self.entities[0].get_entity_id());
+                            }
+                        },
+                        SkillType::Support => {
+                            if let Some(PickerTarget::Entity(entity_id)) = mouse_target {
+                                let _ = self.networking_system.cast_skill(skill.skill_id, skill.skill_level, entity_id);
+                            } else {
+                                let _ =
+                                    self.networking_system
+                                        .cast_skill(skill.skill_id, skill.skill_level, self.entities[0].get_entity_id());
                             }
                         }
+                    },
+                    Some(HotbarEntry::Item(item)) => {
+                        if let Some(saved_login_data) = self.saved_login_data.as_ref() {
+                            let _ = self.networking_system.use_item(item.index, saved_login_data.account_id);
+                        }
                     }
-                }
+                    None => {}
+                },
                 UserEvent::StopSkill(slot) => {
-                    if let Some(skill) = self.hotbar.get_skill_in_slot(slot).as_ref() {
-                        if skill.skill_id == ROLLING_CUTTER_ID {
-                            let _ = self.networking_system.stop_channeling_skill(skill.skill_id);
-                        }
+                    if let Some(HotbarEntry::Skill(skill)) = self.hotbar.get_entry_in_slot(slot).as_ref()
+                        && skill.skill_id == ROLLING_CUTTER_ID
+                    {
+                        let _ = self.networking_system.stop_channeling_skill(skill.skill_id);
                     }
                 }
+                UserEvent::PreviousHotbarRow => self.hotbar.previous_row(),
+                UserEvent::NextHotbarRow => self.hotbar.next_row(),
                 UserEvent::AddFriend(name) => {
                     if name.len() > 24 {
                         #[cfg(feature = "debug")]
-                        print_debug!("[{}] friend name {} is too long", "error".red(), name.magenta());
+                        log_error!("friend name {} is too long", name.magenta());
                     } else {
                         let _ = self.networking_system.add_friend(name);
                     }
@@ -1756,11 +2867,27 @@ impl Client {
                     self.interface
                         .close_window_with_class(&mut self.focus_state, FriendRequestWindow::WINDOW_CLASS);
                 }
-                UserEvent::BuyItems { items } => {
-                    let _ = self.networking_system.purchase_items(items);
-                }
+                UserEvent::BuyItems { items } => match self.vending_store.take() {
+                    Some((entity_id, store_id)) => {
+                        let _ = self.networking_system.purchase_vending_items(entity_id, store_id, items);
+
+                        self.interface
+                            .close_window_with_class(&mut self.focus_state, BuyWindow::WINDOW_CLASS);
+                        self.interface
+                            .close_window_with_class(&mut self.focus_state, BuyCartWindow::WINDOW_CLASS);
+                    }
+                    None => {
+                        let _ = self.networking_system.purchase_items(items);
+                    }
+                },
                 UserEvent::CloseShop => {
-                    let _ = self.networking_system.close_shop();
+                    let vending_store = self.vending_store.take();
+                    let buying_store = self.buying_store.take();
+
+                    // Player stores don't need to be closed on the map server.
+                    if vending_store.is_none() && buying_store.is_none() {
+                        let _ = self.networking_system.close_shop();
+                    }
 
                     self.interface
                         .close_window_with_class(&mut self.focus_state, BuyWindow::WINDOW_CLASS);
@@ -1776,13 +2903,176 @@ impl Client {
                     self.interface
                         .close_window_with_class(&mut self.focus_state, BuyOrSellWindow::WINDOW_CLASS);
                 }
-                UserEvent::SellItems { items } => {
-                    let _ = self.networking_system.sell_items(items);
+                UserEvent::SellItems { items } => match self.buying_store.take() {
+                    Some((entity_id, store_id)) => {
+                        let inventory_items = self.player_inventory.get_items();
+                        let items = items
+                            .iter()
+                            .filter_map(|item| {
+                                let inventory_item = inventory_items
+                                    .iter()
+                                    .find(|inventory_item| inventory_item.index == item.inventory_index)?;
+
+                                Some(SellToBuyingStoreItemInformation {
+                                    inventory_index: item.inventory_index,
+                                    item_id: inventory_item.item_id,
+                                    amount: item.amount,
+                                })
+                            })
+                            .collect();
+
+                        drop(inventory_items);
+                        let _ = self.networking_system.sell_to_buying_store(entity_id, store_id, items);
+
+                        self.interface
+                            .close_window_with_class(&mut self.focus_state, SellWindow::WINDOW_CLASS);
+                        self.interface
+                            .close_window_with_class(&mut self.focus_state, SellCartWindow::WINDOW_CLASS);
+                    }
+                    None => {
+                        let _ = self.networking_system.sell_items(items);
+                    }
+                },
+                UserEvent::StartVending { title, items } => {
+                    let _ = self.networking_system.open_vending_store(title.clone(), items);
+                    self.store_title = title;
+                }
+                UserEvent::CancelVending => {
+                    self.interface
+                        .close_window_with_class(&mut self.focus_state, VendingWindow::WINDOW_CLASS);
+                    self.interface
+                        .close_window_with_class(&mut self.focus_state, VendingCartWindow::WINDOW_CLASS);
+                }
+                UserEvent::CloseVendingStore => {
+                    let _ = self.networking_system.close_vending_store();
+                    self.own_vending_items.clear();
+
+                    if let Some(player) = self.entities.first_mut() {
+                        player.set_store(None);
+                    }
+
+                    self.interface
+                        .close_window_with_class(&mut self.focus_state, VendingStoreWindow::WINDOW_CLASS);
+                }
+                UserEvent::StartBuyingStore { title, zeny_limit, items } => {
+                    let _ = self.networking_system.open_buying_store(title.clone(), zeny_limit, items);
+                    self.store_title = title;
+                }
+                UserEvent::CancelBuyingStore => {
+                    self.interface
+                        .close_window_with_class(&mut self.focus_state, BuyingStoreWindow::WINDOW_CLASS);
+                    self.interface
+                        .close_window_with_class(&mut self.focus_state, BuyingStoreCartWindow::WINDOW_CLASS);
+                }
+                UserEvent::CloseBuyingStore => {
+                    let _ = self.networking_system.close_buying_store();
+
+                    if let Some(player) = self.entities.first_mut() {
+                        player.set_store(None);
+                    }
+
+                    self.interface
+                        .close_window_with_class(&mut self.focus_state, OwnBuyingStoreWindow::WINDOW_CLASS);
+                }
+                UserEvent::RemoveCart => {
+                    let _ = self.networking_system.remove_cart();
+                }
+                UserEvent::CloseMailbox => {
+                    let _ = self.networking_system.close_mailbox();
+
+                    self.interface
+                        .close_window_with_class(&mut self.focus_state, MailboxWindow::WINDOW_CLASS);
+                    self.interface
+                        .close_window_with_class(&mut self.focus_state, MailWindow::WINDOW_CLASS);
+                }
+                UserEvent::RequestMoreMails => {
+                    if !self.mailbox.is_end()
+                        && let Some(mail_id) = self.mailbox.last_mail_id()
+                    {
+                        self.mailbox.expect_next_page();
+                        let _ = self.networking_system.request_mail_list(mail_id);
+                    }
+                }
+                UserEvent::ReadMail { mail_type, mail_id } => {
+                    let _ = self.networking_system.read_mail(mail_type, mail_id);
+
+                    self.interface.open_window(
+                        &self.application,
+                        &mut self.focus_state,
+                        &MailWindow::new(self.mailbox.opened_mail_remote()),
+                    );
+                }
+                UserEvent::DeleteMail { mail_type, mail_id } => {
+                    let _ = self.networking_system.delete_mail(mail_type, mail_id);
+                }
+                UserEvent::TakeMailZeny { mail_type, mail_id } => {
+                    let _ = self.networking_system.request_mail_zeny(mail_type, mail_id);
+                }
+                UserEvent::TakeMailItems { mail_type, mail_id } => {
+                    let _ = self.networking_system.request_mail_items(mail_type, mail_id);
+                }
+                UserEvent::OpenWriteMailWindow => {
+                    let _ = self.networking_system.open_write_mail(String::new());
+                }
+                UserEvent::CheckMailRecipient(name) => {
+                    let _ = self.networking_system.check_mail_recipient(name);
+                }
+                UserEvent::SendMail { title, text, zeny } => {
+                    if let Some(recipient) = self.mailbox.get_recipient() {
+                        let result = self.networking_system.send_mail(
+                            recipient.name,
+                            recipient.character_id,
+                            self.saved_player_name.clone(),
+                            title,
+                            text,
+                            zeny,
+                        );
+
+                        let text = match result {
+                            Err(SendMailError::TitleTooLong) => Some("The title is too long"),
+                            Err(SendMailError::TextTooLong) => Some("The text is too long"),
+                            Ok(()) | Err(SendMailError::NotConnected) => None,
+                        };
+
+                        if let Some(text) = text {
+                            self.chat_messages.push(ChatMessage {
+                                text: text.to_owned(),
+                                color: MessageColor::Error,
+                            });
+                        }
+                    }
+                }
+                UserEvent::CancelWriteMail => {
+                    let _ = self.networking_system.cancel_write_mail();
+
+                    self.mailbox.clear_draft();
+                    self.interface
+                        .close_window_with_class(&mut self.focus_state, WriteMailWindow::WINDOW_CLASS);
                 }
                 UserEvent::FocusChatWindow => {
                     self.interface
                         .focus_window_with_class(&mut self.focus_state, ChatWindow::WINDOW_CLASS);
                 }
+                UserEvent::HomunculusCommand(command) => {
+                    let _ = self.networking_system.homunculus_command(command);
+                }
+                UserEvent::RenameHomunculus(name) => {
+                    let _ = self.networking_system.rename_homunculus(name);
+                }
+                UserEvent::MercenaryCommand(command) => {
+                    let _ = self.networking_system.mercenary_command(command);
+                }
+                UserEvent::PetCommand(command) => {
+                    let _ = self.networking_system.pet_command(command);
+                }
+                UserEvent::RenamePet(name) => {
+                    let _ = self.networking_system.rename_pet(name);
+                }
+                UserEvent::RecallCompanion(companion_type) => {
+                    if let Some(entity_id) = self.companions.get_entity_id(companion_type) {
+                        let _ = self.networking_system.companion_return_to_owner(entity_id);
+                    }
+                }
                 #[cfg(feature = "debug")]
                 UserEvent::OpenMarkerDetails(marker_identifier) => {
                     if let Some(map) = self.map.as_ref() {
@@ -1809,19 +3099,84 @@ impl Client {
                 #[cfg(feature = "debug")]
                 UserEvent::OpenMapsWindow => self.interface.open_window(&self.application, &mut self.focus_state, &MapsWindow),
                 #[cfg(feature = "debug")]
+                UserEvent::OpenSceneInspectorWindow => self.interface.open_window(
+                    &self.application,
+                    &mut self.focus_state,
+                    &SceneInspectorWindow::new(self.scene_inspection.new_remote()),
+                ),
+                #[cfg(feature = "debug")]
+                UserEvent::ControlEntityAnimation { entity_id, control } => {
+                    if let Some(entity) = self.entities.iter_mut().find(|entity| entity.get_entity_id() == entity_id) {
+                        entity.control_animation(control, client_tick);
+                    }
+                }
+                UserEvent::ControlVideos(control) => {
+                    if let Some(map) = self.map.as_ref() {
+                        map.control_videos(control);
+                    }
+                }
+                #[cfg(feature = "debug")]
                 UserEvent::OpenCommandsWindow => self
                     .interface
                     .open_window(&self.application, &mut self.focus_state, &CommandsWindow),
                 #[cfg(feature = "debug")]
                 UserEvent::OpenTimeWindow => self.interface.open_window(&self.application, &mut self.focus_state, &TimeWindow),
                 #[cfg(feature = "debug")]
-                UserEvent::SetDawn => self.game_timer.set_day_timer(5.0 * 3600.0),
+                UserEvent::SetTimeOfDay(hours) => self.game_timer.set_day_timer(hours * 3600.0),
                 #[cfg(feature = "debug")]
-                UserEvent::SetNoon => self.game_timer.set_day_timer(12.0 * 3600.0),
+                UserEvent::OpenConsoleWindow => self.interface.open_window(
+                    &self.application,
+                    &mut self.focus_state,
+                    &ConsoleWindow::new(
+                        self.console_registry.clone(),
+                        self.console_output.clone(),
+                        self.console_history.entries(),
+                        self.font_loader.clone(),
+                    ),
+                ),
                 #[cfg(feature = "debug")]
-                UserEvent::SetDusk => self.game_timer.set_day_timer(17.0 * 3600.0),
+                UserEvent::TeleportDebugCamera(position) => {
+                    self.debug_camera.set_position(position);
+                    self.render_settings.mutate(|settings| settings.use_debug_camera = true);
+                }
                 #[cfg(feature = "debug")]
-                UserEvent::SetMidnight => self.game_timer.set_day_timer(24.0 * 3600.0),
+                UserEvent::SetRenderFlag { flag, enabled } => self.render_settings.mutate(|settings| {
+                    if let Some(value) = settings.flag_mut(flag) {
+                        *value = enabled.unwrap_or(!*value);
+                    }
+                }),
+                #[cfg(feature = "debug")]
+                UserEvent::SpawnEffect { file, position } => {
+                    let center = match position {
+                        Some(position) => {
+                            let Some(map) = self.map.as_ref() else { continue };
+                            EffectCenter::Position(map.get_world_position(position))
+                        }
+                        None => {
+                            let Some(player) = self.entities.first() else { continue };
+                            EffectCenter::Entity(player.get_entity_id(), Point3::new(0.0, 0.0, 0.0))
+                        }
+                    };
+
+                    match self.effect_loader.get_or_load(&file, &self.texture_loader) {
+                        Ok(effect) => {
+                            let frame_timer = effect.new_frame_timer();
+
+                            self.effect_holder.add_effect(Box::new(EffectWithLight::new(
+                                effect,
+                                frame_timer,
+                                center,
+                                Vector3::new(0.0, 9.0, 0.0),
+                                PointLightId::new(0),
+                                Vector3::new(0.0, 12.0, 0.0),
+                                Color::WHITE,
+                                50.0,
+                                false,
+                            )));
+                        }
+                        Err(error) => log_error!("failed to spawn effect {}: {:?}", file, error),
+                    }
+                }
                 #[cfg(feature = "debug")]
                 UserEvent::OpenThemeViewerWindow => {
                     self.interface
@@ -1841,6 +3196,12 @@ impl Client {
                 #[cfg(feature = "debug")]
                 UserEvent::ClearPacketHistory => self.packet_history_callback.clear_all(),
                 #[cfg(feature = "debug")]
+                UserEvent::SendRawPacket { server_type, bytes } => {
+                    if self.networking_system.send_raw_packet(server_type, bytes).is_err() {
+                        log_error!("failed to send packet, not connected to the {} server", server_type.name());
+                    }
+                }
+                #[cfg(feature = "debug")]
                 UserEvent::CameraLookAround(offset) => self.debug_camera.look_around(offset),
                 #[cfg(feature = "debug")]
                 UserEvent::CameraMoveForward => self.debug_camera.move_forward(delta_time as f32),
@@ -1861,9 +3222,11 @@ impl Client {
 
         #[cfg(feature = "debug")]
         user_event_measurement.stop();
+        self.frame_statistics.stop_phase("user events", user_event_phase);
 
         #[cfg(feature = "debug")]
         let loads_measurement = Profiler::start_measurement("complete async loads");
+        let loads_phase = self.frame_statistics.start_phase();
 
         for completed in self.async_loader.take_completed() {
             match completed {
@@ -1872,21 +3235,32 @@ impl Client {
                         entity.set_animation_data(animation_data);
                     }
                 }
-                (LoaderId::ItemSprite(item_id), LoadableResource::ItemSprite { texture, location }) => match location {
-                    ItemLocation::Inventory => {
-                        self.player_inventory.update_item_sprite(item_id, texture);
-                    }
-                    ItemLocation::Shop => {
-                        self.shop_items.mutate(|items| {
-                            items
-                                .iter_mut()
-                                .filter(|item| item.item_id == item_id)
-                                .for_each(|item| item.metadata.texture = Some(texture.clone()));
+                (LoaderId::ItemSprite(item_id), LoadableResource::ItemSprite { texture, location }) => {
+                    // Loads of the same item sprite are deduplicated, so the texture might have
+                    // been requested for a different location.
+                    self.ground_items
+                        .iter_mut()
+                        .filter(|item| item.get_item_id() == item_id)
+                        .for_each(|item| item.set_texture(texture.clone()));
 
-                            ValueState::Mutated(())
-                        });
+                    match location {
+                        ItemLocation::Inventory => {
+                            self.player_inventory.update_item_sprite(item_id, texture.clone());
+                            self.player_cart.update_item_sprite(item_id, texture);
+                        }
+                        ItemLocation::Shop => {
+                            self.shop_items.mutate(|items| {
+                                items
+                                    .iter_mut()
+                                    .filter(|item| item.item_id == item_id)
+                                    .for_each(|item| item.metadata.texture = Some(texture.clone()));
+
+                                ValueState::Mutated(())
+                            });
+                        }
+                        ItemLocation::Ground => {}
                     }
-                },
+                }
                 (LoaderId::Map(..), LoadableResource::Map { map, player_position }) => match self.entities.is_empty() {
                     true => {
                         // Load of main menu map
@@ -1930,10 +3304,13 @@ impl Client {
 
         #[cfg(feature = "debug")]
         loads_measurement.stop();
+        self.frame_statistics.stop_phase("async loads", loads_phase);
 
         // Main map update and render loop
         match self.map.as_ref() {
             Some(map) => {
+                let update_phase = self.frame_statistics.start_phase();
+
                 #[cfg(feature = "debug")]
                 let update_main_camera_measurement = Profiler::start_measurement("update main camera");
 
@@ -1980,6 +3357,16 @@ impl Client {
                         .for_each(|entity| entity.update(&self.audio_engine, map, current_camera, client_tick));
                 }
 
+                set_crash_player_position(self.entities.first().map(|player| player.get_grid_position()));
+
+                if let Some(entity_id) = self.pending_pickup
+                    && let Some(ground_item) = self.ground_items.iter().find(|item| item.get_entity_id() == entity_id)
+                    && ground_item.is_in_pickup_range(self.entities[0].get_grid_position())
+                {
+                    self.pending_pickup = None;
+                    let _ = self.networking_system.pick_up_item(entity_id);
+                }
+
                 match self.entities.is_empty() {
                     true => {
                         self.directional_shadow_camera
@@ -2042,12 +3429,33 @@ impl Client {
                 #[cfg(feature = "debug")]
                 frame_measurement.stop();
 
+                self.frame_statistics.stop_phase("update", update_phase);
+
                 #[cfg(feature = "debug")]
                 let prepare_frame_measurement = Profiler::start_measurement("prepare frame");
+                let prepare_frame_phase = self.frame_statistics.start_phase();
 
                 self.particle_holder.update(delta_time as f32);
                 self.effect_holder.update(&self.entities, delta_time as f32);
 
+                #[cfg(feature = "debug")]
+                {
+                    self.scene_inspection_timer += delta_time;
+
+                    if self.scene_inspection_timer >= SCENE_INSPECTION_INTERVAL {
+                        self.scene_inspection_timer = 0.0;
+                        self.scene_inspection.set(SceneInspection {
+                            entities: self
+                                .entities
+                                .iter()
+                                .map(|entity| (entity.get_entity_id(), entity.inspect()))
+                                .collect(),
+                            effects: self.effect_holder.inspect(),
+                            particles: self.particle_holder.inspect(),
+                        });
+                    }
+                }
+
                 let (clear_interface, render_interface) =
                     self.interface
                         .update(&self.application, self.font_loader.clone(), &mut self.focus_state);
@@ -2085,9 +3493,11 @@ impl Client {
 
                 #[cfg(feature = "debug")]
                 prepare_frame_measurement.stop();
+                self.frame_statistics.stop_phase("prepare frame", prepare_frame_phase);
 
                 #[cfg(feature = "debug")]
                 let collect_instructions_measurement = Profiler::start_measurement("collect instructions");
+                let collect_instructions_phase = self.frame_statistics.start_phase();
 
                 let picker_position = ScreenPosition {
                     left: mouse_position.left.clamp(0.0, window_size.x as f32),
@@ -2243,6 +3653,9 @@ impl Client {
                     #[cfg_attr(feature = "debug", korangar_debug::debug_condition(render_settings.show_entities))]
                     map.render_entities(&mut self.entity_instructions, &mut self.entities, entity_camera);
 
+                    #[cfg_attr(feature = "debug", korangar_debug::debug_condition(render_settings.show_entities))]
+                    map.render_ground_items(&mut self.entity_instructions, &self.ground_items, entity_camera);
+
                     #[cfg(feature = "debug")]
                     if render_settings.show_entities_debug {
                         map.render_entities_debug(&mut self.rectangle_instructions, &self.entities, entity_camera);
@@ -2310,11 +3723,15 @@ impl Client {
 
                             if let Some(name) = &entity.get_details() {
                                 let name = name.split('#').next().unwrap();
+                                let name = match entity.get_owner() {
+                                    Some(_) => format!("{name} ({})", self.saved_player_name),
+                                    None => name.to_owned(),
+                                };
 
                                 let offset = ScreenPosition { left: 15.0, top: 15.0 }.scaled(scaling);
 
                                 self.middle_interface_renderer.render_text(
-                                    name,
+                                    &name,
                                     mouse_position + offset,
                                     Color::WHITE,
                                     FontSize::new(16.0),
@@ -2322,6 +3739,52 @@ impl Client {
                                 );
                             }
                         }
+                    } else if let Some(PickerTarget::GroundItem(entity_id)) = mouse_target
+                        && let Some(ground_item) = self.ground_items.iter().find(|item| item.get_entity_id() == entity_id)
+                    {
+                        let offset = ScreenPosition { left: 15.0, top: 15.0 }.scaled(scaling);
+
+                        self.middle_interface_renderer.render_text(
+                            &ground_item.get_display_name(),
+                            mouse_position + offset,
+                            Color::WHITE,
+                            FontSize::new(16.0),
+                            AlignHorizontal::Mid,
+                        );
+                    }
+
+                    {
+                        #[cfg(feature = "debug")]
+                        profile_block!("render store titles");
+
+                        self.store_title_areas.clear();
+
+                        for entity in &self.entities {
+                            if let Some((position, size)) =
+                                entity.render_store_title(&self.middle_interface_renderer, current_camera, screen_size)
+                            {
+                                let (store_type, _) = entity.get_store().unwrap();
+                                self.store_title_areas.push((entity.get_entity_id(), *store_type, position, size));
+                            }
+                        }
+                    }
+
+                    if self.mailbox.has_new_mail() {
+                        #[cfg(feature = "debug")]
+                        profile_block!("render new mail indicator");
+
+                        let position = ScreenPosition {
+                            left: screen_size.width / 2.0,
+                            top: 0.0,
+                        } + ScreenPosition { left: 0.0, top: 10.0 }.scaled(scaling);
+
+                        self.middle_interface_renderer.render_text(
+                            "New mail",
+                            position,
+                            Color::rgb_u8(255, 200, 80),
+                            FontSize::new(16.0),
+                            AlignHorizontal::Mid,
+                        );
                     }
 
                     if !&self.entities.is_empty() {
@@ -2334,6 +3797,19 @@ impl Client {
                             self.application.get_game_theme(),
                             screen_size,
                         );
+
+                        self.entities
+                            .iter()
+                            .skip(1)
+                            .filter(|entity| entity.get_owner().is_some())
+                            .for_each(|entity| {
+                                entity.render_status(
+                                    &self.middle_interface_renderer,
+                                    current_camera,
+                                    self.application.get_game_theme(),
+                                    screen_size,
+                                )
+                            });
                     }
 
                     if render_interface {
@@ -2344,7 +3820,7 @@ impl Client {
                             &self.interface_renderer,
                             &self.application,
                             hovered_element,
-                            focused_element,
+                            focused_element.clone(),
                             self.input_system.get_mouse_mode(),
                         );
                     }
@@ -2363,9 +3839,33 @@ impl Client {
                     }
 
                     if self.show_interface {
+                        // When navigating the interface with a gamepad, the cursor points at the
+                        // focused element instead.
+                        let cursor_position = match &focused_element {
+                            Some(element) if self.input_system.is_gamepad_active() => {
+                                let element = element.borrow();
+                                let state = element.get_state();
+                                let position = state.screen_position.get();
+
+                                ScreenPosition {
+                                    left: position.left + state.cached_size.width,
+                                    top: position.top + state.cached_size.height / 2.0,
+                                }
+                            }
+                            _ => mouse_position,
+                        };
+
+                        self.tooltip_state.render(
+                            &self.top_interface_renderer,
+                            &self.application,
+                            self.application.get_theme(&InterfaceThemeKind::Main),
+                            cursor_position,
+                            screen_size,
+                        );
+
                         self.mouse_cursor.render(
                             &self.top_interface_renderer,
-                            mouse_position,
+                            cursor_position,
                             self.input_system.get_mouse_mode().grabbed(),
                             self.application.get_game_theme().cursor.color.get(),
                             &self.application,
@@ -2375,9 +3875,11 @@ impl Client {
 
                 #[cfg(feature = "debug")]
                 collect_instructions_measurement.stop();
+                self.frame_statistics.stop_phase("collect instructions", collect_instructions_phase);
 
                 #[cfg(feature = "debug")]
                 let render_frame_measurement = Profiler::start_measurement("render next frame");
+                let render_frame_phase = self.frame_statistics.start_phase();
 
                 let interface_instructions = self.interface_renderer.get_instructions();
                 let bottom_layer_instructions = self.bottom_interface_renderer.get_instructions();
@@ -2440,15 +3942,18 @@ impl Client {
 
                 #[cfg(feature = "debug")]
                 render_frame_measurement.stop();
+                self.frame_statistics.stop_phase("render next frame", render_frame_phase);
             }
             _ => {
                 #[cfg(feature = "debug")]
                 let render_frame_measurement = Profiler::start_measurement("render next frame");
+                let render_frame_phase = self.frame_statistics.start_phase();
 
                 self.graphics_engine.render_next_frame(frame, RenderInstruction::default());
 
                 #[cfg(feature = "debug")]
                 render_frame_measurement.stop();
+                self.frame_statistics.stop_phase("render next frame", render_frame_phase);
             }
         }
     }
@@ -2504,10 +4009,29 @@ impl Client {
             update_interface = true;
         }
 
+        if self.graphics_settings.consume_changed() {
+            set_crash_graphics_settings(&self.graphics_settings.get());
+        }
+
         if update_interface {
             self.interface.schedule_render();
         }
     }
+
+    /// Find the store whose title is rendered at the given position. Uses the
+    /// areas from the last rendered frame.
+    fn get_hovered_store_title(&self, mouse_position: ScreenPosition) -> Option<(EntityId, StoreType)> {
+        self.store_title_areas
+            .iter()
+            .rev()
+            .find(|(_, _, position, size)| {
+                mouse_position.left >= position.left
+                    && mouse_position.top >= position.top
+                    && mouse_position.left <= position.left + size.width
+                    && mouse_position.top <= position.top + size.height
+            })
+            .map(|(entity_id, store_type, ..)| (*entity_id, *store_type))
+    }
 }
 
 impl ApplicationHandler for Client {
@@ -2602,12 +4126,12 @@ impl ApplicationHandler for Client {
             WindowEvent::CursorMoved { position, .. } => self.input_system.update_mouse_position(position),
             WindowEvent::MouseInput { button, state, .. } => self.input_system.update_mouse_buttons(button, state),
             WindowEvent::MouseWheel { delta, .. } => self.input_system.update_mouse_wheel(delta),
+            WindowEvent::Ime(ime) => self.input_system.update_ime(ime),
             WindowEvent::KeyboardInput { event, .. } => {
                 if let PhysicalKey::Code(keycode) = event.physical_key {
                     self.input_system.update_keyboard(keycode, event.state);
                 }
 
-                // TODO: NHA We should also support IME in the long term (winit::event::Ime)
                 if let Some(text) = event.text
                     && event.state.is_pressed()
                 {
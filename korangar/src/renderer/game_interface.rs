@@ -4,7 +4,7 @@ use std::sync::Arc;
 #[cfg(feature = "debug")]
 use cgmath::Point3;
 use cgmath::{EuclideanSpace, Vector2};
-use korangar_interface::application::FontSizeTraitExt;
+use korangar_interface::application::{FontSizeTraitExt, ScalingTrait};
 
 use crate::graphics::{Color, RectangleInstruction, Texture};
 use crate::interface::layout::{ScreenClip, ScreenPosition, ScreenSize};
@@ -228,6 +228,42 @@ impl GameInterfaceRenderer {
         );
     }
 
+    /// Renders text on a solid background above the given position. The
+    /// returned area can be used to check if the text box was clicked.
+    pub fn render_text_box(
+        &self,
+        text: &str,
+        position: ScreenPosition,
+        color: Color,
+        background_color: Color,
+        font_size: FontSize,
+    ) -> (ScreenPosition, ScreenSize) {
+        let padding = ScreenSize::uniform(4.0 * self.scaling.get_factor());
+        let text_size = self
+            .font_loader
+            .get_text_dimensions(text, font_size.scaled(self.scaling), 1.0, f32::MAX);
+
+        let box_size = text_size + padding * 2.0;
+        let box_position = ScreenPosition {
+            left: position.left - box_size.width / 2.0,
+            top: position.top - box_size.height,
+        };
+
+        self.render_rectangle(box_position, box_size, background_color);
+        self.render_text(
+            text,
+            ScreenPosition {
+                left: position.left,
+                top: box_position.top + padding.height,
+            },
+            color,
+            font_size,
+            AlignHorizontal::Mid,
+        );
+
+        (box_position, box_size)
+    }
+
     pub fn render_damage_text(&self, text: &str, position: ScreenPosition, color: Color, font_size: FontSize) {
         self.render_text(text, position, color, font_size, AlignHorizontal::Mid);
     }
@@ -0,0 +1,402 @@
+use std::collections::BTreeMap;
+use std::fmt::{Display, Formatter};
+
+#[cfg(feature = "debug")]
+use korangar_debug::logging::{Colorize, print_debug};
+use ron::ser::PrettyConfig;
+use serde::{Deserialize, Serialize};
+use winit::event::MouseButton;
+use winit::keyboard::KeyCode;
+
+/// Number of hotbar slots that can be bound to a key.
+const HOTBAR_KEY_COUNT: u8 = 10;
+
+/// An action that can be triggered by a key binding.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+pub enum InputAction {
+    OpenMenu,
+    OpenInventory,
+    OpenEquipment,
+    OpenCart,
+    OpenSkillTree,
+    OpenStatus,
+    OpenHomunculus,
+    OpenKeyBindings,
+    ToggleInterface,
+    FocusChat,
+    /// Use the skill or item in a slot of the current hotbar row.
+    Hotbar(u8),
+    PreviousHotbarRow,
+    NextHotbarRow,
+    OpenMaps,
+    OpenRenderSettings,
+    OpenTime,
+    OpenPackets,
+}
+
+impl InputAction {
+    /// All actions that can be bound, in the order they are displayed.
+    pub fn all() -> Vec<InputAction> {
+        let mut actions = vec![
+            InputAction::OpenMenu,
+            InputAction::OpenInventory,
+            InputAction::OpenEquipment,
+            InputAction::OpenCart,
+            InputAction::OpenSkillTree,
+            InputAction::OpenStatus,
+            InputAction::OpenHomunculus,
+            InputAction::OpenKeyBindings,
+            InputAction::ToggleInterface,
+            InputAction::FocusChat,
+        ];
+
+        actions.extend((0..HOTBAR_KEY_COUNT).map(InputAction::Hotbar));
+        actions.extend([InputAction::PreviousHotbarRow, InputAction::NextHotbarRow]);
+
+        #[cfg(feature = "debug")]
+        actions.extend([
+            InputAction::OpenMaps,
+            InputAction::OpenRenderSettings,
+            InputAction::OpenTime,
+            InputAction::OpenPackets,
+        ]);
+
+        actions
+    }
+}
+
+impl Display for InputAction {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            InputAction::OpenMenu => write!(formatter, "Menu"),
+            InputAction::OpenInventory => write!(formatter, "Inventory"),
+            InputAction::OpenEquipment => write!(formatter, "Equipment"),
+            InputAction::OpenCart => write!(formatter, "Cart"),
+            InputAction::OpenSkillTree => write!(formatter, "Skill tree"),
+            InputAction::OpenStatus => write!(formatter, "Status"),
+            InputAction::OpenHomunculus => write!(formatter, "Homunculus"),
+            InputAction::OpenKeyBindings => write!(formatter, "Key bindings"),
+            InputAction::ToggleInterface => write!(formatter, "Toggle interface"),
+            InputAction::FocusChat => write!(formatter, "Focus chat"),
+            InputAction::Hotbar(slot) => write!(formatter, "Hotbar slot {}", slot + 1),
+            InputAction::PreviousHotbarRow => write!(formatter, "Previous hotbar row"),
+            InputAction::NextHotbarRow => write!(formatter, "Next hotbar row"),
+            InputAction::OpenMaps => write!(formatter, "Maps"),
+            InputAction::OpenRenderSettings => write!(formatter, "Render settings"),
+            InputAction::OpenTime => write!(formatter, "Time"),
+            InputAction::OpenPackets => write!(formatter, "Packets"),
+        }
+    }
+}
+
+/// The key or mouse button that triggers a key chord.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum InputTrigger {
+    Key(KeyCode),
+    Mouse(MouseButton),
+}
+
+/// A key or mouse button together with the modifiers that need to be held
+/// down.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct KeyChord {
+    #[serde(default)]
+    pub control: bool,
+    #[serde(default)]
+    pub alt: bool,
+    #[serde(default)]
+    pub shift: bool,
+    pub trigger: InputTrigger,
+}
+
+impl KeyChord {
+    pub const fn key(key_code: KeyCode) -> Self {
+        Self {
+            control: false,
+            alt: false,
+            shift: false,
+            trigger: InputTrigger::Key(key_code),
+        }
+    }
+
+    pub const fn control(key_code: KeyCode) -> Self {
+        Self {
+            control: true,
+            ..Self::key(key_code)
+        }
+    }
+
+    pub const fn alt(key_code: KeyCode) -> Self {
+        Self {
+            alt: true,
+            ..Self::key(key_code)
+        }
+    }
+}
+
+impl Display for KeyChord {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> std::fmt::Result {
+        if self.control {
+            write!(formatter, "Ctrl+")?;
+        }
+
+        if self.alt {
+            write!(formatter, "Alt+")?;
+        }
+
+        if self.shift {
+            write!(formatter, "Shift+")?;
+        }
+
+        match self.trigger {
+            InputTrigger::Key(key_code) => {
+                let name = format!("{key_code:?}");
+                let name = name.strip_prefix("Key").or_else(|| name.strip_prefix("Digit")).unwrap_or(&name);
+
+                write!(formatter, "{name}")
+            }
+            InputTrigger::Mouse(MouseButton::Other(button)) => write!(formatter, "Mouse{button}"),
+            InputTrigger::Mouse(button) => write!(formatter, "Mouse{button:?}"),
+        }
+    }
+}
+
+#[derive(Clone, Serialize, Deserialize)]
+pub struct KeyBindings {
+    bindings: BTreeMap<InputAction, Vec<KeyChord>>,
+}
+
+impl Default for KeyBindings {
+    fn default() -> Self {
+        let hotbar_keys = [
+            KeyCode::F1,
+            KeyCode::F2,
+            KeyCode::F3,
+            KeyCode::F4,
+            KeyCode::F5,
+            KeyCode::F6,
+            KeyCode::F7,
+            KeyCode::F8,
+            KeyCode::F9,
+            KeyCode::F10,
+        ];
+        let extra_hotbar_keys = [KeyCode::KeyJ, KeyCode::KeyL, KeyCode::KeyU];
+
+        let mut bindings = BTreeMap::from([
+            (InputAction::OpenMenu, vec![KeyChord::key(KeyCode::Escape)]),
+            (InputAction::OpenInventory, vec![KeyChord::alt(KeyCode::KeyE)]),
+            (InputAction::OpenEquipment, vec![KeyChord::alt(KeyCode::KeyQ)]),
+            (InputAction::OpenCart, vec![KeyChord::alt(KeyCode::KeyW)]),
+            (InputAction::OpenSkillTree, vec![KeyChord::alt(KeyCode::KeyS)]),
+            (InputAction::OpenStatus, vec![KeyChord::alt(KeyCode::KeyV)]),
+            (InputAction::OpenHomunculus, vec![KeyChord::alt(KeyCode::KeyR)]),
+            (InputAction::OpenKeyBindings, vec![KeyChord::alt(KeyCode::KeyK)]),
+            (InputAction::ToggleInterface, vec![KeyChord::control(KeyCode::KeyH)]),
+            (InputAction::FocusChat, vec![KeyChord::key(KeyCode::Enter)]),
+            (InputAction::PreviousHotbarRow, vec![KeyChord::control(KeyCode::PageUp)]),
+            (InputAction::NextHotbarRow, vec![KeyChord::control(KeyCode::PageDown)]),
+            (InputAction::OpenMaps, vec![KeyChord::control(KeyCode::KeyM)]),
+            (InputAction::OpenRenderSettings, vec![KeyChord::control(KeyCode::KeyR)]),
+            (InputAction::OpenTime, vec![KeyChord::control(KeyCode::KeyT)]),
+            (InputAction::OpenPackets, vec![KeyChord::control(KeyCode::KeyP)]),
+        ]);
+
+        for (slot, key_code) in hotbar_keys.into_iter().enumerate() {
+            let mut chords = vec![KeyChord::key(key_code)];
+
+            if let Some(extra_key_code) = extra_hotbar_keys.get(slot) {
+                chords.push(KeyChord::key(*extra_key_code));
+            }
+
+            bindings.insert(InputAction::Hotbar(slot as u8), chords);
+        }
+
+        Self { bindings }
+    }
+}
+
+impl KeyBindings {
+    const FILE_NAME: &'static str = "client/keybindings.ron";
+
+    pub fn new() -> Self {
+        Self::load().unwrap_or_else(|| {
+            #[cfg(feature = "debug")]
+            print_debug!("failed to load key bindings from {}", Self::FILE_NAME.magenta());
+            Default::default()
+        })
+    }
+
+    pub fn load() -> Option<Self> {
+        #[cfg(feature = "debug")]
+        print_debug!("loading key bindings from {}", Self::FILE_NAME.magenta());
+        std::fs::read_to_string(Self::FILE_NAME)
+            .ok()
+            .and_then(|data| ron::from_str(&data).ok())
+            .map(Self::merge_with_defaults)
+    }
+
+    /// Actions that were added after the bindings were saved are missing from
+    /// the file, so they get their default bindings.
+    fn merge_with_defaults(self) -> Self {
+        let mut bindings = Self::default().bindings;
+        bindings.extend(self.bindings);
+
+        Self { bindings }
+    }
+
+    pub fn save(&self) {
+        #[cfg(feature = "debug")]
+        print_debug!("saving key bindings to {}", Self::FILE_NAME.magenta());
+        let data = ron::ser::to_string_pretty(self, PrettyConfig::new()).unwrap();
+        std::fs::write(Self::FILE_NAME, data).expect("unable to write file");
+    }
+
+    pub fn get_chords(&self, action: InputAction) -> &[KeyChord] {
+        self.bindings.get(&action).map(Vec::as_slice).unwrap_or_default()
+    }
+
+    /// Bind an additional chord to an action. Returns the other actions that
+    /// are bound to the same chord.
+    pub fn add_chord(&mut self, action: InputAction, chord: KeyChord) -> Vec<InputAction> {
+        let chords = self.bindings.entry(action).or_default();
+
+        if !chords.contains(&chord) {
+            chords.push(chord);
+        }
+
+        self.actions_for_chord(chord).into_iter().filter(|other| *other != action).collect()
+    }
+
+    pub fn clear_chords(&mut self, action: InputAction) {
+        // Keep the empty entry, so the default binding is not restored when the
+        // bindings are loaded again.
+        self.bindings.insert(action, Vec::new());
+    }
+
+    /// All actions that are bound to the given chord.
+    pub fn actions_for_chord(&self, chord: KeyChord) -> Vec<InputAction> {
+        self.bindings
+            .iter()
+            .filter(|(_, chords)| chords.contains(&chord))
+            .map(|(action, _)| *action)
+            .collect()
+    }
+
+    /// Check if any of the chords of an action is also bound to a different
+    /// action.
+    pub fn has_conflict(&self, action: InputAction) -> bool {
+        self.get_chords(action)
+            .iter()
+            .any(|chord| self.actions_for_chord(*chord).into_iter().any(|other| other != action))
+    }
+}
+
+#[cfg(test)]
+mod bindings {
+    use winit::event::MouseButton;
+    use winit::keyboard::KeyCode;
+
+    use super::{InputAction, InputTrigger, KeyBindings, KeyChord};
+
+    fn empty() -> KeyBindings {
+        KeyBindings {
+            bindings: Default::default(),
+        }
+    }
+
+    #[test]
+    fn defaults_have_no_conflicts() {
+        let key_bindings = KeyBindings::default();
+
+        for action in InputAction::all() {
+            assert!(!key_bindings.has_conflict(action), "{action} has a conflicting binding");
+        }
+    }
+
+    #[test]
+    fn detect_conflict() {
+        let mut key_bindings = empty();
+
+        assert!(
+            key_bindings
+                .add_chord(InputAction::OpenMenu, KeyChord::key(KeyCode::Escape))
+                .is_empty()
+        );
+        assert_eq!(
+            key_bindings.add_chord(InputAction::OpenCart, KeyChord::key(KeyCode::Escape)),
+            vec![InputAction::OpenMenu]
+        );
+        assert!(key_bindings.has_conflict(InputAction::OpenMenu));
+
+        key_bindings.clear_chords(InputAction::OpenCart);
+        assert!(!key_bindings.has_conflict(InputAction::OpenMenu));
+    }
+
+    #[test]
+    fn modifiers_distinguish_chords() {
+        let mut key_bindings = empty();
+
+        key_bindings.add_chord(InputAction::OpenCart, KeyChord::key(KeyCode::KeyW));
+
+        assert!(
+            key_bindings
+                .add_chord(InputAction::OpenInventory, KeyChord::alt(KeyCode::KeyW))
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn merge_missing_actions() {
+        let mut key_bindings = empty();
+        key_bindings.add_chord(InputAction::OpenMenu, KeyChord::key(KeyCode::KeyM));
+
+        let key_bindings = key_bindings.merge_with_defaults();
+
+        assert_eq!(key_bindings.get_chords(InputAction::OpenMenu), [KeyChord::key(KeyCode::KeyM)]);
+        assert_eq!(key_bindings.get_chords(InputAction::OpenCart), [KeyChord::alt(KeyCode::KeyW)]);
+    }
+
+    #[test]
+    fn merge_keeps_cleared_actions() {
+        let mut key_bindings = empty();
+        key_bindings.clear_chords(InputAction::OpenCart);
+
+        let key_bindings = key_bindings.merge_with_defaults();
+
+        assert!(key_bindings.get_chords(InputAction::OpenCart).is_empty());
+    }
+
+    #[test]
+    fn merge_after_saving() {
+        let mut key_bindings = KeyBindings::default();
+        key_bindings.clear_chords(InputAction::FocusChat);
+
+        let data = ron::to_string(&key_bindings).unwrap();
+        let key_bindings = ron::from_str::<KeyBindings>(&data).unwrap().merge_with_defaults();
+
+        assert!(key_bindings.get_chords(InputAction::FocusChat).is_empty());
+        assert_eq!(key_bindings.get_chords(InputAction::OpenCart), [KeyChord::alt(KeyCode::KeyW)]);
+    }
+
+    #[test]
+    fn display_chord() {
+        assert_eq!(KeyChord::alt(KeyCode::KeyE).to_string(), "Alt+E");
+        assert_eq!(KeyChord::control(KeyCode::Digit1).to_string(), "Ctrl+1");
+        assert_eq!(
+            KeyChord {
+                shift: true,
+                ..KeyChord::key(KeyCode::F5)
+            }
+            .to_string(),
+            "Shift+F5"
+        );
+        assert_eq!(
+            KeyChord {
+                trigger: InputTrigger::Mouse(MouseButton::Middle),
+                ..KeyChord::key(KeyCode::F5)
+            }
+            .to_string(),
+            "MouseMiddle"
+        );
+    }
+}
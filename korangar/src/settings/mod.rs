@@ -2,8 +2,10 @@
 
 mod audio;
 mod graphic;
+mod keybindings;
 mod login;
 
 pub(crate) use audio::*;
 pub(crate) use graphic::*;
+pub(crate) use keybindings::*;
 pub(crate) use login::*;
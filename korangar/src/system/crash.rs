@@ -0,0 +1,190 @@
+use std::backtrace::Backtrace;
+use std::fmt::Display;
+use std::fs::File;
+use std::io::{self, BufWriter, Write};
+use std::panic::PanicHookInfo;
+use std::path::{Path, PathBuf};
+use std::sync::{Mutex, PoisonError, TryLockError};
+
+use cgmath::Vector2;
+use chrono::Local;
+use korangar_debug::logging::recent_log_lines;
+use ron::ser::PrettyConfig;
+use wgpu::AdapterInfo;
+
+use crate::graphics::Capabilities;
+#[cfg(feature = "debug")]
+use crate::interface::elements::PacketHistoryCallback;
+use crate::settings::GraphicsSettings;
+
+const CRASH_DIRECTORY: &str = "client/crashes";
+
+/// State of the client that is written to the crash report. The panic hook
+/// can't access the client, so the client keeps this up to date instead.
+struct CrashContext {
+    adapter: Option<String>,
+    capabilities: Option<String>,
+    graphics_settings: Option<String>,
+    map_name: Option<String>,
+    player_position: Option<Vector2<usize>>,
+}
+
+impl CrashContext {
+    const fn new() -> Self {
+        Self {
+            adapter: None,
+            capabilities: None,
+            graphics_settings: None,
+            map_name: None,
+            player_position: None,
+        }
+    }
+}
+
+static CRASH_CONTEXT: Mutex<CrashContext> = Mutex::new(CrashContext::new());
+
+fn update_crash_context(update: impl FnOnce(&mut CrashContext)) {
+    update(&mut CRASH_CONTEXT.lock().unwrap_or_else(PoisonError::into_inner));
+}
+
+pub fn set_crash_adapter(adapter_info: &AdapterInfo, capabilities: &Capabilities) {
+    let adapter = format!("{adapter_info:#?}");
+    let capabilities = format!("{capabilities:#?}");
+
+    update_crash_context(|context| {
+        context.adapter = Some(adapter);
+        context.capabilities = Some(capabilities);
+    });
+}
+
+pub fn set_crash_graphics_settings(graphics_settings: &GraphicsSettings) {
+    let graphics_settings = ron::ser::to_string_pretty(graphics_settings, PrettyConfig::new()).ok();
+    update_crash_context(|context| context.graphics_settings = graphics_settings);
+}
+
+pub fn set_crash_map(map_name: &str) {
+    let map_name = map_name.to_owned();
+    update_crash_context(|context| context.map_name = Some(map_name));
+}
+
+pub fn set_crash_player_position(player_position: Option<Vector2<usize>>) {
+    update_crash_context(|context| context.player_position = player_position);
+}
+
+/// Write a crash report to [`CRASH_DIRECTORY`] whenever the client panics.
+/// The previous panic hook is still called, so the panic message is printed
+/// as usual.
+pub fn install_crash_reporter() {
+    let previous_hook = std::panic::take_hook();
+
+    std::panic::set_hook(Box::new(move |info| {
+        previous_hook(info);
+
+        match write_crash_report(info) {
+            Ok(directory) => println!("crash report written to {}", directory.display()),
+            Err(error) => println!("failed to write crash report: {error}"),
+        }
+    }));
+}
+
+fn write_crash_report(info: &PanicHookInfo) -> io::Result<PathBuf> {
+    let thread = std::thread::current();
+    let thread_name = thread.name().unwrap_or("<unnamed>");
+    let location = info
+        .location()
+        .map(ToString::to_string)
+        .unwrap_or_else(|| "unknown location".to_owned());
+    let message = info.payload_as_str().unwrap_or("Box<dyn Any>");
+    let backtrace = Backtrace::force_capture();
+
+    // The client might have panicked while updating the context, in which case
+    // we don't want to wait for it.
+    let report = match CRASH_CONTEXT.try_lock() {
+        Ok(context) => format_report(thread_name, &location, message, &context, &backtrace),
+        Err(TryLockError::Poisoned(error)) => format_report(thread_name, &location, message, &error.into_inner(), &backtrace),
+        Err(TryLockError::WouldBlock) => format_report(thread_name, &location, message, &CrashContext::new(), &backtrace),
+    };
+
+    let directory = Path::new(CRASH_DIRECTORY).join(Local::now().format("crash_%Y-%m-%d_%H-%M-%S").to_string());
+    std::fs::create_dir_all(&directory)?;
+
+    std::fs::write(directory.join("report.txt"), report)?;
+
+    let mut writer = BufWriter::new(File::create(directory.join("log.txt"))?);
+    recent_log_lines().iter().try_for_each(|line| writeln!(writer, "{line}"))?;
+    writer.flush()?;
+
+    #[cfg(feature = "debug")]
+    {
+        let mut writer = BufWriter::new(File::create(directory.join("packets.txt"))?);
+        PacketHistoryCallback::get_static_instance().write_history(&mut writer)?;
+        writer.flush()?;
+    }
+
+    Ok(directory)
+}
+
+fn format_report(thread_name: &str, location: &str, message: &str, context: &CrashContext, backtrace: &impl Display) -> String {
+    const UNKNOWN: &str = "unknown";
+
+    let map_name = context.map_name.as_deref().unwrap_or(UNKNOWN);
+    let player_position = context
+        .player_position
+        .map(|position| format!("{}, {}", position.x, position.y))
+        .unwrap_or_else(|| UNKNOWN.to_owned());
+
+    let mut report = format!("thread '{thread_name}' panicked at {location}:\n{message}\n\n");
+
+    report += &format!("version: {}\n", env!("CARGO_PKG_VERSION"));
+    report += &format!("map: {map_name}\n");
+    report += &format!("player position: {player_position}\n\n");
+
+    let sections = [
+        ("adapter", context.adapter.as_deref()),
+        ("capabilities", context.capabilities.as_deref()),
+        ("graphics settings", context.graphics_settings.as_deref()),
+    ];
+
+    for (name, content) in sections {
+        report += &format!("[{name}]\n{}\n\n", content.unwrap_or(UNKNOWN));
+    }
+
+    report += &format!("[backtrace]\n{backtrace}\n");
+    report
+}
+
+#[cfg(test)]
+mod report {
+    use cgmath::Vector2;
+
+    use super::{CrashContext, format_report};
+
+    #[test]
+    fn empty_context() {
+        let report = format_report("main", "src/main.rs:1:1", "oh no", &CrashContext::new(), &"frames");
+
+        assert!(report.starts_with("thread 'main' panicked at src/main.rs:1:1:\noh no\n"));
+        assert!(report.contains("map: unknown\n"));
+        assert!(report.contains("player position: unknown\n"));
+        assert!(report.contains("[adapter]\nunknown\n"));
+        assert!(report.ends_with("[backtrace]\nframes\n"));
+    }
+
+    #[test]
+    fn full_context() {
+        let context = CrashContext {
+            adapter: Some("adapter".to_owned()),
+            capabilities: Some("capabilities".to_owned()),
+            graphics_settings: Some("(vsync: true)".to_owned()),
+            map_name: Some("geffen".to_owned()),
+            player_position: Some(Vector2::new(120, 84)),
+        };
+        let report = format_report("loader", "src/loaders/map.rs:10:5", "missing file", &context, &"frames");
+
+        assert!(report.contains("map: geffen\n"));
+        assert!(report.contains("player position: 120, 84\n"));
+        assert!(report.contains("[adapter]\nadapter\n"));
+        assert!(report.contains("[capabilities]\ncapabilities\n"));
+        assert!(report.contains("[graphics settings]\n(vsync: true)\n"));
+    }
+}
@@ -1,3 +1,5 @@
+mod crash;
 mod timer;
 
+pub use self::crash::{install_crash_reporter, set_crash_adapter, set_crash_graphics_settings, set_crash_map, set_crash_player_position};
 pub use self::timer::{GAME_TIME_DAY_CYCLE, GAME_TIME_SCALE, GameTimer};
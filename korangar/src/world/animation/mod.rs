@@ -7,12 +7,12 @@ use ragnarok_packets::{ClientTick, Direction, EntityId};
 
 #[cfg(feature = "debug")]
 use crate::graphics::DebugRectangleInstruction;
-use crate::graphics::{Color, EntityInstruction};
+use crate::graphics::{Color, EntityInstruction, PickerTarget};
 use crate::loaders::Sprite;
 use crate::world::{ActionEvent, Actions, Camera, EntityType};
 
-const TILE_SIZE: f32 = 10.0;
-const SPRITE_SCALE: f32 = 1.4;
+pub(super) const TILE_SIZE: f32 = 10.0;
+pub(super) const SPRITE_SCALE: f32 = 1.4;
 
 #[allow(dead_code)]
 #[derive(Copy, Clone, Default, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
@@ -53,7 +53,7 @@ impl AnimationActionType {
                 AnimationActionType::Skill => 12,
                 _ => 0,
             },
-            EntityType::Npc | EntityType::Monster => match self {
+            EntityType::Npc | EntityType::Monster | EntityType::Homunculus | EntityType::Mercenary | EntityType::Pet => match self {
                 AnimationActionType::Idle => 0,
                 AnimationActionType::Walk => 1,
                 AnimationActionType::Attack1 => 2,
@@ -74,6 +74,21 @@ pub struct AnimationState {
     pub time: u32,
     pub duration: Option<u32>,
     pub factor: Option<f32>,
+    /// Paused animations keep their time until they are resumed.
+    #[cfg(feature = "debug")]
+    pub paused: bool,
+}
+
+/// Changes to the animation of an entity made from the scene inspector.
+#[cfg(feature = "debug")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AnimationControl {
+    Pause,
+    Resume,
+    /// Pause the animation and move it by the given number of frames.
+    Step(i32),
+    /// Play an action, until the server changes it.
+    Force(AnimationActionType),
 }
 
 impl AnimationState {
@@ -86,6 +101,8 @@ impl AnimationState {
             time: 0,
             duration: None,
             factor: None,
+            #[cfg(feature = "debug")]
+            paused: false,
         }
     }
 
@@ -114,8 +131,37 @@ impl AnimationState {
     }
 
     pub fn update(&mut self, client_tick: ClientTick) {
+        #[cfg(feature = "debug")]
+        if self.paused {
+            return;
+        }
+
         self.time = client_tick.0.wrapping_sub(self.start_time.0);
     }
+
+    #[cfg(feature = "debug")]
+    pub fn control(&mut self, control: AnimationControl, entity_type: EntityType, frame_duration: u32, client_tick: ClientTick) {
+        match control {
+            AnimationControl::Pause => self.paused = true,
+            AnimationControl::Resume => {
+                // Continue from the current time instead of jumping ahead.
+                self.start_time = ClientTick(client_tick.0.wrapping_sub(self.time));
+                self.paused = false;
+            }
+            AnimationControl::Step(frames) => {
+                self.time = self.time.saturating_add_signed(frames.saturating_mul(frame_duration as i32));
+                self.paused = true;
+            }
+            AnimationControl::Force(action_type) => {
+                self.action_type = action_type;
+                self.action_base_offset = action_type.action_base_offset(entity_type);
+                self.start_time = client_tick;
+                self.time = 0;
+                self.duration = None;
+                self.factor = None;
+            }
+        }
+    }
 }
 
 #[derive(Clone, PrototypeElement)]
@@ -214,6 +260,40 @@ impl AnimationData {
         }
     }
 
+    /// Index of the current frame and the number of frames of the animation,
+    /// ignoring the camera direction.
+    #[cfg(feature = "debug")]
+    pub fn frame_progress(&self, animation_state: &AnimationState, direction: Direction) -> (usize, usize) {
+        let animation_action_index = animation_state.action_type.action_base_offset(self.entity_type) * 8 + usize::from(direction);
+        let animation = &self.animations[animation_action_index % self.animations.len()];
+        let frame_count = animation.frames.len();
+
+        if frame_count == 0 {
+            return (0, 0);
+        }
+
+        let frame_index = animation_state.time / self.frame_duration(animation_state, direction);
+
+        (frame_index as usize % frame_count, frame_count)
+    }
+
+    /// Time in milliseconds that a single frame of the animation is shown,
+    /// ignoring the camera direction.
+    #[cfg(feature = "debug")]
+    pub fn frame_duration(&self, animation_state: &AnimationState, direction: Direction) -> u32 {
+        let animation_action_index = animation_state.action_type.action_base_offset(self.entity_type) * 8 + usize::from(direction);
+        let delay = self.delays[animation_action_index % self.delays.len()];
+        let frame_count = self.animations[animation_action_index % self.animations.len()].frames.len() as u32;
+
+        // Without any frames the duration can't be split, so we fall back to the delay.
+        let frame_duration = match animation_state.duration {
+            Some(duration) if frame_count > 0 => duration / frame_count,
+            _ => animation_state.factor.map(|factor| delay * factor).unwrap_or_else(|| delay * 50.0) as u32,
+        };
+
+        frame_duration.max(1)
+    }
+
     pub fn calculate_world_matrix(&self, camera: &dyn Camera, frame: &AnimationFrame, entity_position: Point3<f32>) -> Matrix4<f32> {
         // Offset the image to below the ground by frame.offset.y.
         // Add 0.5 to change from center of pixel to the lower border of pixel
@@ -271,7 +351,7 @@ impl AnimationData {
                 curvature,
                 color: frame_part.color,
                 mirror: frame_part.mirror,
-                entity_id,
+                picker_target: PickerTarget::Entity(entity_id),
                 add_to_picker,
                 texture: texture.clone(),
                 distance,
@@ -313,3 +393,27 @@ impl AnimationData {
         }
     }
 }
+
+#[cfg(all(test, feature = "debug"))]
+mod frames {
+    use ragnarok_packets::{ClientTick, Direction};
+
+    use super::{Animation, AnimationData, AnimationState};
+    use crate::world::EntityType;
+
+    #[test]
+    fn without_frames() {
+        let animation_data = AnimationData {
+            animation_pair: Vec::new(),
+            animations: vec![Animation { frames: Vec::new() }],
+            delays: vec![2.0],
+            entity_type: EntityType::Monster,
+        };
+        let mut animation_state = AnimationState::new(EntityType::Monster, ClientTick(0));
+        animation_state.time = 250;
+        animation_state.duration = Some(1000);
+
+        assert_eq!(animation_data.frame_progress(&animation_state, Direction::N), (0, 0));
+        assert_eq!(animation_data.frame_duration(&animation_state, Direction::N), 100);
+    }
+}
@@ -58,6 +58,10 @@ impl DebugCamera {
         self.camera_position += Vector3::unit_y() * self.fly_speed * delta_time;
     }
 
+    pub fn set_position(&mut self, position: Point3<f32>) {
+        self.camera_position = position;
+    }
+
     pub fn accelerate(&mut self) {
         self.fly_speed = FLY_SPEED_FAST;
     }
@@ -1,5 +1,7 @@
 use korangar_interface::elements::PrototypeElement;
 use ragnarok_bytes::ByteConvertable;
+#[cfg(feature = "debug")]
+use ragnarok_bytes::{ByteReader, ByteWriter, FromBytes, ToBytes};
 
 #[derive(Clone, Debug, ByteConvertable, PrototypeElement)]
 #[numeric_type(u32)]
@@ -1147,3 +1149,57 @@ pub enum EffectId {
     SoulExplosion,
     Max,
 }
+
+#[cfg(feature = "debug")]
+impl EffectId {
+    /// Numeric value of the effect as sent by the server.
+    pub fn value(&self) -> u32 {
+        let mut byte_writer = ByteWriter::new();
+        self.to_bytes(&mut byte_writer).unwrap();
+
+        u32::from_le_bytes(byte_writer.as_slice().try_into().unwrap())
+    }
+
+    /// All effects ordered by their numeric value, excluding
+    /// [`EffectId::Max`].
+    pub fn all() -> Vec<Self> {
+        (0..EffectId::Max.value())
+            .filter_map(|value| Self::from_bytes(&mut ByteReader::without_metadata(&value.to_le_bytes())).ok())
+            .collect()
+    }
+
+    /// There is no table of effect files yet, so we assume that the file is
+    /// named after the effect, e.g. `firewall.str`.
+    pub fn file_name(&self) -> String {
+        format!("{:?}.str", self).to_lowercase()
+    }
+}
+
+#[cfg(all(test, feature = "debug"))]
+mod effect_id {
+    use super::EffectId;
+
+    #[test]
+    fn numeric_values() {
+        assert_eq!(EffectId::Hit1.value(), 0);
+        assert_eq!(EffectId::Firedance.value(), 1133);
+        assert_eq!(EffectId::RichsCoinA.value(), 1134);
+        assert_eq!(EffectId::SoulExplosion.value(), 1242);
+    }
+
+    #[test]
+    fn all_effects() {
+        let effects = EffectId::all();
+
+        assert_eq!(effects.first().map(EffectId::value), Some(0));
+        assert_eq!(effects.last().map(EffectId::value), Some(1242));
+        assert!(effects.windows(2).all(|pair| pair[0].value() < pair[1].value()));
+        assert!(!effects.iter().any(|effect| matches!(effect, EffectId::Max)));
+    }
+
+    #[test]
+    fn file_name() {
+        assert_eq!(EffectId::Firewall.file_name(), "firewall.str");
+        assert_eq!(EffectId::SoulReaper.file_name(), "soulreaper.str");
+    }
+}
@@ -10,13 +10,14 @@ use ragnarok_formats::map::EffectSource;
 use ragnarok_packets::EntityId;
 use wgpu::BlendFactor;
 
+pub use self::lookup::EffectId;
 use crate::graphics::{Color, Texture};
 use crate::renderer::EffectRenderer;
 #[cfg(feature = "debug")]
 use crate::renderer::MarkerRenderer;
-#[cfg(feature = "debug")]
-use crate::world::MarkerIdentifier;
 use crate::world::{Camera, PointLightId, PointLightManager};
+#[cfg(feature = "debug")]
+use crate::world::{Inspection, MarkerIdentifier};
 
 pub trait EffectBase {
     fn update(&mut self, entities: &[crate::world::Entity], delta_time: f32) -> bool;
@@ -26,6 +27,9 @@ pub trait EffectBase {
     fn register_point_lights(&self, point_light_manager: &mut PointLightManager, camera: &dyn Camera);
 
     fn render(&self, renderer: &mut EffectRenderer, camera: &dyn Camera);
+
+    #[cfg(feature = "debug")]
+    fn inspect(&self) -> Inspection;
 }
 
 pub trait EffectSourceExt {
@@ -253,6 +257,14 @@ impl EffectCenter {
             EffectCenter::Entity(_, position) | EffectCenter::Position(position) => *position,
         }
     }
+
+    #[cfg(feature = "debug")]
+    fn describe(&self) -> String {
+        match self {
+            EffectCenter::Entity(entity_id, _) => format!("entity {}", entity_id.0),
+            EffectCenter::Position(_) => "position".to_owned(),
+        }
+    }
 }
 
 pub struct EffectWithLight {
@@ -343,6 +355,26 @@ impl EffectBase for EffectWithLight {
         }
     }
 
+    #[cfg(feature = "debug")]
+    fn inspect(&self) -> Inspection {
+        let position = self.center.to_position() + self.effect_offset;
+
+        Inspection::new("Effect with light")
+            .with_field("center", self.center.describe())
+            .with_field("position", format!("{:.1}, {:.1}, {:.1}", position.x, position.y, position.z))
+            .with_field(
+                "frame",
+                format!("{} / {}", self.frame_timer.current_frame, self.frame_timer.max_key),
+            )
+            .with_field("frames per second", self.frame_timer.frames_per_second)
+            .with_field("repeating", self.repeating)
+            .with_field(
+                "light intensity",
+                format!("{:.1} / {:.1}", self.current_light_intensity, self.light_intensity),
+            )
+            .with_field("fading out", self.gets_deleted)
+    }
+
     fn render(&self, renderer: &mut EffectRenderer, camera: &dyn Camera) {
         if !self.gets_deleted {
             self.effect.render(
@@ -393,4 +425,15 @@ impl EffectHolder {
     pub fn render(&self, renderer: &mut EffectRenderer, camera: &dyn Camera) {
         self.effects.iter().for_each(|(effect, _)| effect.render(renderer, camera));
     }
+
+    #[cfg(feature = "debug")]
+    pub fn inspect(&self) -> Vec<Inspection> {
+        self.effects
+            .iter()
+            .map(|(effect, entity_id)| match entity_id {
+                Some(entity_id) => effect.inspect().with_field("unit", entity_id.0),
+                None => effect.inspect(),
+            })
+            .collect()
+    }
 }
@@ -1,30 +1,36 @@
+mod status;
+
+use std::cell::Ref;
 use std::string::String;
 use std::sync::Arc;
 
 use arrayvec::ArrayVec;
-use cgmath::{EuclideanSpace, Point3, Vector2, VectorSpace, Zero};
+use cgmath::{EuclideanSpace, Point3, Vector2, Vector3, VectorSpace, Zero};
 use derive_new::new;
 use korangar_audio::{AudioEngine, SoundEffectKey};
+use korangar_interface::application::FontSizeTrait;
 use korangar_interface::elements::PrototypeElement;
+use korangar_interface::state::{PlainRemote, PlainTrackedState, TrackedState, ValueState};
 use korangar_interface::windows::{PrototypeWindow, Window};
-use korangar_networking::EntityData;
+use korangar_networking::{EntityData, ObjectType, StoreType};
 use korangar_util::pathing::{MAX_WALK_PATH_SIZE, PathFinder};
-use ragnarok_packets::{AccountId, CharacterInformation, ClientTick, Direction, EntityId, Sex, StatusType, WorldPosition};
+use ragnarok_packets::{AccountId, CharacterInformation, ClientTick, Direction, EntityId, Sex, StatType, StatusType, WorldPosition};
 #[cfg(feature = "debug")]
 use smallvec::smallvec_inline;
 #[cfg(feature = "debug")]
 use wgpu::{BufferUsages, Device, Queue};
 
-use crate::graphics::EntityInstruction;
+pub use self::status::{PlayerStatus, Stat};
 #[cfg(feature = "debug")]
 use crate::graphics::reduce_vertices;
 #[cfg(feature = "debug")]
 use crate::graphics::{BindlessSupport, DebugRectangleInstruction};
+use crate::graphics::{Color, EntityInstruction};
 use crate::interface::application::InterfaceSettings;
 use crate::interface::layout::{ScreenPosition, ScreenSize};
 use crate::interface::theme::GameTheme;
 use crate::interface::windows::WindowCache;
-use crate::loaders::GameFileLoader;
+use crate::loaders::{FontSize, GameFileLoader};
 #[cfg(feature = "debug")]
 use crate::loaders::{GAT_TILE_SIZE, split_mesh_by_texture};
 use crate::renderer::GameInterfaceRenderer;
@@ -32,9 +38,9 @@ use crate::renderer::GameInterfaceRenderer;
 use crate::renderer::MarkerRenderer;
 use crate::world::{ActionEvent, AnimationActionType, AnimationData, AnimationState, Camera, Library, Map};
 #[cfg(feature = "debug")]
-use crate::world::{MarkerIdentifier, SubMesh};
+use crate::world::{AnimationControl, Inspection, MarkerIdentifier, SubMesh};
 #[cfg(feature = "debug")]
-use crate::{Buffer, Color, ModelVertex};
+use crate::{Buffer, ModelVertex};
 
 const MALE_HAIR_LOOKUP: &[usize] = &[2, 2, 1, 7, 5, 4, 3, 6, 8, 9, 10, 12, 11];
 const FEMALE_HAIR_LOOKUP: &[usize] = &[2, 2, 4, 7, 1, 5, 3, 6, 12, 10, 9, 11, 8];
@@ -83,18 +89,40 @@ pub struct Step {
 #[derive(Copy, Clone, PartialEq, Eq, Debug)]
 pub enum EntityType {
     Hidden,
+    Homunculus,
+    Mercenary,
     Monster,
     Npc,
+    Pet,
     Player,
     Warp,
 }
 
+impl EntityType {
+    /// Pets share their job ids with monsters, so we need the object type
+    /// sent by the server to tell them apart.
+    pub fn from_object_type(object_type: ObjectType, job_id: usize) -> Self {
+        match object_type {
+            ObjectType::Pet => EntityType::Pet,
+            ObjectType::Homunculus => EntityType::Homunculus,
+            ObjectType::Mercenary => EntityType::Mercenary,
+            _ => job_id.into(),
+        }
+    }
+
+    pub fn is_companion(self) -> bool {
+        matches!(self, EntityType::Homunculus | EntityType::Mercenary | EntityType::Pet)
+    }
+}
+
 impl From<usize> for EntityType {
     fn from(value: usize) -> Self {
         match value {
             45 => EntityType::Warp,
             111 => EntityType::Hidden, // TODO: check that this is correct
             0..=44 | 4000..=5999 => EntityType::Player,
+            6001..=6016 | 6048..=6052 => EntityType::Homunculus,
+            6017..=6046 => EntityType::Mercenary,
             46..=999 | 10000..=19999 => EntityType::Npc,
             1000..=3999 | 20000..=29999 => EntityType::Monster,
             _ => EntityType::Npc,
@@ -145,6 +173,12 @@ pub struct Common {
 
     #[hidden_element]
     pub entity_type: EntityType,
+    /// Only known for companions of the player, since the server does not
+    /// tell us who owns other companions.
+    pub owner: Option<EntityId>,
+    /// The type and title of the store this player opened.
+    #[hidden_element]
+    pub store: Option<(StoreType, String)>,
     pub active_movement: Option<Movement>,
     pub animation_data: Option<Arc<AnimationData>>,
     pub grid_position: Vector2<usize>,
@@ -341,7 +375,10 @@ fn get_entity_part_files(library: &Library, entity_type: EntityType, job_id: usi
             player_head_path(sex_sprite_path, head_id),
         ],
         EntityType::Npc => vec![format!("npc\\{}", library.get_job_identity_from_id(job_id))],
-        EntityType::Monster => vec![format!("몬스터\\{}", library.get_job_identity_from_id(job_id))],
+        EntityType::Monster | EntityType::Mercenary | EntityType::Pet => {
+            vec![format!("몬스터\\{}", library.get_job_identity_from_id(job_id))]
+        }
+        EntityType::Homunculus => vec![format!("homun\\{}", library.get_job_identity_from_id(job_id))],
         EntityType::Warp | EntityType::Hidden => vec![format!("npc\\{}", library.get_job_identity_from_id(job_id))], // TODO: change
     }
 }
@@ -359,7 +396,7 @@ impl Common {
         let sex = entity_data.sex;
 
         let active_movement = None;
-        let entity_type = job_id.into();
+        let entity_type = EntityType::from_object_type(entity_data.object_type, job_id);
 
         let details = ResourceState::Unavailable;
         let animation_state = AnimationState::new(entity_type, client_tick);
@@ -374,6 +411,8 @@ impl Common {
             sex,
             active_movement,
             entity_type,
+            owner: None,
+            store: None,
             movement_speed,
             health_points,
             maximum_health_points,
@@ -624,6 +663,7 @@ impl Common {
             EntityType::Player => Color::rgb_u8(25, 250, 225),
             EntityType::Npc => Color::rgb_u8(170, 250, 25),
             EntityType::Monster => Color::rgb_u8(250, 100, 25),
+            EntityType::Homunculus | EntityType::Mercenary | EntityType::Pet => Color::rgb_u8(225, 100, 250),
             _ => Color::WHITE,
         };
 
@@ -800,16 +840,62 @@ impl Common {
     ) {
         renderer.render_marker(camera, marker_identifier, self.position, hovered);
     }
+
+    #[cfg(feature = "debug")]
+    pub fn control_animation(&mut self, control: AnimationControl, client_tick: ClientTick) {
+        let frame_duration = self
+            .animation_data
+            .as_ref()
+            .map(|animation_data| animation_data.frame_duration(&self.animation_state, self.direction))
+            .unwrap_or(1);
+
+        self.animation_state.control(control, self.entity_type, frame_duration, client_tick);
+    }
+
+    #[cfg(feature = "debug")]
+    pub fn inspect(&self) -> Inspection {
+        let title = match self.details.as_option() {
+            Some(name) => format!("{:?} {} ({})", self.entity_type, self.entity_id.0, name),
+            None => format!("{:?} {}", self.entity_type, self.entity_id.0),
+        };
+        let frame = self
+            .animation_data
+            .as_ref()
+            .map(|animation_data| animation_data.frame_progress(&self.animation_state, self.direction))
+            .map(|(frame_index, frame_count)| format!("{} / {}", frame_index + 1, frame_count))
+            .unwrap_or_else(|| "not loaded".to_owned());
+
+        Inspection::new(title)
+            .with_field("job", self.job_id)
+            .with_field("sex", format!("{:?}", self.sex))
+            .with_field("health", format!("{} / {}", self.health_points, self.maximum_health_points))
+            .with_field("movement speed", self.movement_speed)
+            .with_field("direction", format!("{:?}", self.direction))
+            .with_field("head direction", self.head_direction)
+            .with_field("grid position", format!("{}, {}", self.grid_position.x, self.grid_position.y))
+            .with_field(
+                "position",
+                format!("{:.1}, {:.1}, {:.1}", self.position.x, self.position.y, self.position.z),
+            )
+            .with_field("moving", self.active_movement.is_some())
+            .with_field("action", format!("{:?}", self.animation_state.action_type))
+            .with_field("animation time", format!("{} ms", self.animation_state.time))
+            .with_field("frame", frame)
+            .with_field("paused", self.animation_state.paused)
+    }
 }
 
 #[derive(PrototypeWindow)]
 pub struct Player {
     common: Common,
     pub hair_id: usize,
+    pub cart_type: Option<usize>,
     pub spell_points: usize,
     pub activity_points: usize,
     pub maximum_spell_points: usize,
     pub maximum_activity_points: usize,
+    #[hidden_element]
+    status: PlainTrackedState<PlayerStatus>,
 }
 
 impl Player {
@@ -818,10 +904,26 @@ impl Player {
     /// the correct position we need to position the player to.
     pub fn new(account_id: AccountId, character_information: &CharacterInformation, client_tick: ClientTick) -> Self {
         let hair_id = character_information.head as usize;
+        let cart_type = None;
         let spell_points = character_information.spell_points as usize;
         let activity_points = 0;
         let maximum_spell_points = character_information.maximum_spell_points as usize;
         let maximum_activity_points = 0;
+        let status = PlainTrackedState::new(PlayerStatus {
+            base_level: character_information.level as u32,
+            job_level: character_information.job_level as u32,
+            base_experience: character_information.experience as u64,
+            job_experience: character_information.job_experience as u64,
+            status_points: character_information.sp_point as u32,
+            strength: Stat::with_base(character_information.strength),
+            agility: Stat::with_base(character_information.agility),
+            vitality: Stat::with_base(character_information.vit),
+            intelligence: Stat::with_base(character_information.intelligence),
+            dexterity: Stat::with_base(character_information.dexterity),
+            luck: Stat::with_base(character_information.luck),
+            zeny: character_information.money as u32,
+            ..Default::default()
+        });
 
         let entity_data = EntityData::from_character(account_id, character_information, WorldPosition::origin());
         let grid_position = Vector2::zero();
@@ -832,10 +934,12 @@ impl Player {
         Self {
             common,
             hair_id,
+            cart_type,
             spell_points,
             activity_points,
             maximum_spell_points,
             maximum_activity_points,
+            status,
         }
     }
 
@@ -858,6 +962,28 @@ impl Player {
             StatusType::MovementSpeed(value) => self.common.movement_speed = value as usize,
             _ => {}
         }
+
+        self.status.with_mut(|status| match status.update(&status_type) {
+            true => ValueState::Mutated(()),
+            false => ValueState::Unchanged(()),
+        });
+    }
+
+    /// Set the base value of a stat after the map server accepted a request
+    /// to raise it.
+    pub fn set_stat_base(&mut self, stat_type: StatType, base: u32) {
+        self.status.with_mut(|status| {
+            status.get_stat_mut(stat_type).base = base;
+            ValueState::Mutated(())
+        });
+    }
+
+    pub fn get_status(&self) -> Ref<'_, PlayerStatus> {
+        self.status.get()
+    }
+
+    pub fn status_remote(&self) -> PlainRemote<PlayerStatus> {
+        self.status.new_remote()
     }
 
     pub fn render_status(&self, renderer: &GameInterfaceRenderer, camera: &dyn Camera, theme: &GameTheme, window_size: ScreenSize) {
@@ -926,7 +1052,13 @@ impl Player {
 
     pub fn get_entity_part_files(&self, library: &Library) -> Vec<String> {
         let common = self.get_common();
-        get_entity_part_files(library, common.entity_type, common.job_id, common.sex, Some(self.hair_id))
+        let mut part_files = get_entity_part_files(library, common.entity_type, common.job_id, common.sex, Some(self.hair_id));
+
+        if let Some(cart_type) = self.cart_type {
+            part_files.push(format!("이팩트\\손수레{cart_type}"));
+        }
+
+        part_files
     }
 }
 
@@ -961,9 +1093,13 @@ impl Npc {
     }
 
     pub fn render_status(&self, renderer: &GameInterfaceRenderer, camera: &dyn Camera, theme: &GameTheme, window_size: ScreenSize) {
-        if self.common.entity_type != EntityType::Monster {
-            return;
-        }
+        // Companions of the player get a status bar, other companions are just
+        // decoration.
+        let health_color = match self.common.entity_type {
+            EntityType::Monster => theme.status_bar.enemy_health_color.get(),
+            entity_type if entity_type.is_companion() && self.common.owner.is_some() => theme.status_bar.player_health_color.get(),
+            _ => return,
+        };
 
         let clip_space_position = camera.view_projection_matrix() * self.common.position.to_homogeneous();
         let screen_position = camera.clip_to_screen_space(clip_space_position);
@@ -989,7 +1125,7 @@ impl Npc {
                 width: bar_width,
                 height: theme.status_bar.enemy_health_height.get(),
             },
-            theme.status_bar.enemy_health_color.get(),
+            health_color,
             self.common.maximum_health_points as f32,
             self.common.health_points as f32,
         );
@@ -1033,6 +1169,26 @@ impl Entity {
         }
     }
 
+    pub fn get_owner(&self) -> Option<EntityId> {
+        self.get_common().owner
+    }
+
+    pub fn set_owner(&mut self, owner: Option<EntityId>) {
+        self.get_common_mut().owner = owner;
+    }
+
+    pub fn get_store(&self) -> Option<&(StoreType, String)> {
+        self.get_common().store.as_ref()
+    }
+
+    pub fn set_store(&mut self, store: Option<(StoreType, String)>) {
+        self.get_common_mut().store = store;
+    }
+
+    pub fn get_job(&self) -> usize {
+        self.get_common().job_id
+    }
+
     pub fn set_job(&mut self, job_id: usize) {
         self.get_common_mut().job_id = job_id;
     }
@@ -1043,6 +1199,12 @@ impl Entity {
         }
     }
 
+    pub fn set_cart(&mut self, cart_type: Option<usize>) {
+        if let Self::Player(player) = self {
+            player.cart_type = cart_type
+        }
+    }
+
     pub fn set_animation_data(&mut self, animation_data: Arc<AnimationData>) {
         self.get_common_mut().animation_data = Some(animation_data)
     }
@@ -1123,6 +1285,16 @@ impl Entity {
         self.get_common().render_debug(instructions, camera);
     }
 
+    #[cfg(feature = "debug")]
+    pub fn control_animation(&mut self, control: AnimationControl, client_tick: ClientTick) {
+        self.get_common_mut().control_animation(control, client_tick);
+    }
+
+    #[cfg(feature = "debug")]
+    pub fn inspect(&self) -> Inspection {
+        self.get_common().inspect()
+    }
+
     #[cfg(feature = "debug")]
     pub fn get_pathing(&self) -> Option<&Pathing> {
         self.get_common()
@@ -1148,6 +1320,33 @@ impl Entity {
             Self::Npc(npc) => npc.render_status(renderer, camera, theme, window_size),
         }
     }
+
+    /// Renders the title of the player's store above their head. Returns the
+    /// area covered by the title, so it can be clicked.
+    pub fn render_store_title(
+        &self,
+        renderer: &GameInterfaceRenderer,
+        camera: &dyn Camera,
+        window_size: ScreenSize,
+    ) -> Option<(ScreenPosition, ScreenSize)> {
+        let (store_type, title) = self.get_store()?;
+
+        // TODO: get height of the entity as offset
+        let position = self.get_position() + Vector3::new(0.0, 25.0, 0.0);
+        let clip_space_position = camera.view_projection_matrix() * position.to_homogeneous();
+        let screen_position = camera.clip_to_screen_space(clip_space_position);
+        let final_position = ScreenPosition {
+            left: screen_position.x * window_size.width,
+            top: screen_position.y * window_size.height,
+        };
+
+        let background_color = match store_type {
+            StoreType::Vending => Color::rgba_u8(90, 60, 20, 220),
+            StoreType::BuyingStore => Color::rgba_u8(20, 60, 90, 220),
+        };
+
+        Some(renderer.render_text_box(title, final_position, Color::WHITE, background_color, FontSize::new(14.0)))
+    }
 }
 
 impl PrototypeWindow<InterfaceSettings> for Entity {
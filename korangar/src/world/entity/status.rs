@@ -0,0 +1,123 @@
+use ragnarok_packets::{StatType, StatusType};
+
+/// One of the base stats of the player.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Stat {
+    pub base: u32,
+    /// Bonus from equipment, cards and status effects.
+    pub bonus: u32,
+    /// Number of status points required to raise the base value by one.
+    pub required_points: u8,
+}
+
+impl Stat {
+    pub(super) fn with_base(base: u8) -> Self {
+        Self {
+            base: base as u32,
+            ..Default::default()
+        }
+    }
+}
+
+/// All values displayed in the status window of the player.
+#[derive(Clone, Debug, Default)]
+pub struct PlayerStatus {
+    pub base_level: u32,
+    pub job_level: u32,
+    pub base_experience: u64,
+    pub next_base_experience: u64,
+    pub job_experience: u64,
+    pub next_job_experience: u64,
+    pub status_points: u32,
+    pub strength: Stat,
+    pub agility: Stat,
+    pub vitality: Stat,
+    pub intelligence: Stat,
+    pub dexterity: Stat,
+    pub luck: Stat,
+    pub attack: (u32, u32),
+    pub magic_attack: (u32, u32),
+    pub defense: (u32, u32),
+    pub magic_defense: (u32, u32),
+    pub hit: u32,
+    pub flee: (u32, u32),
+    pub critical: u32,
+    pub attack_speed: u32,
+    pub weight: u32,
+    pub maximum_weight: u32,
+    pub zeny: u32,
+}
+
+impl PlayerStatus {
+    pub fn get_stat(&self, stat_type: StatType) -> &Stat {
+        match stat_type {
+            StatType::Strength => &self.strength,
+            StatType::Agility => &self.agility,
+            StatType::Vitality => &self.vitality,
+            StatType::Intelligence => &self.intelligence,
+            StatType::Dexterity => &self.dexterity,
+            StatType::Luck => &self.luck,
+        }
+    }
+
+    pub fn get_stat_mut(&mut self, stat_type: StatType) -> &mut Stat {
+        match stat_type {
+            StatType::Strength => &mut self.strength,
+            StatType::Agility => &mut self.agility,
+            StatType::Vitality => &mut self.vitality,
+            StatType::Intelligence => &mut self.intelligence,
+            StatType::Dexterity => &mut self.dexterity,
+            StatType::Luck => &mut self.luck,
+        }
+    }
+
+    /// Apply a status update from the map server. Returns `false` if the
+    /// status type is not part of the player status.
+    pub fn update(&mut self, status_type: &StatusType) -> bool {
+        let set_stat = |stat: &mut Stat, base: u32, bonus: u32| {
+            stat.base = base;
+            stat.bonus = bonus;
+        };
+
+        match *status_type {
+            StatusType::BaseLevel(value) => self.base_level = value,
+            StatusType::JobLevel(value) => self.job_level = value,
+            StatusType::BaseExperience(value) => self.base_experience = value,
+            StatusType::NextBaseExperience(value) => self.next_base_experience = value,
+            StatusType::JobExperience(value) => self.job_experience = value,
+            StatusType::NextJobExperience(value) => self.next_job_experience = value,
+            StatusType::StatusPoint(value) => self.status_points = value,
+            StatusType::Strength(base, bonus) => set_stat(&mut self.strength, base, bonus),
+            StatusType::Agility(base, bonus) => set_stat(&mut self.agility, base, bonus),
+            StatusType::Vitality(base, bonus) => set_stat(&mut self.vitality, base, bonus),
+            StatusType::Intelligence(base, bonus) => set_stat(&mut self.intelligence, base, bonus),
+            StatusType::Dexterity(base, bonus) => set_stat(&mut self.dexterity, base, bonus),
+            StatusType::Luck(base, bonus) => set_stat(&mut self.luck, base, bonus),
+            StatusType::SpUstr(value) => self.strength.required_points = value,
+            StatusType::SpUagi(value) => self.agility.required_points = value,
+            StatusType::SpUvit(value) => self.vitality.required_points = value,
+            StatusType::SpUint(value) => self.intelligence.required_points = value,
+            StatusType::SpUdex(value) => self.dexterity.required_points = value,
+            StatusType::SpUluk(value) => self.luck.required_points = value,
+            StatusType::Attack1(value) => self.attack.0 = value,
+            StatusType::Attack2(value) => self.attack.1 = value,
+            StatusType::MagicAttack1(value) => self.magic_attack.0 = value,
+            StatusType::MagicAttack2(value) => self.magic_attack.1 = value,
+            StatusType::Defense1(value) => self.defense.0 = value,
+            StatusType::Defense2(value) => self.defense.1 = value,
+            StatusType::MagicDefense1(value) => self.magic_defense.0 = value,
+            StatusType::MagicDefense2(value) => self.magic_defense.1 = value,
+            StatusType::Hit(value) => self.hit = value,
+            StatusType::Flee1(value) => self.flee.0 = value,
+            StatusType::Flee2(value) => self.flee.1 = value,
+            StatusType::Critical(value) => self.critical = value,
+            StatusType::AttackSpeed(value) => self.attack_speed = value,
+            StatusType::Weight(value) => self.weight = value,
+            StatusType::MaximumWeight(value) => self.maximum_weight = value,
+            StatusType::Zeny(value) => self.zeny = value,
+            _ => return false,
+        }
+
+        true
+    }
+}
@@ -0,0 +1,80 @@
+use ragnarok_packets::EntityId;
+
+/// Current values of a single object in the scene, formatted for the scene
+/// inspector.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Inspection {
+    pub title: String,
+    pub fields: Vec<(&'static str, String)>,
+}
+
+impl Inspection {
+    pub fn new(title: impl Into<String>) -> Self {
+        Self {
+            title: title.into(),
+            fields: Vec::new(),
+        }
+    }
+
+    pub fn with_field(mut self, name: &'static str, value: impl ToString) -> Self {
+        self.fields.push((name, value.to_string()));
+        self
+    }
+
+    /// Get the value of a field by its name.
+    pub fn field(&self, name: &str) -> Option<&str> {
+        self.fields
+            .iter()
+            .find(|(field_name, _)| *field_name == name)
+            .map(|(_, value)| value.as_str())
+    }
+}
+
+/// Snapshot of all entities, effects and particles in the scene.
+#[derive(Default)]
+pub struct SceneInspection {
+    pub entities: Vec<(EntityId, Inspection)>,
+    pub effects: Vec<Inspection>,
+    pub particles: Vec<Inspection>,
+}
+
+impl SceneInspection {
+    pub fn entity(&self, entity_id: EntityId) -> Option<&Inspection> {
+        self.entities
+            .iter()
+            .find(|(inspected_id, _)| *inspected_id == entity_id)
+            .map(|(_, inspection)| inspection)
+    }
+}
+
+#[cfg(test)]
+mod scene {
+    use ragnarok_packets::EntityId;
+
+    use super::{Inspection, SceneInspection};
+
+    #[test]
+    fn find_fields() {
+        let inspection = Inspection::new("Player")
+            .with_field("health", "10 / 20")
+            .with_field("paused", false);
+
+        assert_eq!(inspection.field("health"), Some("10 / 20"));
+        assert_eq!(inspection.field("paused"), Some("false"));
+        assert_eq!(inspection.field("speed"), None);
+    }
+
+    #[test]
+    fn find_entities() {
+        let scene = SceneInspection {
+            entities: vec![(EntityId(1), Inspection::new("first")), (EntityId(2), Inspection::new("second"))],
+            ..Default::default()
+        };
+
+        assert_eq!(
+            scene.entity(EntityId(2)).map(|inspection| inspection.title.as_str()),
+            Some("second")
+        );
+        assert!(scene.entity(EntityId(3)).is_none());
+    }
+}
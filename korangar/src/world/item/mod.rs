@@ -0,0 +1,120 @@
+use std::sync::Arc;
+
+use cgmath::{Array, Matrix4, Point3, SquareMatrix, Transform, Vector2, Vector3};
+use ragnarok_packets::{EntityId, ItemId, TilePosition};
+
+use super::animation::{SPRITE_SCALE, TILE_SIZE};
+use crate::graphics::{Color, EntityInstruction, PickerTarget, Texture};
+use crate::loaders::GAT_TILE_SIZE;
+use crate::world::{Camera, Map, ResourceMetadata};
+
+/// Size of the item sprite in pixels.
+const ITEM_SPRITE_SIZE: f32 = 24.0;
+/// The map server places items on a 16 by 16 grid inside of each tile.
+const SUB_TILE_COUNT: f32 = 16.0;
+/// Maximum distance in tiles from which the map server lets the player pick
+/// up an item.
+const PICKUP_RANGE: usize = 2;
+
+/// An item lying on the ground that can be picked up by the player.
+pub struct GroundItem {
+    entity_id: EntityId,
+    item_id: ItemId,
+    amount: u16,
+    grid_position: Vector2<usize>,
+    position: Point3<f32>,
+    metadata: ResourceMetadata,
+}
+
+impl GroundItem {
+    pub fn new(
+        map: &Map,
+        entity_id: EntityId,
+        item_id: ItemId,
+        amount: u16,
+        position: TilePosition,
+        sub_position: (u8, u8),
+        metadata: ResourceMetadata,
+    ) -> Self {
+        let grid_position = Vector2::new(position.x as usize, position.y as usize);
+        let sub_offset = Vector3::new(
+            (sub_position.0 as f32 / SUB_TILE_COUNT - 0.5) * GAT_TILE_SIZE,
+            0.0,
+            (sub_position.1 as f32 / SUB_TILE_COUNT - 0.5) * GAT_TILE_SIZE,
+        );
+        let position = map.get_world_position(grid_position) + sub_offset;
+
+        Self {
+            entity_id,
+            item_id,
+            amount,
+            grid_position,
+            position,
+            metadata,
+        }
+    }
+
+    pub fn get_entity_id(&self) -> EntityId {
+        self.entity_id
+    }
+
+    pub fn get_item_id(&self) -> ItemId {
+        self.item_id
+    }
+
+    pub fn get_grid_position(&self) -> Vector2<usize> {
+        self.grid_position
+    }
+
+    pub fn get_position(&self) -> Point3<f32> {
+        self.position
+    }
+
+    pub fn is_in_pickup_range(&self, player_position: Vector2<usize>) -> bool {
+        self.grid_position.x.abs_diff(player_position.x) <= PICKUP_RANGE && self.grid_position.y.abs_diff(player_position.y) <= PICKUP_RANGE
+    }
+
+    /// Name of the item, including the amount if there is more than one.
+    pub fn get_display_name(&self) -> String {
+        match self.amount {
+            1 => self.metadata.name.clone(),
+            amount => format!("{} x {amount}", self.metadata.name),
+        }
+    }
+
+    pub fn set_texture(&mut self, texture: Arc<Texture>) {
+        self.metadata.texture = Some(texture);
+    }
+
+    pub fn render(&self, instructions: &mut Vec<EntityInstruction>, camera: &dyn Camera) {
+        let Some(texture) = self.metadata.texture.as_ref() else {
+            return;
+        };
+
+        // Place the bottom of the sprite on the ground, the same way an animation
+        // frame is placed relative to the entity position.
+        let origin = Point3::new(0.0, ITEM_SPRITE_SIZE / 2.0 + 0.5, 0.0) * SPRITE_SCALE / TILE_SIZE + Vector3::unit_z();
+        let size = Vector2::from_value(ITEM_SPRITE_SIZE) * SPRITE_SCALE / TILE_SIZE;
+        let world_matrix = camera.billboard_matrix(self.position, origin, size);
+
+        let (depth_offset, curvature) = camera.calculate_depth_offset_and_curvature(&world_matrix, SPRITE_SCALE, SPRITE_SCALE);
+        let distance = camera.distance_to(world_matrix.transform_point(Point3::from_value(0.0)));
+
+        instructions.push(EntityInstruction {
+            world: world_matrix,
+            frame_part_transform: Matrix4::identity(),
+            texture_position: Vector2::from_value(0.0),
+            texture_size: Vector2::from_value(1.0),
+            frame_size: Vector2::from_value(ITEM_SPRITE_SIZE),
+            extra_depth_offset: 0.0,
+            depth_offset,
+            curvature,
+            color: Color::WHITE,
+            mirror: false,
+            picker_target: PickerTarget::GroundItem(self.entity_id),
+            add_to_picker: true,
+            texture: texture.clone(),
+            distance,
+        });
+    }
+}
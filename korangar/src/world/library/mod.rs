@@ -2,10 +2,10 @@ use std::sync::Arc;
 
 use encoding_rs::EUC_KR;
 use hashbrown::HashMap;
-use korangar_networking::{InventoryItem, NoMetadata, ShopItem};
+use korangar_networking::{InventoryItem, InventoryItemDetails, NoMetadata, ShopItem};
 use korangar_util::FileLoader;
 use mlua::{Lua, Value};
-use ragnarok_packets::ItemId;
+use ragnarok_packets::{ItemId, SkillId, SkillLevel};
 
 use crate::graphics::{Color, Texture};
 use crate::loaders::{AsyncLoader, GameFileLoader, ImageType, ItemLocation};
@@ -14,6 +14,11 @@ use crate::loaders::{AsyncLoader, GameFileLoader, ImageType, ItemLocation};
 pub struct ResourceMetadata {
     pub texture: Option<Arc<Texture>>,
     pub name: String,
+    /// Lines of the item description. May contain color codes.
+    pub description: Vec<String>,
+    pub slot_count: usize,
+    /// Names of the cards inserted into the item.
+    pub cards: Vec<String>,
 }
 
 #[derive(Debug, Clone)]
@@ -22,6 +27,19 @@ struct ItemInfo {
     unidentified_name: Option<String>,
     identified_resource: Option<String>,
     unidentified_resource: Option<String>,
+    identified_description: Vec<String>,
+    unidentified_description: Vec<String>,
+    slot_count: usize,
+}
+
+#[derive(Debug, Clone)]
+struct SkillInfo {
+    /// Name used for the skill resources, e.g. `SM_BASH`.
+    identifier: Option<String>,
+    maximum_level: SkillLevel,
+    required_skills: Vec<(SkillId, SkillLevel)>,
+    /// Some skills have different requirements depending on the job.
+    job_required_skills: HashMap<usize, Vec<(SkillId, SkillLevel)>>,
 }
 
 #[allow(unused)]
@@ -57,6 +75,9 @@ pub struct Library {
     job_identity_table: HashMap<usize, String>,
     item_table: HashMap<ItemId, ItemInfo>,
     map_sky_data_table: HashMap<String, MapSkyData>,
+    skill_table: HashMap<SkillId, SkillInfo>,
+    skill_tree_table: HashMap<usize, Vec<SkillId>>,
+    job_inherit_table: HashMap<usize, usize>,
 }
 
 impl Library {
@@ -91,10 +112,27 @@ impl Library {
 
         let map_sky_data_table = Self::load_map_sky_data_table(&state)?;
 
+        let state = Lua::new();
+
+        // Not every client ships the skill tables, so missing files only result in an
+        // empty skill tree.
+        for file_name in ["jobinheritlist", "skillid", "skilltreeview", "skillinfolist"] {
+            if let Ok(data) = game_file_loader.get(&format!("data\\luafiles514\\lua files\\skillinfoz\\{file_name}.lub")) {
+                state.load(&data).exec()?;
+            }
+        }
+
+        let skill_table = Self::load_skill_table(&state)?;
+        let skill_tree_table = Self::load_skill_tree_table(&state)?;
+        let job_inherit_table = Self::load_job_inherit_table(&state)?;
+
         Ok(Self {
             job_identity_table,
             item_table,
             map_sky_data_table,
+            skill_table,
+            skill_tree_table,
+            job_inherit_table,
         })
     }
 
@@ -144,6 +182,8 @@ impl Library {
         let globals = state.globals();
         let mut result = HashMap::new();
 
+        let parse_description = |table: mlua::Table| table.sequence_values::<String>().flatten().map(fix_encoding).collect::<Vec<_>>();
+
         if let Ok(table) = globals.get::<mlua::Table>("tbl") {
             for (item_id, item_table) in table.pairs::<u32, mlua::Table>().flatten() {
                 let info = ItemInfo {
@@ -151,6 +191,15 @@ impl Library {
                     unidentified_name: item_table.get("unidentifiedDisplayName").ok().map(fix_encoding),
                     identified_resource: item_table.get("identifiedResourceName").ok().map(fix_encoding),
                     unidentified_resource: item_table.get("unidentifiedResourceName").ok().map(fix_encoding),
+                    identified_description: item_table
+                        .get::<mlua::Table>("identifiedDescriptionName")
+                        .map(parse_description)
+                        .unwrap_or_default(),
+                    unidentified_description: item_table
+                        .get::<mlua::Table>("unidentifiedDescriptionName")
+                        .map(parse_description)
+                        .unwrap_or_default(),
+                    slot_count: item_table.get("slotCount").unwrap_or_default(),
                 };
 
                 result.insert(ItemId(item_id), info);
@@ -162,6 +211,82 @@ impl Library {
         Ok(compacted)
     }
 
+    fn load_skill_table(state: &Lua) -> mlua::Result<HashMap<SkillId, SkillInfo>> {
+        let globals = state.globals();
+        let mut result = HashMap::new();
+
+        let parse_requirements = |table: mlua::Table| {
+            table
+                .sequence_values::<mlua::Table>()
+                .flatten()
+                .filter_map(|requirement| {
+                    let skill_id = requirement.get::<u16>(1).ok()?;
+                    let skill_level = requirement.get::<u16>(2).ok()?;
+                    Some((SkillId(skill_id), SkillLevel(skill_level)))
+                })
+                .collect::<Vec<_>>()
+        };
+
+        if let Ok(table) = globals.get::<mlua::Table>("SKILL_INFO_LIST") {
+            for (skill_id, skill_table) in table.pairs::<u16, mlua::Table>().flatten() {
+                let required_skills = skill_table
+                    .get::<mlua::Table>("_NeedSkillList")
+                    .map(parse_requirements)
+                    .unwrap_or_default();
+
+                let job_required_skills = skill_table
+                    .get::<mlua::Table>("NeedSkillList")
+                    .map(|table| {
+                        table
+                            .pairs::<usize, mlua::Table>()
+                            .flatten()
+                            .map(|(job_id, requirements)| (job_id, parse_requirements(requirements)))
+                            .collect()
+                    })
+                    .unwrap_or_default();
+
+                let info = SkillInfo {
+                    identifier: skill_table.get(1).ok(),
+                    maximum_level: SkillLevel(skill_table.get("MaxLv").unwrap_or_default()),
+                    required_skills,
+                    job_required_skills,
+                };
+
+                result.insert(SkillId(skill_id), info);
+            }
+        }
+
+        Ok(result)
+    }
+
+    fn load_skill_tree_table(state: &Lua) -> mlua::Result<HashMap<usize, Vec<SkillId>>> {
+        let globals = state.globals();
+        let mut result = HashMap::new();
+
+        if let Ok(table) = globals.get::<mlua::Table>("SKILL_TREEVIEW_FOR_JOB") {
+            for (job_id, tree_table) in table.pairs::<usize, mlua::Table>().flatten() {
+                // The keys are the positions of the skills in the skill tree window.
+                let mut skills: Vec<(usize, u16)> = tree_table.pairs::<usize, u16>().flatten().collect();
+                skills.sort_by_key(|(position, _)| *position);
+
+                result.insert(job_id, skills.into_iter().map(|(_, skill_id)| SkillId(skill_id)).collect());
+            }
+        }
+
+        Ok(result)
+    }
+
+    fn load_job_inherit_table(state: &Lua) -> mlua::Result<HashMap<usize, usize>> {
+        let globals = state.globals();
+        let mut result = HashMap::new();
+
+        if let Ok(table) = globals.get::<mlua::Table>("JOB_INHERIT_LIST") {
+            result.extend(table.pairs::<usize, usize>().flatten());
+        }
+
+        Ok(result)
+    }
+
     fn load_map_sky_data_table(state: &Lua) -> mlua::Result<HashMap<String, MapSkyData>> {
         let globals = state.globals();
         let mut result = HashMap::new();
@@ -222,7 +347,7 @@ impl Library {
             .unwrap_or("1_f_maria")
     }
 
-    fn get_item_name_from_id(&self, item_id: ItemId, is_identified: bool) -> &str {
+    pub fn get_item_name_from_id(&self, item_id: ItemId, is_identified: bool) -> &str {
         match is_identified {
             true => self.item_table.get(&item_id).and_then(|info| info.identified_name.as_deref()),
             false => self.item_table.get(&item_id).and_then(|info| info.unidentified_name.as_deref()),
@@ -230,6 +355,18 @@ impl Library {
         .unwrap_or("NOTFOUND")
     }
 
+    fn get_item_description_from_id(&self, item_id: ItemId, is_identified: bool) -> Vec<String> {
+        match is_identified {
+            true => self.item_table.get(&item_id).map(|info| info.identified_description.clone()),
+            false => self.item_table.get(&item_id).map(|info| info.unidentified_description.clone()),
+        }
+        .unwrap_or_default()
+    }
+
+    fn get_item_slot_count_from_id(&self, item_id: ItemId) -> usize {
+        self.item_table.get(&item_id).map(|info| info.slot_count).unwrap_or_default()
+    }
+
     fn get_item_resource_from_id(&self, item_id: ItemId, is_identified: bool) -> &str {
         match is_identified {
             true => self.item_table.get(&item_id).and_then(|info| info.identified_resource.as_deref()),
@@ -238,6 +375,43 @@ impl Library {
         .unwrap_or("사과") // Apple
     }
 
+    pub fn get_skill_identifier(&self, skill_id: SkillId) -> Option<&str> {
+        self.skill_table.get(&skill_id).and_then(|info| info.identifier.as_deref())
+    }
+
+    pub fn get_skill_maximum_level(&self, skill_id: SkillId) -> Option<SkillLevel> {
+        self.skill_table.get(&skill_id).map(|info| info.maximum_level)
+    }
+
+    /// Skills and their levels that need to be learned before the skill can
+    /// be learned by the given job.
+    pub fn get_skill_requirements(&self, skill_id: SkillId, job_id: usize) -> &[(SkillId, SkillLevel)] {
+        self.skill_table
+            .get(&skill_id)
+            .map(|info| info.job_required_skills.get(&job_id).unwrap_or(&info.required_skills).as_slice())
+            .unwrap_or_default()
+    }
+
+    /// All skills in the skill tree of a job, including the skills of the
+    /// jobs it inherits from. Skills of the base jobs come first.
+    pub fn get_job_skill_tree(&self, job_id: usize) -> Vec<SkillId> {
+        let mut job_ids = vec![job_id];
+
+        while let Some(parent_job_id) = self.job_inherit_table.get(job_ids.last().unwrap())
+            && !job_ids.contains(parent_job_id)
+        {
+            job_ids.push(*parent_job_id);
+        }
+
+        job_ids
+            .iter()
+            .rev()
+            .filter_map(|job_id| self.skill_tree_table.get(job_id))
+            .flatten()
+            .copied()
+            .collect()
+    }
+
     pub fn get_map_sky_data_from_resource_file(&self, resource_file: &str) -> Option<&MapSkyData> {
         self.map_sky_data_table.get(resource_file)
     }
@@ -253,19 +427,65 @@ impl Library {
         let full_path = format!("유저인터페이스\\item\\{resource_name}.bmp");
         let texture = async_loader.request_item_sprite_load(ItemLocation::Inventory, item.item_id, &full_path, ImageType::Color);
         let name = self.get_item_name_from_id(item.item_id, is_identified).to_string();
-
-        let metadata = ResourceMetadata { texture, name };
+        let description = self.get_item_description_from_id(item.item_id, is_identified);
+        let slot_count = self.get_item_slot_count_from_id(item.item_id);
+
+        // Only equipment can have cards, for other items the slots contain unrelated
+        // data.
+        let cards = match item.details {
+            InventoryItemDetails::Equippable { .. } => item
+                .slot
+                .iter()
+                .take(slot_count)
+                .filter(|card_id| **card_id != 0)
+                .map(|card_id| self.get_item_name_from_id(ItemId(*card_id), true).to_string())
+                .collect(),
+            InventoryItemDetails::Regular { .. } => Vec::new(),
+        };
+
+        let metadata = ResourceMetadata {
+            texture,
+            name,
+            description,
+            slot_count,
+            cards,
+        };
 
         InventoryItem { metadata, ..item }
     }
 
+    pub fn load_ground_item_metadata(&self, async_loader: &AsyncLoader, item_id: ItemId, is_identified: bool) -> ResourceMetadata {
+        let resource_name = self.get_item_resource_from_id(item_id, is_identified);
+        let full_path = format!("유저인터페이스\\item\\{resource_name}.bmp");
+        let texture = async_loader.request_item_sprite_load(ItemLocation::Ground, item_id, &full_path, ImageType::Color);
+        let name = self.get_item_name_from_id(item_id, is_identified).to_string();
+        let description = self.get_item_description_from_id(item_id, is_identified);
+        let slot_count = self.get_item_slot_count_from_id(item_id);
+
+        ResourceMetadata {
+            texture,
+            name,
+            description,
+            slot_count,
+            cards: Vec::new(),
+        }
+    }
+
     pub fn load_shop_item_metadata(&self, async_loader: &AsyncLoader, item: ShopItem<NoMetadata>) -> ShopItem<ResourceMetadata> {
         let resource_name = self.get_item_resource_from_id(item.item_id, true);
         let full_path = format!("유저인터페이스\\item\\{resource_name}.bmp");
         let texture = async_loader.request_item_sprite_load(ItemLocation::Shop, item.item_id, &full_path, ImageType::Color);
         let name = self.get_item_name_from_id(item.item_id, true).to_string();
+        let description = self.get_item_description_from_id(item.item_id, true);
+        let slot_count = self.get_item_slot_count_from_id(item.item_id);
 
-        let metadata = ResourceMetadata { texture, name };
+        let metadata = ResourceMetadata {
+            texture,
+            name,
+            description,
+            slot_count,
+            cards: Vec::new(),
+        };
 
         ShopItem { metadata, ..item }
     }
@@ -278,3 +498,66 @@ fn fix_encoding(broken: String) -> String {
         Some(char) => char.to_string(),
     }
 }
+
+#[cfg(test)]
+mod skill_tree {
+    use hashbrown::HashMap;
+    use ragnarok_packets::SkillId;
+
+    use super::Library;
+
+    const NOVICE: usize = 0;
+    const SWORDMAN: usize = 1;
+    const KNIGHT: usize = 7;
+
+    fn library(job_inherit_table: HashMap<usize, usize>) -> Library {
+        let skill_tree_table = HashMap::from([
+            (NOVICE, vec![SkillId(1)]),
+            (SWORDMAN, vec![SkillId(2), SkillId(3)]),
+            (KNIGHT, vec![SkillId(55)]),
+        ]);
+
+        Library {
+            job_identity_table: HashMap::new(),
+            item_table: HashMap::new(),
+            map_sky_data_table: HashMap::new(),
+            skill_table: HashMap::new(),
+            skill_tree_table,
+            job_inherit_table,
+        }
+    }
+
+    #[test]
+    fn base_jobs_come_first() {
+        let library = library(HashMap::from([(KNIGHT, SWORDMAN), (SWORDMAN, NOVICE)]));
+
+        assert_eq!(library.get_job_skill_tree(KNIGHT), [
+            SkillId(1),
+            SkillId(2),
+            SkillId(3),
+            SkillId(55)
+        ]);
+        assert_eq!(library.get_job_skill_tree(SWORDMAN), [SkillId(1), SkillId(2), SkillId(3)]);
+    }
+
+    #[test]
+    fn job_without_parent() {
+        let library = library(HashMap::new());
+
+        assert_eq!(library.get_job_skill_tree(KNIGHT), [SkillId(55)]);
+    }
+
+    #[test]
+    fn unknown_job() {
+        let library = library(HashMap::new());
+
+        assert!(library.get_job_skill_tree(4000).is_empty());
+    }
+
+    #[test]
+    fn inheritance_cycle() {
+        let library = library(HashMap::from([(KNIGHT, SWORDMAN), (SWORDMAN, KNIGHT)]));
+
+        assert_eq!(library.get_job_skill_tree(KNIGHT), [SkillId(2), SkillId(3), SkillId(55)]);
+    }
+}
@@ -25,7 +25,9 @@ use ragnarok_formats::transform::Transform;
 use wgpu::Queue;
 
 pub use self::lighting::Lighting;
-use super::{Camera, Entity, Object, PointLightId, PointLightManager, ResourceSet, ResourceSetBuffer, SubMesh, Video};
+use super::{
+    Camera, Entity, GroundItem, Object, PointLightId, PointLightManager, ResourceSet, ResourceSetBuffer, SubMesh, Video, VideoControl,
+};
 #[cfg(feature = "debug")]
 use super::{LightSourceExt, Model, PointLightSet};
 #[cfg(feature = "debug")]
@@ -282,6 +284,11 @@ impl Map {
         });
     }
 
+    #[cfg_attr(feature = "debug", korangar_debug::profile)]
+    pub fn render_ground_items(&self, instructions: &mut Vec<EntityInstruction>, ground_items: &[GroundItem], camera: &dyn Camera) {
+        ground_items.iter().for_each(|ground_item| ground_item.render(instructions, camera));
+    }
+
     #[cfg(feature = "debug")]
     #[korangar_debug::profile]
     pub fn render_entities_debug(&self, instructions: &mut Vec<DebugRectangleInstruction>, entities: &[Entity], camera: &dyn Camera) {
@@ -665,6 +672,10 @@ impl Map {
         Some((screen_position, screen_size))
     }
 
+    pub fn control_videos(&self, control: VideoControl) {
+        self.videos.lock().unwrap().iter_mut().for_each(|video| video.control(control));
+    }
+
     pub fn advance_videos(&self, queue: &Queue, delta_time: f64) {
         let mut videos = self.videos.lock().unwrap();
 
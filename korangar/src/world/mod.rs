@@ -3,6 +3,9 @@ mod animation;
 mod cameras;
 mod effect;
 mod entity;
+#[cfg(feature = "debug")]
+mod inspection;
+mod item;
 mod library;
 mod light;
 mod map;
@@ -17,6 +20,9 @@ pub use self::animation::*;
 pub use self::cameras::*;
 pub use self::effect::*;
 pub use self::entity::*;
+#[cfg(feature = "debug")]
+pub use self::inspection::*;
+pub use self::item::*;
 pub use self::library::*;
 pub use self::light::*;
 pub use self::map::*;
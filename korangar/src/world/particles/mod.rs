@@ -12,12 +12,22 @@ use crate::interface::layout::{ScreenClip, ScreenPosition, ScreenSize};
 use crate::loaders::{FontSize, ImageType, Scaling, TextureLoader};
 use crate::renderer::{GameInterfaceRenderer, SpriteRenderer};
 use crate::world::Camera;
+#[cfg(feature = "debug")]
+use crate::world::Inspection;
 use crate::{Entity, Map};
 
 pub trait Particle {
     fn update(&mut self, delta_time: f32) -> bool;
 
     fn render(&self, renderer: &GameInterfaceRenderer, camera: &dyn Camera, window_size: ScreenSize);
+
+    #[cfg(feature = "debug")]
+    fn inspect(&self) -> Inspection;
+}
+
+#[cfg(feature = "debug")]
+fn format_position(position: Point3<f32>) -> String {
+    format!("{:.1}, {:.1}, {:.1}", position.x, position.y, position.z)
 }
 
 fn random_velocity() -> f32 {
@@ -60,6 +70,18 @@ impl Particle for DamageNumber {
 
         renderer.render_damage_text(&self.damage_amount, final_position, Color::WHITE, FontSize::new(16.0));
     }
+
+    #[cfg(feature = "debug")]
+    fn inspect(&self) -> Inspection {
+        Inspection::new("Damage number")
+            .with_field("amount", &self.damage_amount)
+            .with_field("position", format_position(self.position))
+            .with_field(
+                "velocity",
+                format!("{:.1}, {:.1}, {:.1}", self.velocity_x, self.velocity_y, self.velocity_z),
+            )
+            .with_field("remaining", format!("{:.2} s", self.timer))
+    }
 }
 
 #[derive(new)]
@@ -97,6 +119,15 @@ impl Particle for HealNumber {
             FontSize::new(16.0),
         );
     }
+
+    #[cfg(feature = "debug")]
+    fn inspect(&self) -> Inspection {
+        Inspection::new("Heal number")
+            .with_field("amount", &self.heal_amount)
+            .with_field("position", format_position(self.position))
+            .with_field("velocity", format!("{:.1}", self.velocity_y))
+            .with_field("remaining", format!("{:.2} s", self.timer))
+    }
 }
 
 pub struct QuestIcon {
@@ -143,6 +174,13 @@ impl QuestIcon {
             true,
         );
     }
+
+    #[cfg(feature = "debug")]
+    fn inspect(&self, entity_id: EntityId) -> Inspection {
+        Inspection::new("Quest icon")
+            .with_field("entity", entity_id.0)
+            .with_field("position", format_position(self.position))
+    }
 }
 
 #[derive(Default)]
@@ -193,4 +231,15 @@ impl ParticleHolder {
             .filter_map(|entity| self.quest_icons.get(&entity.get_entity_id()))
             .for_each(|quest_icon| quest_icon.render(renderer, camera, window_size, scaling.get_factor()));
     }
+
+    #[cfg(feature = "debug")]
+    pub fn inspect(&self) -> Vec<Inspection> {
+        let particles = self.particles.iter().map(|particle| particle.inspect());
+        let quest_icons = self
+            .quest_icons
+            .iter()
+            .map(|(entity_id, quest_icon)| quest_icon.inspect(*entity_id));
+
+        particles.chain(quest_icons).collect()
+    }
 }
@@ -1,17 +1,94 @@
 use std::sync::Arc;
 
-use korangar_video::{Decoder, Error, Picture};
+use korangar_audio::{AudioEngine, VideoAudioKey};
+use korangar_video::ivf::{IvfHeader, IvfIndex};
+use korangar_video::{Decoder, Error, Picture, PlaybackClock};
 use wgpu::{Extent3d, Queue, TexelCopyBufferLayout, TexelCopyTextureInfo};
 
 use crate::graphics::Texture;
+use crate::loaders::GameFileLoader;
+
+/// Audio track of a video. The audio is the reference clock of the video
+/// while it is playing.
+pub struct VideoAudio {
+    audio_engine: Arc<AudioEngine<GameFileLoader>>,
+    video_audio_key: VideoAudioKey,
+    started: bool,
+}
+
+impl VideoAudio {
+    pub fn new(audio_engine: Arc<AudioEngine<GameFileLoader>>, video_audio_key: VideoAudioKey) -> Self {
+        Self {
+            audio_engine,
+            video_audio_key,
+            started: false,
+        }
+    }
+
+    fn restart(&mut self) {
+        self.audio_engine.play_video_audio(self.video_audio_key);
+        self.started = true;
+    }
+
+    /// Playback position in seconds. Starts the audio on first use, so that
+    /// it doesn't start playing before the video is shown.
+    fn position(&mut self) -> Option<f64> {
+        if !self.started {
+            self.restart();
+        }
+
+        self.audio_engine.video_audio_position(self.video_audio_key)
+    }
+
+    fn control(&mut self, control: VideoControl) {
+        match control {
+            VideoControl::Pause => self.audio_engine.pause_video_audio(self.video_audio_key),
+            VideoControl::Resume => self.audio_engine.resume_video_audio(self.video_audio_key),
+            VideoControl::Speed(speed) => self.audio_engine.set_video_audio_speed(self.video_audio_key, speed),
+            VideoControl::Seek(time) => {
+                if !self.started {
+                    self.restart();
+                }
+
+                self.audio_engine.seek_video_audio(self.video_audio_key, time as f64 / 1000.0);
+            }
+        }
+    }
+}
+
+impl Drop for VideoAudio {
+    fn drop(&mut self) {
+        self.audio_engine.unload_video_audio(self.video_audio_key);
+    }
+}
+
+/// Changes to the playback of a video.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum VideoControl {
+    Pause,
+    Resume,
+    /// Play the video with the given speed, where `1.0` is the normal speed.
+    Speed(f64),
+    /// Continue the video at the given time in milliseconds.
+    Seek(i64),
+}
 
 pub struct Video {
     width: u32,
     height: u32,
-    timescale: f64,
     frames: Vec<VideoFrame>,
+    /// Keyframes and timestamps of the frames, used for seeking.
+    index: IvfIndex,
+    header: IvfHeader,
+    audio: Option<VideoAudio>,
     decoder: Decoder,
-    current_timestamp: f64,
+    clock: PlaybackClock,
+    paused: bool,
+    speed: f64,
+    /// Pictures before the last picture at or before this timestamp are
+    /// decoded but not shown, since seeking has to start decoding at a
+    /// keyframe.
+    seek_target: Option<i64>,
     last_timestamp: i64,
     next_picture_timestamp: i64,
     next_frame_index: usize,
@@ -21,19 +98,40 @@ pub struct Video {
 }
 
 pub struct VideoFrame {
+    /// Presentation timestamp in milliseconds.
     pub timestamp: i64,
     pub packet: Arc<[u8]>,
 }
 
+/// Timestamp of the frame that is shown after the frame with the given
+/// timestamp, or `None` if it is the last one.
+fn following_timestamp(frames: &[VideoFrame], timestamp: i64) -> Option<i64> {
+    let index = frames.partition_point(|frame| frame.timestamp <= timestamp);
+    frames.get(index).map(|frame| frame.timestamp)
+}
+
 impl Video {
-    pub fn new(width: u32, height: u32, timescale: f64, frames: Vec<VideoFrame>, texture: Arc<Texture>) -> Self {
+    pub fn new(
+        width: u32,
+        height: u32,
+        frames: Vec<VideoFrame>,
+        index: IvfIndex,
+        header: IvfHeader,
+        audio: Option<VideoAudio>,
+        texture: Arc<Texture>,
+    ) -> Self {
         Self {
             width,
             height,
-            timescale,
             frames,
+            index,
+            header,
+            audio,
             decoder: Decoder::new().expect("Can't create decoder"),
-            current_timestamp: 0.0,
+            clock: PlaybackClock::new(),
+            paused: false,
+            speed: 1.0,
+            seek_target: None,
             last_timestamp: -1,
             next_picture_timestamp: -1,
             next_frame_index: 0,
@@ -48,66 +146,144 @@ impl Video {
             return;
         }
 
+        // After seeking we decode forward from the keyframe until we reach the
+        // target, which might take several pictures.
+        for _ in 0..self.frames.len() {
+            if !self.decode_next_picture() {
+                break;
+            }
+        }
+    }
+
+    /// Returns `true` if a picture was skipped and the next one should be
+    /// decoded right away.
+    fn decode_next_picture(&mut self) -> bool {
         match self.decoder.get_picture(self.next_picture.take()) {
             Ok(picture) => {
                 let next_timestamp = picture.timestamp().unwrap_or(0);
 
+                if let Some(seek_target) = self.seek_target {
+                    // The target usually lies between two pictures, so we show the last
+                    // picture at or before it.
+                    if following_timestamp(&self.frames, next_timestamp).is_some_and(|following| following <= seek_target) {
+                        self.next_picture = Some(picture);
+                        return true;
+                    }
+
+                    self.seek_target = None;
+                }
+
                 if next_timestamp < self.last_timestamp {
                     // Video is looping.
-                    self.current_timestamp = 0.0;
+                    self.clock.reset();
+
+                    if let Some(audio) = self.audio.as_mut() {
+                        audio.restart();
+                    }
                 }
                 self.last_timestamp = next_timestamp;
 
                 self.next_picture_timestamp = next_timestamp;
                 self.next_picture = Some(picture);
+
+                false
             }
-            Err(Error::Again) => loop {
-                match self.decoder.send_pending_data() {
-                    Ok(_) => { /* No pending data left */ }
-                    Err(Error::Again) => break,
-                    Err(_error) => {
-                        /* Decoding error. Nothing we can do. */
-                        return;
+            Err(Error::Again) => {
+                loop {
+                    match self.decoder.send_pending_data() {
+                        Ok(_) => { /* No pending data left */ }
+                        Err(Error::Again) => break,
+                        Err(_error) => {
+                            /* Decoding error. Nothing we can do. */
+                            return false;
+                        }
                     }
-                }
-
-                let Some(frame) = self.frames.get(self.next_frame_index) else {
-                    self.next_frame_index = 0;
-                    continue;
-                };
-                self.next_frame_index += 1;
 
-                let timestamp = (frame.timestamp as f64 / self.timescale).floor() as i64;
+                    let Some(frame) = self.frames.get(self.next_frame_index) else {
+                        self.next_frame_index = 0;
+                        continue;
+                    };
+                    self.next_frame_index += 1;
 
-                match self.decoder.send_data(Arc::clone(&frame.packet), None, Some(timestamp), None) {
-                    Ok(_) => continue,
-                    Err(Error::Again) => match self.decoder.send_pending_data() {
-                        Ok(_) | Err(Error::Again) => break,
+                    match self.decoder.send_data(Arc::clone(&frame.packet), None, Some(frame.timestamp), None) {
+                        Ok(_) => continue,
+                        Err(Error::Again) => match self.decoder.send_pending_data() {
+                            Ok(_) | Err(Error::Again) => break,
+                            Err(_error) => {
+                                /* Decoding error. Nothing we can do. */
+                                return false;
+                            }
+                        },
                         Err(_error) => {
                             /* Decoding error. Nothing we can do. */
-                            return;
+                            return false;
                         }
-                    },
-                    Err(_error) => {
-                        /* Decoding error. Nothing we can do. */
-                        return;
                     }
                 }
-            },
-            Err(_error) => { /* Decoding error. Nothing we can do. */ }
-        };
+
+                // Keep decoding while we are seeking.
+                self.seek_target.is_some()
+            }
+            Err(_error) => {
+                /* Decoding error. Nothing we can do. */
+                false
+            }
+        }
     }
 
     /// Delta time is expected to be in seconds.
     pub fn should_show_next_frame(&mut self, delta_time: f64) -> bool {
-        if self.frames.is_empty() {
+        if self.frames.is_empty() || self.paused {
             return false;
         }
 
-        let ms = delta_time * 1000.0;
-        self.current_timestamp += ms;
+        let audio_position = self.audio.as_mut().and_then(VideoAudio::position);
+        self.clock.advance(delta_time * self.speed, audio_position);
 
-        self.next_picture_timestamp >= 0 && self.current_timestamp.floor() as i64 >= self.next_picture_timestamp
+        self.next_picture_timestamp >= 0 && self.clock.is_due(self.next_picture_timestamp)
+    }
+
+    pub fn control(&mut self, control: VideoControl) {
+        let control = match control {
+            VideoControl::Pause => {
+                self.paused = true;
+                control
+            }
+            VideoControl::Resume => {
+                self.paused = false;
+                control
+            }
+            VideoControl::Speed(speed) => {
+                self.speed = speed;
+                control
+            }
+            VideoControl::Seek(time) => {
+                let Some(last_frame) = self.frames.last() else {
+                    return;
+                };
+
+                // Decoding has to start at the keyframe before the target.
+                let target = time.clamp(0, last_frame.timestamp);
+                let keyframe_index = self
+                    .index
+                    .keyframe_before(self.header.timestamp_from_milliseconds(target))
+                    .unwrap_or(0);
+
+                self.decoder.flush();
+                self.next_frame_index = keyframe_index;
+                self.next_picture = None;
+                self.next_picture_timestamp = -1;
+                self.last_timestamp = -1;
+                self.seek_target = Some(target);
+                self.clock.set_time(target as f64);
+
+                VideoControl::Seek(target)
+            }
+        };
+
+        if let Some(audio) = self.audio.as_mut() {
+            audio.control(control);
+        }
     }
 
     pub fn update_texture(&mut self, queue: &Queue) {
@@ -144,3 +320,30 @@ impl Video {
         &self.texture
     }
 }
+
+#[cfg(test)]
+mod seeking {
+    use std::sync::Arc;
+
+    use super::{VideoFrame, following_timestamp};
+
+    fn frames(timestamps: &[i64]) -> Vec<VideoFrame> {
+        timestamps
+            .iter()
+            .map(|timestamp| VideoFrame {
+                timestamp: *timestamp,
+                packet: Arc::from([]),
+            })
+            .collect()
+    }
+
+    #[test]
+    fn following_frame() {
+        let frames = frames(&[0, 40, 80, 120]);
+
+        assert_eq!(following_timestamp(&frames, 0), Some(40));
+        assert_eq!(following_timestamp(&frames, 40), Some(80));
+        assert_eq!(following_timestamp(&frames, 120), None);
+        assert_eq!(following_timestamp(&[], 0), None);
+    }
+}
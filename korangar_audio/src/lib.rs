@@ -21,9 +21,9 @@ use kira::sound::static_sound::{StaticSoundData, StaticSoundHandle};
 use kira::sound::streaming::{StreamingSoundData, StreamingSoundHandle};
 use kira::sound::{FromFileError, PlaybackState};
 use kira::track::{MainTrackBuilder, SpatialTrackBuilder, SpatialTrackDistances, SpatialTrackHandle, TrackBuilder, TrackHandle};
-use kira::{AudioManager, AudioManagerSettings, Capacities, Decibels, Easing, Frame, Tween};
+use kira::{AudioManager, AudioManagerSettings, Capacities, Decibels, Easing, Frame, PlaybackRate, Tween};
 #[cfg(feature = "debug")]
-use korangar_debug::logging::{Colorize, print_debug};
+use korangar_debug::logging::log_error;
 use korangar_util::collision::{KDTree, Sphere};
 use korangar_util::container::{Cacheable, GenerationalSlab, SimpleCache, SimpleSlab};
 use korangar_util::{FileLoader, create_generational_key, create_simple_key};
@@ -31,6 +31,7 @@ use rayon::spawn;
 
 create_generational_key!(SoundEffectKey, "The key for a cached sound effect");
 create_simple_key!(AmbientKey, "The key for a ambient sound");
+create_simple_key!(VideoAudioKey, "The key for the audio track of a video");
 
 const MAX_QUEUE_TIME_SECONDS: f32 = 1.0;
 const MAX_CACHE_COUNT: u32 = 400;
@@ -73,6 +74,12 @@ struct PlayingAmbient {
     last_start: Instant,
 }
 
+struct VideoAudio {
+    data: StaticSoundData,
+    handle: Option<StaticSoundHandle>,
+    playback_rate: f64,
+}
+
 #[repr(transparent)]
 struct CachedSoundEffect(StaticSoundData);
 
@@ -127,6 +134,8 @@ struct EngineContext<F> {
     scratchpad: Vec<AmbientKey>,
     sound_effect_paths: GenerationalSlab<SoundEffectKey, String>,
     sound_effect_track: TrackHandle,
+    video_audio: SimpleSlab<VideoAudioKey, VideoAudio>,
+    video_audio_track: TrackHandle,
 }
 
 impl<F: FileLoader> AudioEngine<F> {
@@ -150,6 +159,7 @@ impl<F: FileLoader> AudioEngine<F> {
         let spatial_sound_effect_track = manager
             .add_sub_track(TrackBuilder::new())
             .expect("Can't create spatial sound effect track");
+        let video_audio_track = manager.add_sub_track(TrackBuilder::new()).expect("Can't create video audio track");
         let position = Vector3::new(0.0, 0.0, 0.0);
         let orientation = Quaternion::one();
         let spatial_listener = manager.add_listener(position, orientation).expect("Can't create spatial listener");
@@ -190,6 +200,8 @@ impl<F: FileLoader> AudioEngine<F> {
             scratchpad: Vec::default(),
             sound_effect_paths: GenerationalSlab::default(),
             sound_effect_track,
+            video_audio: SimpleSlab::default(),
+            video_audio_track,
         });
         AudioEngine { engine_context }
     }
@@ -278,6 +290,14 @@ impl<F: FileLoader> AudioEngine<F> {
             .set_spatial_sound_effect_volume(linear_to_decibel(volume))
     }
 
+    /// Sets the volume of the audio tracks of videos.
+    pub fn set_video_audio_volume(&self, volume: f32) {
+        self.engine_context
+            .lock()
+            .unwrap()
+            .set_video_audio_volume(linear_to_decibel(volume))
+    }
+
     /// Plays the background music track. Fades out the currently playing
     /// background music track and then start the new background music
     /// track.
@@ -330,6 +350,51 @@ impl<F: FileLoader> AudioEngine<F> {
             .add_ambient_sound(sound_effect_key, position, range, linear_to_decibel(volume), cycle)
     }
 
+    /// Registers the audio track of a video. The data needs to be a complete
+    /// audio file, since it is decoded up front. Returns `None` if the data
+    /// can't be decoded.
+    pub fn load_video_audio(&self, data: Vec<u8>) -> Option<VideoAudioKey> {
+        self.engine_context.lock().unwrap().load_video_audio(data)
+    }
+
+    /// Unloads the audio track of a video, stopping it if it is playing.
+    pub fn unload_video_audio(&self, video_audio_key: VideoAudioKey) {
+        self.engine_context.lock().unwrap().unload_video_audio(video_audio_key)
+    }
+
+    /// Plays the audio track of a video from the start. Video audio has its own
+    /// track, so its volume can be set independently of the sound effects.
+    pub fn play_video_audio(&self, video_audio_key: VideoAudioKey) {
+        self.engine_context.lock().unwrap().play_video_audio(video_audio_key)
+    }
+
+    /// The playback position of the audio track of a video in seconds.
+    /// Returns `None` if the audio track is not playing.
+    pub fn video_audio_position(&self, video_audio_key: VideoAudioKey) -> Option<f64> {
+        self.engine_context.lock().unwrap().video_audio_position(video_audio_key)
+    }
+
+    /// Pauses the audio track of a video.
+    pub fn pause_video_audio(&self, video_audio_key: VideoAudioKey) {
+        self.engine_context.lock().unwrap().pause_video_audio(video_audio_key)
+    }
+
+    /// Resumes the audio track of a video after it was paused.
+    pub fn resume_video_audio(&self, video_audio_key: VideoAudioKey) {
+        self.engine_context.lock().unwrap().resume_video_audio(video_audio_key)
+    }
+
+    /// Moves the audio track of a video to the given position in seconds.
+    pub fn seek_video_audio(&self, video_audio_key: VideoAudioKey, position: f64) {
+        self.engine_context.lock().unwrap().seek_video_audio(video_audio_key, position)
+    }
+
+    /// Sets the playback speed of the audio track of a video, where `1.0` is
+    /// the normal speed. The speed is kept when the audio is played again.
+    pub fn set_video_audio_speed(&self, video_audio_key: VideoAudioKey, speed: f64) {
+        self.engine_context.lock().unwrap().set_video_audio_speed(video_audio_key, speed)
+    }
+
     /// Removes all ambient-sound tracks.
     pub fn clear_ambient_sound(&self) {
         self.engine_context.lock().unwrap().clear_ambient_sound()
@@ -376,6 +441,13 @@ impl<F: FileLoader> EngineContext<F> {
         });
     }
 
+    fn set_video_audio_volume(&mut self, volume: Decibels) {
+        self.video_audio_track.set_volume(volume, Tween {
+            duration: Duration::from_millis(500),
+            ..Default::default()
+        });
+    }
+
     fn play_background_music_track(&mut self, track_name: Option<&str>) {
         let Some(track_name) = track_name else {
             if let Some(playing) = self.current_background_music_track.as_mut() {
@@ -418,7 +490,7 @@ impl<F: FileLoader> EngineContext<F> {
         {
             if let Err(_error) = self.sound_effect_track.play(data.clone()) {
                 #[cfg(feature = "debug")]
-                print_debug!("[{}] can't play sound effect: {:?}", "error".red(), _error);
+                log_error!("can't play sound effect: {:?}", _error);
             }
 
             return;
@@ -458,12 +530,12 @@ impl<F: FileLoader> EngineContext<F> {
                 Ok(mut spatial_track_handle) => {
                     if let Err(_error) = spatial_track_handle.play(data) {
                         #[cfg(feature = "debug")]
-                        print_debug!("[{}] can't play sound effect: {:?}", "error".red(), _error);
+                        log_error!("can't play sound effect: {:?}", _error);
                     }
                 }
                 Err(_error) => {
                     #[cfg(feature = "debug")]
-                    print_debug!("[{}] can't add spatial sound track: {:?}", "error".red(), _error);
+                    log_error!("can't add spatial sound track: {:?}", _error);
                 }
             };
         }
@@ -491,7 +563,7 @@ impl<F: FileLoader> EngineContext<F> {
         for ambient_key in self.scratchpad.iter().copied() {
             let Some(sound_config) = self.ambient_sound.get(ambient_key) else {
                 #[cfg(feature = "debug")]
-                print_debug!("[{}] can't find sound config for: {:?}", "error".red(), ambient_key);
+                log_error!("can't find sound config for: {:?}", ambient_key);
                 continue;
             };
 
@@ -515,7 +587,7 @@ impl<F: FileLoader> EngineContext<F> {
                     Ok(spatial_track_handle) => spatial_track_handle,
                     Err(_error) => {
                         #[cfg(feature = "debug")]
-                        print_debug!("[{}] can't add ambient sound track: {:?}", "error".red(), _error);
+                        log_error!("can't add ambient sound track: {:?}", _error);
                         continue;
                     }
                 };
@@ -542,7 +614,7 @@ impl<F: FileLoader> EngineContext<F> {
                         }
                         Err(_error) => {
                             #[cfg(feature = "debug")]
-                            print_debug!("[{}] can't ambient sound effect: {:?}", "error".red(), _error);
+                            log_error!("can't ambient sound effect: {:?}", _error);
                         }
                     }
                 }
@@ -614,6 +686,97 @@ impl<F: FileLoader> EngineContext<F> {
             .expect("Ambient sound slab is full")
     }
 
+    fn load_video_audio(&mut self, data: Vec<u8>) -> Option<VideoAudioKey> {
+        let data = match StaticSoundData::from_cursor(Cursor::new(data)) {
+            Ok(data) => data,
+            Err(_error) => {
+                #[cfg(feature = "debug")]
+                log_error!("can't decode video audio: {:?}", _error);
+                return None;
+            }
+        };
+
+        self.video_audio.insert(VideoAudio {
+            data,
+            handle: None,
+            playback_rate: 1.0,
+        })
+    }
+
+    fn unload_video_audio(&mut self, video_audio_key: VideoAudioKey) {
+        if let Some(mut video_audio) = self.video_audio.remove(video_audio_key)
+            && let Some(handle) = video_audio.handle.as_mut()
+        {
+            handle.stop(Tween::default());
+        }
+    }
+
+    fn play_video_audio(&mut self, video_audio_key: VideoAudioKey) {
+        let Some(video_audio) = self.video_audio.get_mut(video_audio_key) else {
+            return;
+        };
+
+        if let Some(handle) = video_audio.handle.as_mut() {
+            handle.stop(Tween::default());
+        }
+
+        let data = video_audio.data.clone().playback_rate(PlaybackRate(video_audio.playback_rate));
+
+        match self.video_audio_track.play(data) {
+            Ok(handle) => video_audio.handle = Some(handle),
+            Err(_error) => {
+                video_audio.handle = None;
+
+                #[cfg(feature = "debug")]
+                log_error!("can't play video audio: {:?}", _error);
+            }
+        }
+    }
+
+    fn video_audio_position(&self, video_audio_key: VideoAudioKey) -> Option<f64> {
+        self.video_audio
+            .get(video_audio_key)
+            .and_then(|video_audio| video_audio.handle.as_ref())
+            .filter(|handle| handle.state() == PlaybackState::Playing)
+            .map(|handle| handle.position())
+    }
+
+    fn video_audio_handle(&mut self, video_audio_key: VideoAudioKey) -> Option<&mut StaticSoundHandle> {
+        self.video_audio
+            .get_mut(video_audio_key)
+            .and_then(|video_audio| video_audio.handle.as_mut())
+    }
+
+    fn pause_video_audio(&mut self, video_audio_key: VideoAudioKey) {
+        if let Some(handle) = self.video_audio_handle(video_audio_key) {
+            handle.pause(Tween::default());
+        }
+    }
+
+    fn resume_video_audio(&mut self, video_audio_key: VideoAudioKey) {
+        if let Some(handle) = self.video_audio_handle(video_audio_key) {
+            handle.resume(Tween::default());
+        }
+    }
+
+    fn seek_video_audio(&mut self, video_audio_key: VideoAudioKey, position: f64) {
+        if let Some(handle) = self.video_audio_handle(video_audio_key) {
+            handle.seek_to(position);
+        }
+    }
+
+    fn set_video_audio_speed(&mut self, video_audio_key: VideoAudioKey, speed: f64) {
+        let Some(video_audio) = self.video_audio.get_mut(video_audio_key) else {
+            return;
+        };
+
+        video_audio.playback_rate = speed;
+
+        if let Some(handle) = video_audio.handle.as_mut() {
+            handle.set_playback_rate(PlaybackRate(speed), Tween::default());
+        }
+    }
+
     fn clear_ambient_sound(&mut self) {
         self.query_result.clear();
         self.previous_query_result.clear();
@@ -652,12 +815,7 @@ impl<F: FileLoader> EngineContext<F> {
 
                     if let Err(_error) = self.cache.insert(key, CachedSoundEffect(*sound_effect)) {
                         #[cfg(feature = "debug")]
-                        print_debug!(
-                            "[{}] audio file is too big for cache. Path: '{}': {:?}",
-                            "error".red(),
-                            &_path,
-                            _error
-                        );
+                        log_error!("audio file is too big for cache. Path: '{}': {:?}", &_path, _error);
                     }
                 }
                 AsyncLoadResult::Error {
@@ -668,12 +826,7 @@ impl<F: FileLoader> EngineContext<F> {
                     self.loading_sound_effect.remove(&key);
 
                     #[cfg(feature = "debug")]
-                    print_debug!(
-                        "[{}] could not load audio file. Path: '{}' : {}",
-                        "error".red(),
-                        _path,
-                        _message
-                    );
+                    log_error!("could not load audio file. Path: '{}' : {}", _path, _message);
                 }
             }
         }
@@ -709,7 +862,7 @@ impl<F: FileLoader> EngineContext<F> {
                 QueuedSoundEffectType::Sound => {
                     if let Err(_error) = self.sound_effect_track.play(data) {
                         #[cfg(feature = "debug")]
-                        print_debug!("[{}] can't play sound effect: {:?}", "error".red(), _error);
+                        log_error!("can't play sound effect: {:?}", _error);
                     }
                 }
                 QueuedSoundEffectType::SpatialSound { position, range } => {
@@ -728,12 +881,12 @@ impl<F: FileLoader> EngineContext<F> {
                         Ok(mut spatial_track_handle) => {
                             if let Err(_error) = spatial_track_handle.play(data) {
                                 #[cfg(feature = "debug")]
-                                print_debug!("[{}] can't play sound effect: {:?}", "error".red(), _error);
+                                log_error!("can't play sound effect: {:?}", _error);
                             }
                         }
                         Err(_error) => {
                             #[cfg(feature = "debug")]
-                            print_debug!("[{}] can't add spatial sound track: {:?}", "error".red(), _error);
+                            log_error!("can't add spatial sound track: {:?}", _error);
                         }
                     };
                 }
@@ -756,7 +909,7 @@ impl<F: FileLoader> EngineContext<F> {
                             }
                             Err(_error) => {
                                 #[cfg(feature = "debug")]
-                                print_debug!("[{}] can't play ambient sound effect: {:?}", "error".red(), _error);
+                                log_error!("can't play ambient sound effect: {:?}", _error);
                             }
                         }
                     }
@@ -783,7 +936,7 @@ impl<F: FileLoader> EngineContext<F> {
                     }
                     Err(_error) => {
                         #[cfg(feature = "debug")]
-                        print_debug!("[{}] can't play ambient sound effect: {:?}", "error".red(), _error);
+                        log_error!("can't play ambient sound effect: {:?}", _error);
                     }
                 }
             }
@@ -793,7 +946,7 @@ impl<F: FileLoader> EngineContext<F> {
     fn change_background_music_track(&mut self, track_name: &str) {
         let Some(path) = find_file_path(track_name) else {
             #[cfg(feature = "debug")]
-            print_debug!("[{}] can't find background music track: {:?}", "error".red(), track_name);
+            log_error!("can't find background music track: {:?}", track_name);
             return;
         };
 
@@ -801,7 +954,7 @@ impl<F: FileLoader> EngineContext<F> {
             Ok(sound_effect_data) => sound_effect_data,
             Err(_error) => {
                 #[cfg(feature = "debug")]
-                print_debug!("[{}] can't decode background music track: {:?}", "error".red(), _error);
+                log_error!("can't decode background music track: {:?}", _error);
                 return;
             }
         };
@@ -817,7 +970,7 @@ impl<F: FileLoader> EngineContext<F> {
             Ok(handle) => handle,
             Err(_error) => {
                 #[cfg(feature = "debug")]
-                print_debug!("[{}] can't play background music track: {:?}", "error".red(), _error);
+                log_error!("can't play background music track: {:?}", _error);
                 return;
             }
         };
@@ -902,7 +1055,7 @@ fn parse_background_music_track_mapping(game_file_loader: &impl FileLoader) -> H
         }
         Err(_error) => {
             #[cfg(feature = "debug")]
-            print_debug!("[{}] can't find background music mapping file: {:?}", "error".red(), _error);
+            log_error!("can't find background music mapping file: {:?}", _error);
         }
     }
 
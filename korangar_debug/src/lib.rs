@@ -3,6 +3,7 @@
 #![feature(slice_take)]
 #![feature(thread_local)]
 
+mod json;
 #[macro_use]
 pub mod logging;
 #[macro_use]
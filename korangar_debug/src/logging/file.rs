@@ -0,0 +1,286 @@
+//! Persistent log output. Every message is written as a single JSON object
+//! per line, including the depth of the timer stack at the time it was
+//! logged, so the structure of the console output can be restored from the
+//! file.
+
+use std::collections::VecDeque;
+use std::fs::{File, OpenOptions};
+use std::io::{LineWriter, Write};
+use std::path::{Path, PathBuf};
+use std::sync::{Mutex, PoisonError};
+use std::time::Duration;
+
+use chrono::SecondsFormat;
+
+use super::level::LogLevel;
+use super::stack::stack_size;
+use crate::json::escape_json;
+
+/// Name of the current log file. Older files are called `korangar.1.log`,
+/// `korangar.2.log` and so on.
+const FILE_STEM: &str = "korangar";
+/// Size at which the current log file is rotated.
+pub const MAXIMUM_LOG_FILE_SIZE: u64 = 8 * 1024 * 1024;
+/// Number of log files that are kept, including the current one.
+pub const LOG_FILE_COUNT: usize = 5;
+/// Number of lines kept in memory for [`recent_log_lines`].
+pub const RECENT_LINE_COUNT: usize = 512;
+
+static LOG_FILE: Mutex<Option<LogFile>> = Mutex::new(None);
+static RECENT_LINES: Mutex<VecDeque<String>> = Mutex::new(VecDeque::new());
+
+pub(super) enum LogEvent<'a> {
+    Message {
+        level: LogLevel,
+        target: &'a str,
+        message: &'a str,
+    },
+    TimerStart {
+        name: &'a str,
+    },
+    TimerEnd {
+        name: &'a str,
+        duration: Duration,
+        completed: bool,
+    },
+}
+
+/// Log file that is rotated once it grows beyond a maximum size.
+struct LogFile {
+    directory: PathBuf,
+    writer: LineWriter<File>,
+    size: u64,
+    maximum_size: u64,
+    file_count: usize,
+}
+
+impl LogFile {
+    /// Rotate the existing log files and open a new one.
+    fn open(directory: &Path, maximum_size: u64, file_count: usize) -> std::io::Result<Self> {
+        std::fs::create_dir_all(directory)?;
+        rotate_files(directory, file_count)?;
+
+        let file = OpenOptions::new()
+            .create(true)
+            .write(true)
+            .truncate(true)
+            .open(file_path(directory, 0))?;
+
+        Ok(Self {
+            directory: directory.to_path_buf(),
+            writer: LineWriter::new(file),
+            size: 0,
+            maximum_size,
+            file_count,
+        })
+    }
+
+    fn write_line(&mut self, line: &str) -> std::io::Result<()> {
+        if self.size > 0 && self.size + line.len() as u64 + 1 > self.maximum_size {
+            *self = Self::open(&self.directory, self.maximum_size, self.file_count)?;
+        }
+
+        self.writer.write_all(line.as_bytes())?;
+        self.writer.write_all(b"\n")?;
+        self.size += line.len() as u64 + 1;
+
+        Ok(())
+    }
+}
+
+fn file_path(directory: &Path, index: usize) -> PathBuf {
+    match index {
+        0 => directory.join(format!("{FILE_STEM}.log")),
+        index => directory.join(format!("{FILE_STEM}.{index}.log")),
+    }
+}
+
+/// Shift every log file one index up, dropping the oldest one.
+fn rotate_files(directory: &Path, file_count: usize) -> std::io::Result<()> {
+    let oldest = file_path(directory, file_count.saturating_sub(1));
+
+    if oldest.exists() {
+        std::fs::remove_file(oldest)?;
+    }
+
+    for index in (0..file_count.saturating_sub(1)).rev() {
+        let path = file_path(directory, index);
+
+        if path.exists() {
+            std::fs::rename(path, file_path(directory, index + 1))?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Remove ANSI color codes from a message.
+pub(super) fn strip_colors(message: &str) -> String {
+    let mut stripped = String::with_capacity(message.len());
+    let mut characters = message.chars();
+
+    while let Some(character) = characters.next() {
+        if character != '\x1B' {
+            stripped.push(character);
+            continue;
+        }
+
+        // Skip the control sequence up to and including its final byte.
+        if characters.next() == Some('[') {
+            for character in characters.by_ref() {
+                if ('\x40'..='\x7E').contains(&character) {
+                    break;
+                }
+            }
+        }
+    }
+
+    stripped
+}
+
+fn format_event(event: &LogEvent, time: &str, thread: &str, depth: usize) -> String {
+    let prefix = format!(r#"{{"time":"{time}","thread":"{}","depth":{depth},"#, escape_json(thread));
+
+    match event {
+        LogEvent::Message { level, target, message } => format!(
+            r#"{prefix}"event":"message","level":"{level}","target":"{}","message":"{}"}}"#,
+            escape_json(target),
+            escape_json(&strip_colors(message)),
+        ),
+        LogEvent::TimerStart { name } => format!(r#"{prefix}"event":"timer_start","name":"{}"}}"#, escape_json(name)),
+        LogEvent::TimerEnd { name, duration, completed } => format!(
+            r#"{prefix}"event":"timer_end","name":"{}","status":"{}","duration_ms":{:.3}}}"#,
+            escape_json(name),
+            match completed {
+                true => "completed",
+                false => "failed",
+            },
+            duration.as_secs_f64() * 1000.0,
+        ),
+    }
+}
+
+/// Record an event in the recent lines and the log file, if one is open.
+pub(super) fn record_event(event: LogEvent) {
+    let time = chrono::offset::Local::now().to_rfc3339_opts(SecondsFormat::Millis, false);
+    let thread = std::thread::current();
+    let line = format_event(&event, &time, thread.name().unwrap_or("unnamed"), stack_size());
+
+    if let Some(log_file) = LOG_FILE.lock().unwrap().as_mut()
+        && let Err(error) = log_file.write_line(&line)
+    {
+        // Printing through the logger would try to write to the file again.
+        println!("failed to write log file: {error}");
+    }
+
+    let mut recent_lines = RECENT_LINES.lock().unwrap();
+
+    if recent_lines.len() >= RECENT_LINE_COUNT {
+        recent_lines.pop_front();
+    }
+
+    recent_lines.push_back(line);
+}
+
+/// Start writing all log messages to `korangar.log` inside `directory`. Log
+/// files from previous runs are rotated, keeping at most [`LOG_FILE_COUNT`]
+/// files. Returns the path of the new log file.
+pub fn start_file_logging(directory: impl AsRef<Path>) -> std::io::Result<PathBuf> {
+    let directory = directory.as_ref();
+    let log_file = LogFile::open(directory, MAXIMUM_LOG_FILE_SIZE, LOG_FILE_COUNT)?;
+
+    *LOG_FILE.lock().unwrap() = Some(log_file);
+
+    Ok(file_path(directory, 0))
+}
+
+/// Stop writing log messages to a file.
+pub fn stop_file_logging() {
+    if let Some(mut log_file) = LOG_FILE.lock().unwrap().take() {
+        let _ = log_file.writer.flush();
+    }
+}
+
+/// The last [`RECENT_LINE_COUNT`] log lines in the same format as the log
+/// file, oldest first. These are collected even if no log file is open.
+/// Poisoned locks are ignored, so this can be called from a panic hook.
+pub fn recent_log_lines() -> Vec<String> {
+    RECENT_LINES.lock().unwrap_or_else(PoisonError::into_inner).iter().cloned().collect()
+}
+
+#[cfg(test)]
+mod file_output {
+    use std::path::PathBuf;
+    use std::time::Duration;
+
+    use super::{LogEvent, LogFile, file_path, format_event, strip_colors};
+    use crate::logging::{Colorize, LogLevel};
+
+    fn temporary_directory(name: &str) -> PathBuf {
+        let directory = std::env::temp_dir().join(format!("korangar_debug_{name}_{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&directory);
+        directory
+    }
+
+    #[test]
+    fn strips_colors() {
+        let message = format!("loaded {} in {}", "map".magenta(), "12ms".cyan());
+
+        assert_eq!(strip_colors(&message), "loaded map in 12ms");
+        assert_eq!(strip_colors("plain"), "plain");
+        assert_eq!(strip_colors("\x1B[1;31mbold\x1B[0m"), "bold");
+    }
+
+    #[test]
+    fn formats_events() {
+        let message = format!("{} \"quoted\"", "red".red());
+        let event = LogEvent::Message {
+            level: LogLevel::Warning,
+            target: "korangar::loaders",
+            message: &message,
+        };
+
+        assert_eq!(
+            format_event(&event, "now", "main", 2),
+            r#"{"time":"now","thread":"main","depth":2,"event":"message","level":"warning","target":"korangar::loaders","message":"red \"quoted\""}"#
+        );
+
+        let event = LogEvent::TimerEnd {
+            name: "load map",
+            duration: Duration::from_micros(1500),
+            completed: false,
+        };
+
+        assert_eq!(
+            format_event(&event, "now", "main", 0),
+            r#"{"time":"now","thread":"main","depth":0,"event":"timer_end","name":"load map","status":"failed","duration_ms":1.500}"#
+        );
+    }
+
+    #[test]
+    fn rotates_files() {
+        let directory = temporary_directory("rotation");
+
+        for run in 0..4 {
+            let mut log_file = LogFile::open(&directory, 1024, 3).unwrap();
+            log_file.write_line(&format!("run {run}")).unwrap();
+        }
+
+        let read = |index| std::fs::read_to_string(file_path(&directory, index)).unwrap();
+        assert_eq!(read(0), "run 3\n");
+        assert_eq!(read(1), "run 2\n");
+        assert_eq!(read(2), "run 1\n");
+        assert!(!file_path(&directory, 3).exists());
+
+        // Exceeding the maximum size rotates the file while running.
+        let mut log_file = LogFile::open(&directory, 8, 3).unwrap();
+        log_file.write_line("first").unwrap();
+        log_file.write_line("second").unwrap();
+        drop(log_file);
+
+        assert_eq!(read(0), "second\n");
+        assert_eq!(read(1), "first\n");
+
+        std::fs::remove_dir_all(&directory).unwrap();
+    }
+}
@@ -0,0 +1,221 @@
+use std::fmt::Display;
+use std::str::FromStr;
+use std::sync::RwLock;
+
+use super::colors::Colorize;
+
+static LOG_FILTER: RwLock<LogFilter> = RwLock::new(LogFilter::new(LogLevel::Info));
+
+/// Severity of a log message. Levels are ordered from most to least severe,
+/// so a filter level lets through every message with a level that is less or
+/// equal.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum LogLevel {
+    Error,
+    Warning,
+    Info,
+    Debug,
+    Trace,
+}
+
+impl LogLevel {
+    pub const ALL: [LogLevel; 5] = [LogLevel::Error, LogLevel::Warning, LogLevel::Info, LogLevel::Debug, LogLevel::Trace];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            LogLevel::Error => "error",
+            LogLevel::Warning => "warning",
+            LogLevel::Info => "info",
+            LogLevel::Debug => "debug",
+            LogLevel::Trace => "trace",
+        }
+    }
+
+    /// Prefix that is printed in front of messages with this level. Info
+    /// messages don't get a prefix so they look like regular debug prints.
+    pub(super) fn prefix(self) -> Option<String> {
+        match self {
+            LogLevel::Error => Some(format!("[{}] ", self.name().red())),
+            LogLevel::Warning => Some(format!("[{}] ", self.name().yellow())),
+            LogLevel::Info => None,
+            LogLevel::Debug => Some(format!("[{}] ", self.name().cyan())),
+            LogLevel::Trace => Some(format!("[{}] ", self.name().magenta())),
+        }
+    }
+}
+
+impl Display for LogLevel {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+impl FromStr for LogLevel {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value.trim().to_ascii_lowercase().as_str() {
+            "error" => Ok(LogLevel::Error),
+            "warning" | "warn" => Ok(LogLevel::Warning),
+            "info" => Ok(LogLevel::Info),
+            "debug" => Ok(LogLevel::Debug),
+            "trace" => Ok(LogLevel::Trace),
+            other => Err(format!("unknown log level `{other}`")),
+        }
+    }
+}
+
+/// Decides which messages are logged. Every target (the module path of the
+/// call site) uses the level of the longest matching module prefix, or the
+/// default level if no prefix matches.
+///
+/// Filters can be parsed from strings like `info,korangar::loaders=debug`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LogFilter {
+    default_level: LogLevel,
+    targets: Vec<(String, LogLevel)>,
+}
+
+impl Default for LogFilter {
+    fn default() -> Self {
+        Self::new(LogLevel::Info)
+    }
+}
+
+impl LogFilter {
+    pub const fn new(default_level: LogLevel) -> Self {
+        Self {
+            default_level,
+            targets: Vec::new(),
+        }
+    }
+
+    pub fn with_target(mut self, target: impl Into<String>, level: LogLevel) -> Self {
+        self.set_target(target, level);
+        self
+    }
+
+    pub fn default_level(&self) -> LogLevel {
+        self.default_level
+    }
+
+    pub fn set_default_level(&mut self, level: LogLevel) {
+        self.default_level = level;
+    }
+
+    /// Set the level of a target, replacing the previous level if there was
+    /// one.
+    pub fn set_target(&mut self, target: impl Into<String>, level: LogLevel) {
+        let target = target.into();
+
+        match self.targets.iter_mut().find(|(existing, _)| *existing == target) {
+            Some((_, existing_level)) => *existing_level = level,
+            None => self.targets.push((target, level)),
+        }
+    }
+
+    pub fn remove_target(&mut self, target: &str) {
+        self.targets.retain(|(existing, _)| existing != target);
+    }
+
+    pub fn targets(&self) -> &[(String, LogLevel)] {
+        &self.targets
+    }
+
+    /// The most verbose level that is logged for a target.
+    pub fn level(&self, target: &str) -> LogLevel {
+        self.targets
+            .iter()
+            .filter(|(prefix, _)| is_module_prefix(prefix, target))
+            .max_by_key(|(prefix, _)| prefix.len())
+            .map(|(_, level)| *level)
+            .unwrap_or(self.default_level)
+    }
+
+    pub fn is_enabled(&self, level: LogLevel, target: &str) -> bool {
+        level <= self.level(target)
+    }
+}
+
+impl FromStr for LogFilter {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let mut filter = LogFilter::default();
+
+        for directive in value.split(',').map(str::trim).filter(|directive| !directive.is_empty()) {
+            match directive.split_once('=') {
+                Some((target, level)) => filter.set_target(target.trim(), level.parse()?),
+                None => filter.default_level = directive.parse()?,
+            }
+        }
+
+        Ok(filter)
+    }
+}
+
+/// `true` if `prefix` is `target` or one of its parent modules.
+fn is_module_prefix(prefix: &str, target: &str) -> bool {
+    match target.strip_prefix(prefix) {
+        Some(rest) => rest.is_empty() || rest.starts_with("::"),
+        None => false,
+    }
+}
+
+/// Replace the global log filter.
+pub fn set_log_filter(filter: LogFilter) {
+    *LOG_FILTER.write().unwrap() = filter;
+}
+
+/// Get a copy of the global log filter.
+pub fn log_filter() -> LogFilter {
+    LOG_FILTER.read().unwrap().clone()
+}
+
+/// Change the global log filter in place.
+pub fn update_log_filter(function: impl FnOnce(&mut LogFilter)) {
+    function(&mut LOG_FILTER.write().unwrap());
+}
+
+pub fn is_log_enabled(level: LogLevel, target: &str) -> bool {
+    LOG_FILTER.read().unwrap().is_enabled(level, target)
+}
+
+#[cfg(test)]
+mod filtering {
+    use super::{LogFilter, LogLevel};
+
+    #[test]
+    fn parse_levels() {
+        assert_eq!("warn".parse::<LogLevel>(), Ok(LogLevel::Warning));
+        assert_eq!(" Debug ".parse::<LogLevel>(), Ok(LogLevel::Debug));
+        assert!("verbose".parse::<LogLevel>().is_err());
+    }
+
+    #[test]
+    fn parse_filter() {
+        let filter: LogFilter = "warning, korangar::loaders=debug,korangar_audio=error".parse().unwrap();
+
+        assert_eq!(filter.default_level(), LogLevel::Warning);
+        assert_eq!(filter.targets(), [
+            (String::from("korangar::loaders"), LogLevel::Debug),
+            (String::from("korangar_audio"), LogLevel::Error),
+        ]);
+        assert!("korangar=loud".parse::<LogFilter>().is_err());
+        assert_eq!("".parse::<LogFilter>(), Ok(LogFilter::default()));
+    }
+
+    #[test]
+    fn longest_prefix_wins() {
+        let filter = LogFilter::new(LogLevel::Info)
+            .with_target("korangar", LogLevel::Warning)
+            .with_target("korangar::loaders", LogLevel::Trace);
+
+        assert_eq!(filter.level("korangar::loaders::map"), LogLevel::Trace);
+        assert_eq!(filter.level("korangar::graphics"), LogLevel::Warning);
+        assert_eq!(filter.level("korangar"), LogLevel::Warning);
+        assert_eq!(filter.level("korangar_audio"), LogLevel::Info);
+        assert!(filter.is_enabled(LogLevel::Error, "korangar::graphics"));
+        assert!(!filter.is_enabled(LogLevel::Info, "korangar::graphics"));
+    }
+}
@@ -1,4 +1,6 @@
 mod colors;
+mod file;
+mod level;
 mod stack;
 pub mod symbols;
 #[macro_use]
@@ -6,5 +8,7 @@ mod print;
 mod timer;
 
 pub use self::colors::{Colorize, Colorized};
-pub use self::print::{print_debug, print_indented};
-pub use self::timer::Timer;
+pub use self::file::{LOG_FILE_COUNT, MAXIMUM_LOG_FILE_SIZE, RECENT_LINE_COUNT, recent_log_lines, start_file_logging, stop_file_logging};
+pub use self::level::{LogFilter, LogLevel, is_log_enabled, log_filter, set_log_filter, update_log_filter};
+pub use self::print::{log_debug, log_error, log_info, log_message, log_trace, log_warning, print_debug, print_indented};
+pub use self::timer::{TIMER_TARGET, Timer};
@@ -1,9 +1,38 @@
+use super::file::{LogEvent, record_event};
+use super::level::{LogLevel, is_log_enabled};
 use crate::logging::stack::{get_message_count, increment_message_count, message_offset, stack_size};
 use crate::logging::symbols::{ARROW, NEWLINE};
 
+/// Log a message at [`LogLevel::Info`]. Kept for the many call sites that
+/// predate log levels.
 pub macro print_debug {
-    ($format:expr) => (print_indented(String::from($format), true)),
-    ($format:expr, $($arguments:tt)*) => (print_indented(format!($format, $($arguments)*), true)),
+    ($format:expr) => (log_message(LogLevel::Info, module_path!(), String::from($format))),
+    ($format:expr, $($arguments:tt)*) => (log_message(LogLevel::Info, module_path!(), format!($format, $($arguments)*))),
+}
+
+pub macro log_error {
+    ($format:expr) => (log_message(LogLevel::Error, module_path!(), String::from($format))),
+    ($format:expr, $($arguments:tt)*) => (log_message(LogLevel::Error, module_path!(), format!($format, $($arguments)*))),
+}
+
+pub macro log_warning {
+    ($format:expr) => (log_message(LogLevel::Warning, module_path!(), String::from($format))),
+    ($format:expr, $($arguments:tt)*) => (log_message(LogLevel::Warning, module_path!(), format!($format, $($arguments)*))),
+}
+
+pub macro log_info {
+    ($format:expr) => (log_message(LogLevel::Info, module_path!(), String::from($format))),
+    ($format:expr, $($arguments:tt)*) => (log_message(LogLevel::Info, module_path!(), format!($format, $($arguments)*))),
+}
+
+pub macro log_debug {
+    ($format:expr) => (log_message(LogLevel::Debug, module_path!(), String::from($format))),
+    ($format:expr, $($arguments:tt)*) => (log_message(LogLevel::Debug, module_path!(), format!($format, $($arguments)*))),
+}
+
+pub macro log_trace {
+    ($format:expr) => (log_message(LogLevel::Trace, module_path!(), String::from($format))),
+    ($format:expr, $($arguments:tt)*) => (log_message(LogLevel::Trace, module_path!(), format!($format, $($arguments)*))),
 }
 
 pub(crate) macro print_debug_prefix {
@@ -11,6 +40,25 @@ pub(crate) macro print_debug_prefix {
     ($format:expr, $($arguments:tt)*) => (print_indented(format!($format, $($arguments)*), false)),
 }
 
+/// Print a message if the log filter allows it for the given target and
+/// record it in the log file.
+pub fn log_message(level: LogLevel, target: &str, message: String) {
+    if !is_log_enabled(level, target) {
+        return;
+    }
+
+    record_event(LogEvent::Message {
+        level,
+        target,
+        message: &message,
+    });
+
+    match level.prefix() {
+        Some(prefix) => print_indented(prefix + &message, true),
+        None => print_indented(message, true),
+    }
+}
+
 pub fn print_indented(message: String, newline: bool) {
     let offset = message_offset();
 
@@ -36,3 +84,20 @@ pub fn print_indented(message: String, newline: bool) {
         println!();
     }
 }
+
+#[cfg(test)]
+mod targets {
+    mod definition {
+        pub(super) macro target() {
+            module_path!()
+        }
+    }
+
+    #[test]
+    fn module_path_of_call_site() {
+        // The logging macros use `module_path!` as the target of a message, which
+        // has to resolve to the module of the caller rather than the module the
+        // macro is defined in.
+        assert_eq!(definition::target!(), "korangar_debug::logging::print::targets");
+    }
+}
@@ -1,14 +1,20 @@
 use std::time::SystemTime;
 
+use super::file::{LogEvent, record_event};
+use super::level::{LogLevel, is_log_enabled};
 use super::stack::{get_message_count, increment_stack, stack_size};
 use crate::logging::print::print_debug_prefix;
 use crate::logging::stack::decrement_stack;
 use crate::logging::symbols::ARROW;
-use crate::logging::{Colorize, print_debug};
+use crate::logging::{Colorize, print_indented};
+
+/// Target used to filter timers, e.g. `timer=warning` hides all timers.
+pub const TIMER_TARGET: &str = "timer";
 
 pub struct Timer {
     start_time: SystemTime,
     completed: bool,
+    visible: bool,
     name: String,
 }
 
@@ -18,14 +24,22 @@ impl Timer {
     }
 
     pub fn new_dynamic(name: String) -> Self {
-        if stack_size() == 0 {
-            let timestamp = chrono::offset::Local::now().time().format("%H:%M:%S").to_string();
-            print_debug_prefix!("[{}] {}", timestamp.red(), name);
-        } else {
-            print_debug_prefix!("{}", name);
-        }
+        // A hidden timer doesn't print and doesn't indent the messages logged while
+        // it is running.
+        let visible = is_log_enabled(LogLevel::Info, TIMER_TARGET);
+
+        if visible {
+            record_event(LogEvent::TimerStart { name: &name });
+
+            if stack_size() == 0 {
+                let timestamp = chrono::offset::Local::now().time().format("%H:%M:%S").to_string();
+                print_debug_prefix!("[{}] {}", timestamp.red(), name);
+            } else {
+                print_debug_prefix!("{}", name);
+            }
 
-        increment_stack(2);
+            increment_stack(2);
+        }
 
         let start_time = SystemTime::now();
         let completed = false;
@@ -33,53 +47,60 @@ impl Timer {
         Self {
             start_time,
             completed,
+            visible,
             name,
         }
     }
 
     pub fn stop(mut self) {
+        self.finish(true);
+        self.completed = true;
+    }
+
+    fn finish(&self, completed: bool) {
+        if !self.visible {
+            return;
+        }
+
+        let elapsed = self.start_time.elapsed().unwrap();
+        let status = match completed {
+            true => "completed".green(),
+            false => "failed".red(),
+        };
+
         if stack_size() > 0 && get_message_count() == 0 {
             decrement_stack();
-            println!(" ({})", format!("{}ms", self.start_time.elapsed().unwrap().as_millis()).cyan());
+            println!(" ({})", format!("{}ms", elapsed.as_millis()).cyan());
         } else {
             decrement_stack();
-            print_debug!(
-                "{} {} {} ({})",
-                self.name,
-                ARROW,
-                "completed".green(),
-                format!("{}ms", self.start_time.elapsed().unwrap().as_millis()).cyan(),
+            print_indented(
+                format!(
+                    "{} {} {} ({})",
+                    self.name,
+                    ARROW,
+                    status,
+                    format!("{}ms", elapsed.as_millis()).cyan()
+                ),
+                true,
             );
         }
 
+        record_event(LogEvent::TimerEnd {
+            name: &self.name,
+            duration: elapsed,
+            completed,
+        });
+
         if stack_size() == 0 {
             println!();
         }
-
-        self.completed = true;
     }
 }
 
 impl Drop for Timer {
     fn drop(&mut self) {
         if !self.completed {
-            if stack_size() > 0 && get_message_count() == 0 {
-                decrement_stack();
-                println!(" ({})", format!("{}ms", self.start_time.elapsed().unwrap().as_millis()).cyan());
-            } else {
-                decrement_stack();
-                print_debug!(
-                    "{} {} {} ({})",
-                    self.name,
-                    ARROW,
-                    "failed".red(),
-                    format!("{}ms", self.start_time.elapsed().unwrap().as_millis()).cyan(),
-                );
-            }
-
-            if stack_size() == 0 {
-                println!();
-            }
+            self.finish(false);
         }
     }
 }
@@ -0,0 +1,362 @@
+use std::time::{Duration, Instant};
+
+use super::statistics::{MeasurementStatistics, calculate_standard_deviation};
+use crate::logging::{Colorize, print_debug};
+
+/// Timer for a single phase of a frame. Does not hold a time if the
+/// collector was disabled when the phase started.
+#[must_use = "PhaseTimer must be passed to `stop_phase`, otherwise nothing is recorded"]
+pub struct PhaseTimer(Option<Instant>);
+
+/// Statistics of a single phase (or the whole frame) over a report interval.
+#[derive(Debug)]
+pub struct PhaseStatistics {
+    pub name: &'static str,
+    pub statistics: MeasurementStatistics,
+    pub median: Duration,
+    pub percentile_95: Duration,
+    pub percentile_99: Duration,
+    pub maximum: Duration,
+}
+
+/// A frame that took longer than the budget.
+#[derive(Debug)]
+pub struct FrameSpike {
+    pub frame_time: Duration,
+    pub budget: Duration,
+    /// Phases of the frame, longest first.
+    pub phases: Vec<(&'static str, Duration)>,
+}
+
+/// Summary of all frames since the last report.
+#[derive(Debug)]
+pub struct FrameReport {
+    pub frame_count: usize,
+    pub spike_count: usize,
+    pub budget: Duration,
+    pub frame: PhaseStatistics,
+    pub phases: Vec<PhaseStatistics>,
+}
+
+struct PhaseTimes {
+    name: &'static str,
+    times: Vec<Duration>,
+}
+
+/// Lightweight collector of frame and phase timings. Unlike the [`Profiler`]
+/// this is available without the `debug` feature and can be enabled at
+/// runtime, so optimized builds can be profiled without the overhead of the
+/// full profiler.
+///
+/// [`Profiler`]: super::Profiler
+pub struct FrameStatistics {
+    budget: Option<Duration>,
+    report_interval: usize,
+    frame_start: Option<Instant>,
+    frame_times: Vec<Duration>,
+    phases: Vec<PhaseTimes>,
+    current_phases: Vec<(&'static str, Duration)>,
+    spike_count: usize,
+}
+
+impl Default for FrameStatistics {
+    fn default() -> Self {
+        Self {
+            budget: None,
+            report_interval: Self::DEFAULT_REPORT_INTERVAL,
+            frame_start: None,
+            frame_times: Vec::new(),
+            phases: Vec::new(),
+            current_phases: Vec::new(),
+            spike_count: 0,
+        }
+    }
+}
+
+impl FrameStatistics {
+    /// Number of frames between two reports.
+    pub const DEFAULT_REPORT_INTERVAL: usize = 1000;
+
+    pub fn with_report_interval(mut self, report_interval: usize) -> Self {
+        self.report_interval = report_interval.max(1);
+        self
+    }
+
+    /// Start collecting. Frames that take longer than `budget` are logged as
+    /// spikes.
+    pub fn enable(&mut self, budget: Duration) {
+        self.budget = Some(budget);
+    }
+
+    /// Stop collecting and discard all collected timings.
+    pub fn disable(&mut self) {
+        *self = Self::default().with_report_interval(self.report_interval);
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        self.budget.is_some()
+    }
+
+    pub fn start_phase(&self) -> PhaseTimer {
+        PhaseTimer(self.budget.map(|_| Instant::now()))
+    }
+
+    pub fn stop_phase(&mut self, name: &'static str, timer: PhaseTimer) {
+        if let Some(start_time) = timer.0
+            && self.is_enabled()
+        {
+            self.record_phase(name, start_time.elapsed());
+        }
+    }
+
+    /// Finish the previous frame and start a new one. The frame time is the
+    /// time between two calls, so it includes everything the main loop does.
+    /// Spikes and reports are logged.
+    pub fn start_frame(&mut self) {
+        if !self.is_enabled() {
+            return;
+        }
+
+        let now = Instant::now();
+
+        if let Some(frame_start) = self.frame_start.replace(now) {
+            let (spike, report) = self.finish_frame(now - frame_start);
+
+            if let Some(spike) = spike {
+                spike.log();
+            }
+
+            if let Some(report) = report {
+                report.log();
+            }
+        }
+    }
+
+    fn record_phase(&mut self, name: &'static str, duration: Duration) {
+        match self.current_phases.iter_mut().find(|(phase_name, _)| *phase_name == name) {
+            Some((_, total)) => *total += duration,
+            None => self.current_phases.push((name, duration)),
+        }
+    }
+
+    fn finish_frame(&mut self, frame_time: Duration) -> (Option<FrameSpike>, Option<FrameReport>) {
+        let Some(budget) = self.budget else {
+            return (None, None);
+        };
+
+        self.frame_times.push(frame_time);
+
+        for (name, duration) in &self.current_phases {
+            match self.phases.iter_mut().find(|phase| phase.name == *name) {
+                Some(phase) => phase.times.push(*duration),
+                None => self.phases.push(PhaseTimes {
+                    name,
+                    times: vec![*duration],
+                }),
+            }
+        }
+
+        let spike = (frame_time > budget).then(|| {
+            self.spike_count += 1;
+
+            let mut phases = std::mem::take(&mut self.current_phases);
+            phases.sort_by(|left, right| right.1.cmp(&left.1));
+
+            FrameSpike {
+                frame_time,
+                budget,
+                phases,
+            }
+        });
+
+        self.current_phases.clear();
+
+        let report = (self.frame_times.len() >= self.report_interval).then(|| {
+            let frame_count = self.frame_times.len();
+            let frame = phase_statistics("frame", &mut self.frame_times);
+            let phases = self
+                .phases
+                .iter_mut()
+                .filter(|phase| !phase.times.is_empty())
+                .map(|phase| phase_statistics(phase.name, &mut phase.times))
+                .collect();
+            let spike_count = self.spike_count;
+
+            self.frame_times.clear();
+            self.phases.iter_mut().for_each(|phase| phase.times.clear());
+            self.spike_count = 0;
+
+            FrameReport {
+                frame_count,
+                spike_count,
+                budget,
+                frame,
+                phases,
+            }
+        });
+
+        (spike, report)
+    }
+}
+
+/// Nearest-rank percentile of a sorted slice.
+fn percentile(sorted_times: &[Duration], percentile: f64) -> Duration {
+    if sorted_times.is_empty() {
+        return Duration::ZERO;
+    }
+
+    let rank = (percentile / 100.0 * sorted_times.len() as f64).ceil() as usize;
+    sorted_times[rank.clamp(1, sorted_times.len()) - 1]
+}
+
+fn phase_statistics(name: &'static str, times: &mut [Duration]) -> PhaseStatistics {
+    times.sort_unstable();
+
+    let total: Duration = times.iter().sum();
+    let mean = match times.is_empty() {
+        true => Duration::ZERO,
+        false => total / times.len() as u32,
+    };
+    let standard_deviation = calculate_standard_deviation(mean, times);
+
+    PhaseStatistics {
+        name,
+        statistics: MeasurementStatistics { mean, standard_deviation },
+        median: percentile(times, 50.0),
+        percentile_95: percentile(times, 95.0),
+        percentile_99: percentile(times, 99.0),
+        maximum: times.last().copied().unwrap_or_default(),
+    }
+}
+
+fn milliseconds(duration: Duration) -> String {
+    format!("{:.2}ms", duration.as_secs_f64() * 1000.0)
+}
+
+impl FrameSpike {
+    pub fn log(&self) {
+        let phases = self
+            .phases
+            .iter()
+            .map(|(name, duration)| format!("{} {}", name, milliseconds(*duration)))
+            .collect::<Vec<_>>()
+            .join(", ");
+
+        print_debug!(
+            "[{}] frame took {} (budget {}); {}",
+            "spike".yellow(),
+            milliseconds(self.frame_time).red(),
+            milliseconds(self.budget).magenta(),
+            phases,
+        );
+    }
+}
+
+impl PhaseStatistics {
+    fn log(&self) {
+        print_debug!(
+            "{}: mean {} (deviation {:.2}ms), median {}, p95 {}, p99 {}, max {}",
+            self.name.magenta(),
+            milliseconds(self.statistics.mean).yellow(),
+            self.statistics.standard_deviation,
+            milliseconds(self.median),
+            milliseconds(self.percentile_95),
+            milliseconds(self.percentile_99).yellow(),
+            milliseconds(self.maximum).red(),
+        );
+    }
+}
+
+impl FrameReport {
+    pub fn log(&self) {
+        print_debug!(
+            "[{}] {} frames, {} over the budget of {}",
+            "frame statistics".green(),
+            self.frame_count.yellow(),
+            self.spike_count.red(),
+            milliseconds(self.budget).magenta(),
+        );
+
+        self.frame.log();
+        self.phases.iter().for_each(PhaseStatistics::log);
+    }
+}
+
+#[cfg(test)]
+mod collection {
+    use std::time::Duration;
+
+    use super::{FrameStatistics, percentile};
+
+    fn milliseconds(value: u64) -> Duration {
+        Duration::from_millis(value)
+    }
+
+    #[test]
+    fn nearest_rank_percentile() {
+        let times: Vec<Duration> = (1..=100).map(milliseconds).collect();
+
+        assert_eq!(percentile(&times, 50.0), milliseconds(50));
+        assert_eq!(percentile(&times, 95.0), milliseconds(95));
+        assert_eq!(percentile(&times, 100.0), milliseconds(100));
+        assert_eq!(percentile(&times, 0.0), milliseconds(1));
+        assert_eq!(percentile(&[], 50.0), Duration::ZERO);
+    }
+
+    #[test]
+    fn disabled_collects_nothing() {
+        let mut statistics = FrameStatistics::default().with_report_interval(1);
+        let timer = statistics.start_phase();
+        statistics.stop_phase("update", timer);
+
+        assert!(statistics.current_phases.is_empty());
+        assert!(matches!(statistics.finish_frame(milliseconds(100)), (None, None)));
+    }
+
+    #[test]
+    fn detects_spikes() {
+        let mut statistics = FrameStatistics::default();
+        statistics.enable(milliseconds(16));
+
+        statistics.record_phase("update", milliseconds(2));
+        statistics.record_phase("render", milliseconds(10));
+        statistics.record_phase("update", milliseconds(1));
+        assert!(statistics.finish_frame(milliseconds(15)).0.is_none());
+
+        statistics.record_phase("update", milliseconds(3));
+        statistics.record_phase("render", milliseconds(20));
+        let spike = statistics.finish_frame(milliseconds(25)).0.unwrap();
+
+        assert_eq!(spike.frame_time, milliseconds(25));
+        assert_eq!(spike.phases, [("render", milliseconds(20)), ("update", milliseconds(3))]);
+    }
+
+    #[test]
+    fn reports_after_interval() {
+        let mut statistics = FrameStatistics::default().with_report_interval(4);
+        statistics.enable(milliseconds(16));
+
+        for frame_time in [10, 12, 30, 14] {
+            statistics.record_phase("render", milliseconds(frame_time / 2));
+            let (_, report) = statistics.finish_frame(milliseconds(frame_time));
+
+            if frame_time != 14 {
+                assert!(report.is_none());
+                continue;
+            }
+
+            let report = report.unwrap();
+            assert_eq!(report.frame_count, 4);
+            assert_eq!(report.spike_count, 1);
+            assert_eq!(report.frame.median, milliseconds(12));
+            assert_eq!(report.frame.maximum, milliseconds(30));
+            assert_eq!(report.frame.statistics.mean, milliseconds(16) + Duration::from_micros(500));
+            assert_eq!(report.phases.len(), 1);
+            assert_eq!(report.phases[0].maximum, milliseconds(15));
+        }
+
+        // Everything is reset after a report.
+        assert!(statistics.frame_times.is_empty());
+        assert_eq!(statistics.spike_count, 0);
+    }
+}
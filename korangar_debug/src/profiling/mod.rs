@@ -1,11 +1,15 @@
 mod frame_measurement;
+mod frame_statistics;
 mod measurement;
 mod profiler;
 mod ring_buffer;
 mod statistics;
+mod trace;
 
 pub use self::frame_measurement::FrameMeasurement;
+pub use self::frame_statistics::{FrameReport, FrameSpike, FrameStatistics, PhaseStatistics, PhaseTimer};
 pub use self::measurement::{ActiveMeasurement, Measurement};
 pub use self::profiler::{LockThreadProfiler, Profiler};
 pub use self::ring_buffer::RingBuffer;
-pub use self::statistics::{get_frame_by_index, get_number_of_saved_frames, get_statistics_data};
+pub use self::statistics::{MeasurementStatistics, get_frame_by_index, get_number_of_saved_frames, get_statistics_data};
+pub use self::trace::{TraceWriter, export_chrome_trace, is_recording_trace, start_trace_recording, stop_trace_recording};
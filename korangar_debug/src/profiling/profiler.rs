@@ -3,7 +3,7 @@ use std::sync::Mutex;
 use std::sync::atomic::AtomicBool;
 
 use super::{ActiveMeasurement, RingBuffer};
-use crate::logging::{Colorize, print_debug};
+use crate::logging::{Colorize, log_warning};
 use crate::profiling::frame_measurement::FrameMeasurement;
 
 #[thread_local]
@@ -12,6 +12,7 @@ static PROFILER_HALTED: AtomicBool = AtomicBool::new(false);
 
 #[derive(Default)]
 pub struct Profiler {
+    thread_name: &'static str,
     active_measurements: Vec<usize>,
     latest_frame: FrameMeasurement,
     saved_frames: RingBuffer<FrameMeasurement, { Profiler::SAVED_FRAME_COUNT }>,
@@ -21,6 +22,15 @@ impl Profiler {
     pub const ROOT_MEASUREMENT_NAME: &'static str = "total";
     pub const SAVED_FRAME_COUNT: usize = 128;
 
+    /// Create a new profiler for the thread with the given name.
+    #[doc(hidden)]
+    pub fn new(thread_name: &'static str) -> Self {
+        Self {
+            thread_name,
+            ..Default::default()
+        }
+    }
+
     /// Set the active profiler.
     #[doc(hidden)]
     pub fn set_active(profiler: &'static Mutex<Profiler>) {
@@ -77,13 +87,18 @@ impl Profiler {
                 .collect::<Vec<&'static str>>()
                 .join(", ");
 
-            print_debug!(
-                "[{}] active measurements at the start of the frame; measurement names: {}",
-                "warning".yellow(),
+            log_warning!(
+                "active measurements at the start of the frame; measurement names: {}",
                 measurement_names.magenta(),
             );
         }
 
+        // Recording a trace is independent of the halted state, since halting is only
+        // used to inspect the saved frames.
+        if self.latest_frame.has_measurements() {
+            super::trace::record_frame(self.thread_name, &self.latest_frame);
+        }
+
         // Make sure that the profiler is not halted and only save the frame data when
         // there is at least one measurement.
         if !PROFILER_HALTED.load(std::sync::atomic::Ordering::Relaxed) && self.latest_frame.has_measurements() {
@@ -128,11 +143,7 @@ impl Profiler {
     fn stop_measurement_inner(&mut self, name: &'static str) {
         // Remove the measurement from the list of active measurements.
         let Some(index) = self.active_measurements.pop() else {
-            print_debug!(
-                "[{}] tried to stop measurement {} but no measurement is active",
-                "warning".yellow(),
-                name.magenta(),
-            );
+            log_warning!("tried to stop measurement {} but no measurement is active", name.magenta(),);
             return;
         };
 
@@ -143,9 +154,8 @@ impl Profiler {
 
         // Assert that the names match to emit a warning when something went wrong.
         if !std::ptr::addr_eq(name, measurement.name) {
-            print_debug!(
-                "[{}] active measurement mismatch; expected {} but got {}",
-                "warning".yellow(),
+            log_warning!(
+                "active measurement mismatch; expected {} but got {}",
                 measurement.name.magenta(),
                 name.magenta(),
             );
@@ -157,6 +167,9 @@ impl Profiler {
 pub trait LockThreadProfiler {
     /// Lock the profiler corresponding to the variant.
     fn lock_profiler(&self) -> std::sync::MutexGuard<'_, Profiler>;
+
+    /// Name of the thread corresponding to the variant.
+    fn thread_name(&self) -> &'static str;
 }
 
 /// Profile the entire block.
@@ -184,7 +197,7 @@ macro_rules! create_profiler_threads {
                 $(
                     #[allow(non_upper_case_globals)]
                     pub(super) static $thread: LazyLock<Mutex<Profiler>> = LazyLock::new(|| {
-                        Mutex::new(Profiler::default())
+                        Mutex::new(Profiler::new(stringify!($thread)))
                     });
                 )*
             }
@@ -195,12 +208,23 @@ macro_rules! create_profiler_threads {
                 $($thread),*
             }
 
+            impl Enum {
+                /// All profiler threads.
+                pub const ALL: &'static [Enum] = &[$(Self::$thread),*];
+            }
+
             impl LockThreadProfiler for Enum {
                 fn lock_profiler(&self) -> MutexGuard<'_, Profiler> {
                     match self {
                         $(Self::$thread => locks::$thread.lock().unwrap()),*
                     }
                 }
+
+                fn thread_name(&self) -> &'static str {
+                    match self {
+                        $(Self::$thread => stringify!($thread)),*
+                    }
+                }
             }
 
             $(
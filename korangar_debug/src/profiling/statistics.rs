@@ -15,6 +15,7 @@ struct MeasurementTiming {
     times_called: usize,
 }
 
+#[derive(Debug)]
 pub struct MeasurementStatistics {
     pub mean: Duration,
     pub standard_deviation: f64,
@@ -40,7 +41,7 @@ fn process_timing(measurement: &Measurement, timings: &mut HashMap<&'static str,
     timing.times_called += 1;
 }
 
-fn calculate_standard_deviation(mean: Duration, times: &[Duration]) -> f64 {
+pub(super) fn calculate_standard_deviation(mean: Duration, times: &[Duration]) -> f64 {
     let mean = mean.as_secs_f64() * 1000.0;
 
     times
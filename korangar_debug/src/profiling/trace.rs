@@ -0,0 +1,289 @@
+//! Export of profiler measurements to the Chrome trace event format, which
+//! can be opened in `chrome://tracing`, Perfetto and most other trace
+//! viewers.
+
+use std::fs::File;
+use std::io::{BufWriter, Write};
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::{Duration, Instant};
+
+use super::{FrameMeasurement, LockThreadProfiler, Measurement};
+use crate::json::escape_json;
+use crate::logging::{Colorize, log_error, print_debug};
+
+/// Process id used for all events. The trace only ever contains a single
+/// process.
+const PROCESS_ID: usize = 1;
+
+static TRACE_RECORDING: Mutex<Option<TraceRecording>> = Mutex::new(None);
+static TRACE_RECORDING_ACTIVE: AtomicBool = AtomicBool::new(false);
+
+/// Writes frame measurements as Chrome trace events. Every measurement is
+/// written as a complete event, every profiler thread gets its own track.
+pub struct TraceWriter<W>
+where
+    W: Write,
+{
+    writer: W,
+    epoch: Instant,
+    thread_names: Vec<&'static str>,
+    has_events: bool,
+}
+
+impl<W> TraceWriter<W>
+where
+    W: Write,
+{
+    /// Create a new writer. All timestamps are relative to `epoch`.
+    pub fn new(mut writer: W, epoch: Instant) -> std::io::Result<Self> {
+        writer.write_all(b"{\"displayTimeUnit\":\"ms\",\"traceEvents\":[")?;
+
+        Ok(Self {
+            writer,
+            epoch,
+            thread_names: Vec::new(),
+            has_events: false,
+        })
+    }
+
+    /// Write all measurements of a frame.
+    pub fn write_frame(&mut self, thread_name: &'static str, frame: &FrameMeasurement) -> std::io::Result<()> {
+        if !frame.has_measurements() {
+            return Ok(());
+        }
+
+        let thread_id = self.thread_id(thread_name)?;
+        self.write_measurement(thread_id, frame, frame.root_measurement())
+    }
+
+    /// Close the event list and flush the underlying writer.
+    pub fn finish(mut self) -> std::io::Result<W> {
+        self.writer.write_all(b"\n]}\n")?;
+        self.writer.flush()?;
+        Ok(self.writer)
+    }
+
+    /// Get the id of a thread, emitting the metadata event naming the thread
+    /// the first time it is seen.
+    fn thread_id(&mut self, thread_name: &'static str) -> std::io::Result<usize> {
+        if let Some(index) = self.thread_names.iter().position(|name| *name == thread_name) {
+            return Ok(index + 1);
+        }
+
+        self.thread_names.push(thread_name);
+        let thread_id = self.thread_names.len();
+
+        self.write_event(&format!(
+            r#"{{"name":"thread_name","ph":"M","pid":{PROCESS_ID},"tid":{thread_id},"args":{{"name":"{}"}}}}"#,
+            escape_json(thread_name),
+        ))?;
+
+        Ok(thread_id)
+    }
+
+    fn write_measurement(&mut self, thread_id: usize, frame: &FrameMeasurement, measurement: &Measurement) -> std::io::Result<()> {
+        let timestamp = microseconds_since(self.epoch, measurement.start_time);
+        let duration = measurement.total_time_taken().as_secs_f64() * 1_000_000.0;
+
+        self.write_event(&format!(
+            r#"{{"name":"{}","cat":"profile","ph":"X","ts":{timestamp:.3},"dur":{duration:.3},"pid":{PROCESS_ID},"tid":{thread_id}}}"#,
+            escape_json(measurement.name),
+        ))?;
+
+        measurement
+            .indices
+            .iter()
+            .try_for_each(|index| self.write_measurement(thread_id, frame, &frame[*index]))
+    }
+
+    fn write_event(&mut self, event: &str) -> std::io::Result<()> {
+        if self.has_events {
+            self.writer.write_all(b",")?;
+        }
+
+        self.has_events = true;
+        self.writer.write_all(b"\n")?;
+        self.writer.write_all(event.as_bytes())
+    }
+}
+
+fn microseconds_since(epoch: Instant, instant: Instant) -> f64 {
+    instant.saturating_duration_since(epoch).as_secs_f64() * 1_000_000.0
+}
+
+/// Write the saved frames of all given profiler threads to a Chrome trace
+/// file. Returns the number of frames written.
+pub fn export_chrome_trace<T>(threads: impl IntoIterator<Item = T>, path: impl AsRef<Path>) -> std::io::Result<usize>
+where
+    T: LockThreadProfiler,
+{
+    // Copy the frames first so the profilers are not locked while writing to disk.
+    let frames: Vec<(&'static str, Vec<FrameMeasurement>)> = threads
+        .into_iter()
+        .map(|thread| {
+            let profiler = thread.lock_profiler();
+            let frames = profiler
+                .get_saved_frames()
+                .iter()
+                .filter(|frame| frame.has_measurements())
+                .cloned()
+                .collect();
+
+            (thread.thread_name(), frames)
+        })
+        .collect();
+
+    let epoch = frames
+        .iter()
+        .flat_map(|(_, frames)| frames.iter())
+        .map(|frame| frame.root_measurement().start_time)
+        .min()
+        .unwrap_or_else(Instant::now);
+
+    let mut writer = TraceWriter::new(BufWriter::new(File::create(path)?), epoch)?;
+    let mut frame_count = 0;
+
+    for (thread_name, frames) in &frames {
+        for frame in frames {
+            writer.write_frame(thread_name, frame)?;
+            frame_count += 1;
+        }
+    }
+
+    writer.finish()?;
+
+    Ok(frame_count)
+}
+
+struct TraceRecording {
+    writer: TraceWriter<BufWriter<File>>,
+    path: PathBuf,
+    end_time: Instant,
+}
+
+/// Start streaming every finished frame of every profiler thread to a Chrome
+/// trace file for the given duration. Unlike [`export_chrome_trace`] this is
+/// not limited by the number of saved frames. A recording that is still
+/// running is finished first.
+pub fn start_trace_recording(path: impl AsRef<Path>, duration: Duration) -> std::io::Result<()> {
+    stop_trace_recording()?;
+
+    let path = path.as_ref().to_path_buf();
+    let start_time = Instant::now();
+    let writer = TraceWriter::new(BufWriter::new(File::create(&path)?), start_time)?;
+
+    *TRACE_RECORDING.lock().unwrap() = Some(TraceRecording {
+        writer,
+        path,
+        end_time: start_time + duration,
+    });
+    TRACE_RECORDING_ACTIVE.store(true, Ordering::Relaxed);
+
+    Ok(())
+}
+
+/// Stop the current recording early. Returns the path of the finished
+/// trace, if a recording was running.
+pub fn stop_trace_recording() -> std::io::Result<Option<PathBuf>> {
+    TRACE_RECORDING_ACTIVE.store(false, Ordering::Relaxed);
+
+    match TRACE_RECORDING.lock().unwrap().take() {
+        Some(recording) => {
+            recording.writer.finish()?;
+            Ok(Some(recording.path))
+        }
+        None => Ok(None),
+    }
+}
+
+/// Returns `true` while a trace is being recorded.
+pub fn is_recording_trace() -> bool {
+    TRACE_RECORDING_ACTIVE.load(Ordering::Relaxed)
+}
+
+/// Add a finished frame to the running recording, if any. Finishes the
+/// recording once its duration has passed.
+pub(super) fn record_frame(thread_name: &'static str, frame: &FrameMeasurement) {
+    if !TRACE_RECORDING_ACTIVE.load(Ordering::Relaxed) {
+        return;
+    }
+
+    let mut guard = TRACE_RECORDING.lock().unwrap();
+
+    let Some(recording) = guard.as_mut() else {
+        return;
+    };
+
+    let expired = Instant::now() >= recording.end_time;
+    let result = match expired {
+        true => Ok(()),
+        false => recording.writer.write_frame(thread_name, frame),
+    };
+
+    if !expired && result.is_ok() {
+        return;
+    }
+
+    TRACE_RECORDING_ACTIVE.store(false, Ordering::Relaxed);
+    let recording = guard.take().unwrap();
+    let path = recording.path.display().to_string();
+
+    match result.and_then(|_| recording.writer.finish()) {
+        Ok(_) => print_debug!("finished recording trace to {}", path.magenta()),
+        Err(error) => log_error!("failed to record trace to {}: {}", path.magenta(), error.to_string().red()),
+    }
+}
+
+#[cfg(test)]
+mod chrome_trace {
+    use std::time::Instant;
+
+    use super::TraceWriter;
+    use crate::json::escape_json;
+    use crate::profiling::FrameMeasurement;
+
+    fn frame() -> FrameMeasurement {
+        let mut frame = FrameMeasurement::default();
+        let root = frame.new_measurement("total");
+        let child = frame.new_measurement("render");
+        frame[root].indices.push(child);
+        frame[child].stop_measurement();
+        frame[root].stop_measurement();
+        frame
+    }
+
+    #[test]
+    fn escapes_strings() {
+        assert_eq!(escape_json("plain"), "plain");
+        assert_eq!(escape_json("a \"b\" \\ c\n"), "a \\\"b\\\" \\\\ c\\n");
+        assert_eq!(escape_json("\u{1}"), "\\u0001");
+    }
+
+    #[test]
+    fn writes_complete_events() {
+        let frame = frame();
+        let mut writer = TraceWriter::new(Vec::new(), Instant::now()).unwrap();
+        writer.write_frame("Main", &frame).unwrap();
+        writer.write_frame("Main", &frame).unwrap();
+        writer.write_frame("Loader", &frame).unwrap();
+        let output = String::from_utf8(writer.finish().unwrap()).unwrap();
+
+        assert!(output.starts_with("{\"displayTimeUnit\":\"ms\",\"traceEvents\":["));
+        assert!(output.ends_with("\n]}\n"));
+        assert_eq!(output.matches("\"ph\":\"M\"").count(), 2);
+        assert_eq!(output.matches("\"ph\":\"X\"").count(), 6);
+        assert_eq!(output.matches("\"name\":\"render\"").count(), 3);
+        assert_eq!(output.matches("\"tid\":2").count(), 3);
+        assert!(!output.contains(",\n]"));
+    }
+
+    #[test]
+    fn empty_trace() {
+        let writer = TraceWriter::new(Vec::new(), Instant::now()).unwrap();
+        let output = String::from_utf8(writer.finish().unwrap()).unwrap();
+
+        assert_eq!(output, "{\"displayTimeUnit\":\"ms\",\"traceEvents\":[\n]}\n");
+    }
+}
@@ -26,6 +26,12 @@ pub trait Application: Sized + 'static {
     fn get_scaling(&self) -> Self::Scaling;
 
     fn get_theme(&self, kind: &Self::ThemeKind) -> &Self::Theme;
+
+    /// Length of the text in bytes once it is encoded for sending. Used to
+    /// limit the length of input fields.
+    fn encoded_length(&self, text: &str) -> usize {
+        text.len()
+    }
 }
 
 pub trait MouseInputModeTrait<App>
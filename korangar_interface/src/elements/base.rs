@@ -5,8 +5,9 @@ use crate::application::{
     Application, ClipTrait, CornerRadiusTraitExt, FontSizeTraitExt, InterfaceRenderer, PartialSizeTraitExt, PositionTrait,
     PositionTraitExt, SizeTrait, SizeTraitExt,
 };
-use crate::event::{ChangeEvent, ClickAction, HoverInformation};
+use crate::event::{ChangeEvent, ClickAction, EditAction, HoverInformation};
 use crate::layout::{PlacementResolver, SizeBound};
+use crate::tooltip::Tooltip;
 
 pub type ElementCell<App> = Rc<RefCell<dyn Element<App>>>;
 pub type WeakElementCell<App> = Weak<RefCell<dyn Element<App>>>;
@@ -140,6 +141,8 @@ where
     pub self_element: Option<WeakElementCell<App>>,
     pub parent_element: Option<WeakElementCell<App>>,
     pub mouse_position: Cell<App::Position>,
+    /// Position on the screen where the element was last rendered.
+    pub screen_position: Cell<App::Position>,
 }
 
 impl<App> Default for ElementState<App>
@@ -153,6 +156,7 @@ where
             self_element: None,
             parent_element: None,
             mouse_position: Cell::new(App::Position::zero()),
+            screen_position: Cell::new(App::Position::zero()),
         }
     }
 }
@@ -197,6 +201,8 @@ where
         let position = parent_position.combined(self.cached_position);
         let size = self.cached_size;
 
+        self.screen_position.set(position);
+
         let screen_clip = App::Clip::new(
             screen_clip.left().max(position.left()),
             screen_clip.top().max(position.top()),
@@ -313,10 +319,36 @@ where
         None
     }
 
-    fn input_character(&mut self, _character: char) -> (bool, Vec<ClickAction<App>>) {
+    fn input_character(&mut self, _application: &App, _character: char) -> (bool, Vec<ClickAction<App>>) {
+        (false, Vec::new())
+    }
+
+    fn edit(&mut self, _application: &App, _action: EditAction) -> (bool, Vec<ClickAction<App>>) {
         (false, Vec::new())
     }
 
+    /// Text that is currently selected, used for copying to the clipboard.
+    fn selected_text(&self) -> Option<String> {
+        None
+    }
+
+    /// Whether an input method is composing text in the element. Keys are
+    /// handled by the input method while composing.
+    fn is_composing(&self) -> bool {
+        false
+    }
+
+    /// Screen area of the text cursor if the element accepts text input. Used
+    /// to enable input methods and to place their candidate window.
+    fn text_cursor_area(&self) -> Option<(App::Position, App::Size)> {
+        None
+    }
+
+    /// Content of the tooltip shown when hovering the element.
+    fn tooltip(&self) -> Option<Tooltip> {
+        None
+    }
+
     fn drop_resource(&mut self, drop_resource: App::DropResource) -> Option<App::DropResult> {
         let _ = drop_resource;
         None
@@ -1,17 +1,17 @@
 use std::marker::PhantomData;
 
 use super::StateButton;
-use crate::ElementEvent;
 use crate::application::Application;
 use crate::builder::{Set, Unset};
 use crate::layout::DimensionBound;
 use crate::state::Remote;
+use crate::{ElementEvent, tooltip};
 
 /// Type state [`StateButton`] builder. This builder utilizes the type system to
 /// prevent calling the same method multiple times and calling
 /// [`build`](Self::build) before the mandatory methods have been called.
 #[must_use = "`build` needs to be called"]
-pub struct StateButtonBuilder<App, Text, Event, State, Background, Width>
+pub struct StateButtonBuilder<App, Text, Event, State, Background, Width, Tooltip>
 where
     App: Application,
 {
@@ -20,10 +20,11 @@ where
     remote: State,
     transparent_background: bool,
     width_bound: DimensionBound,
-    marker: PhantomData<(App, State, Background, Width)>,
+    tooltip: Option<tooltip::Tooltip>,
+    marker: PhantomData<(App, State, Background, Width, Tooltip)>,
 }
 
-impl<App> Default for StateButtonBuilder<App, Unset, Unset, Unset, Unset, Unset>
+impl<App> Default for StateButtonBuilder<App, Unset, Unset, Unset, Unset, Unset, Unset>
 where
     App: Application,
 {
@@ -34,12 +35,13 @@ where
             remote: Unset,
             transparent_background: false,
             width_bound: DimensionBound::RELATIVE_ONE_HUNDRED,
+            tooltip: None,
             marker: PhantomData,
         }
     }
 }
 
-impl<App> StateButtonBuilder<App, Unset, Unset, Unset, Unset, Unset>
+impl<App> StateButtonBuilder<App, Unset, Unset, Unset, Unset, Unset, Unset>
 where
     App: Application,
 {
@@ -48,32 +50,35 @@ where
     }
 }
 
-impl<App, Event, State, Background, Width> StateButtonBuilder<App, Unset, Event, State, Background, Width>
+impl<App, Event, State, Background, Width, Tooltip> StateButtonBuilder<App, Unset, Event, State, Background, Width, Tooltip>
 where
     App: Application,
 {
-    pub fn with_text<Text: AsRef<str> + 'static>(self, text: Text) -> StateButtonBuilder<App, Text, Event, State, Background, Width> {
+    pub fn with_text<Text: AsRef<str> + 'static>(
+        self,
+        text: Text,
+    ) -> StateButtonBuilder<App, Text, Event, State, Background, Width, Tooltip> {
         StateButtonBuilder { text, ..self }
     }
 }
 
-impl<App, Text, State, Background, Width> StateButtonBuilder<App, Text, Unset, State, Background, Width>
+impl<App, Text, State, Background, Width, Tooltip> StateButtonBuilder<App, Text, Unset, State, Background, Width, Tooltip>
 where
     App: Application,
 {
     pub fn with_event<Event: ElementEvent<App> + 'static>(
         self,
         event: Event,
-    ) -> StateButtonBuilder<App, Text, Event, State, Background, Width> {
+    ) -> StateButtonBuilder<App, Text, Event, State, Background, Width, Tooltip> {
         StateButtonBuilder { event, ..self }
     }
 }
 
-impl<App, Text, Event, Background, Width> StateButtonBuilder<App, Text, Event, Unset, Background, Width>
+impl<App, Text, Event, Background, Width, Tooltip> StateButtonBuilder<App, Text, Event, Unset, Background, Width, Tooltip>
 where
     App: Application,
 {
-    pub fn with_remote<State>(self, remote: State) -> StateButtonBuilder<App, Text, Event, State, Background, Width>
+    pub fn with_remote<State>(self, remote: State) -> StateButtonBuilder<App, Text, Event, State, Background, Width, Tooltip>
     where
         State: Remote<bool> + 'static,
     {
@@ -85,11 +90,11 @@ where
     }
 }
 
-impl<App, Text, Event, State, Width> StateButtonBuilder<App, Text, Event, State, Unset, Width>
+impl<App, Text, Event, State, Width, Tooltip> StateButtonBuilder<App, Text, Event, State, Unset, Width, Tooltip>
 where
     App: Application,
 {
-    pub fn with_transparent_background(self) -> StateButtonBuilder<App, Text, Event, State, Set, Width> {
+    pub fn with_transparent_background(self) -> StateButtonBuilder<App, Text, Event, State, Set, Width, Tooltip> {
         StateButtonBuilder {
             transparent_background: true,
             marker: PhantomData,
@@ -98,11 +103,11 @@ where
     }
 }
 
-impl<App, Text, Event, State, Background> StateButtonBuilder<App, Text, Event, State, Background, Unset>
+impl<App, Text, Event, State, Background, Tooltip> StateButtonBuilder<App, Text, Event, State, Background, Unset, Tooltip>
 where
     App: Application,
 {
-    pub fn with_width_bound(self, width_bound: DimensionBound) -> StateButtonBuilder<App, Text, Event, State, Background, Set> {
+    pub fn with_width_bound(self, width_bound: DimensionBound) -> StateButtonBuilder<App, Text, Event, State, Background, Set, Tooltip> {
         StateButtonBuilder {
             width_bound,
             marker: PhantomData,
@@ -111,7 +116,20 @@ where
     }
 }
 
-impl<App, Text, Event, State, Background, Width> StateButtonBuilder<App, Text, Event, State, Background, Width>
+impl<App, Text, Event, State, Background, Width> StateButtonBuilder<App, Text, Event, State, Background, Width, Unset>
+where
+    App: Application,
+{
+    pub fn with_tooltip(self, tooltip: tooltip::Tooltip) -> StateButtonBuilder<App, Text, Event, State, Background, Width, Set> {
+        StateButtonBuilder {
+            tooltip: Some(tooltip),
+            marker: PhantomData,
+            ..self
+        }
+    }
+}
+
+impl<App, Text, Event, State, Background, Width, Tooltip> StateButtonBuilder<App, Text, Event, State, Background, Width, Tooltip>
 where
     App: Application,
     Text: AsRef<str> + 'static,
@@ -131,6 +149,7 @@ where
             remote,
             transparent_background,
             width_bound,
+            tooltip,
             ..
         } = self;
 
@@ -140,6 +159,7 @@ where
             remote,
             transparent_background,
             width_bound,
+            tooltip,
             state: Default::default(),
         }
     }
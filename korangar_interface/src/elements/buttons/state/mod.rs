@@ -8,6 +8,7 @@ use crate::event::{ChangeEvent, ClickAction, HoverInformation};
 use crate::layout::{DimensionBound, PlacementResolver};
 use crate::state::{Remote, RemoteClone};
 use crate::theme::{ButtonTheme, InterfaceTheme};
+use crate::tooltip::Tooltip;
 
 // FIX: State button won't redraw just because the state changes
 pub struct StateButton<App, Text, Event, State>
@@ -22,6 +23,7 @@ where
     remote: State,
     width_bound: DimensionBound,
     transparent_background: bool,
+    tooltip: Option<Tooltip>,
     state: ElementState<App>,
 }
 
@@ -56,6 +58,10 @@ where
         self.event.trigger()
     }
 
+    fn tooltip(&self) -> Option<Tooltip> {
+        self.tooltip.clone()
+    }
+
     fn update(&mut self) -> Option<ChangeEvent> {
         self.remote.consume_changed().then_some(ChangeEvent::RENDER_WINDOW)
     }
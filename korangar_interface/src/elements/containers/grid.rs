@@ -0,0 +1,166 @@
+use std::cell::RefCell;
+use std::rc::Weak;
+
+use super::ContainerState;
+use crate::application::{Application, PartialSizeTrait, PartialSizeTraitExt, PositionTraitExt, SizeTraitExt};
+use crate::elements::{Element, ElementCell, ElementState, Focus};
+use crate::event::{ChangeEvent, HoverInformation};
+use crate::layout::{Dimension, PlacementResolver, SizeBound};
+
+/// Container that places its elements in cells of equal size. Every row has
+/// `columns` cells and every cell is `cell_height` high, independent of the
+/// size of the element inside it.
+pub struct Grid<App>
+where
+    App: Application,
+{
+    columns: usize,
+    cell_height: f32,
+    size_bound: Option<SizeBound>,
+    state: ContainerState<App>,
+}
+
+impl<App> Grid<App>
+where
+    App: Application,
+{
+    pub fn new(elements: Vec<ElementCell<App>>, columns: usize, cell_height: f32) -> Self {
+        assert!(columns > 0, "a grid needs at least one column");
+
+        Self {
+            columns,
+            cell_height,
+            size_bound: None,
+            state: ContainerState::new(elements),
+        }
+    }
+
+    pub fn with_size(mut self, size_bound: SizeBound) -> Self {
+        self.size_bound = Some(size_bound);
+        self
+    }
+
+    fn cell_bound(&self, index: usize) -> SizeBound {
+        // The last cell of a row takes the remaining space, so rounding errors
+        // can't push it onto the next line.
+        let width = match (index + 1) % self.columns == 0 {
+            true => Dimension::Remaining,
+            false => Dimension::Relative(100.0 / self.columns as f32),
+        };
+
+        SizeBound {
+            width,
+            minimum_width: None,
+            maximum_width: None,
+            height: Dimension::Absolute(self.cell_height),
+            minimum_height: None,
+            maximum_height: None,
+        }
+    }
+}
+
+impl<App> Element<App> for Grid<App>
+where
+    App: Application,
+{
+    fn get_state(&self) -> &ElementState<App> {
+        &self.state.state
+    }
+
+    fn get_state_mut(&mut self) -> &mut ElementState<App> {
+        &mut self.state.state
+    }
+
+    fn link_back(&mut self, weak_self: Weak<RefCell<dyn Element<App>>>, weak_parent: Option<Weak<RefCell<dyn Element<App>>>>) {
+        self.state.link_back(weak_self, weak_parent);
+    }
+
+    fn is_focusable(&self) -> bool {
+        self.state.is_focusable::<false>()
+    }
+
+    fn focus_next(&self, self_cell: ElementCell<App>, caller_cell: Option<ElementCell<App>>, focus: Focus) -> Option<ElementCell<App>> {
+        self.state.focus_next::<false>(self_cell, caller_cell, focus)
+    }
+
+    fn restore_focus(&self, self_cell: ElementCell<App>) -> Option<ElementCell<App>> {
+        self.state.restore_focus(self_cell)
+    }
+
+    fn resolve(&mut self, placement_resolver: &mut PlacementResolver<App>, application: &App, theme: &App::Theme) {
+        let default_size_bound = SizeBound::only_height(Dimension::Flexible);
+        let size_bound = self.size_bound.unwrap_or(default_size_bound);
+
+        let (mut inner_placement_resolver, mut size, position) =
+            placement_resolver.derive(&size_bound, App::Position::zero(), App::Size::zero());
+        let parent_limits = inner_placement_resolver.get_parent_limits();
+
+        for (index, element) in self.state.elements.iter().enumerate() {
+            let cell_bound = self.cell_bound(index);
+            let (mut cell_placement_resolver, _, cell_position) =
+                inner_placement_resolver.derive(&cell_bound, App::Position::zero(), App::Size::zero());
+
+            let mut element = element.borrow_mut();
+            element.resolve(&mut cell_placement_resolver, application, theme);
+
+            // The element is placed inside the cell, but its position needs to be
+            // relative to the grid.
+            let element_state = element.get_state_mut();
+            element_state.cached_position = cell_position.combined(element_state.cached_position);
+        }
+
+        let final_height = inner_placement_resolver.final_height();
+
+        if size_bound.height.is_flexible() {
+            let final_height = size_bound.validated_height(
+                final_height,
+                placement_resolver.get_available().height(),
+                placement_resolver.get_available().height(),
+                &parent_limits,
+                application.get_scaling(),
+            );
+
+            size = App::PartialSize::new(size.width(), Some(final_height));
+            placement_resolver.register_height(final_height);
+        }
+
+        self.state.state.cached_size = size.finalize();
+        self.state.state.cached_position = position;
+    }
+
+    fn update(&mut self) -> Option<ChangeEvent> {
+        self.state.update()
+    }
+
+    fn hovered_element(&self, mouse_position: App::Position, mouse_mode: &App::MouseInputMode) -> HoverInformation<App> {
+        self.state.hovered_element(mouse_position, mouse_mode, false)
+    }
+
+    fn render(
+        &self,
+        renderer: &App::Renderer,
+        application: &App,
+        theme: &App::Theme,
+        parent_position: App::Position,
+        screen_clip: App::Clip,
+        hovered_element: Option<&dyn Element<App>>,
+        focused_element: Option<&dyn Element<App>>,
+        mouse_mode: &App::MouseInputMode,
+        second_theme: bool,
+    ) {
+        let mut renderer = self
+            .state
+            .state
+            .element_renderer(renderer, application, parent_position, screen_clip);
+
+        self.state.render(
+            &mut renderer,
+            application,
+            theme,
+            hovered_element,
+            focused_element,
+            mouse_mode,
+            second_theme,
+        );
+    }
+}
@@ -1,6 +1,9 @@
 mod default;
 mod expandable;
+mod grid;
 mod scroll;
+mod tab;
+mod table;
 
 use std::cell::{Cell, RefCell};
 use std::ops::Add;
@@ -8,7 +11,10 @@ use std::rc::{Rc, Weak};
 
 pub use self::default::Container;
 pub use self::expandable::Expandable;
+pub use self::grid::Grid;
 pub use self::scroll::ScrollView;
+pub use self::tab::TabContainer;
+pub use self::table::{SortDirection, Table, TableColumn};
 use super::{Element, ElementCell, ElementRenderer, ElementState, Focus, FocusMode};
 use crate::application::{Application, PartialSizeTrait, PartialSizeTraitExt, PositionTrait, PositionTraitExt, SizeTrait};
 use crate::event::{ChangeEvent, HoverInformation};
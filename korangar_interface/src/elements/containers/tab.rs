@@ -0,0 +1,264 @@
+use std::cell::{Cell, RefCell};
+use std::rc::{Rc, Weak};
+
+use super::ContainerState;
+use crate::application::{Application, MouseInputModeTrait, SizeTraitExt};
+use crate::elements::{Element, ElementCell, ElementState, ElementWrap, Focus};
+use crate::event::{ChangeEvent, ClickAction, HoverInformation};
+use crate::layout::{Dimension, DimensionBound, PlacementResolver, SizeBound};
+use crate::theme::{InterfaceTheme, TabTheme};
+
+/// Header of a single tab. Clicking it selects the tab.
+struct TabButton<App>
+where
+    App: Application,
+{
+    title: String,
+    index: usize,
+    selected: Rc<Cell<usize>>,
+    width_bound: DimensionBound,
+    state: ElementState<App>,
+}
+
+impl<App> Element<App> for TabButton<App>
+where
+    App: Application,
+{
+    fn get_state(&self) -> &ElementState<App> {
+        &self.state
+    }
+
+    fn get_state_mut(&mut self) -> &mut ElementState<App> {
+        &mut self.state
+    }
+
+    fn resolve(&mut self, placement_resolver: &mut PlacementResolver<App>, _application: &App, theme: &App::Theme) {
+        let size_bound = self.width_bound.add_height(theme.tab().height_bound());
+        self.state.resolve(placement_resolver, &size_bound);
+    }
+
+    fn hovered_element(&self, mouse_position: App::Position, mouse_mode: &App::MouseInputMode) -> HoverInformation<App> {
+        match mouse_mode.is_none() {
+            true => self.state.hovered_element(mouse_position),
+            false => HoverInformation::Missed,
+        }
+    }
+
+    fn left_click(&mut self, force_update: &mut bool) -> Vec<ClickAction<App>> {
+        if self.selected.get() != self.index {
+            self.selected.set(self.index);
+            *force_update = true;
+        }
+
+        Vec::new()
+    }
+
+    fn render(
+        &self,
+        renderer: &App::Renderer,
+        application: &App,
+        theme: &App::Theme,
+        parent_position: App::Position,
+        screen_clip: App::Clip,
+        hovered_element: Option<&dyn Element<App>>,
+        focused_element: Option<&dyn Element<App>>,
+        _mouse_mode: &App::MouseInputMode,
+        _second_theme: bool,
+    ) {
+        let mut renderer = self.state.element_renderer(renderer, application, parent_position, screen_clip);
+
+        let is_selected = self.selected.get() == self.index;
+        let background_color = match self.is_element_self(hovered_element) || self.is_element_self(focused_element) {
+            true => theme.tab().hovered_background_color(),
+            false if is_selected => theme.tab().selected_background_color(),
+            false => theme.tab().background_color(),
+        };
+
+        renderer.render_background(theme.tab().corner_radius(), background_color);
+
+        let foreground_color = match is_selected {
+            true => theme.tab().selected_foreground_color(),
+            false => theme.tab().foreground_color(),
+        };
+
+        renderer.render_text(
+            &self.title,
+            theme.tab().text_offset(),
+            foreground_color,
+            theme.tab().font_size(),
+        );
+    }
+}
+
+/// Container that shows a row of tab headers and the content of the
+/// selected tab below them. Only the selected content is resolved and
+/// rendered.
+pub struct TabContainer<App>
+where
+    App: Application,
+{
+    contents: Vec<ElementCell<App>>,
+    selected: Rc<Cell<usize>>,
+    displayed: usize,
+    size_bound: Option<SizeBound>,
+    state: ContainerState<App>,
+}
+
+impl<App> TabContainer<App>
+where
+    App: Application,
+{
+    pub fn new(tabs: Vec<(String, ElementCell<App>)>) -> Self {
+        assert!(!tabs.is_empty(), "a tab container needs at least one tab");
+
+        let tab_count = tabs.len();
+        let selected = Rc::new(Cell::new(0));
+        let (titles, contents): (Vec<String>, Vec<ElementCell<App>>) = tabs.into_iter().unzip();
+
+        let mut elements: Vec<ElementCell<App>> = titles
+            .into_iter()
+            .enumerate()
+            .map(|(index, title)| {
+                // The last header takes the remaining space, so rounding errors can't push
+                // it onto the next line.
+                let size = match index + 1 == tab_count {
+                    true => Dimension::Remaining,
+                    false => Dimension::Relative(100.0 / tab_count as f32),
+                };
+
+                TabButton {
+                    title,
+                    index,
+                    selected: selected.clone(),
+                    width_bound: DimensionBound {
+                        size,
+                        minimum_size: None,
+                        maximum_size: None,
+                    },
+                    state: ElementState::default(),
+                }
+                .wrap()
+            })
+            .collect();
+
+        elements.push(contents[0].clone());
+
+        Self {
+            contents,
+            selected,
+            displayed: 0,
+            size_bound: None,
+            state: ContainerState::new(elements),
+        }
+    }
+
+    pub fn with_selected(self, index: usize) -> Self {
+        self.selected.set(index.min(self.contents.len() - 1));
+        self
+    }
+
+    pub fn with_size(mut self, size_bound: SizeBound) -> Self {
+        self.size_bound = Some(size_bound);
+        self
+    }
+
+    /// Index of the currently selected tab.
+    pub fn selected(&self) -> usize {
+        self.selected.get()
+    }
+
+    /// Replace the displayed content if a different tab was selected.
+    fn swap_content(&mut self) {
+        let selected = self.selected.get();
+
+        if selected == self.displayed {
+            return;
+        }
+
+        let content = self.contents[selected].clone();
+        *self.state.elements.last_mut().unwrap() = content.clone();
+        self.state.focus_cache.take();
+        self.displayed = selected;
+
+        // important: link back the new content, otherwise focus navigation and
+        // scrolling would break
+        if let Some(weak_self) = self.state.state.self_element.clone() {
+            content.borrow_mut().link_back(Rc::downgrade(&content), Some(weak_self));
+        }
+    }
+}
+
+impl<App> Element<App> for TabContainer<App>
+where
+    App: Application,
+{
+    fn get_state(&self) -> &ElementState<App> {
+        &self.state.state
+    }
+
+    fn get_state_mut(&mut self) -> &mut ElementState<App> {
+        &mut self.state.state
+    }
+
+    fn link_back(&mut self, weak_self: Weak<RefCell<dyn Element<App>>>, weak_parent: Option<Weak<RefCell<dyn Element<App>>>>) {
+        self.state.link_back(weak_self, weak_parent);
+    }
+
+    fn is_focusable(&self) -> bool {
+        self.state.is_focusable::<false>()
+    }
+
+    fn focus_next(&self, self_cell: ElementCell<App>, caller_cell: Option<ElementCell<App>>, focus: Focus) -> Option<ElementCell<App>> {
+        self.state.focus_next::<false>(self_cell, caller_cell, focus)
+    }
+
+    fn restore_focus(&self, self_cell: ElementCell<App>) -> Option<ElementCell<App>> {
+        self.state.restore_focus(self_cell)
+    }
+
+    fn resolve(&mut self, placement_resolver: &mut PlacementResolver<App>, application: &App, theme: &App::Theme) {
+        self.swap_content();
+
+        let default_size_bound = SizeBound::only_height(Dimension::Flexible);
+        let size_bound = self.size_bound.as_ref().unwrap_or(&default_size_bound);
+
+        self.state
+            .resolve(placement_resolver, application, theme, size_bound, App::Size::zero());
+    }
+
+    fn update(&mut self) -> Option<ChangeEvent> {
+        self.state.update()
+    }
+
+    fn hovered_element(&self, mouse_position: App::Position, mouse_mode: &App::MouseInputMode) -> HoverInformation<App> {
+        self.state.hovered_element(mouse_position, mouse_mode, false)
+    }
+
+    fn render(
+        &self,
+        renderer: &App::Renderer,
+        application: &App,
+        theme: &App::Theme,
+        parent_position: App::Position,
+        screen_clip: App::Clip,
+        hovered_element: Option<&dyn Element<App>>,
+        focused_element: Option<&dyn Element<App>>,
+        mouse_mode: &App::MouseInputMode,
+        second_theme: bool,
+    ) {
+        let mut renderer = self
+            .state
+            .state
+            .element_renderer(renderer, application, parent_position, screen_clip);
+
+        self.state.render(
+            &mut renderer,
+            application,
+            theme,
+            hovered_element,
+            focused_element,
+            mouse_mode,
+            second_theme,
+        );
+    }
+}
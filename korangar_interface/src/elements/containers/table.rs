@@ -0,0 +1,565 @@
+use std::cell::RefCell;
+use std::cmp::Ordering;
+use std::rc::{Rc, Weak};
+
+use super::ContainerState;
+use crate::application::{
+    Application, ClipTrait, CornerRadiusTraitExt, MouseInputModeTrait, PartialSizeTrait, PartialSizeTraitExt, PositionTrait,
+    PositionTraitExt, ScalingTrait, SizeTrait, SizeTraitExt,
+};
+use crate::elements::{Element, ElementCell, ElementRenderer, ElementState, ElementWrap, Focus};
+use crate::event::{ChangeEvent, ClickAction, HoverInformation};
+use crate::layout::{Dimension, DimensionBound, PlacementResolver, SizeBound};
+use crate::state::Remote;
+use crate::theme::{InterfaceTheme, TableTheme};
+
+/// Width of the area at the right edge of a column header that can be
+/// dragged to resize the column.
+const RESIZE_HANDLE_WIDTH: f32 = 6.0;
+
+type Comparator<Row> = dyn Fn(&Row, &Row) -> Ordering;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SortDirection {
+    Ascending,
+    Descending,
+}
+
+/// Column of a [`Table`]. The `cell` function turns a row into the text
+/// displayed in this column.
+pub struct TableColumn<Row> {
+    title: String,
+    width: f32,
+    cell: Box<dyn Fn(&Row) -> String>,
+    compare: Option<Box<Comparator<Row>>>,
+}
+
+impl<Row> TableColumn<Row> {
+    /// Create a new column that is `width` percent of the table wide.
+    pub fn new(title: impl Into<String>, width: f32, cell: impl Fn(&Row) -> String + 'static) -> Self {
+        Self {
+            title: title.into(),
+            width,
+            cell: Box::new(cell),
+            compare: None,
+        }
+    }
+
+    /// Allow sorting the table by this column by clicking on the header.
+    pub fn with_sorting(mut self, compare: impl Fn(&Row, &Row) -> Ordering + 'static) -> Self {
+        self.compare = Some(Box::new(compare));
+        self
+    }
+}
+
+/// Layout state shared between the table and its headers.
+struct TableLayout {
+    /// Width of every column in percent of the table width.
+    widths: Vec<f32>,
+    sort: Option<(usize, SortDirection)>,
+    /// Width of the table in pixels, used to convert mouse movement to
+    /// percentages.
+    table_width: f32,
+    minimum_width: f32,
+}
+
+/// Clicking the same column cycles through ascending, descending and
+/// unsorted. Clicking a different column sorts it ascending.
+fn next_sort(current: Option<(usize, SortDirection)>, column: usize) -> Option<(usize, SortDirection)> {
+    match current {
+        Some((index, SortDirection::Ascending)) if index == column => Some((column, SortDirection::Descending)),
+        Some((index, SortDirection::Descending)) if index == column => None,
+        _ => Some((column, SortDirection::Ascending)),
+    }
+}
+
+/// Move the border between column `index` and the column after it by
+/// `delta` percent, keeping both columns at least `minimum` percent wide.
+fn resize_columns(widths: &mut [f32], index: usize, delta: f32, minimum: f32) {
+    let Some([left, right]) = widths.get_mut(index..index + 2) else {
+        return;
+    };
+
+    // Columns that are already smaller than the minimum can't be resized in
+    // either direction.
+    if minimum - *left > *right - minimum {
+        return;
+    }
+
+    let delta = delta.clamp(minimum - *left, *right - minimum);
+    *left += delta;
+    *right -= delta;
+}
+
+/// Indices of the rows in display order. Sorting is stable, so rows that
+/// compare equal keep their original order.
+fn sorted_order<Row>(rows: &[Row], compare: Option<&Comparator<Row>>, direction: SortDirection) -> Vec<usize> {
+    let mut order: Vec<usize> = (0..rows.len()).collect();
+
+    if let Some(compare) = compare {
+        order.sort_by(|&left, &right| match direction {
+            SortDirection::Ascending => compare(&rows[left], &rows[right]),
+            SortDirection::Descending => compare(&rows[right], &rows[left]),
+        });
+    }
+
+    order
+}
+
+/// Header of a single column. Clicking it changes the sorting, dragging its
+/// right edge resizes the column.
+struct TableHeader<App>
+where
+    App: Application,
+{
+    title: String,
+    index: usize,
+    is_last: bool,
+    sortable: bool,
+    layout: Rc<RefCell<TableLayout>>,
+    handle_width: f32,
+    state: ElementState<App>,
+}
+
+impl<App> TableHeader<App>
+where
+    App: Application,
+{
+    fn is_over_handle(&self) -> bool {
+        !self.is_last && self.state.mouse_position.get().left() >= self.state.cached_size.width() - self.handle_width
+    }
+}
+
+impl<App> Element<App> for TableHeader<App>
+where
+    App: Application,
+{
+    fn get_state(&self) -> &ElementState<App> {
+        &self.state
+    }
+
+    fn get_state_mut(&mut self) -> &mut ElementState<App> {
+        &mut self.state
+    }
+
+    fn is_focusable(&self) -> bool {
+        self.sortable
+    }
+
+    fn resolve(&mut self, placement_resolver: &mut PlacementResolver<App>, application: &App, theme: &App::Theme) {
+        // The last column takes the remaining space, so rounding errors can't push
+        // it onto the next line.
+        let size = match self.is_last {
+            true => Dimension::Remaining,
+            false => Dimension::Relative(self.layout.borrow().widths[self.index]),
+        };
+        let width_bound = DimensionBound {
+            size,
+            minimum_size: None,
+            maximum_size: None,
+        };
+
+        self.handle_width = RESIZE_HANDLE_WIDTH * application.get_scaling().get_factor();
+        self.state
+            .resolve(placement_resolver, &width_bound.add_height(theme.table().header_height_bound()));
+    }
+
+    fn hovered_element(&self, mouse_position: App::Position, mouse_mode: &App::MouseInputMode) -> HoverInformation<App> {
+        if mouse_mode.is_none() {
+            self.state.hovered_element(mouse_position)
+        } else if mouse_mode.is_self_dragged(self) {
+            HoverInformation::Hovered
+        } else {
+            HoverInformation::Missed
+        }
+    }
+
+    fn left_click(&mut self, force_update: &mut bool) -> Vec<ClickAction<App>> {
+        if self.is_over_handle() {
+            return vec![ClickAction::DragElement];
+        }
+
+        if self.sortable {
+            let mut layout = self.layout.borrow_mut();
+            layout.sort = next_sort(layout.sort, self.index);
+            *force_update = true;
+        }
+
+        Vec::new()
+    }
+
+    fn drag(&mut self, mouse_delta: App::Position) -> Option<ChangeEvent> {
+        let mut layout = self.layout.borrow_mut();
+
+        if layout.table_width <= 0.0 {
+            return None;
+        }
+
+        let delta = mouse_delta.left() / layout.table_width * 100.0;
+        let minimum_width = layout.minimum_width;
+        resize_columns(&mut layout.widths, self.index, delta, minimum_width);
+
+        Some(ChangeEvent::RESOLVE_WINDOW)
+    }
+
+    fn render(
+        &self,
+        renderer: &App::Renderer,
+        application: &App,
+        theme: &App::Theme,
+        parent_position: App::Position,
+        screen_clip: App::Clip,
+        hovered_element: Option<&dyn Element<App>>,
+        focused_element: Option<&dyn Element<App>>,
+        mouse_mode: &App::MouseInputMode,
+        _second_theme: bool,
+    ) {
+        let mut renderer = self.state.element_renderer(renderer, application, parent_position, screen_clip);
+
+        let is_hovered = self.is_element_self(hovered_element);
+        let is_dragged = mouse_mode.is_self_dragged(self);
+        let background_color = match (is_hovered && !self.is_over_handle()) || self.is_element_self(focused_element) {
+            true if self.sortable => theme.table().hovered_header_background_color(),
+            _ => theme.table().header_background_color(),
+        };
+
+        renderer.render_background(theme.table().corner_radius(), background_color);
+
+        let sort_marker = match self.layout.borrow().sort {
+            Some((index, SortDirection::Ascending)) if index == self.index => " ^",
+            Some((index, SortDirection::Descending)) if index == self.index => " v",
+            _ => "",
+        };
+
+        renderer.render_text(
+            &format!("{}{}", self.title, sort_marker),
+            theme.table().text_offset(),
+            theme.table().header_foreground_color(),
+            theme.table().font_size(),
+        );
+
+        if is_dragged || (is_hovered && self.is_over_handle()) {
+            let size = self.state.cached_size;
+            renderer.render_rectangle(
+                App::Position::new(size.width() - self.handle_width, 0.0),
+                App::Size::new(self.handle_width, size.height()),
+                App::CornerRadius::uniform(0.0),
+                theme.table().resize_handle_color(),
+            );
+        }
+    }
+}
+
+/// Table with column headers. Columns can be resized by dragging the edge of
+/// the header and sorted by clicking on it.
+pub struct Table<App, Row, Rows>
+where
+    App: Application,
+    Row: 'static,
+    Rows: Remote<Vec<Row>> + 'static,
+{
+    columns: Vec<TableColumn<Row>>,
+    rows: Rows,
+    layout: Rc<RefCell<TableLayout>>,
+    row_order: Vec<usize>,
+    rows_top: f32,
+    row_height: f32,
+    size_bound: Option<SizeBound>,
+    state: ContainerState<App>,
+}
+
+impl<App, Row, Rows> Table<App, Row, Rows>
+where
+    App: Application,
+    Row: 'static,
+    Rows: Remote<Vec<Row>> + 'static,
+{
+    pub fn new(columns: Vec<TableColumn<Row>>, rows: Rows) -> Self {
+        assert!(!columns.is_empty(), "a table needs at least one column");
+
+        let layout = Rc::new(RefCell::new(TableLayout {
+            widths: columns.iter().map(|column| column.width).collect(),
+            sort: None,
+            table_width: 0.0,
+            minimum_width: 0.0,
+        }));
+
+        let column_count = columns.len();
+        let elements = columns
+            .iter()
+            .enumerate()
+            .map(|(index, column)| {
+                TableHeader {
+                    title: column.title.clone(),
+                    index,
+                    is_last: index + 1 == column_count,
+                    sortable: column.compare.is_some(),
+                    layout: layout.clone(),
+                    handle_width: 0.0,
+                    state: ElementState::default(),
+                }
+                .wrap()
+            })
+            .collect();
+
+        Self {
+            columns,
+            rows,
+            layout,
+            row_order: Vec::new(),
+            rows_top: 0.0,
+            row_height: 0.0,
+            size_bound: None,
+            state: ContainerState::new(elements),
+        }
+    }
+
+    pub fn with_size(mut self, size_bound: SizeBound) -> Self {
+        self.size_bound = Some(size_bound);
+        self
+    }
+
+    fn update_row_order(&mut self) {
+        let rows = self.rows.get();
+
+        self.row_order = match self.layout.borrow().sort {
+            Some((index, direction)) => sorted_order(&rows, self.columns[index].compare.as_deref(), direction),
+            None => (0..rows.len()).collect(),
+        };
+    }
+}
+
+impl<App, Row, Rows> Element<App> for Table<App, Row, Rows>
+where
+    App: Application,
+    Row: 'static,
+    Rows: Remote<Vec<Row>> + 'static,
+{
+    fn get_state(&self) -> &ElementState<App> {
+        &self.state.state
+    }
+
+    fn get_state_mut(&mut self) -> &mut ElementState<App> {
+        &mut self.state.state
+    }
+
+    fn link_back(&mut self, weak_self: Weak<RefCell<dyn Element<App>>>, weak_parent: Option<Weak<RefCell<dyn Element<App>>>>) {
+        self.state.link_back(weak_self, weak_parent);
+    }
+
+    fn is_focusable(&self) -> bool {
+        self.state.is_focusable::<false>()
+    }
+
+    fn focus_next(&self, self_cell: ElementCell<App>, caller_cell: Option<ElementCell<App>>, focus: Focus) -> Option<ElementCell<App>> {
+        self.state.focus_next::<false>(self_cell, caller_cell, focus)
+    }
+
+    fn restore_focus(&self, self_cell: ElementCell<App>) -> Option<ElementCell<App>> {
+        self.state.restore_focus(self_cell)
+    }
+
+    fn resolve(&mut self, placement_resolver: &mut PlacementResolver<App>, application: &App, theme: &App::Theme) {
+        self.layout.borrow_mut().minimum_width = theme.table().minimum_column_width();
+        self.update_row_order();
+
+        let default_size_bound = SizeBound::only_height(Dimension::Flexible);
+        let size_bound = self.size_bound.unwrap_or(default_size_bound);
+
+        let (mut inner_placement_resolver, mut size, position) =
+            placement_resolver.derive(&size_bound, App::Position::zero(), App::Size::zero());
+        let parent_limits = inner_placement_resolver.get_parent_limits();
+
+        self.state
+            .elements
+            .iter_mut()
+            .for_each(|element| element.borrow_mut().resolve(&mut inner_placement_resolver, application, theme));
+
+        self.rows_top = inner_placement_resolver.final_height();
+        self.row_height = theme.table().row_height() * application.get_scaling().get_factor();
+
+        let final_height = self.rows_top + self.row_order.len() as f32 * self.row_height;
+
+        if size_bound.height.is_flexible() {
+            let final_height = size_bound.validated_height(
+                final_height,
+                placement_resolver.get_available().height(),
+                placement_resolver.get_available().height(),
+                &parent_limits,
+                application.get_scaling(),
+            );
+
+            size = App::PartialSize::new(size.width(), Some(final_height));
+            placement_resolver.register_height(final_height);
+        }
+
+        self.state.state.cached_size = size.finalize();
+        self.state.state.cached_position = position;
+        self.layout.borrow_mut().table_width = self.state.state.cached_size.width();
+    }
+
+    fn update(&mut self) -> Option<ChangeEvent> {
+        match self.rows.consume_changed() {
+            true => Some(ChangeEvent::RESOLVE_WINDOW),
+            false => None,
+        }
+    }
+
+    fn hovered_element(&self, mouse_position: App::Position, mouse_mode: &App::MouseInputMode) -> HoverInformation<App> {
+        self.state.hovered_element(mouse_position, mouse_mode, false)
+    }
+
+    fn render(
+        &self,
+        renderer: &App::Renderer,
+        application: &App,
+        theme: &App::Theme,
+        parent_position: App::Position,
+        screen_clip: App::Clip,
+        hovered_element: Option<&dyn Element<App>>,
+        focused_element: Option<&dyn Element<App>>,
+        mouse_mode: &App::MouseInputMode,
+        second_theme: bool,
+    ) {
+        let mut renderer = self
+            .state
+            .state
+            .element_renderer(renderer, application, parent_position, screen_clip);
+
+        let column_bounds: Vec<(f32, f32)> = self
+            .state
+            .elements
+            .iter()
+            .map(|element| {
+                let element = element.borrow();
+                let state = element.get_state();
+                (state.cached_position.left(), state.cached_size.width())
+            })
+            .collect();
+
+        let rows = self.rows.get();
+        let table_width = self.state.state.cached_size.width();
+
+        for (display_index, row) in self.row_order.iter().filter_map(|index| rows.get(*index)).enumerate() {
+            let top = self.rows_top + display_index as f32 * self.row_height;
+            let screen_top = renderer.position.top() + top;
+
+            // Rows outside of the visible area are skipped entirely, since large tables
+            // are usually inside a scroll view.
+            if screen_top + self.row_height < renderer.clip.top() || screen_top > renderer.clip.bottom() {
+                continue;
+            }
+
+            let background_color = match display_index % 2 == 0 {
+                true => theme.table().row_background_color(),
+                false => theme.table().second_row_background_color(),
+            };
+
+            renderer.render_rectangle(
+                App::Position::new(0.0, top),
+                App::Size::new(table_width, self.row_height),
+                App::CornerRadius::uniform(0.0),
+                background_color,
+            );
+
+            for (column, (left, width)) in self.columns.iter().zip(column_bounds.iter().copied()) {
+                let position = renderer.position.combined(App::Position::new(left, top));
+
+                // Text wraps at the right edge of the clip, so every cell gets its own clip
+                // to keep the text inside of the cell.
+                let mut cell_renderer = ElementRenderer {
+                    renderer: renderer.renderer,
+                    application,
+                    position,
+                    size: App::Size::new(width, self.row_height),
+                    clip: App::Clip::new(
+                        renderer.clip.left().max(position.left()),
+                        renderer.clip.top().max(position.top()),
+                        renderer.clip.right().min(position.left() + width),
+                        renderer.clip.bottom().min(position.top() + self.row_height),
+                    ),
+                };
+
+                cell_renderer.render_text(
+                    &(column.cell)(row),
+                    theme.table().text_offset(),
+                    theme.table().row_foreground_color(),
+                    theme.table().font_size(),
+                );
+            }
+        }
+
+        self.state.render(
+            &mut renderer,
+            application,
+            theme,
+            hovered_element,
+            focused_element,
+            mouse_mode,
+            second_theme,
+        );
+    }
+}
+
+#[cfg(test)]
+mod sorting {
+    use super::{SortDirection, next_sort, sorted_order};
+
+    #[test]
+    fn cycles_through_directions() {
+        let ascending = next_sort(None, 1);
+        assert_eq!(ascending, Some((1, SortDirection::Ascending)));
+
+        let descending = next_sort(ascending, 1);
+        assert_eq!(descending, Some((1, SortDirection::Descending)));
+
+        assert_eq!(next_sort(descending, 1), None);
+    }
+
+    #[test]
+    fn other_column_starts_ascending() {
+        assert_eq!(
+            next_sort(Some((0, SortDirection::Descending)), 2),
+            Some((2, SortDirection::Ascending))
+        );
+    }
+
+    #[test]
+    fn stable_order() {
+        let rows = [(2, 'a'), (1, 'b'), (2, 'c'), (0, 'd')];
+        let compare = |left: &(i32, char), right: &(i32, char)| left.0.cmp(&right.0);
+
+        assert_eq!(sorted_order(&rows, Some(&compare), SortDirection::Ascending), [3, 1, 0, 2]);
+        assert_eq!(sorted_order(&rows, Some(&compare), SortDirection::Descending), [0, 2, 1, 3]);
+        assert_eq!(sorted_order(&rows, None, SortDirection::Ascending), [0, 1, 2, 3]);
+    }
+}
+
+#[cfg(test)]
+mod resizing {
+    use super::resize_columns;
+
+    #[test]
+    fn moves_border() {
+        let mut widths = [30.0, 30.0, 40.0];
+        resize_columns(&mut widths, 0, 10.0, 5.0);
+        assert_eq!(widths, [40.0, 20.0, 40.0]);
+    }
+
+    #[test]
+    fn keeps_minimum_width() {
+        let mut widths = [30.0, 30.0, 40.0];
+        resize_columns(&mut widths, 1, 50.0, 5.0);
+        assert_eq!(widths, [30.0, 65.0, 5.0]);
+
+        resize_columns(&mut widths, 0, -50.0, 5.0);
+        assert_eq!(widths, [5.0, 90.0, 5.0]);
+    }
+
+    #[test]
+    fn last_column_has_no_border() {
+        let mut widths = [50.0, 50.0];
+        resize_columns(&mut widths, 1, 10.0, 5.0);
+        assert_eq!(widths, [50.0, 50.0]);
+    }
+}
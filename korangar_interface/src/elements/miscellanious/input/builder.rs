@@ -1,7 +1,8 @@
+use std::cell::Cell;
 use std::fmt::Display;
 use std::marker::PhantomData;
 
-use super::{EnterAction, InputField};
+use super::{CompletionFunction, EnterAction, InputField};
 use crate::application::Application;
 use crate::builder::{Set, Unset};
 use crate::event::ClickAction;
@@ -12,7 +13,7 @@ use crate::state::PlainTrackedState;
 /// prevent calling the same method multiple times and calling
 /// [`build`](Self::build) before the mandatory methods have been called.
 #[must_use = "`build` needs to be called"]
-pub struct InputFieldBuilder<App, State, Text, Action, Length, Hidden, Width>
+pub struct InputFieldBuilder<App, State, Text, Action, Length, Hidden, History, Completion, Width>
 where
     App: Application,
 {
@@ -21,11 +22,13 @@ where
     enter_action: Action,
     length: usize,
     hidden: bool,
+    history: Option<PlainTrackedState<Vec<String>>>,
+    completion: Option<CompletionFunction>,
     width_bound: DimensionBound,
-    marker: PhantomData<(App, Length, Hidden, Width)>,
+    marker: PhantomData<(App, Length, Hidden, History, Completion, Width)>,
 }
 
-impl<App> Default for InputFieldBuilder<App, Unset, Unset, Unset, Unset, Unset, Unset>
+impl<App> Default for InputFieldBuilder<App, Unset, Unset, Unset, Unset, Unset, Unset, Unset, Unset>
 where
     App: Application,
 {
@@ -36,13 +39,15 @@ where
             enter_action: Unset,
             length: 0,
             hidden: false,
+            history: None,
+            completion: None,
             width_bound: DimensionBound::RELATIVE_ONE_HUNDRED,
             marker: PhantomData,
         }
     }
 }
 
-impl<App> InputFieldBuilder<App, Unset, Unset, Unset, Unset, Unset, Unset>
+impl<App> InputFieldBuilder<App, Unset, Unset, Unset, Unset, Unset, Unset, Unset, Unset>
 where
     App: Application,
 {
@@ -51,14 +56,15 @@ where
     }
 }
 
-impl<App, Text, Action, Length, Hidden, Width> InputFieldBuilder<App, Unset, Text, Action, Length, Hidden, Width>
+impl<App, Text, Action, Length, Hidden, History, Completion, Width>
+    InputFieldBuilder<App, Unset, Text, Action, Length, Hidden, History, Completion, Width>
 where
     App: Application,
 {
     pub fn with_state(
         self,
         state: PlainTrackedState<String>,
-    ) -> InputFieldBuilder<App, PlainTrackedState<String>, Text, Action, Length, Hidden, Width> {
+    ) -> InputFieldBuilder<App, PlainTrackedState<String>, Text, Action, Length, Hidden, History, Completion, Width> {
         InputFieldBuilder {
             input_state: state,
             ..self
@@ -66,12 +72,16 @@ where
     }
 }
 
-impl<App, State, Action, Length, Hidden, Width> InputFieldBuilder<App, State, Unset, Action, Length, Hidden, Width>
+impl<App, State, Action, Length, Hidden, History, Completion, Width>
+    InputFieldBuilder<App, State, Unset, Action, Length, Hidden, History, Completion, Width>
 where
     App: Application,
 {
     /// Set the text that will be displayed when the [`InputField`] is empty.
-    pub fn with_ghost_text<Text>(self, ghost_text: Text) -> InputFieldBuilder<App, State, Text, Action, Length, Hidden, Width>
+    pub fn with_ghost_text<Text>(
+        self,
+        ghost_text: Text,
+    ) -> InputFieldBuilder<App, State, Text, Action, Length, Hidden, History, Completion, Width>
     where
         Text: Display + 'static,
     {
@@ -79,7 +89,8 @@ where
     }
 }
 
-impl<App, State, Text, Length, Hidden, Width> InputFieldBuilder<App, State, Text, Unset, Length, Hidden, Width>
+impl<App, State, Text, Length, Hidden, History, Completion, Width>
+    InputFieldBuilder<App, State, Text, Unset, Length, Hidden, History, Completion, Width>
 where
     App: Application,
 {
@@ -87,7 +98,7 @@ where
     pub fn with_enter_action(
         self,
         enter_action: impl FnMut() -> Vec<ClickAction<App>> + 'static,
-    ) -> InputFieldBuilder<App, State, Text, EnterAction<App>, Length, Hidden, Width> {
+    ) -> InputFieldBuilder<App, State, Text, EnterAction<App>, Length, Hidden, History, Completion, Width> {
         InputFieldBuilder {
             enter_action: Box::new(enter_action),
             ..self
@@ -95,12 +106,14 @@ where
     }
 }
 
-impl<App, State, Text, Action, Hidden, Width> InputFieldBuilder<App, State, Text, Action, Unset, Hidden, Width>
+impl<App, State, Text, Action, Hidden, History, Completion, Width>
+    InputFieldBuilder<App, State, Text, Action, Unset, Hidden, History, Completion, Width>
 where
     App: Application,
 {
-    /// Set the maximum number of allowed characters.
-    pub fn with_length(self, length: usize) -> InputFieldBuilder<App, State, Text, Action, Set, Hidden, Width> {
+    /// Set the maximum length of the text in bytes, as given by
+    /// [`Application::encoded_length`](crate::application::Application::encoded_length).
+    pub fn with_length(self, length: usize) -> InputFieldBuilder<App, State, Text, Action, Set, Hidden, History, Completion, Width> {
         InputFieldBuilder {
             length,
             marker: PhantomData,
@@ -109,12 +122,13 @@ where
     }
 }
 
-impl<App, State, Text, Action, Length, Width> InputFieldBuilder<App, State, Text, Action, Length, Unset, Width>
+impl<App, State, Text, Action, Length, History, Completion, Width>
+    InputFieldBuilder<App, State, Text, Action, Length, Unset, History, Completion, Width>
 where
     App: Application,
 {
     /// Only show text as `*` characters. Useful for password fields.
-    pub fn hidden(self) -> InputFieldBuilder<App, State, Text, Action, Length, Set, Width> {
+    pub fn hidden(self) -> InputFieldBuilder<App, State, Text, Action, Length, Set, History, Completion, Width> {
         InputFieldBuilder {
             hidden: true,
             marker: PhantomData,
@@ -123,11 +137,54 @@ where
     }
 }
 
-impl<App, State, Text, Action, Length, Hidden> InputFieldBuilder<App, State, Text, Action, Length, Hidden, Unset>
+impl<App, State, Text, Action, Length, Hidden, Completion, Width>
+    InputFieldBuilder<App, State, Text, Action, Length, Hidden, Unset, Completion, Width>
+where
+    App: Application,
+{
+    /// Remember submitted text, so previous entries can be recalled with the
+    /// arrow keys. The history can be shared between multiple fields.
+    pub fn with_history(
+        self,
+        history: PlainTrackedState<Vec<String>>,
+    ) -> InputFieldBuilder<App, State, Text, Action, Length, Hidden, Set, Completion, Width> {
+        InputFieldBuilder {
+            history: Some(history),
+            marker: PhantomData,
+            ..self
+        }
+    }
+}
+
+impl<App, State, Text, Action, Length, Hidden, History, Width>
+    InputFieldBuilder<App, State, Text, Action, Length, Hidden, History, Unset, Width>
 where
     App: Application,
 {
-    pub fn with_width_bound(self, width_bound: DimensionBound) -> InputFieldBuilder<App, State, Text, Action, Length, Hidden, Set> {
+    /// Complete the text when the user presses the tab key. The completion
+    /// receives the current text and returns the completed text, or `None` if
+    /// there is nothing to complete.
+    pub fn with_completion(
+        self,
+        completion: impl Fn(&str) -> Option<String> + 'static,
+    ) -> InputFieldBuilder<App, State, Text, Action, Length, Hidden, History, Set, Width> {
+        InputFieldBuilder {
+            completion: Some(Box::new(completion)),
+            marker: PhantomData,
+            ..self
+        }
+    }
+}
+
+impl<App, State, Text, Action, Length, Hidden, History, Completion>
+    InputFieldBuilder<App, State, Text, Action, Length, Hidden, History, Completion, Unset>
+where
+    App: Application,
+{
+    pub fn with_width_bound(
+        self,
+        width_bound: DimensionBound,
+    ) -> InputFieldBuilder<App, State, Text, Action, Length, Hidden, History, Completion, Set> {
         InputFieldBuilder {
             width_bound,
             marker: PhantomData,
@@ -136,7 +193,8 @@ where
     }
 }
 
-impl<App, Text, Hidden, Width> InputFieldBuilder<App, PlainTrackedState<String>, Text, EnterAction<App>, Set, Hidden, Width>
+impl<App, Text, Hidden, History, Completion, Width>
+    InputFieldBuilder<App, PlainTrackedState<String>, Text, EnterAction<App>, Set, Hidden, History, Completion, Width>
 where
     App: Application,
     Text: Display + 'static,
@@ -154,6 +212,8 @@ where
             enter_action,
             length,
             hidden,
+            history,
+            completion,
             width_bound,
             ..
         } = self;
@@ -164,7 +224,14 @@ where
             enter_action,
             length,
             hidden,
+            history,
+            completion,
             width_bound,
+            editor: Default::default(),
+            character_offsets: Default::default(),
+            preedit: String::new(),
+            preedit_cursor: None,
+            cursor_area: Cell::new(None),
             state: Default::default(),
         }
     }
@@ -0,0 +1,522 @@
+use std::ops::Range;
+
+use crate::event::EditAction;
+
+/// Maximum number of steps that can be undone.
+const UNDO_LIMIT: usize = 100;
+/// Maximum number of entries kept in the history of an input field.
+const HISTORY_LIMIT: usize = 50;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum EditResult {
+    Unchanged,
+    /// Only the cursor or the selection changed.
+    Moved,
+    Edited,
+}
+
+struct Snapshot {
+    text: String,
+    cursor: usize,
+}
+
+/// Cursor, selection and undo state of an input field. The text itself is
+/// owned by the state of the field, so it's passed in for every operation.
+#[derive(Default)]
+pub struct TextEditor {
+    /// Byte offset of the cursor.
+    cursor: usize,
+    /// Byte offset where the selection started, if any.
+    anchor: Option<usize>,
+    undo_stack: Vec<Snapshot>,
+    redo_stack: Vec<Snapshot>,
+    /// Set while the user is typing, so consecutive characters are undone in
+    /// one step.
+    typing: bool,
+    history_index: Option<usize>,
+    /// Text that was in the field before browsing the history.
+    draft: String,
+}
+
+fn floor_boundary(text: &str, mut index: usize) -> usize {
+    index = index.min(text.len());
+
+    while !text.is_char_boundary(index) {
+        index -= 1;
+    }
+
+    index
+}
+
+fn previous_boundary(text: &str, index: usize) -> usize {
+    text[..index].char_indices().next_back().map_or(0, |(index, _)| index)
+}
+
+fn next_boundary(text: &str, index: usize) -> usize {
+    text[index..].chars().next().map_or(index, |character| index + character.len_utf8())
+}
+
+fn previous_word_boundary(text: &str, index: usize) -> usize {
+    let mut characters = text[..index].char_indices().rev().peekable();
+
+    while characters.next_if(|(_, character)| character.is_whitespace()).is_some() {}
+
+    let mut boundary = characters.peek().map_or(0, |(index, _)| *index);
+
+    while let Some((index, _)) = characters.next_if(|(_, character)| !character.is_whitespace()) {
+        boundary = index;
+    }
+
+    boundary
+}
+
+fn next_word_boundary(text: &str, index: usize) -> usize {
+    let mut characters = text[index..].char_indices().peekable();
+
+    while characters.next_if(|(_, character)| !character.is_whitespace()).is_some() {}
+    while characters.next_if(|(_, character)| character.is_whitespace()).is_some() {}
+
+    characters.peek().map_or(text.len(), |(offset, _)| index + offset)
+}
+
+impl TextEditor {
+    pub fn cursor(&self, text: &str) -> usize {
+        floor_boundary(text, self.cursor)
+    }
+
+    /// Byte range of the selection, if any text is selected.
+    pub fn selection(&self, text: &str) -> Option<Range<usize>> {
+        let cursor = self.cursor(text);
+        let anchor = floor_boundary(text, self.anchor?);
+
+        (anchor != cursor).then(|| anchor.min(cursor)..anchor.max(cursor))
+    }
+
+    pub fn selected_text<'a>(&self, text: &'a str) -> Option<&'a str> {
+        self.selection(text).map(|range| &text[range])
+    }
+
+    /// Place the cursor at the given byte offset.
+    pub fn set_cursor(&mut self, text: &str, index: usize, select: bool) {
+        let cursor = self.cursor(text);
+
+        self.anchor = match select {
+            true => Some(self.anchor.unwrap_or(cursor)),
+            false => None,
+        };
+        self.cursor = floor_boundary(text, index);
+        self.typing = false;
+    }
+
+    /// Forget the undo steps and the history position, for example after the
+    /// text was submitted.
+    pub fn reset(&mut self) {
+        *self = Self::default();
+    }
+
+    /// Add the text to the history, skipping empty text and direct repeats.
+    pub fn push_history(history: &mut Vec<String>, text: &str) {
+        if text.is_empty() || history.last().is_some_and(|last| last == text) {
+            return;
+        }
+
+        history.push(text.to_owned());
+
+        if history.len() > HISTORY_LIMIT {
+            history.remove(0);
+        }
+    }
+
+    fn save_undo(&mut self, text: &str) {
+        self.undo_stack.push(Snapshot {
+            text: text.to_owned(),
+            cursor: self.cursor(text),
+        });
+        self.redo_stack.clear();
+
+        if self.undo_stack.len() > UNDO_LIMIT {
+            self.undo_stack.remove(0);
+        }
+    }
+
+    fn delete_range(&mut self, text: &mut String, range: Range<usize>) -> EditResult {
+        if range.is_empty() {
+            return EditResult::Unchanged;
+        }
+
+        self.save_undo(text);
+        text.replace_range(range.clone(), "");
+        self.cursor = range.start;
+        self.anchor = None;
+        self.typing = false;
+
+        EditResult::Edited
+    }
+
+    fn insert(&mut self, text: &mut String, insertion: &str, maximum_length: usize, encoded_length: &dyn Fn(&str) -> usize) -> EditResult {
+        let selection = self.selection(text);
+        let cursor = self.cursor(text);
+        let range = selection.clone().unwrap_or(cursor..cursor);
+
+        let mut length = encoded_length(&text[..range.start]) + encoded_length(&text[range.end..]);
+        let mut buffer = [0; 4];
+        let insertion: String = insertion
+            .chars()
+            .filter(|character| !character.is_control())
+            .take_while(|character| {
+                length += encoded_length(character.encode_utf8(&mut buffer));
+                length <= maximum_length
+            })
+            .collect();
+
+        if insertion.is_empty() && selection.is_none() {
+            return EditResult::Unchanged;
+        }
+
+        // Consecutive characters are undone in a single step.
+        let typing = selection.is_none() && insertion.chars().count() == 1;
+
+        if !(typing && self.typing) {
+            self.save_undo(text);
+        }
+
+        text.replace_range(range.clone(), &insertion);
+        self.cursor = range.start + insertion.len();
+        self.anchor = None;
+        self.typing = typing;
+
+        EditResult::Edited
+    }
+
+    fn restore(&mut self, text: &mut String, from_undo: bool) -> EditResult {
+        let (source, target) = match from_undo {
+            true => (&mut self.undo_stack, &mut self.redo_stack),
+            false => (&mut self.redo_stack, &mut self.undo_stack),
+        };
+
+        let Some(snapshot) = source.pop() else {
+            return EditResult::Unchanged;
+        };
+
+        target.push(Snapshot {
+            text: std::mem::replace(text, snapshot.text),
+            cursor: self.cursor,
+        });
+
+        self.cursor = snapshot.cursor;
+        self.anchor = None;
+        self.typing = false;
+
+        EditResult::Edited
+    }
+
+    fn browse_history(&mut self, text: &mut String, history: &[String], backwards: bool) -> EditResult {
+        let index = match (self.history_index, backwards) {
+            (None, true) if !history.is_empty() => {
+                self.draft = text.clone();
+                Some(history.len() - 1)
+            }
+            (Some(index), true) => Some(index.saturating_sub(1)),
+            (Some(index), false) if index + 1 < history.len() => Some(index + 1),
+            (Some(_), false) => None,
+            (None, _) => return EditResult::Unchanged,
+        };
+
+        *text = match index {
+            Some(index) => history[index].clone(),
+            None => std::mem::take(&mut self.draft),
+        };
+
+        self.history_index = index;
+        self.cursor = text.len();
+        self.anchor = None;
+        self.typing = false;
+
+        EditResult::Edited
+    }
+
+    /// Apply an edit action to the text. `encoded_length` is used to make sure
+    /// that the text never exceeds `maximum_length` bytes.
+    pub fn apply(
+        &mut self,
+        text: &mut String,
+        action: EditAction,
+        maximum_length: usize,
+        encoded_length: &dyn Fn(&str) -> usize,
+        history: &[String],
+    ) -> EditResult {
+        let cursor = self.cursor(text);
+        let selection = self.selection(text);
+
+        let result = match action {
+            EditAction::MoveLeft { word, select } => {
+                let target = match (word, &selection) {
+                    (false, Some(range)) if !select => range.start,
+                    (false, _) => previous_boundary(text, cursor),
+                    (true, _) => previous_word_boundary(text, cursor),
+                };
+                self.set_cursor(text, target, select);
+                EditResult::Moved
+            }
+            EditAction::MoveRight { word, select } => {
+                let target = match (word, &selection) {
+                    (false, Some(range)) if !select => range.end,
+                    (false, _) => next_boundary(text, cursor),
+                    (true, _) => next_word_boundary(text, cursor),
+                };
+                self.set_cursor(text, target, select);
+                EditResult::Moved
+            }
+            EditAction::MoveHome { select } => {
+                self.set_cursor(text, 0, select);
+                EditResult::Moved
+            }
+            EditAction::MoveEnd { select } => {
+                self.set_cursor(text, text.len(), select);
+                EditResult::Moved
+            }
+            EditAction::SelectAll => {
+                self.anchor = Some(0);
+                self.cursor = text.len();
+                EditResult::Moved
+            }
+            EditAction::DeleteBackward { word } => {
+                let range = selection.unwrap_or_else(|| match word {
+                    true => previous_word_boundary(text, cursor)..cursor,
+                    false => previous_boundary(text, cursor)..cursor,
+                });
+                self.delete_range(text, range)
+            }
+            EditAction::DeleteForward { word } => {
+                let range = selection.unwrap_or_else(|| match word {
+                    true => cursor..next_word_boundary(text, cursor),
+                    false => cursor..next_boundary(text, cursor),
+                });
+                self.delete_range(text, range)
+            }
+            EditAction::Insert(insertion) => self.insert(text, &insertion, maximum_length, encoded_length),
+            EditAction::DeleteSelection => selection.map_or(EditResult::Unchanged, |range| self.delete_range(text, range)),
+            // Composition text is kept by the input field until it is committed.
+            EditAction::Preedit { .. } => EditResult::Unchanged,
+            // Completion is handled by the input field.
+            EditAction::Complete => EditResult::Unchanged,
+            EditAction::Undo => self.restore(text, true),
+            EditAction::Redo => self.restore(text, false),
+            EditAction::HistoryPrevious => return self.browse_history(text, history, true),
+            EditAction::HistoryNext => return self.browse_history(text, history, false),
+        };
+
+        if result == EditResult::Edited {
+            self.history_index = None;
+        }
+
+        result
+    }
+}
+
+#[cfg(test)]
+mod editing {
+    use super::{EditResult, TextEditor};
+    use crate::event::EditAction;
+
+    fn apply(editor: &mut TextEditor, text: &mut String, action: EditAction) -> EditResult {
+        editor.apply(text, action, 20, &str::len, &[])
+    }
+
+    fn type_text(editor: &mut TextEditor, text: &mut String, input: &str) {
+        for character in input.chars() {
+            apply(editor, text, EditAction::Insert(character.to_string()));
+        }
+    }
+
+    #[test]
+    fn insert_at_cursor() {
+        let mut editor = TextEditor::default();
+        let mut text = String::new();
+
+        type_text(&mut editor, &mut text, "helo");
+        apply(&mut editor, &mut text, EditAction::MoveLeft {
+            word: false,
+            select: false,
+        });
+        type_text(&mut editor, &mut text, "l");
+
+        assert_eq!(text, "hello");
+        assert_eq!(editor.cursor(&text), 4);
+    }
+
+    #[test]
+    fn multi_byte_characters() {
+        let mut editor = TextEditor::default();
+        let mut text = String::new();
+
+        type_text(&mut editor, &mut text, "안녕");
+        apply(&mut editor, &mut text, EditAction::MoveLeft {
+            word: false,
+            select: false,
+        });
+        assert_eq!(editor.cursor(&text), 3);
+
+        apply(&mut editor, &mut text, EditAction::DeleteBackward { word: false });
+        assert_eq!(text, "녕");
+    }
+
+    #[test]
+    fn word_jumps() {
+        let mut editor = TextEditor::default();
+        let mut text = String::from("one two  three");
+
+        editor.set_cursor(&text, text.len(), false);
+        apply(&mut editor, &mut text, EditAction::MoveLeft { word: true, select: false });
+        assert_eq!(editor.cursor(&text), 9);
+
+        apply(&mut editor, &mut text, EditAction::MoveLeft { word: true, select: false });
+        assert_eq!(editor.cursor(&text), 4);
+
+        apply(&mut editor, &mut text, EditAction::MoveRight { word: true, select: false });
+        assert_eq!(editor.cursor(&text), 9);
+
+        apply(&mut editor, &mut text, EditAction::DeleteBackward { word: true });
+        assert_eq!(text, "one three");
+    }
+
+    #[test]
+    fn selection_is_replaced() {
+        let mut editor = TextEditor::default();
+        let mut text = String::from("hello world");
+
+        editor.set_cursor(&text, 0, false);
+        apply(&mut editor, &mut text, EditAction::MoveRight { word: true, select: true });
+        assert_eq!(editor.selected_text(&text), Some("hello "));
+
+        apply(&mut editor, &mut text, EditAction::Insert("bye ".to_owned()));
+        assert_eq!(text, "bye world");
+        assert_eq!(editor.selection(&text), None);
+
+        apply(&mut editor, &mut text, EditAction::SelectAll);
+        apply(&mut editor, &mut text, EditAction::DeleteSelection);
+        assert_eq!(text, "");
+    }
+
+    #[test]
+    fn length_limit() {
+        let mut editor = TextEditor::default();
+        let mut text = String::new();
+
+        apply(&mut editor, &mut text, EditAction::Insert("a".repeat(25)));
+        assert_eq!(text.len(), 20);
+
+        // Characters that would exceed the limit are not split.
+        let mut text = "a".repeat(18);
+        editor.set_cursor(&text, text.len(), false);
+        apply(&mut editor, &mut text, EditAction::Insert("b안".to_owned()));
+        assert_eq!(text.len(), 19);
+    }
+
+    #[test]
+    fn undo_and_redo() {
+        let mut editor = TextEditor::default();
+        let mut text = String::new();
+
+        type_text(&mut editor, &mut text, "hello");
+        apply(&mut editor, &mut text, EditAction::Insert(" world".to_owned()));
+        apply(&mut editor, &mut text, EditAction::DeleteBackward { word: false });
+
+        apply(&mut editor, &mut text, EditAction::Undo);
+        assert_eq!(text, "hello world");
+
+        apply(&mut editor, &mut text, EditAction::Undo);
+        assert_eq!(text, "hello");
+
+        // Typed characters are undone together.
+        apply(&mut editor, &mut text, EditAction::Undo);
+        assert_eq!(text, "");
+
+        assert_eq!(apply(&mut editor, &mut text, EditAction::Undo), EditResult::Unchanged);
+
+        apply(&mut editor, &mut text, EditAction::Redo);
+        assert_eq!(text, "hello");
+    }
+
+    #[test]
+    fn preedit_keeps_text() {
+        let mut editor = TextEditor::default();
+        let mut text = String::from("hello world");
+
+        editor.set_cursor(&text, 0, false);
+        apply(&mut editor, &mut text, EditAction::MoveRight { word: true, select: true });
+
+        let preedit = EditAction::Preedit {
+            text: "ㅎ".to_owned(),
+            cursor: Some((0, 3)),
+        };
+        assert_eq!(apply(&mut editor, &mut text, preedit), EditResult::Unchanged);
+        assert_eq!(text, "hello world");
+        assert_eq!(editor.selected_text(&text), Some("hello "));
+
+        // The committed composition replaces the selection.
+        apply(&mut editor, &mut text, EditAction::Insert("한".to_owned()));
+        assert_eq!(text, "한world");
+        assert_eq!(editor.cursor(&text), 3);
+
+        apply(&mut editor, &mut text, EditAction::Undo);
+        assert_eq!(text, "hello world");
+    }
+
+    #[test]
+    fn preedit_does_not_end_history_browsing() {
+        let mut editor = TextEditor::default();
+        let mut text = String::from("draft");
+        let history = vec!["first".to_owned(), "second".to_owned()];
+
+        editor.apply(&mut text, EditAction::HistoryPrevious, 20, &str::len, &history);
+        apply(&mut editor, &mut text, EditAction::Preedit {
+            text: "ㅎ".to_owned(),
+            cursor: None,
+        });
+        editor.apply(&mut text, EditAction::HistoryPrevious, 20, &str::len, &history);
+        assert_eq!(text, "first");
+    }
+
+    #[test]
+    fn browse_history() {
+        let mut editor = TextEditor::default();
+        let mut text = String::from("draft");
+        let mut history = Vec::new();
+
+        TextEditor::push_history(&mut history, "first");
+        TextEditor::push_history(&mut history, "second");
+        TextEditor::push_history(&mut history, "second");
+        TextEditor::push_history(&mut history, "");
+        assert_eq!(history, ["first", "second"]);
+
+        editor.apply(&mut text, EditAction::HistoryPrevious, 20, &str::len, &history);
+        assert_eq!(text, "second");
+
+        editor.apply(&mut text, EditAction::HistoryPrevious, 20, &str::len, &history);
+        editor.apply(&mut text, EditAction::HistoryPrevious, 20, &str::len, &history);
+        assert_eq!(text, "first");
+
+        editor.apply(&mut text, EditAction::HistoryNext, 20, &str::len, &history);
+        assert_eq!(text, "second");
+
+        editor.apply(&mut text, EditAction::HistoryNext, 20, &str::len, &history);
+        assert_eq!(text, "draft");
+        assert_eq!(editor.cursor(&text), 5);
+    }
+
+    #[test]
+    fn external_change_clamps_cursor() {
+        let mut editor = TextEditor::default();
+        let mut text = String::new();
+
+        type_text(&mut editor, &mut text, "message");
+        text.clear();
+
+        assert_eq!(editor.cursor(&text), 0);
+        assert_eq!(
+            apply(&mut editor, &mut text, EditAction::DeleteBackward { word: false }),
+            EditResult::Unchanged
+        );
+    }
+}
@@ -1,19 +1,23 @@
 mod builder;
+mod editor;
 
+use std::cell::{Cell, RefCell};
 use std::fmt::Display;
 
 pub use self::builder::InputFieldBuilder;
+use self::editor::{EditResult, TextEditor};
 use crate::application::{
     Application, CornerRadiusTraitExt, MouseInputModeTrait, PositionTrait, PositionTraitExt, ScalingTrait, SizeTrait,
 };
 use crate::elements::{Element, ElementState};
-use crate::event::{ChangeEvent, ClickAction, HoverInformation};
+use crate::event::{ChangeEvent, ClickAction, EditAction, HoverInformation};
 use crate::layout::{DimensionBound, PlacementResolver};
-use crate::state::{PlainTrackedState, TrackedState, ValueState};
+use crate::state::{PlainTrackedState, TrackedState, TrackedStateExt, ValueState};
 use crate::theme::{InputTheme, InterfaceTheme};
 
 /// Local type alias to simplify the builder.
 type EnterAction<App> = Box<dyn FnMut() -> Vec<ClickAction<App>>>;
+type CompletionFunction = Box<dyn Fn(&str) -> Option<String>>;
 
 pub struct InputField<App, Text>
 where
@@ -23,9 +27,23 @@ where
     input_state: PlainTrackedState<String>,
     ghost_text: Text,
     enter_action: EnterAction<App>,
+    /// Maximum length of the text in bytes, as given by
+    /// [`Application::encoded_length`].
     length: usize,
     hidden: bool,
+    history: Option<PlainTrackedState<Vec<String>>>,
+    completion: Option<CompletionFunction>,
     width_bound: DimensionBound,
+    editor: TextEditor,
+    /// Horizontal offset of every character boundary from the last render,
+    /// used to place the cursor with the mouse.
+    character_offsets: RefCell<Vec<(usize, f32)>>,
+    /// Composition text of the input method, shown at the cursor until it is
+    /// committed.
+    preedit: String,
+    preedit_cursor: Option<(usize, usize)>,
+    /// Screen area of the cursor from the last render.
+    cursor_area: Cell<Option<(App::Position, App::Size)>>,
     state: ElementState<App>,
 }
 
@@ -34,28 +52,63 @@ where
     App: Application,
     Text: Display + 'static,
 {
-    fn remove_character(&mut self) -> Vec<ClickAction<App>> {
+    fn apply_edit(&mut self, application: &App, action: EditAction) -> Vec<ClickAction<App>> {
+        let history = self.history.as_ref().map(|history| history.get().clone()).unwrap_or_default();
+        let editor = &mut self.editor;
+        let length = self.length;
+
         self.input_state.with_mut(|input_state| {
-            if input_state.is_empty() {
-                return ValueState::Unchanged(Vec::new());
+            match editor.apply(input_state, action, length, &|text| application.encoded_length(text), &history) {
+                EditResult::Edited => ValueState::Mutated(vec![ClickAction::ChangeEvent(ChangeEvent::RENDER_WINDOW)]),
+                EditResult::Moved => ValueState::Unchanged(vec![ClickAction::ChangeEvent(ChangeEvent::RENDER_WINDOW)]),
+                EditResult::Unchanged => ValueState::Unchanged(Vec::new()),
             }
+        })
+    }
 
-            input_state.pop();
+    fn submit(&mut self) -> Vec<ClickAction<App>> {
+        if let Some(history) = &mut self.history {
+            let text = self.input_state.get().clone();
+            history.mutate(|history| TextEditor::push_history(history, &text));
+        }
 
-            ValueState::Mutated(vec![ClickAction::ChangeEvent(ChangeEvent::RENDER_WINDOW)])
-        })
+        self.editor.reset();
+        (self.enter_action)()
     }
 
-    fn add_character(&mut self, character: char) -> Vec<ClickAction<App>> {
-        self.input_state.with_mut(|input_state| {
-            if input_state.len() >= self.length {
-                return ValueState::Unchanged(Vec::new());
-            }
+    /// Replace the text with its completion. Fields without a completion
+    /// don't handle the action, so the key can be used for something else.
+    fn complete(&mut self, application: &App) -> (bool, Vec<ClickAction<App>>) {
+        let Some(completion) = &self.completion else {
+            return (false, Vec::new());
+        };
 
-            input_state.push(character);
+        let Some(completed_text) = completion(&self.input_state.get()) else {
+            return (true, Vec::new());
+        };
 
-            ValueState::Mutated(vec![ClickAction::ChangeEvent(ChangeEvent::RENDER_WINDOW)])
-        })
+        let mut actions = self.apply_edit(application, EditAction::SelectAll);
+        actions.extend(self.apply_edit(application, EditAction::Insert(completed_text)));
+
+        (true, actions)
+    }
+
+    /// Text as it is displayed, replacing every character with `*` if the
+    /// field is hidden.
+    fn display_text(&self, text: &str) -> String {
+        match self.hidden {
+            true => text.chars().map(|_| '*').collect(),
+            false => text.to_owned(),
+        }
+    }
+
+    /// Byte offset of the position in the displayed text that corresponds to
+    /// the byte offset in the actual text.
+    fn display_index(&self, text: &str, index: usize) -> usize {
+        match self.hidden {
+            true => text[..index].chars().count(),
+            false => index,
+        }
     }
 }
 
@@ -84,16 +137,72 @@ where
         }
     }
 
-    fn left_click(&mut self, _update: &mut bool) -> Vec<ClickAction<App>> {
+    fn left_click(&mut self, update: &mut bool) -> Vec<ClickAction<App>> {
+        let mouse_offset = self.state.mouse_position.get().left();
+        let closest_index = self
+            .character_offsets
+            .borrow()
+            .iter()
+            .min_by(|(_, first), (_, second)| (first - mouse_offset).abs().total_cmp(&(second - mouse_offset).abs()))
+            .map(|(index, _)| *index);
+
+        if let Some(index) = closest_index {
+            self.editor.set_cursor(&self.input_state.get(), index, false);
+            // Make sure the cursor is rendered at the new position.
+            *update = true;
+        }
+
         vec![ClickAction::FocusElement]
     }
 
-    fn input_character(&mut self, character: char) -> (bool, Vec<ClickAction<App>>) {
-        (true, match character {
-            '\u{8}' | '\u{7f}' => self.remove_character(),
-            '\r' => (self.enter_action)(),
-            character => self.add_character(character),
-        })
+    fn input_character(&mut self, application: &App, character: char) -> (bool, Vec<ClickAction<App>>) {
+        match character {
+            // Enter is used by the input method to commit the composition.
+            '\r' if !self.preedit.is_empty() => (true, Vec::new()),
+            '\r' => (true, self.submit()),
+            // Control characters such as backspace are handled as edit actions.
+            character if character.is_control() => (false, Vec::new()),
+            character => (true, self.apply_edit(application, EditAction::Insert(character.to_string()))),
+        }
+    }
+
+    fn edit(&mut self, application: &App, action: EditAction) -> (bool, Vec<ClickAction<App>>) {
+        match action {
+            EditAction::Preedit { text, cursor } => {
+                self.preedit = text;
+                self.preedit_cursor =
+                    cursor.filter(|(start, end)| self.preedit.is_char_boundary(*start) && self.preedit.is_char_boundary(*end));
+
+                return (true, vec![ClickAction::ChangeEvent(ChangeEvent::RENDER_WINDOW)]);
+            }
+            EditAction::Insert(..) => self.preedit.clear(),
+            // While composing, keys are handled by the input method.
+            _ if !self.preedit.is_empty() => return (true, Vec::new()),
+            EditAction::HistoryPrevious | EditAction::HistoryNext if self.history.is_none() => return (false, Vec::new()),
+            EditAction::Complete => return self.complete(application),
+            _ => {}
+        }
+
+        (true, self.apply_edit(application, action))
+    }
+
+    fn selected_text(&self) -> Option<String> {
+        match self.hidden {
+            true => None,
+            false => self.editor.selected_text(&self.input_state.get()).map(str::to_owned),
+        }
+    }
+
+    fn is_composing(&self) -> bool {
+        !self.preedit.is_empty()
+    }
+
+    fn text_cursor_area(&self) -> Option<(App::Position, App::Size)> {
+        // Input methods are not used for hidden text like passwords.
+        match self.hidden {
+            true => None,
+            false => self.cursor_area.get(),
+        }
     }
 
     fn render(
@@ -115,12 +224,16 @@ where
         let is_focused = self.is_element_self(focused_element);
         let text_offset = theme.input().text_offset();
 
+        let cursor = self.editor.cursor(&input_state);
+        let display_cursor = self.display_index(&input_state, cursor);
+
+        let mut displayed_text = self.display_text(&input_state);
+        displayed_text.insert_str(display_cursor, &self.preedit);
+
         let text = if input_state.is_empty() && !is_focused {
             self.ghost_text.to_string()
-        } else if self.hidden {
-            input_state.chars().map(|_| '*').collect()
         } else {
-            input_state.clone()
+            displayed_text.clone()
         };
 
         let background_color = if is_hovererd {
@@ -139,22 +252,96 @@ where
             theme.input().text_color()
         };
 
+        let scaling = application.get_scaling().get_factor();
+        let font_size = theme.input().font_size();
+        let text_start = text_offset.left() * scaling;
+        let offset_in_display = |display_index: usize| {
+            text_start
+                + renderer
+                    .get_text_dimensions(&displayed_text[..display_index], font_size, f32::MAX)
+                    .width()
+        };
+
+        // Only the character boundaries are valid cursor positions. The composition
+        // text is skipped, since it's not part of the actual text yet.
+        let character_offsets: Vec<(usize, f32)> = input_state
+            .char_indices()
+            .map(|(index, _)| index)
+            .chain(std::iter::once(input_state.len()))
+            .map(|index| {
+                let display_index = match index > cursor {
+                    true => self.display_index(&input_state, index) + self.preedit.len(),
+                    false => self.display_index(&input_state, index),
+                };
+                (index, offset_in_display(display_index))
+            })
+            .collect();
+
+        let offset_of = |index: usize| {
+            character_offsets
+                .iter()
+                .find(|(boundary, _)| *boundary == index)
+                .map_or(text_start, |(_, offset)| *offset)
+        };
+
+        let preedit_start = offset_in_display(display_cursor);
+        let preedit_end = offset_in_display(display_cursor + self.preedit.len());
+
+        let cursor_offset = match self.preedit.is_empty() {
+            true => Some(offset_of(cursor)),
+            false => self.preedit_cursor.map(|(start, _)| offset_in_display(display_cursor + start)),
+        };
+
         renderer.render_background(theme.input().corner_radius(), background_color);
-        renderer.render_text(&text, text_offset, text_color, theme.input().font_size());
 
-        if is_focused {
-            let cursor_offset = (text_offset.left() + theme.input().cursor_offset()) * application.get_scaling().get_factor()
-                + renderer.get_text_dimensions(&text, theme.input().font_size(), f32::MAX).width();
+        if is_focused && let Some(selection) = self.editor.selection(&input_state) {
+            let start = offset_of(selection.start);
+            let selection_position = App::Position::only_left(start);
+            let selection_size = App::Size::new(offset_of(selection.end) - start, self.state.cached_size.height());
+
+            renderer.render_rectangle(
+                selection_position,
+                selection_size,
+                App::CornerRadius::zero(),
+                theme.input().selection_color(),
+            );
+        }
+
+        renderer.render_text(&text, text_offset, text_color, font_size);
 
-            let cursor_position = App::Position::only_left(cursor_offset);
-            let cursor_size = App::Size::new(theme.input().cursor_width(), self.state.cached_size.height());
+        // Underline the composition text.
+        if is_focused && !self.preedit.is_empty() {
+            let underline_height = scaling.max(1.0);
+            let underline_position = App::Position::new(preedit_start, self.state.cached_size.height() - underline_height * 2.0);
+            let underline_size = App::Size::new(preedit_end - preedit_start, underline_height);
 
             renderer.render_rectangle(
-                cursor_position,
-                cursor_size,
+                underline_position,
+                underline_size,
                 App::CornerRadius::zero(),
                 theme.input().text_color(),
             );
         }
+
+        let cursor_area = match is_focused {
+            true => cursor_offset.map(|cursor_offset| {
+                let cursor_offset = cursor_offset + theme.input().cursor_offset() * scaling;
+                let cursor_size = App::Size::new(theme.input().cursor_width(), self.state.cached_size.height());
+
+                renderer.render_rectangle(
+                    App::Position::only_left(cursor_offset),
+                    cursor_size,
+                    App::CornerRadius::zero(),
+                    theme.input().text_color(),
+                );
+
+                let cursor_position = App::Position::new(renderer.position.left() + cursor_offset, renderer.position.top());
+                (cursor_position, cursor_size)
+            }),
+            false => None,
+        };
+
+        self.cursor_area.set(cursor_area);
+        *self.character_offsets.borrow_mut() = character_offsets;
     }
 }
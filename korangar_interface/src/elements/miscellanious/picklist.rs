@@ -8,6 +8,7 @@ use crate::event::{ClickAction, HoverInformation};
 use crate::layout::{Dimension, DimensionBound, PlacementResolver, SizeBound};
 use crate::state::{TrackedState, TrackedStateClone};
 use crate::theme::{ButtonTheme, InterfaceTheme};
+use crate::tooltip::Tooltip;
 
 pub struct PickList<App, Key, Value, State, Event>
 where
@@ -21,6 +22,7 @@ where
     selected: Option<State>,
     event: Option<Event>,
     width_bound: Option<DimensionBound>,
+    tooltip: Option<Tooltip>,
     state: ElementState<App>,
     latest_position: Rc<RefCell<App::Position>>,
     latest_size: Rc<RefCell<App::Size>>,
@@ -42,6 +44,7 @@ where
             selected: Default::default(),
             event: Default::default(),
             width_bound: Default::default(),
+            tooltip: Default::default(),
             state: Default::default(),
             latest_position: Rc::new(RefCell::new(App::Position::zero())),
             latest_size: Rc::new(RefCell::new(App::Size::zero())),
@@ -76,6 +79,11 @@ where
         self.width_bound = Some(width_bound);
         self
     }
+
+    pub fn with_tooltip(mut self, tooltip: Tooltip) -> Self {
+        self.tooltip = Some(tooltip);
+        self
+    }
 }
 
 impl<App, Key, Value, State, Event> Element<App> for PickList<App, Key, Value, State, Event>
@@ -113,6 +121,10 @@ where
         }
     }
 
+    fn tooltip(&self) -> Option<Tooltip> {
+        self.tooltip.clone()
+    }
+
     fn left_click(&mut self, _force_update: &mut bool) -> Vec<ClickAction<App>> {
         let position_tracker = {
             let latest_position = Rc::downgrade(&self.latest_position);
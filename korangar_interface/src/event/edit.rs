@@ -0,0 +1,46 @@
+/// Text editing action for the focused element, usually created from a key
+/// press.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum EditAction {
+    MoveLeft {
+        word: bool,
+        select: bool,
+    },
+    MoveRight {
+        word: bool,
+        select: bool,
+    },
+    MoveHome {
+        select: bool,
+    },
+    MoveEnd {
+        select: bool,
+    },
+    SelectAll,
+    DeleteBackward {
+        word: bool,
+    },
+    DeleteForward {
+        word: bool,
+    },
+    /// Insert text at the cursor, replacing the current selection.
+    Insert(String),
+    /// Composition text of an input method that is not committed yet. The
+    /// cursor is a byte range inside of the composition text, or `None` if the
+    /// cursor should be hidden. Committed text is inserted with
+    /// [`Insert`](Self::Insert).
+    Preedit {
+        text: String,
+        cursor: Option<(usize, usize)>,
+    },
+    /// Delete the current selection. Used for cutting, after the selected text
+    /// was retrieved with
+    /// [`selected_text`](crate::elements::Element::selected_text).
+    DeleteSelection,
+    Undo,
+    Redo,
+    HistoryPrevious,
+    HistoryNext,
+    /// Complete the text, if the element supports completion.
+    Complete,
+}
@@ -10,6 +10,7 @@ pub mod event;
 pub mod layout;
 pub mod state;
 pub mod theme;
+pub mod tooltip;
 #[macro_use]
 pub mod elements;
 pub mod builder;
@@ -19,7 +20,7 @@ use std::marker::PhantomData;
 
 use application::{Application, FocusState, SizeTrait, SizeTraitExt, WindowCache};
 use elements::ElementCell;
-use event::{ChangeEvent, ClickAction, HoverInformation};
+use event::{ChangeEvent, ClickAction, EditAction, HoverInformation};
 // Re-export proc macros.
 pub use interface_procedural::{dimension_bound, size_bound};
 #[cfg(feature = "debug")]
@@ -295,12 +296,11 @@ where
     }
 
     #[cfg_attr(feature = "debug", korangar_debug::profile)]
-    pub fn drag_element(&mut self, element: &ElementCell<App>, _window_index: usize, mouse_delta: App::Position) {
-        //let (_window, post_update) = &mut self.windows[window_index];
+    pub fn drag_element(&mut self, element: &ElementCell<App>, window_index: usize, mouse_delta: App::Position) {
+        let (_, post_update) = &mut self.windows[window_index];
 
         if let Some(change_event) = element.borrow_mut().drag(mouse_delta) {
-            // TODO: Use the window post_update here (?)
-            Self::handle_change_event(&mut self.post_update, &mut PostUpdate::new(), change_event);
+            Self::handle_change_event(&mut self.post_update, post_update, change_event);
         }
     }
 
@@ -316,14 +316,36 @@ where
     #[cfg_attr(feature = "debug", korangar_debug::profile)]
     pub fn input_character_element(
         &mut self,
+        application: &App,
         element: &ElementCell<App>,
         window_index: usize,
         character: char,
     ) -> (bool, Vec<ClickAction<App>>) {
+        let (key_handled, actions) = element.borrow_mut().input_character(application, character);
+        let propagated_actions = self.propagate_actions(window_index, actions);
+
+        (key_handled, propagated_actions)
+    }
+
+    #[cfg_attr(feature = "debug", korangar_debug::profile)]
+    pub fn edit_element(
+        &mut self,
+        application: &App,
+        element: &ElementCell<App>,
+        window_index: usize,
+        action: EditAction,
+    ) -> (bool, Vec<ClickAction<App>>) {
+        let (key_handled, actions) = element.borrow_mut().edit(application, action);
+        let propagated_actions = self.propagate_actions(window_index, actions);
+
+        (key_handled, propagated_actions)
+    }
+
+    /// Apply change events to the window and return all other actions.
+    fn propagate_actions(&mut self, window_index: usize, actions: Vec<ClickAction<App>>) -> Vec<ClickAction<App>> {
         let (_, post_update) = &mut self.windows[window_index];
         let mut propagated_actions = Vec::new();
 
-        let (key_handled, actions) = element.borrow_mut().input_character(character);
         for action in actions {
             match action {
                 ClickAction::ChangeEvent(change_event) => Self::handle_change_event(&mut self.post_update, post_update, change_event),
@@ -331,7 +353,7 @@ where
             }
         }
 
-        (key_handled, propagated_actions)
+        propagated_actions
     }
 
     #[cfg_attr(feature = "debug", korangar_debug::profile)]
@@ -124,6 +124,7 @@ where
     fn text_color(&self) -> App::Color;
     fn ghost_text_color(&self) -> App::Color;
     fn focused_text_color(&self) -> App::Color;
+    fn selection_color(&self) -> App::Color;
     fn corner_radius(&self) -> App::CornerRadius;
     fn font_size(&self) -> App::FontSize;
     fn text_offset(&self) -> App::Position;
@@ -195,6 +196,59 @@ where
     fn walking(&self) -> App::Color;
 }
 
+pub trait TooltipTheme<App>
+where
+    App: Application,
+{
+    fn background_color(&self) -> App::Color;
+    fn title_color(&self) -> App::Color;
+    fn foreground_color(&self) -> App::Color;
+    fn corner_radius(&self) -> App::CornerRadius;
+    fn text_offset(&self) -> App::Position;
+    fn mouse_offset(&self) -> App::Position;
+    fn title_font_size(&self) -> App::FontSize;
+    fn font_size(&self) -> App::FontSize;
+    fn maximum_width(&self) -> f32;
+    /// Time in seconds that an element needs to be hovered before the tooltip
+    /// is shown.
+    fn delay(&self) -> f32;
+}
+
+pub trait TabTheme<App>
+where
+    App: Application,
+{
+    fn background_color(&self) -> App::Color;
+    fn hovered_background_color(&self) -> App::Color;
+    fn selected_background_color(&self) -> App::Color;
+    fn foreground_color(&self) -> App::Color;
+    fn selected_foreground_color(&self) -> App::Color;
+    fn corner_radius(&self) -> App::CornerRadius;
+    fn text_offset(&self) -> App::Position;
+    fn font_size(&self) -> App::FontSize;
+    fn height_bound(&self) -> DimensionBound;
+}
+
+pub trait TableTheme<App>
+where
+    App: Application,
+{
+    fn header_background_color(&self) -> App::Color;
+    fn hovered_header_background_color(&self) -> App::Color;
+    fn header_foreground_color(&self) -> App::Color;
+    fn row_background_color(&self) -> App::Color;
+    fn second_row_background_color(&self) -> App::Color;
+    fn row_foreground_color(&self) -> App::Color;
+    fn resize_handle_color(&self) -> App::Color;
+    fn corner_radius(&self) -> App::CornerRadius;
+    fn text_offset(&self) -> App::Position;
+    fn font_size(&self) -> App::FontSize;
+    fn header_height_bound(&self) -> DimensionBound;
+    fn row_height(&self) -> f32;
+    /// Minimum width of a column in percent of the table width.
+    fn minimum_column_width(&self) -> f32;
+}
+
 pub trait InterfaceTheme {
     type Settings: Application;
     type Button: ButtonTheme<Self::Settings>;
@@ -207,6 +261,9 @@ pub trait InterfaceTheme {
     type Input: InputTheme<Self::Settings>;
     type Profiler: ProfilerTheme<Self::Settings>;
     type Chat: ChatTheme<Self::Settings>;
+    type Tooltip: TooltipTheme<Self::Settings>;
+    type Tab: TabTheme<Self::Settings>;
+    type Table: TableTheme<Self::Settings>;
 
     fn button(&self) -> &Self::Button;
     fn window(&self) -> &Self::Window;
@@ -218,4 +275,7 @@ pub trait InterfaceTheme {
     fn input(&self) -> &Self::Input;
     fn profiler(&self) -> &Self::Profiler;
     fn chat(&self) -> &Self::Chat;
+    fn tooltip(&self) -> &Self::Tooltip;
+    fn tab(&self) -> &Self::Tab;
+    fn table(&self) -> &Self::Table;
 }
@@ -0,0 +1,222 @@
+use std::rc::Rc;
+
+use crate::application::{
+    Application, ClipTrait, CornerRadiusTraitExt, FontSizeTrait, FontSizeTraitExt, InterfaceRenderer, PositionTrait, PositionTraitExt,
+    ScalingTrait, SizeTrait,
+};
+use crate::elements::{ElementCell, WeakElementCell};
+use crate::theme::{InterfaceTheme, TooltipTheme};
+
+/// Content of a tooltip. Every line is rendered separately and wrapped to
+/// the maximum width of the tooltip.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Tooltip {
+    pub title: String,
+    pub lines: Vec<String>,
+}
+
+impl Tooltip {
+    pub fn new(title: impl Into<String>) -> Self {
+        Self {
+            title: title.into(),
+            lines: Vec::new(),
+        }
+    }
+
+    pub fn with_line(mut self, line: impl Into<String>) -> Self {
+        self.lines.push(line.into());
+        self
+    }
+
+    pub fn with_lines(mut self, lines: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        self.lines.extend(lines.into_iter().map(Into::into));
+        self
+    }
+}
+
+/// Keeps track of how long the same element has been hovered, so that the
+/// tooltip is only shown after a delay.
+pub struct TooltipState<App>
+where
+    App: Application,
+{
+    hovered_element: Option<WeakElementCell<App>>,
+    hovered_time: f32,
+}
+
+impl<App> Default for TooltipState<App>
+where
+    App: Application,
+{
+    fn default() -> Self {
+        Self {
+            hovered_element: None,
+            hovered_time: 0.0,
+        }
+    }
+}
+
+impl<App> TooltipState<App>
+where
+    App: Application,
+{
+    /// Update the hovered element. Changing the hovered element restarts the
+    /// delay.
+    pub fn update(&mut self, hovered_element: Option<&ElementCell<App>>, delta_time: f32) {
+        let same_element = self
+            .hovered_element
+            .as_ref()
+            .zip(hovered_element)
+            .is_some_and(|(previous, current)| previous.ptr_eq(&Rc::downgrade(current)));
+
+        match same_element {
+            true => self.hovered_time += delta_time,
+            false => {
+                self.hovered_element = hovered_element.map(Rc::downgrade);
+                self.hovered_time = 0.0;
+            }
+        }
+    }
+
+    /// Tooltip of the hovered element, if it has been hovered for at least
+    /// `delay` seconds.
+    pub fn tooltip(&self, delay: f32) -> Option<Tooltip> {
+        if self.hovered_time < delay {
+            return None;
+        }
+
+        self.hovered_element
+            .as_ref()
+            .and_then(|element| element.upgrade())
+            .and_then(|element| element.borrow().tooltip())
+    }
+
+    pub fn render(
+        &self,
+        renderer: &App::Renderer,
+        application: &App,
+        theme: &App::Theme,
+        mouse_position: App::Position,
+        available_space: App::Size,
+    ) {
+        let theme = theme.tooltip();
+
+        let Some(tooltip) = self.tooltip(theme.delay()) else {
+            return;
+        };
+
+        let scaling = application.get_scaling();
+        let title_font_size = theme.title_font_size().scaled(scaling);
+        let font_size = theme.font_size().scaled(scaling);
+        let text_offset = theme.text_offset().scaled(scaling);
+        let mouse_offset = theme.mouse_offset().scaled(scaling);
+        let maximum_width = theme.maximum_width() * scaling.get_factor();
+
+        // Empty lines are used as separators, so they still take up space.
+        let line_height = |text: &str, font_size: App::FontSize, text_height: f32| match text.is_empty() {
+            true => font_size.get_value(),
+            false => text_height,
+        };
+
+        let title_size = renderer.get_text_dimensions(&tooltip.title, title_font_size, maximum_width);
+        let title_height = line_height(&tooltip.title, title_font_size, title_size.height());
+        let line_sizes: Vec<(f32, f32)> = tooltip
+            .lines
+            .iter()
+            .map(|line| {
+                let size = renderer.get_text_dimensions(line, font_size, maximum_width);
+                (size.width(), line_height(line, font_size, size.height()))
+            })
+            .collect();
+
+        let content_width = line_sizes.iter().map(|(width, _)| *width).fold(title_size.width(), f32::max);
+        let content_height = title_height + line_sizes.iter().map(|(_, height)| *height).sum::<f32>();
+        let size = App::Size::new(
+            content_width + text_offset.left() * 2.0,
+            content_height + text_offset.top() * 2.0,
+        );
+
+        let position = App::Position::new(
+            place_on_axis(
+                mouse_position.left(),
+                mouse_offset.left(),
+                size.width(),
+                available_space.width(),
+            ),
+            place_on_axis(
+                mouse_position.top(),
+                mouse_offset.top(),
+                size.height(),
+                available_space.height(),
+            ),
+        );
+
+        // Text is wrapped at the right edge of the clip, so it needs to match the
+        // width used for measuring.
+        let text_position = position.combined(text_offset);
+        let clip = App::Clip::new(
+            position.left(),
+            position.top(),
+            text_position.left() + maximum_width,
+            position.top() + size.height(),
+        );
+
+        renderer.render_rectangle(
+            position,
+            size,
+            clip,
+            theme.corner_radius().scaled(scaling),
+            theme.background_color(),
+        );
+
+        renderer.render_text(&tooltip.title, text_position, clip, theme.title_color(), title_font_size);
+
+        let mut top = text_position.top() + title_height;
+
+        for (line, (_, height)) in tooltip.lines.iter().zip(line_sizes) {
+            let position = App::Position::new(text_position.left(), top);
+            renderer.render_text(line, position, clip, theme.foreground_color(), font_size);
+            top += height;
+        }
+    }
+}
+
+/// Place the tooltip after the mouse if there is enough space, otherwise
+/// before it. If it fits on neither side, it's pushed against the edge of
+/// the screen.
+fn place_on_axis(mouse: f32, offset: f32, size: f32, available: f32) -> f32 {
+    let after = mouse + offset;
+
+    if after + size <= available {
+        return after;
+    }
+
+    let before = mouse - offset - size;
+
+    if before >= 0.0 {
+        return before;
+    }
+
+    (available - size).max(0.0)
+}
+
+#[cfg(test)]
+mod placement {
+    use super::place_on_axis;
+
+    #[test]
+    fn after_mouse() {
+        assert_eq!(place_on_axis(100.0, 10.0, 50.0, 500.0), 110.0);
+    }
+
+    #[test]
+    fn before_mouse_at_screen_edge() {
+        assert_eq!(place_on_axis(480.0, 10.0, 50.0, 500.0), 420.0);
+    }
+
+    #[test]
+    fn pushed_against_edge() {
+        assert_eq!(place_on_axis(100.0, 10.0, 400.0, 500.0), 100.0);
+        assert_eq!(place_on_axis(100.0, 10.0, 600.0, 500.0), 0.0);
+    }
+}
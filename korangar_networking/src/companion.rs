@@ -0,0 +1,120 @@
+/// The different kinds of companions a player can own.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CompanionType {
+    Homunculus,
+    Mercenary,
+    Pet,
+}
+
+/// A single property of a companion that was updated by the map server.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CompanionUpdate {
+    /// The companion was spawned. The server only sends this to the owner, so
+    /// it can be used to determine the entity id of the player's companion.
+    Spawned,
+    Intimacy(u32),
+    Hunger(u32),
+    Accessory(u32),
+    Performance(u32),
+    Hairstyle(u32),
+}
+
+impl CompanionUpdate {
+    pub(crate) fn from_homunculus_state(state: u8, value: u32) -> Option<Self> {
+        match state {
+            0 => Some(Self::Spawned),
+            1 => Some(Self::Intimacy(value)),
+            2 => Some(Self::Hunger(value)),
+            _ => None,
+        }
+    }
+
+    pub(crate) fn from_pet_state(state: u8, value: u32) -> Option<Self> {
+        match state {
+            0 => Some(Self::Spawned),
+            1 => Some(Self::Intimacy(value)),
+            2 => Some(Self::Hunger(value)),
+            3 => Some(Self::Accessory(value)),
+            4 => Some(Self::Performance(value)),
+            5 => Some(Self::Hairstyle(value)),
+            _ => None,
+        }
+    }
+}
+
+/// A single property of the player's mercenary that was updated by the map
+/// server.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MercenaryParameter {
+    HealthPoints(u32),
+    MaximumHealthPoints(u32),
+    SpellPoints(u32),
+    MaximumSpellPoints(u32),
+    Attack(u32),
+    MagicAttack(u32),
+    Defense(u32),
+    MagicDefense(u32),
+    Hit(u32),
+    Critical(u32),
+    AttackSpeed(u32),
+    Flee(u32),
+    KillCount(u32),
+    Loyalty(u32),
+}
+
+impl MercenaryParameter {
+    pub(crate) fn from_parameter(parameter: u16, value: u32) -> Option<Self> {
+        match parameter {
+            5 => Some(Self::HealthPoints(value)),
+            6 => Some(Self::MaximumHealthPoints(value)),
+            7 => Some(Self::SpellPoints(value)),
+            8 => Some(Self::MaximumSpellPoints(value)),
+            41 => Some(Self::Attack(value)),
+            43 => Some(Self::MagicAttack(value)),
+            45 => Some(Self::Defense(value)),
+            47 => Some(Self::MagicDefense(value)),
+            49 => Some(Self::Hit(value)),
+            52 => Some(Self::Critical(value)),
+            53 => Some(Self::AttackSpeed(value)),
+            165 => Some(Self::Flee(value)),
+            189 => Some(Self::KillCount(value)),
+            190 => Some(Self::Loyalty(value)),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod conversion {
+    use super::{CompanionUpdate, MercenaryParameter};
+
+    #[test]
+    fn homunculus_state() {
+        assert_eq!(CompanionUpdate::from_homunculus_state(0, 0), Some(CompanionUpdate::Spawned));
+        assert_eq!(CompanionUpdate::from_homunculus_state(2, 75), Some(CompanionUpdate::Hunger(75)));
+        assert_eq!(CompanionUpdate::from_homunculus_state(3, 0), None);
+    }
+
+    #[test]
+    fn pet_state() {
+        assert_eq!(CompanionUpdate::from_pet_state(1, 900), Some(CompanionUpdate::Intimacy(900)));
+        assert_eq!(
+            CompanionUpdate::from_pet_state(3, 10013),
+            Some(CompanionUpdate::Accessory(10013))
+        );
+        assert_eq!(CompanionUpdate::from_pet_state(6, 0), None);
+    }
+
+    #[test]
+    fn mercenary_parameter() {
+        assert_eq!(
+            MercenaryParameter::from_parameter(5, 1200),
+            Some(MercenaryParameter::HealthPoints(1200))
+        );
+        assert_eq!(
+            MercenaryParameter::from_parameter(189, 3),
+            Some(MercenaryParameter::KillCount(3))
+        );
+        assert_eq!(MercenaryParameter::from_parameter(9999, 0), None);
+    }
+}
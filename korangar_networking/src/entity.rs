@@ -1,8 +1,45 @@
 use ragnarok_packets::*;
 
+/// The kind of object the server reports when an entity appears.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ObjectType {
+    Player,
+    Npc,
+    Item,
+    Skill,
+    Chat,
+    Monster,
+    EventNpc,
+    Pet,
+    Homunculus,
+    Mercenary,
+    Elemental,
+    Unknown(u8),
+}
+
+impl From<u8> for ObjectType {
+    fn from(value: u8) -> Self {
+        match value {
+            0x0 => ObjectType::Player,
+            0x1 => ObjectType::Npc,
+            0x2 => ObjectType::Item,
+            0x3 => ObjectType::Skill,
+            0x4 => ObjectType::Chat,
+            0x5 => ObjectType::Monster,
+            0x6 => ObjectType::EventNpc,
+            0x7 => ObjectType::Pet,
+            0x8 => ObjectType::Homunculus,
+            0x9 => ObjectType::Mercenary,
+            0xA => ObjectType::Elemental,
+            other => ObjectType::Unknown(other),
+        }
+    }
+}
+
 #[derive(Debug)]
 pub struct EntityData {
     pub entity_id: EntityId,
+    pub object_type: ObjectType,
     pub movement_speed: u16,
     pub job: u16,
     pub head: u16,
@@ -18,6 +55,7 @@ impl EntityData {
     pub fn from_character(account_id: AccountId, character_information: &CharacterInformation, position: WorldPosition) -> Self {
         Self {
             entity_id: EntityId(account_id.0),
+            object_type: ObjectType::Player,
             movement_speed: character_information.movement_speed as u16,
             job: character_information.job as u16,
             head: character_information.head as u16,
@@ -35,6 +73,7 @@ impl From<EntityAppearedPacket> for EntityData {
     fn from(packet: EntityAppearedPacket) -> Self {
         Self {
             entity_id: packet.entity_id,
+            object_type: packet.object_type.into(),
             movement_speed: packet.movement_speed,
             job: packet.job,
             head: packet.head,
@@ -52,6 +91,7 @@ impl From<EntityAppeared2Packet> for EntityData {
     fn from(packet: EntityAppeared2Packet) -> Self {
         Self {
             entity_id: packet.entity_id,
+            object_type: packet.object_type.into(),
             movement_speed: packet.movement_speed,
             job: packet.job,
             head: packet.head,
@@ -71,6 +111,7 @@ impl From<MovingEntityAppearedPacket> for EntityData {
 
         Self {
             entity_id: packet.entity_id,
+            object_type: packet.object_type.into(),
             movement_speed: packet.movement_speed,
             job: packet.job,
             head: packet.head,
@@ -3,10 +3,10 @@ use std::time::Instant;
 use ragnarok_packets::*;
 
 use crate::hotkey::HotkeyState;
-use crate::items::ShopItem;
+use crate::items::{ShopItem, StoreType};
 use crate::{
-    CharacterServerLoginData, EntityData, InventoryItem, LoginServerLoginData, MessageColor, NoMetadata,
-    UnifiedCharacterSelectionFailedReason, UnifiedLoginFailedReason,
+    CharacterServerLoginData, CompanionType, CompanionUpdate, EntityData, InventoryItem, LoginServerLoginData, MercenaryParameter,
+    MessageColor, NoMetadata, UnifiedCharacterSelectionFailedReason, UnifiedLoginFailedReason,
 };
 
 /// An event triggered by one of the Ragnarok Online servers.
@@ -105,6 +105,13 @@ pub enum NetworkEvent {
     },
     HealEffect(EntityId, usize),
     UpdateStatus(StatusType),
+    /// Response to a request to raise one of the base stats. `value` is the
+    /// new base value of the stat.
+    StatUpResult {
+        stat_type: StatType,
+        success: bool,
+        value: u8,
+    },
     OpenDialog(String, EntityId),
     AddNextButton,
     AddCloseButton,
@@ -114,10 +121,21 @@ pub enum NetworkEvent {
     SetInventory {
         items: Vec<InventoryItem<NoMetadata>>,
     },
+    SetCartItems {
+        items: Vec<InventoryItem<NoMetadata>>,
+    },
     IventoryItemAdded {
         item: InventoryItem<NoMetadata>,
     },
     SkillTree(Vec<SkillInformation>),
+    /// The level of one of the player's skills changed.
+    SkillUpdated {
+        skill_id: SkillId,
+        skill_level: SkillLevel,
+        upgradable: bool,
+    },
+    /// The player can learn a new skill.
+    SkillAdded(SkillInformation),
     UpdateEquippedPosition {
         index: InventoryIndex,
         equipped_position: EquipPosition,
@@ -171,6 +189,197 @@ pub enum NetworkEvent {
         index: InventoryIndex,
         amount: u16,
     },
+    ItemPickupFailed {
+        result: ItemPickupResult,
+    },
+    /// An entity used an item. If the entity is the player, `amount` is the
+    /// number of items left in the inventory.
+    ItemUsed {
+        index: InventoryIndex,
+        item_id: ItemId,
+        entity_id: EntityId,
+        amount: u16,
+        success: bool,
+    },
+    /// An item appeared on the ground. `dropped` is true if the item was just
+    /// dropped, rather than coming into view.
+    GroundItemAppeared {
+        entity_id: EntityId,
+        item_id: ItemId,
+        is_identified: bool,
+        amount: u16,
+        position: TilePosition,
+        sub_position: (u8, u8),
+        dropped: bool,
+    },
+    GroundItemDisappeared {
+        entity_id: EntityId,
+    },
+    /// Update all properties of the player's homunculus.
+    HomunculusInformation(HomunculusInformationPacket),
+    /// Update all properties of the player's mercenary.
+    MercenaryInformation(MercenaryInformationPacket),
+    MercenaryParameterChanged(MercenaryParameter),
+    /// Update all properties of the player's pet.
+    PetInformation(PetInformationPacket),
+    /// A companion spawned or one of its properties changed.
+    CompanionUpdated {
+        companion_type: CompanionType,
+        entity_id: EntityId,
+        update: CompanionUpdate,
+    },
+    CompanionFed {
+        companion_type: CompanionType,
+        result: FeedResult,
+        item_id: ItemId,
+    },
+    /// The player used the vending skill and can now open a store.
+    VendingAvailable {
+        maximum_item_count: u16,
+    },
+    VendingStoreOpened {
+        result: OpenVendingStoreResult,
+    },
+    /// The items in the player's own vending store.
+    OwnVendingItems {
+        items: Vec<OwnVendingItemInformation>,
+    },
+    /// Someone bought an item from the player's store.
+    VendingItemSold {
+        cart_index: InventoryIndex,
+        amount: u16,
+        zeny: u32,
+    },
+    /// A player opened a store. The title is displayed above the player.
+    StoreEntryAdded {
+        entity_id: EntityId,
+        store_type: StoreType,
+        title: String,
+    },
+    StoreEntryRemoved {
+        entity_id: EntityId,
+    },
+    OpenVendingStore {
+        entity_id: EntityId,
+        store_id: u32,
+        items: Vec<ShopItem<NoMetadata>>,
+    },
+    BuyingVendingItemsFailed {
+        result: BuyVendingItemsResult,
+    },
+    OpenBuyingStore {
+        entity_id: EntityId,
+        store_id: u32,
+        zeny_limit: u32,
+        items: Vec<BuyingStoreItemInformation>,
+    },
+    /// The player sold an item to a buying store.
+    BuyingStoreItemSold {
+        inventory_index: InventoryIndex,
+        amount: u16,
+        zeny: u32,
+    },
+    SellingToBuyingStoreFailed {
+        item_id: ItemId,
+    },
+    /// The player used the buying store skill and can now open a store.
+    BuyingStoreAvailable {
+        maximum_item_count: u8,
+    },
+    OpeningBuyingStoreFailed {
+        result: u16,
+        total_weight: u32,
+    },
+    /// The player's own buying store was opened.
+    BuyingStoreOpened {
+        zeny_limit: u32,
+    },
+    /// Someone sold an item to the player's buying store.
+    BuyingStoreItemBought {
+        item_id: ItemId,
+        amount: u16,
+        zeny_limit: u32,
+    },
+    /// A trade with the player's buying store failed or the store ran out of
+    /// items or zeny to buy.
+    BuyingStoreTradeFailed {
+        result: u16,
+    },
+    /// Update the number of items and the weight of the player's cart.
+    UpdateCartCapacity {
+        item_count: u16,
+        maximum_item_count: u16,
+        weight: u32,
+        maximum_weight: u32,
+    },
+    CartItemAdded {
+        item: InventoryItem<NoMetadata>,
+    },
+    CartItemRemoved {
+        index: InventoryIndex,
+        amount: u16,
+    },
+    AddItemToCartFailed {
+        result: AddItemToCartResult,
+    },
+    CartRemoved,
+    /// The cart of an entity changed. A cart type of `None` means that the
+    /// entity is no longer pushing a cart.
+    ChangeCart {
+        entity_id: EntityId,
+        cart_type: Option<usize>,
+    },
+    /// Sent when logging in and whenever the player receives a new mail.
+    MailNotification {
+        has_new_mail: bool,
+    },
+    /// A page of mails from the mailbox. Mails are sent from newest to oldest.
+    MailList {
+        mails: Vec<MailInformation>,
+        is_end: bool,
+    },
+    MailContent {
+        mail_type: MailType,
+        mail_id: MailId,
+        text: String,
+        zeny: u64,
+        items: Vec<MailItemInformation>,
+    },
+    MailDeleted {
+        mail_id: MailId,
+    },
+    MailZenyReceived {
+        mail_id: MailId,
+        result: MailAttachmentResult,
+    },
+    MailItemsReceived {
+        mail_id: MailId,
+        result: MailAttachmentResult,
+    },
+    WriteMailOpened {
+        recipient: String,
+        success: bool,
+    },
+    /// Result of checking the recipient of a mail. The character id is `None`
+    /// if no character with that name exists.
+    MailRecipientChecked {
+        name: String,
+        character_id: Option<CharacterId>,
+        base_level: u16,
+    },
+    MailItemAttached {
+        result: AddItemToMailResult,
+        index: InventoryIndex,
+        amount: u16,
+    },
+    MailItemDetached {
+        success: bool,
+        index: InventoryIndex,
+        amount: u16,
+    },
+    MailSent {
+        result: SendMailResult,
+    },
 }
 
 /// New-type so we can implement some `From` traits. This will help when
@@ -41,6 +41,25 @@ impl<Meta> InventoryItem<Meta> {
             InventoryItemDetails::Equippable { flags, .. } => flags.contains(EquippableItemFlags::IDENTIFIED),
         }
     }
+
+    /// Whether the item is currently equipped. This includes ammunition, which
+    /// is a regular item.
+    pub fn is_equipped(&self) -> bool {
+        match &self.details {
+            InventoryItemDetails::Regular { equipped_position, .. } | InventoryItemDetails::Equippable { equipped_position, .. } => {
+                !equipped_position.is_empty()
+            }
+        }
+    }
+
+    /// The number of items in this stack. Equippable items don't stack, so
+    /// they always have an amount of one.
+    pub fn amount(&self) -> u16 {
+        match &self.details {
+            InventoryItemDetails::Regular { amount, .. } => *amount,
+            InventoryItemDetails::Equippable { .. } => 1,
+        }
+    }
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -67,6 +86,15 @@ pub struct ShopItem<Meta> {
     pub quantity: ItemQuantity,
     pub weight: u16,
     pub location: u32,
+    /// Index of the item in the vendor's cart. Only set for items sold by
+    /// other players, since they can sell multiple items with the same id.
+    pub cart_index: Option<InventoryIndex>,
+}
+
+impl<Meta> ShopItem<Meta> {
+    pub fn is_same_item<Other>(&self, other: &ShopItem<Other>) -> bool {
+        self.item_id == other.item_id && self.cart_index == other.cart_index
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -76,3 +104,12 @@ pub struct SellItem<Meta> {
     pub price: Price,
     pub overcharge_price: Price,
 }
+
+/// The kinds of stores players can open.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StoreType {
+    /// Sells items from the cart of the vendor.
+    Vending,
+    /// Buys items from other players.
+    BuyingStore,
+}
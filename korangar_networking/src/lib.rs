@@ -1,11 +1,13 @@
 #![feature(let_chains)]
 
+mod companion;
 mod entity;
 mod event;
 mod hotkey;
 mod items;
 mod message;
 mod server;
+mod status;
 
 use std::cell::RefCell;
 use std::net::{IpAddr, SocketAddr};
@@ -17,9 +19,9 @@ use event::{
     CharacterServerDisconnectedEvent, DisconnectedEvent, LoginServerDisconnectedEvent, MapServerDisconnectedEvent, NetworkEventList,
     NoNetworkEvents,
 };
-use ragnarok_bytes::encoding::UTF_8;
+use ragnarok_bytes::encoding::{EUC_KR, Encoding};
 use ragnarok_bytes::{ByteReader, ByteWriter, FromBytes};
-use ragnarok_packets::handler::{DuplicateHandlerError, HandlerResult, NoPacketCallback, PacketCallback, PacketHandler};
+use ragnarok_packets::handler::{DuplicateHandlerError, HandlerResult, NoPacketCallback, PacketCallback, PacketHandler, ServerType};
 use ragnarok_packets::*;
 use server::{ServerConnectCommand, ServerConnection};
 use tokio::io::{AsyncReadExt, AsyncWriteExt};
@@ -28,16 +30,30 @@ use tokio::sync::mpsc::error::TryRecvError;
 use tokio::sync::mpsc::{UnboundedReceiver, UnboundedSender};
 use tokio::task::JoinHandle;
 
-pub use self::entity::EntityData;
+pub use self::companion::{CompanionType, CompanionUpdate, MercenaryParameter};
+pub use self::entity::{EntityData, ObjectType};
 pub use self::event::{DisconnectReason, NetworkEvent};
 pub use self::hotkey::HotkeyState;
-pub use self::items::{InventoryItem, InventoryItemDetails, ItemQuantity, NoMetadata, SellItem, ShopItem};
+pub use self::items::{InventoryItem, InventoryItemDetails, ItemQuantity, NoMetadata, SellItem, ShopItem, StoreType};
 pub use self::message::MessageColor;
 pub use self::server::{
-    CharacterServerLoginData, LoginServerLoginData, NotConnectedError, UnifiedCharacterSelectionFailedReason, UnifiedLoginFailedReason,
+    CharacterServerLoginData, LoginServerLoginData, NotConnectedError, SendMailError, UnifiedCharacterSelectionFailedReason,
+    UnifiedLoginFailedReason,
 };
 use crate::server::NetworkTaskError;
 
+/// Highest length of a mail title in bytes, including the null terminator.
+pub const MAXIMUM_MAIL_TITLE_LENGTH: usize = 40;
+/// Highest length of a mail text in bytes, including the null terminator.
+pub const MAXIMUM_MAIL_TEXT_LENGTH: usize = 500;
+
+/// Length of a string in a mail packet, including the null terminator.
+/// Returns `None` if the encoded string is longer than `maximum_length`.
+fn mail_string_length(encoding: &'static Encoding, text: &str, maximum_length: usize) -> Option<u16> {
+    let length = encoding.encode(text).0.len() + 1;
+    (length <= maximum_length).then_some(length as u16)
+}
+
 /// Buffer for networking events. This struct exists to reduce heap allocations
 /// and is purely an optimization.
 pub struct NetworkEventBuffer(Vec<NetworkEvent>);
@@ -90,6 +106,8 @@ pub struct NetworkingSystem<Callback> {
     character_server_connection: ServerConnection,
     map_server_connection: ServerConnection,
     packet_callback: Callback,
+    /// Encoding of the strings sent to and received from the servers.
+    encoding: &'static Encoding,
 }
 
 impl NetworkingSystem<NoPacketCallback> {
@@ -115,6 +133,7 @@ where
             character_server_connection: ServerConnection::Disconnected,
             map_server_connection: ServerConnection::Disconnected,
             packet_callback,
+            encoding: EUC_KR,
         };
         let event_buffer = NetworkEventBuffer(Vec::new());
 
@@ -148,13 +167,15 @@ where
                             address,
                             action_receiver,
                             event_sender,
+                            encoding,
                         } => {
                             if let Some(handle) = login_server_task_handle.take() {
                                 // TODO: Maybe add a timeout here? Maybe handle Result?
                                 let _ = handle.await.unwrap();
                             }
 
-                            let packet_handler = Self::create_login_server_packet_handler(packet_callback.clone()).unwrap();
+                            let packet_handler =
+                                Self::create_login_server_packet_handler(packet_callback.for_server(ServerType::Login)).unwrap();
                             let handle = local_set.spawn_local(Self::handle_server_connection(
                                 address,
                                 action_receiver,
@@ -164,6 +185,7 @@ where
                                 Duration::from_secs(58),
                                 false,
                                 thread_time_synchronization.clone(),
+                                encoding,
                             ));
 
                             login_server_task_handle = Some(handle);
@@ -172,13 +194,15 @@ where
                             address,
                             action_receiver,
                             event_sender,
+                            encoding,
                         } => {
                             if let Some(handle) = character_server_task_handle.take() {
                                 // TODO: Maybe add a timeout here? Maybe handle Result?
                                 let _ = handle.await.unwrap();
                             }
 
-                            let packet_handler = Self::create_character_server_packet_handler(packet_callback.clone()).unwrap();
+                            let packet_handler =
+                                Self::create_character_server_packet_handler(packet_callback.for_server(ServerType::Character)).unwrap();
                             let handle = local_set.spawn_local(Self::handle_server_connection(
                                 address,
                                 action_receiver,
@@ -188,6 +212,7 @@ where
                                 Duration::from_secs(10),
                                 true,
                                 thread_time_synchronization.clone(),
+                                encoding,
                             ));
 
                             character_server_task_handle = Some(handle);
@@ -196,13 +221,15 @@ where
                             address,
                             action_receiver,
                             event_sender,
+                            encoding,
                         } => {
                             if let Some(handle) = map_server_task_handle.take() {
                                 // TODO: Maybe add a timeout here? Maybe handle Result?
                                 let _ = handle.await.unwrap();
                             }
 
-                            let packet_handler = Self::create_map_server_packet_handler(packet_callback.clone()).unwrap();
+                            let packet_handler =
+                                Self::create_map_server_packet_handler(packet_callback.for_server(ServerType::Map)).unwrap();
                             let handle = local_set.spawn_local(Self::handle_server_connection(
                                 address,
                                 action_receiver,
@@ -218,6 +245,7 @@ where
                                 Duration::from_secs(10),
                                 false,
                                 thread_time_synchronization.clone(),
+                                encoding,
                             ));
 
                             map_server_task_handle = Some(handle);
@@ -284,6 +312,7 @@ where
         // logic.
         mut read_account_id: bool,
         time_synchronization: Arc<Mutex<TimeSynchronization>>,
+        encoding: &'static Encoding,
     ) -> Result<(), NetworkTaskError>
     where
         PingPacket: Packet + ClientPacket,
@@ -294,7 +323,7 @@ where
         let mut buffer = [0u8; 8192];
         let mut cut_off_buffer_base = 0;
         let mut events = Vec::new();
-        let mut byte_writer = ByteWriter::with_encoding(UTF_8);
+        let mut byte_writer = ByteWriter::with_encoding(encoding);
 
         loop {
             tokio::select! {
@@ -322,7 +351,7 @@ where
 
                     let data = &buffer[..cut_off_buffer_base + received_bytes];
                     let mut byte_reader = ByteReader::without_metadata(data);
-                    byte_reader.set_encoding(UTF_8);
+                    byte_reader.set_encoding(encoding);
 
                     if read_account_id {
                         let account_id = AccountId::from_bytes(&mut byte_reader).unwrap();
@@ -393,14 +422,15 @@ where
                 address,
                 action_receiver,
                 event_sender,
+                encoding: self.encoding,
             })
             .expect("network thread dropped");
 
         let login_packet = LoginServerLoginPacket::new(username.into(), password.into());
 
-        self.packet_callback.outgoing_packet(&login_packet);
+        self.packet_callback.for_server(ServerType::Login).outgoing_packet(&login_packet);
 
-        let mut byte_writer = ByteWriter::with_encoding(UTF_8);
+        let mut byte_writer = ByteWriter::with_encoding(self.encoding);
         login_packet.packet_to_bytes(&mut byte_writer).unwrap();
         action_sender
             .send(byte_writer.into_inner())
@@ -427,6 +457,7 @@ where
                 address,
                 action_receiver,
                 event_sender,
+                encoding: self.encoding,
             })
             .expect("network thread dropped");
 
@@ -437,9 +468,11 @@ where
             login_data.sex,
         );
 
-        self.packet_callback.outgoing_packet(&login_packet);
+        self.packet_callback
+            .for_server(ServerType::Character)
+            .outgoing_packet(&login_packet);
 
-        let mut byte_writer = ByteWriter::with_encoding(UTF_8);
+        let mut byte_writer = ByteWriter::with_encoding(self.encoding);
         login_packet.packet_to_bytes(&mut byte_writer).unwrap();
         action_sender
             .send(byte_writer.into_inner())
@@ -470,6 +503,7 @@ where
                 address,
                 action_receiver,
                 event_sender,
+                encoding: self.encoding,
             })
             .expect("network thread dropped");
 
@@ -483,9 +517,9 @@ where
             login_server_login_data.sex,
         );
 
-        self.packet_callback.outgoing_packet(&login_packet);
+        self.packet_callback.for_server(ServerType::Map).outgoing_packet(&login_packet);
 
-        let mut byte_writer = ByteWriter::with_encoding(UTF_8);
+        let mut byte_writer = ByteWriter::with_encoding(self.encoding);
         login_packet.packet_to_bytes(&mut byte_writer).unwrap();
         action_sender
             .send(byte_writer.into_inner())
@@ -497,6 +531,12 @@ where
         };
     }
 
+    /// Set the encoding of the strings sent to and received from the servers.
+    /// Only affects connections that are established afterwards.
+    pub fn set_encoding(&mut self, encoding: &'static Encoding) {
+        self.encoding = encoding;
+    }
+
     pub fn disconnect_from_login_server(&mut self) {
         self.login_server_connection = ServerConnection::ClosingManually;
     }
@@ -512,10 +552,10 @@ where
     pub fn send_login_server_packet(&mut self, packet: &impl LoginServerPacket) -> Result<(), NotConnectedError> {
         match &mut self.login_server_connection {
             ServerConnection::Connected { action_sender, .. } => {
-                self.packet_callback.outgoing_packet(packet);
+                self.packet_callback.for_server(ServerType::Login).outgoing_packet(packet);
 
                 // FIX: Don't unwrap.
-                let mut byte_writer = ByteWriter::with_encoding(UTF_8);
+                let mut byte_writer = ByteWriter::with_encoding(self.encoding);
                 packet.packet_to_bytes(&mut byte_writer).unwrap();
                 action_sender.send(byte_writer.into_inner()).map_err(|_| NotConnectedError)
             }
@@ -526,10 +566,10 @@ where
     pub fn send_character_server_packet(&mut self, packet: &impl CharacterServerPacket) -> Result<(), NotConnectedError> {
         match &mut self.character_server_connection {
             ServerConnection::Connected { action_sender, .. } => {
-                self.packet_callback.outgoing_packet(packet);
+                self.packet_callback.for_server(ServerType::Character).outgoing_packet(packet);
 
                 // FIX: Don't unwrap.
-                let mut byte_writer = ByteWriter::with_encoding(UTF_8);
+                let mut byte_writer = ByteWriter::with_encoding(self.encoding);
                 packet.packet_to_bytes(&mut byte_writer).unwrap();
                 action_sender.send(byte_writer.into_inner()).map_err(|_| NotConnectedError)
             }
@@ -540,10 +580,10 @@ where
     pub fn send_map_server_packet(&mut self, packet: &impl MapServerPacket) -> Result<(), NotConnectedError> {
         match &mut self.map_server_connection {
             ServerConnection::Connected { action_sender, .. } => {
-                self.packet_callback.outgoing_packet(packet);
+                self.packet_callback.for_server(ServerType::Map).outgoing_packet(packet);
 
                 // FIX: Don't unwrap.
-                let mut byte_writer = ByteWriter::with_encoding(UTF_8);
+                let mut byte_writer = ByteWriter::with_encoding(self.encoding);
                 packet.packet_to_bytes(&mut byte_writer).unwrap();
                 action_sender.send(byte_writer.into_inner()).map_err(|_| NotConnectedError)
             }
@@ -551,6 +591,24 @@ where
         }
     }
 
+    /// Send bytes to a server without checking that they form a valid packet.
+    /// This is used to replay packets from the packet history.
+    pub fn send_raw_packet(&mut self, server_type: ServerType, bytes: Vec<u8>) -> Result<(), NotConnectedError> {
+        let connection = match server_type {
+            ServerType::Login => &mut self.login_server_connection,
+            ServerType::Character => &mut self.character_server_connection,
+            ServerType::Map => &mut self.map_server_connection,
+        };
+
+        match connection {
+            ServerConnection::Connected { action_sender, .. } => {
+                self.packet_callback.for_server(server_type).outgoing_bytes(&bytes);
+                action_sender.send(bytes).map_err(|_| NotConnectedError)
+            }
+            _ => Err(NotConnectedError),
+        }
+    }
+
     fn create_login_server_packet_handler(
         packet_callback: Callback,
     ) -> Result<PacketHandler<NetworkEventList, (), Callback>, DuplicateHandlerError> {
@@ -763,8 +821,15 @@ where
         packet_handler.register(|packet: UpdateStatusPacket1| NetworkEvent::UpdateStatus(packet.status_type))?;
         packet_handler.register(|packet: UpdateStatusPacket2| NetworkEvent::UpdateStatus(packet.status_type))?;
         packet_handler.register(|packet: UpdateStatusPacket3| NetworkEvent::UpdateStatus(packet.status_type))?;
+        packet_handler.register(|packet: RequestStatUpResponsePacket| NetworkEvent::StatUpResult {
+            stat_type: packet.stat_type,
+            success: packet.success != 0,
+            value: packet.value,
+        })?;
         packet_handler.register_noop::<UpdateAttackRangePacket>()?;
-        packet_handler.register_noop::<NewMailStatusPacket>()?;
+        packet_handler.register(|packet: NewMailStatusPacket| NetworkEvent::MailNotification {
+            has_new_mail: packet.new_available != 0,
+        })?;
         packet_handler.register_noop::<AchievementUpdatePacket>()?;
         packet_handler.register_noop::<AchievementListPacket>()?;
         packet_handler.register_noop::<CriticalWeightUpdatePacket>()?;
@@ -872,14 +937,26 @@ where
         packet_handler.register({
             let inventory_items = inventory_items.clone();
 
-            move |_: InventoyEndPacket| {
+            move |packet: InventoyEndPacket| {
                 let items = inventory_items.borrow_mut().take().expect("Unexpected inventory end packet");
-                NetworkEvent::SetInventory { items }
+
+                // The cart uses the same packets as the inventory but with an inventory
+                // type of 1.
+                match packet.inventory_type {
+                    1 => NetworkEvent::SetCartItems { items },
+                    _ => NetworkEvent::SetInventory { items },
+                }
             }
         })?;
         packet_handler.register_noop::<EquippableSwitchItemListPacket>()?;
         packet_handler.register_noop::<MapTypePacket>()?;
         packet_handler.register(|packet: UpdateSkillTreePacket| NetworkEvent::SkillTree(packet.skill_information))?;
+        packet_handler.register(|packet: UpdateSkillPacket| NetworkEvent::SkillUpdated {
+            skill_id: packet.skill_id,
+            skill_level: packet.skill_level,
+            upgradable: packet.upgradable != 0,
+        })?;
+        packet_handler.register(|packet: AddSkillPacket| NetworkEvent::SkillAdded(packet.skill_information))?;
         packet_handler.register(|packet: UpdateHotkeysPacket| NetworkEvent::SetHotkeyData {
             tab: packet.tab,
             hotkeys: packet
@@ -891,7 +968,12 @@ where
                 })
                 .collect(),
         })?;
-        packet_handler.register_noop::<InitialStatusPacket>()?;
+        packet_handler.register(|packet: InitialStatusPacket| {
+            status::initial_status(packet)
+                .into_iter()
+                .map(NetworkEvent::UpdateStatus)
+                .collect::<Vec<_>>()
+        })?;
         packet_handler.register_noop::<UpdatePartyInvitationStatePacket>()?;
         packet_handler.register_noop::<UpdateShowEquipPacket>()?;
         packet_handler.register_noop::<UpdateConfigurationPacket>()?;
@@ -916,7 +998,14 @@ where
             NetworkEvent::HealEffect(packet.destination_entity_id, packet.heal_amount as usize)
         })?;
         packet_handler.register_noop::<DisplayPlayerHealEffect>()?;
-        packet_handler.register_noop::<StatusChangePacket>()?;
+        packet_handler.register(|packet: StatusChangePacket| {
+            // Status 673 is used to signal that the player is pushing a cart. The first
+            // value is the type of cart.
+            (packet.index == 673).then(|| NetworkEvent::ChangeCart {
+                entity_id: packet.entity_id,
+                cart_type: (packet.state != 0).then_some(packet.value[0] as usize),
+            })
+        })?;
         packet_handler.register_noop::<QuestNotificationPacket1>()?;
         packet_handler.register_noop::<HuntingQuestNotificationPacket>()?;
         packet_handler.register_noop::<HuntingQuestUpdateObjectivePacket>()?;
@@ -967,7 +1056,7 @@ where
             } = packet;
 
             if result != ItemPickupResult::Success {
-                todo!();
+                return NetworkEvent::ItemPickupFailed { result };
             }
 
             // TODO: Not sure where to store these, since the *InventoryItem packets are not
@@ -1019,6 +1108,34 @@ where
             index: packet.index,
             amount: packet.amount,
         })?;
+        packet_handler.register(|packet: UseItemResultPacket| NetworkEvent::ItemUsed {
+            index: packet.index,
+            item_id: packet.item_id,
+            entity_id: packet.entity_id,
+            amount: packet.amount,
+            success: packet.success != 0,
+        })?;
+        packet_handler.register(|packet: GroundItemAppearedPacket| NetworkEvent::GroundItemAppeared {
+            entity_id: packet.entity_id,
+            item_id: packet.item_id,
+            is_identified: packet.is_identified != 0,
+            amount: packet.amount,
+            position: packet.position,
+            sub_position: (packet.sub_x, packet.sub_y),
+            dropped: false,
+        })?;
+        packet_handler.register(|packet: GroundItemDroppedPacket| NetworkEvent::GroundItemAppeared {
+            entity_id: packet.entity_id,
+            item_id: packet.item_id,
+            is_identified: packet.is_identified != 0,
+            amount: packet.amount,
+            position: packet.position,
+            sub_position: (packet.sub_x, packet.sub_y),
+            dropped: true,
+        })?;
+        packet_handler.register(|packet: GroundItemDisappearedPacket| NetworkEvent::GroundItemDisappeared {
+            entity_id: packet.entity_id,
+        })?;
         packet_handler.register(|packet: ServerTickPacket| NetworkEvent::UpdateClientTick {
             client_tick: packet.client_tick,
             received_at: Instant::now(),
@@ -1145,15 +1262,236 @@ where
                     quantity: items::ItemQuantity::Infinite,
                     weight: 0,
                     location: item.location,
+                    cart_index: None,
                 })
                 .collect();
 
             NetworkEvent::OpenShop { items }
         })?;
         packet_handler.register(|packet: BuyShopItemsResultPacket| NetworkEvent::BuyingCompleted { result: packet.result })?;
-        packet_handler.register_noop::<ParameterChangePacket>()?;
+        packet_handler.register(|packet: ParameterChangePacket| {
+            status::status_from_parameter(packet.variable_id, packet.value).map(NetworkEvent::UpdateStatus)
+        })?;
         packet_handler.register(|packet: SellListPacket| NetworkEvent::SellItemList { items: packet.items })?;
         packet_handler.register(|packet: SellItemsResultPacket| NetworkEvent::SellingCompleted { result: packet.result })?;
+        packet_handler.register(NetworkEvent::HomunculusInformation)?;
+        packet_handler.register(|packet: HomunculusStatePacket| {
+            CompanionUpdate::from_homunculus_state(packet.state, packet.value).map(|update| NetworkEvent::CompanionUpdated {
+                companion_type: CompanionType::Homunculus,
+                entity_id: packet.entity_id,
+                update,
+            })
+        })?;
+        packet_handler.register(|packet: HomunculusFeedResultPacket| NetworkEvent::CompanionFed {
+            companion_type: CompanionType::Homunculus,
+            result: packet.result,
+            item_id: ItemId(packet.item_id as u32),
+        })?;
+        packet_handler.register(NetworkEvent::MercenaryInformation)?;
+        packet_handler.register(|packet: MercenaryParameterChangedPacket| {
+            MercenaryParameter::from_parameter(packet.parameter, packet.value).map(NetworkEvent::MercenaryParameterChanged)
+        })?;
+        packet_handler.register(NetworkEvent::PetInformation)?;
+        packet_handler.register(|packet: PetStatePacket| {
+            CompanionUpdate::from_pet_state(packet.state, packet.value).map(|update| NetworkEvent::CompanionUpdated {
+                companion_type: CompanionType::Pet,
+                entity_id: packet.entity_id,
+                update,
+            })
+        })?;
+        packet_handler.register(|packet: PetFeedResultPacket| NetworkEvent::CompanionFed {
+            companion_type: CompanionType::Pet,
+            result: packet.result,
+            item_id: ItemId(packet.item_id as u32),
+        })?;
+        packet_handler.register(|packet: VendingAvailablePacket| NetworkEvent::VendingAvailable {
+            maximum_item_count: packet.item_count,
+        })?;
+        packet_handler.register(|packet: OpenVendingStoreResultPacket| NetworkEvent::VendingStoreOpened { result: packet.result })?;
+        packet_handler.register(|packet: OwnVendingItemListPacket| NetworkEvent::OwnVendingItems { items: packet.items })?;
+        packet_handler.register(|packet: VendingItemSoldPacket| NetworkEvent::VendingItemSold {
+            cart_index: packet.cart_index,
+            amount: packet.amount,
+            zeny: packet.zeny,
+        })?;
+        packet_handler.register(|packet: VendingStoreEntryPacket| NetworkEvent::StoreEntryAdded {
+            entity_id: packet.entity_id,
+            store_type: StoreType::Vending,
+            title: packet.title,
+        })?;
+        packet_handler.register(|packet: VendingStoreDisappearedPacket| NetworkEvent::StoreEntryRemoved {
+            entity_id: packet.entity_id,
+        })?;
+        packet_handler.register(|packet: VendingItemListPacket| {
+            let items = packet
+                .items
+                .into_iter()
+                .map(|item| ShopItem {
+                    metadata: NoMetadata,
+                    item_id: item.item_id,
+                    item_type: item.item_type,
+                    price: item.price,
+                    quantity: ItemQuantity::Fixed(item.amount as u32),
+                    weight: 0,
+                    location: item.location,
+                    cart_index: Some(item.cart_index),
+                })
+                .collect();
+
+            NetworkEvent::OpenVendingStore {
+                entity_id: packet.entity_id,
+                store_id: packet.store_id,
+                items,
+            }
+        })?;
+        packet_handler.register(|packet: BuyVendingItemsResultPacket| NetworkEvent::BuyingVendingItemsFailed { result: packet.result })?;
+        packet_handler.register(|packet: BuyingStoreAvailablePacket| NetworkEvent::BuyingStoreAvailable {
+            maximum_item_count: packet.item_count,
+        })?;
+        packet_handler.register(|packet: OpenBuyingStoreFailedPacket| NetworkEvent::OpeningBuyingStoreFailed {
+            result: packet.result,
+            total_weight: packet.total_weight,
+        })?;
+        packet_handler.register(|packet: OwnBuyingStoreItemListPacket| NetworkEvent::BuyingStoreOpened {
+            zeny_limit: packet.zeny_limit,
+        })?;
+        packet_handler.register(|packet: BuyingStoreTradeFailedPacket| NetworkEvent::BuyingStoreTradeFailed { result: packet.result })?;
+        packet_handler.register(|packet: BuyingStoreItemUpdatePacket| NetworkEvent::BuyingStoreItemBought {
+            item_id: packet.item_id,
+            amount: packet.amount,
+            zeny_limit: packet.zeny_limit,
+        })?;
+        packet_handler.register(|packet: BuyingStoreEntryPacket| NetworkEvent::StoreEntryAdded {
+            entity_id: packet.entity_id,
+            store_type: StoreType::BuyingStore,
+            title: packet.title,
+        })?;
+        packet_handler.register(|packet: BuyingStoreDisappearedPacket| NetworkEvent::StoreEntryRemoved {
+            entity_id: packet.entity_id,
+        })?;
+        packet_handler.register(|packet: BuyingStoreItemListPacket| NetworkEvent::OpenBuyingStore {
+            entity_id: packet.entity_id,
+            store_id: packet.store_id,
+            zeny_limit: packet.zeny_limit,
+            items: packet.items,
+        })?;
+        packet_handler.register(|packet: BuyingStoreItemSoldPacket| NetworkEvent::BuyingStoreItemSold {
+            inventory_index: packet.inventory_index,
+            amount: packet.amount,
+            zeny: packet.zeny,
+        })?;
+        packet_handler
+            .register(|packet: SellToBuyingStoreFailedPacket| NetworkEvent::SellingToBuyingStoreFailed { item_id: packet.item_id })?;
+        packet_handler.register(|packet: CartInformationPacket| NetworkEvent::UpdateCartCapacity {
+            item_count: packet.item_count,
+            maximum_item_count: packet.maximum_item_count,
+            weight: packet.weight,
+            maximum_weight: packet.maximum_weight,
+        })?;
+        packet_handler.register(|packet: CartItemAddedPacket| {
+            let CartItemAddedPacket {
+                index,
+                amount,
+                item_id,
+                item_type,
+                is_identified,
+                is_broken,
+                cards,
+                option_data,
+                refinement_level,
+                enchantment_level,
+            } = packet;
+
+            // The packet doesn't contain the equip position, so we tell equippable items
+            // apart by their type (armor, weapon, pet armor and shadow gear).
+            let is_equippable = matches!(item_type, 4 | 5 | 8 | 12);
+
+            let details = match is_equippable {
+                false => InventoryItemDetails::Regular {
+                    amount: amount as u16,
+                    equipped_position: EquipPosition::empty(),
+                    flags: {
+                        let mut flags = RegularItemFlags::empty();
+                        flags.set(RegularItemFlags::IDENTIFIED, is_identified != 0);
+                        flags
+                    },
+                },
+                true => InventoryItemDetails::Equippable {
+                    equip_position: EquipPosition::empty(),
+                    equipped_position: EquipPosition::empty(),
+                    bind_on_equip_type: 0,
+                    w_item_sprite_number: 0,
+                    option_count: option_data.len() as u8,
+                    option_data,
+                    refinement_level,
+                    enchantment_level,
+                    flags: {
+                        let mut flags = EquippableItemFlags::empty();
+                        flags.set(EquippableItemFlags::IDENTIFIED, is_identified != 0);
+                        flags.set(EquippableItemFlags::IS_BROKEN, is_broken != 0);
+                        flags
+                    },
+                },
+            };
+
+            let item = InventoryItem {
+                metadata: NoMetadata,
+                index,
+                item_id,
+                item_type,
+                slot: cards,
+                hire_expiration_date: 0,
+                details,
+            };
+
+            NetworkEvent::CartItemAdded { item }
+        })?;
+        packet_handler.register(|packet: CartItemRemovedPacket| NetworkEvent::CartItemRemoved {
+            index: packet.index,
+            amount: packet.amount as u16,
+        })?;
+        packet_handler.register(|_: CartRemovedPacket| NetworkEvent::CartRemoved)?;
+        packet_handler.register(|packet: AddItemToCartFailedPacket| NetworkEvent::AddItemToCartFailed { result: packet.result })?;
+        packet_handler.register(|packet: MailListPacket| NetworkEvent::MailList {
+            mails: packet.mails,
+            is_end: packet.is_end != 0,
+        })?;
+        packet_handler.register(|packet: MailContentPacket| NetworkEvent::MailContent {
+            mail_type: packet.mail_type,
+            mail_id: packet.mail_id,
+            text: packet.text,
+            zeny: packet.zeny,
+            items: packet.items,
+        })?;
+        packet_handler.register(|packet: MailDeletedPacket| NetworkEvent::MailDeleted { mail_id: packet.mail_id })?;
+        packet_handler.register(|packet: MailZenyResultPacket| NetworkEvent::MailZenyReceived {
+            mail_id: packet.mail_id,
+            result: packet.result,
+        })?;
+        packet_handler.register(|packet: MailItemsResultPacket| NetworkEvent::MailItemsReceived {
+            mail_id: packet.mail_id,
+            result: packet.result,
+        })?;
+        packet_handler.register(|packet: OpenWriteMailResultPacket| NetworkEvent::WriteMailOpened {
+            recipient: packet.recipient,
+            success: packet.success != 0,
+        })?;
+        packet_handler.register(|packet: MailRecipientPacket| NetworkEvent::MailRecipientChecked {
+            name: packet.name,
+            character_id: (packet.character_id.0 != 0).then_some(packet.character_id),
+            base_level: packet.base_level,
+        })?;
+        packet_handler.register(|packet: AddItemToMailResultPacket| NetworkEvent::MailItemAttached {
+            result: packet.result,
+            index: packet.index,
+            amount: packet.amount,
+        })?;
+        packet_handler.register(|packet: RemoveItemFromMailResultPacket| NetworkEvent::MailItemDetached {
+            success: packet.success != 0,
+            index: packet.index,
+            amount: packet.amount,
+        })?;
+        packet_handler.register(|packet: SendMailResultPacket| NetworkEvent::MailSent { result: packet.result })?;
 
         Ok(packet_handler)
     }
@@ -1233,6 +1571,10 @@ where
         self.send_map_server_packet(&RequestUnequipItemPacket::new(item_index))
     }
 
+    pub fn upgrade_skill(&mut self, skill_id: SkillId) -> Result<(), NotConnectedError> {
+        self.send_map_server_packet(&RequestSkillUpPacket::new(skill_id))
+    }
+
     pub fn cast_skill(&mut self, skill_id: SkillId, skill_level: SkillLevel, entity_id: EntityId) -> Result<(), NotConnectedError> {
         self.send_map_server_packet(&UseSkillAtIdPacket::new(skill_level, skill_id, entity_id))
     }
@@ -1331,6 +1673,203 @@ where
     pub fn sell_items(&mut self, items: Vec<SoldItemInformation>) -> Result<(), NotConnectedError> {
         self.send_map_server_packet(&SellItemsPacket { items })
     }
+
+    pub fn open_vending_store(&mut self, title: String, items: Vec<OpenVendingStoreItemInformation>) -> Result<(), NotConnectedError> {
+        self.send_map_server_packet(&OpenVendingStorePacket::new(title, items))
+    }
+
+    pub fn close_vending_store(&mut self) -> Result<(), NotConnectedError> {
+        self.send_map_server_packet(&CloseVendingStorePacket::new())
+    }
+
+    pub fn open_buying_store(
+        &mut self,
+        title: String,
+        zeny_limit: u32,
+        items: Vec<OpenBuyingStoreItemInformation>,
+    ) -> Result<(), NotConnectedError> {
+        self.send_map_server_packet(&OpenBuyingStorePacket::new(zeny_limit, title, items))
+    }
+
+    pub fn close_buying_store(&mut self) -> Result<(), NotConnectedError> {
+        self.send_map_server_packet(&CloseBuyingStorePacket::new())
+    }
+
+    pub fn request_store_item_list(&mut self, entity_id: EntityId, store_type: StoreType) -> Result<(), NotConnectedError> {
+        match store_type {
+            StoreType::Vending => self.send_map_server_packet(&RequestVendingItemListPacket::new(entity_id)),
+            StoreType::BuyingStore => self.send_map_server_packet(&RequestBuyingStoreItemListPacket::new(entity_id)),
+        }
+    }
+
+    pub fn purchase_vending_items(
+        &mut self,
+        entity_id: EntityId,
+        store_id: u32,
+        items: Vec<ShopItem<u32>>,
+    ) -> Result<(), NotConnectedError> {
+        let item_information = items
+            .into_iter()
+            .filter_map(|item| {
+                Some(BuyVendingItemInformation {
+                    amount: item.metadata as u16,
+                    cart_index: item.cart_index?,
+                })
+            })
+            .collect();
+
+        self.send_map_server_packet(&BuyVendingItemsPacket::new(entity_id, store_id, item_information))
+    }
+
+    pub fn sell_to_buying_store(
+        &mut self,
+        entity_id: EntityId,
+        store_id: u32,
+        items: Vec<SellToBuyingStoreItemInformation>,
+    ) -> Result<(), NotConnectedError> {
+        self.send_map_server_packet(&SellToBuyingStorePacket::new(entity_id, store_id, items))
+    }
+
+    pub fn raise_stat(&mut self, stat_type: StatType, amount: u8) -> Result<(), NotConnectedError> {
+        self.send_map_server_packet(&RequestStatUpPacket::new(stat_type, amount))
+    }
+
+    pub fn use_item(&mut self, index: InventoryIndex, account_id: AccountId) -> Result<(), NotConnectedError> {
+        self.send_map_server_packet(&RequestUseItemPacket::new(index, account_id))
+    }
+
+    pub fn drop_item(&mut self, index: InventoryIndex, amount: u16) -> Result<(), NotConnectedError> {
+        self.send_map_server_packet(&RequestDropItemPacket::new(index, amount))
+    }
+
+    pub fn pick_up_item(&mut self, entity_id: EntityId) -> Result<(), NotConnectedError> {
+        self.send_map_server_packet(&RequestPickUpItemPacket::new(entity_id))
+    }
+
+    pub fn move_item_to_cart(&mut self, index: InventoryIndex, amount: u16) -> Result<(), NotConnectedError> {
+        self.send_map_server_packet(&MoveItemToCartPacket::new(index, amount as u32))
+    }
+
+    pub fn move_item_from_cart(&mut self, index: InventoryIndex, amount: u16) -> Result<(), NotConnectedError> {
+        self.send_map_server_packet(&MoveItemFromCartPacket::new(index, amount as u32))
+    }
+
+    pub fn remove_cart(&mut self) -> Result<(), NotConnectedError> {
+        self.send_map_server_packet(&RemoveCartPacket::new())
+    }
+
+    pub fn open_mailbox(&mut self) -> Result<(), NotConnectedError> {
+        self.send_map_server_packet(&OpenMailboxPacket::new())
+    }
+
+    /// Request the page of mails that are older than the given mail.
+    pub fn request_mail_list(&mut self, last_mail_id: MailId) -> Result<(), NotConnectedError> {
+        self.send_map_server_packet(&RequestMailListPacket::new(last_mail_id))
+    }
+
+    pub fn close_mailbox(&mut self) -> Result<(), NotConnectedError> {
+        self.send_map_server_packet(&CloseMailboxPacket::new())
+    }
+
+    pub fn read_mail(&mut self, mail_type: MailType, mail_id: MailId) -> Result<(), NotConnectedError> {
+        self.send_map_server_packet(&ReadMailPacket::new(mail_type, mail_id))
+    }
+
+    pub fn delete_mail(&mut self, mail_type: MailType, mail_id: MailId) -> Result<(), NotConnectedError> {
+        self.send_map_server_packet(&DeleteMailPacket::new(mail_type, mail_id))
+    }
+
+    pub fn request_mail_zeny(&mut self, mail_type: MailType, mail_id: MailId) -> Result<(), NotConnectedError> {
+        self.send_map_server_packet(&RequestMailZenyPacket::new(mail_id, mail_type))
+    }
+
+    pub fn request_mail_items(&mut self, mail_type: MailType, mail_id: MailId) -> Result<(), NotConnectedError> {
+        self.send_map_server_packet(&RequestMailItemsPacket::new(mail_id, mail_type))
+    }
+
+    pub fn open_write_mail(&mut self, recipient: String) -> Result<(), NotConnectedError> {
+        self.send_map_server_packet(&OpenWriteMailPacket::new(recipient))
+    }
+
+    pub fn cancel_write_mail(&mut self) -> Result<(), NotConnectedError> {
+        self.send_map_server_packet(&CancelWriteMailPacket::new())
+    }
+
+    pub fn check_mail_recipient(&mut self, name: String) -> Result<(), NotConnectedError> {
+        self.send_map_server_packet(&CheckMailRecipientPacket::new(name))
+    }
+
+    pub fn add_item_to_mail(&mut self, index: InventoryIndex, amount: u16) -> Result<(), NotConnectedError> {
+        self.send_map_server_packet(&AddItemToMailPacket::new(index, amount))
+    }
+
+    pub fn remove_item_from_mail(&mut self, index: InventoryIndex, amount: u16) -> Result<(), NotConnectedError> {
+        self.send_map_server_packet(&RemoveItemFromMailPacket::new(index, amount))
+    }
+
+    /// Send the mail that is currently being written. Attached items are
+    /// tracked by the map server, so only the zeny are sent here.
+    pub fn send_mail(
+        &mut self,
+        recipient: String,
+        recipient_id: CharacterId,
+        sender: String,
+        title: String,
+        text: String,
+        zeny: u64,
+    ) -> Result<(), SendMailError> {
+        // The server expects the lengths of the encoded strings, not the length of the
+        // UTF-8 strings.
+        let title_length = mail_string_length(self.encoding, &title, MAXIMUM_MAIL_TITLE_LENGTH).ok_or(SendMailError::TitleTooLong)?;
+        let text_length = mail_string_length(self.encoding, &text, MAXIMUM_MAIL_TEXT_LENGTH).ok_or(SendMailError::TextTooLong)?;
+
+        Ok(self.send_map_server_packet(&SendMailPacket::new(
+            recipient,
+            sender,
+            zeny,
+            title_length,
+            text_length,
+            recipient_id,
+            title,
+            text,
+        ))?)
+    }
+
+    pub fn homunculus_command(&mut self, command: HomunculusCommand) -> Result<(), NotConnectedError> {
+        self.send_map_server_packet(&HomunculusCommandPacket::new(command))
+    }
+
+    pub fn rename_homunculus(&mut self, name: String) -> Result<(), NotConnectedError> {
+        self.send_map_server_packet(&RenameHomunculusPacket::new(name))
+    }
+
+    pub fn mercenary_command(&mut self, command: MercenaryCommand) -> Result<(), NotConnectedError> {
+        self.send_map_server_packet(&MercenaryCommandPacket::new(command))
+    }
+
+    pub fn pet_command(&mut self, command: PetCommand) -> Result<(), NotConnectedError> {
+        self.send_map_server_packet(&PetCommandPacket::new(command))
+    }
+
+    pub fn rename_pet(&mut self, name: String) -> Result<(), NotConnectedError> {
+        self.send_map_server_packet(&RenamePetPacket::new(name))
+    }
+
+    pub fn move_companion(&mut self, entity_id: EntityId, position: WorldPosition) -> Result<(), NotConnectedError> {
+        self.send_map_server_packet(&MoveCompanionPacket::new(entity_id, position))
+    }
+
+    pub fn companion_attack(&mut self, entity_id: EntityId, target_entity_id: EntityId) -> Result<(), NotConnectedError> {
+        self.send_map_server_packet(&CompanionAttackPacket::new(
+            entity_id,
+            target_entity_id,
+            CompanionAttackType::Continuous,
+        ))
+    }
+
+    pub fn companion_return_to_owner(&mut self, entity_id: EntityId) -> Result<(), NotConnectedError> {
+        self.send_map_server_packet(&CompanionReturnToOwnerPacket::new(entity_id))
+    }
 }
 
 #[cfg(test)]
@@ -1357,3 +1896,63 @@ mod packet_handlers {
         assert!(result.is_ok());
     }
 }
+
+#[cfg(test)]
+mod mail {
+    use ragnarok_bytes::ByteWriter;
+    use ragnarok_bytes::encoding::{EUC_KR, UTF_8};
+    use ragnarok_packets::{CharacterId, PacketExt, SendMailPacket};
+
+    use crate::{MAXIMUM_MAIL_TEXT_LENGTH, MAXIMUM_MAIL_TITLE_LENGTH, mail_string_length};
+
+    #[test]
+    fn ascii_length() {
+        assert_eq!(mail_string_length(EUC_KR, "Hello", MAXIMUM_MAIL_TITLE_LENGTH), Some(6));
+        assert_eq!(mail_string_length(EUC_KR, "", MAXIMUM_MAIL_TITLE_LENGTH), Some(1));
+    }
+
+    #[test]
+    fn encoded_length() {
+        // Hangul takes two bytes in EUC-KR but three in UTF-8.
+        assert_eq!(mail_string_length(EUC_KR, "안녕", MAXIMUM_MAIL_TITLE_LENGTH), Some(5));
+        assert_eq!(mail_string_length(UTF_8, "안녕", MAXIMUM_MAIL_TITLE_LENGTH), Some(7));
+    }
+
+    #[test]
+    fn reject_too_long() {
+        let title = "a".repeat(MAXIMUM_MAIL_TITLE_LENGTH - 1);
+        assert_eq!(mail_string_length(EUC_KR, &title, MAXIMUM_MAIL_TITLE_LENGTH), Some(40));
+
+        let title = "a".repeat(MAXIMUM_MAIL_TITLE_LENGTH);
+        assert_eq!(mail_string_length(EUC_KR, &title, MAXIMUM_MAIL_TITLE_LENGTH), None);
+
+        let text = "가".repeat(MAXIMUM_MAIL_TEXT_LENGTH / 2);
+        assert_eq!(mail_string_length(EUC_KR, &text, MAXIMUM_MAIL_TEXT_LENGTH), None);
+    }
+
+    #[test]
+    fn packet_matches_lengths() {
+        let title = "포션 팝니다".to_owned();
+        let text = "Red Potion x10".to_owned();
+        let title_length = mail_string_length(EUC_KR, &title, MAXIMUM_MAIL_TITLE_LENGTH).unwrap();
+        let text_length = mail_string_length(EUC_KR, &text, MAXIMUM_MAIL_TEXT_LENGTH).unwrap();
+
+        let packet = SendMailPacket::new(
+            "recipient".to_owned(),
+            "sender".to_owned(),
+            0,
+            title_length,
+            text_length,
+            CharacterId(150000),
+            title,
+            text,
+        );
+        let mut byte_writer = ByteWriter::with_encoding(EUC_KR);
+        packet.packet_to_bytes(&mut byte_writer).unwrap();
+
+        // Header, packet length, recipient, sender, zeny, both lengths and the
+        // character id, followed by the title and the text.
+        let fixed_size = 2 + 2 + 24 + 24 + 8 + 2 + 2 + 4;
+        assert_eq!(byte_writer.len(), fixed_size + title_length as usize + text_length as usize);
+    }
+}
@@ -1,5 +1,6 @@
 use std::net::{IpAddr, SocketAddr};
 
+use ragnarok_bytes::encoding::Encoding;
 use ragnarok_packets::{AccountId, CharacterId, Sex};
 use tokio::sync::mpsc::{UnboundedReceiver, UnboundedSender};
 
@@ -47,16 +48,19 @@ pub(crate) enum ServerConnectCommand {
         address: SocketAddr,
         action_receiver: UnboundedReceiver<Vec<u8>>,
         event_sender: UnboundedSender<NetworkEvent>,
+        encoding: &'static Encoding,
     },
     Character {
         address: SocketAddr,
         action_receiver: UnboundedReceiver<Vec<u8>>,
         event_sender: UnboundedSender<NetworkEvent>,
+        encoding: &'static Encoding,
     },
     Map {
         address: SocketAddr,
         action_receiver: UnboundedReceiver<Vec<u8>>,
         event_sender: UnboundedSender<NetworkEvent>,
+        encoding: &'static Encoding,
     },
 }
 
@@ -69,6 +73,19 @@ pub(crate) enum NetworkTaskError {
 #[derive(Debug)]
 pub struct NotConnectedError;
 
+#[derive(Debug)]
+pub enum SendMailError {
+    NotConnected,
+    TitleTooLong,
+    TextTooLong,
+}
+
+impl From<NotConnectedError> for SendMailError {
+    fn from(_: NotConnectedError) -> Self {
+        Self::NotConnected
+    }
+}
+
 pub(crate) enum ServerConnection {
     Connected {
         action_sender: UnboundedSender<Vec<u8>>,
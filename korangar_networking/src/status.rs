@@ -0,0 +1,63 @@
+use ragnarok_packets::{InitialStatusPacket, StatusType};
+
+/// Converts the variables of a `ParameterChangePacket` to their status type.
+/// The map server uses this packet for values that don't fit into an
+/// `UpdateStatusPacket` on older clients.
+pub(crate) fn status_from_parameter(parameter: u16, value: u32) -> Option<StatusType> {
+    match parameter {
+        1 => Some(StatusType::BaseExperience(value as u64)),
+        2 => Some(StatusType::JobExperience(value as u64)),
+        20 => Some(StatusType::Zeny(value)),
+        22 => Some(StatusType::NextBaseExperience(value as u64)),
+        23 => Some(StatusType::NextJobExperience(value as u64)),
+        _ => None,
+    }
+}
+
+/// Splits the initial status of the player into individual status updates.
+/// The bonus values of the base stats are not part of the packet, they are
+/// sent right after it.
+pub(crate) fn initial_status(packet: InitialStatusPacket) -> Vec<StatusType> {
+    vec![
+        StatusType::StatusPoint(packet.status_points as u32),
+        StatusType::Strength(packet.strength as u32, 0),
+        StatusType::Agility(packet.agility as u32, 0),
+        StatusType::Vitality(packet.vitatity as u32, 0),
+        StatusType::Intelligence(packet.intelligence as u32, 0),
+        StatusType::Dexterity(packet.dexterity as u32, 0),
+        StatusType::Luck(packet.luck as u32, 0),
+        StatusType::SpUstr(packet.required_strength),
+        StatusType::SpUagi(packet.required_agility),
+        StatusType::SpUvit(packet.required_vitatity),
+        StatusType::SpUint(packet.required_intelligence),
+        StatusType::SpUdex(packet.required_dexterity),
+        StatusType::SpUluk(packet.required_luck),
+        StatusType::Attack1(packet.left_attack as u32),
+        StatusType::Attack2(packet.rigth_attack as u32),
+        StatusType::MagicAttack1(packet.rigth_magic_attack as u32),
+        StatusType::MagicAttack2(packet.left_magic_attack as u32),
+        StatusType::Defense1(packet.left_defense as u32),
+        StatusType::Defense2(packet.rigth_defense as u32),
+        StatusType::MagicDefense1(packet.rigth_magic_defense as u32),
+        StatusType::MagicDefense2(packet.left_magic_defense as u32),
+        StatusType::Hit(packet.hit as u32),
+        StatusType::Flee1(packet.flee as u32),
+        StatusType::Flee2(packet.flee2 as u32),
+        StatusType::Critical(packet.crit as u32),
+        StatusType::AttackSpeed(packet.attack_speed as u32),
+    ]
+}
+
+#[cfg(test)]
+mod conversion {
+    use ragnarok_packets::StatusType;
+
+    use super::status_from_parameter;
+
+    #[test]
+    fn parameter() {
+        assert!(matches!(status_from_parameter(20, 500), Some(StatusType::Zeny(500))));
+        assert!(matches!(status_from_parameter(1, 10), Some(StatusType::BaseExperience(10))));
+        assert!(status_from_parameter(5, 10).is_none());
+    }
+}
@@ -0,0 +1,131 @@
+//! Index of the frames inside an IVF.
+
+/// OBU type of a sequence header.
+const OBU_SEQUENCE_HEADER: u8 = 1;
+/// OBU type of a frame header without the tile data.
+const OBU_FRAME_HEADER: u8 = 3;
+/// OBU type of a frame header followed by the tile data.
+const OBU_FRAME: u8 = 6;
+/// Frame type of an AV1 key frame.
+const KEY_FRAME: u8 = 0;
+
+/// Position, timestamp and keyframe flag of every frame inside an IVF.
+/// Created by [`Ivf::build_index`](super::Ivf::build_index).
+#[derive(Debug, Clone, Default)]
+pub struct IvfIndex {
+    pub(super) entries: Vec<IvfIndexEntry>,
+}
+
+impl IvfIndex {
+    /// The entries of all frames in file order.
+    pub fn entries(&self) -> &[IvfIndexEntry] {
+        &self.entries
+    }
+
+    /// Number of frames inside the index.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Whether the index doesn't contain any frames.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Index of the frame that is shown at the given timestamp. Returns the
+    /// first frame if the timestamp is before the start of the video.
+    pub fn frame_at(&self, timestamp: u64) -> Option<usize> {
+        match self.entries.iter().rposition(|entry| entry.timestamp <= timestamp) {
+            Some(index) => Some(index),
+            None => (!self.entries.is_empty()).then_some(0),
+        }
+    }
+
+    /// Index of the keyframe decoding has to start at, to show the frame at
+    /// the given timestamp.
+    pub fn keyframe_before(&self, timestamp: u64) -> Option<usize> {
+        let frame_index = self.frame_at(timestamp)?;
+        self.entries[..=frame_index].iter().rposition(|entry| entry.keyframe)
+    }
+}
+
+/// The position of a frame inside an IVF.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct IvfIndexEntry {
+    /// The offset of the frame header from the start of the file.
+    pub offset: u64,
+    /// The size of the data packet.
+    pub size: u32,
+    /// The timestamp of the frame.
+    pub timestamp: u64,
+    /// Whether the frame can be decoded without the previous frames.
+    pub keyframe: bool,
+}
+
+/// Checks if the AV1 temporal unit contains a key frame that is shown.
+pub(super) fn is_keyframe(packet: &[u8]) -> bool {
+    let mut reduced_still_picture_header = false;
+    let mut remaining = packet;
+
+    while let Some((obu_type, payload, rest)) = split_obu(remaining) {
+        remaining = rest;
+
+        match obu_type {
+            // Only the profile and still picture flags are needed, which are the
+            // first bits of the sequence header.
+            OBU_SEQUENCE_HEADER => reduced_still_picture_header = payload.first().is_some_and(|byte| byte & 0b0000_1000 != 0),
+            // Videos with a reduced still picture header only contain key frames.
+            OBU_FRAME_HEADER | OBU_FRAME if reduced_still_picture_header => return true,
+            OBU_FRAME_HEADER | OBU_FRAME => {
+                let Some(&byte) = payload.first() else {
+                    return false;
+                };
+
+                let show_existing_frame = byte & 0b1000_0000 != 0;
+                let frame_type = (byte >> 5) & 0b11;
+
+                return !show_existing_frame && frame_type == KEY_FRAME;
+            }
+            _ => {}
+        }
+    }
+
+    false
+}
+
+/// Splits the next OBU from the data. Returns the OBU type, its payload and
+/// the remaining data.
+fn split_obu(data: &[u8]) -> Option<(u8, &[u8], &[u8])> {
+    let (&header, rest) = data.split_first()?;
+    let obu_type = (header >> 3) & 0b1111;
+    let has_extension = header & 0b100 != 0;
+    let has_size = header & 0b10 != 0;
+
+    let rest = match has_extension {
+        true => rest.get(1..)?,
+        false => rest,
+    };
+
+    let (size, rest) = match has_size {
+        true => read_leb128(rest)?,
+        false => (rest.len(), rest),
+    };
+
+    let payload = rest.get(..size)?;
+    Some((obu_type, payload, &rest[size..]))
+}
+
+/// Reads an unsigned LEB128 value, which AV1 uses for the OBU sizes.
+fn read_leb128(data: &[u8]) -> Option<(usize, &[u8])> {
+    let mut value = 0usize;
+
+    for (index, &byte) in data.iter().enumerate().take(8) {
+        value |= usize::from(byte & 0x7F) << (index * 7);
+
+        if byte & 0x80 == 0 {
+            return Some((value, &data[index + 1..]));
+        }
+    }
+
+    None
+}
@@ -1,12 +1,14 @@
 //! IVF container parsing.
 
 use std::convert::TryFrom;
-use std::io::BufRead;
+use std::io::{BufRead, Seek, SeekFrom};
 
 use bytemuck::{Pod, Zeroable};
 pub use error::IvfError;
+pub use index::{IvfIndex, IvfIndexEntry};
 
 mod error;
+mod index;
 
 #[cfg(not(target_endian = "little"))]
 compile_error!("This code requires a little-endian system because of the bytemuck::cast");
@@ -108,6 +110,53 @@ impl<R: BufRead> Ivf<R> {
     }
 }
 
+impl<R: BufRead + Seek> Ivf<R> {
+    /// Reads all frames to build an index of the IVF. Afterwards the next
+    /// frame read is the first frame of the IVF again.
+    ///
+    /// Keyframes are found by parsing the AV1 frame headers.
+    pub fn build_index(&mut self) -> Result<IvfIndex> {
+        let header_size = size_of::<IvfHeader>() as u64;
+        let file_length = self.reader.seek(SeekFrom::End(0))?;
+
+        // The frame count is not trusted for the allocation, since every frame
+        // needs at least its frame header.
+        let maximum_frame_count = file_length.saturating_sub(header_size) / FRAME_HEADER_SIZE;
+        let mut entries = Vec::with_capacity(u64::from(self.header.frame_count).min(maximum_frame_count) as usize);
+
+        self.reader.seek(SeekFrom::Start(header_size))?;
+
+        loop {
+            let offset = self.reader.stream_position()?;
+
+            let Some(frame) = self.read_frame()? else {
+                break;
+            };
+
+            entries.push(IvfIndexEntry {
+                offset,
+                size: u32::try_from(frame.packet.len())?,
+                timestamp: frame.timestamp,
+                keyframe: index::is_keyframe(&frame.packet),
+            });
+        }
+
+        self.reader.seek(SeekFrom::Start(header_size))?;
+
+        Ok(IvfIndex { entries })
+    }
+
+    /// Moves to the given frame, so that it is the next frame that is read.
+    pub fn seek_to(&mut self, entry: &IvfIndexEntry) -> Result<()> {
+        self.reader.seek(SeekFrom::Start(entry.offset))?;
+        Ok(())
+    }
+}
+
+/// Size of the header in front of every frame, containing the packet size and
+/// the timestamp.
+const FRAME_HEADER_SIZE: u64 = 12;
+
 /// The IVF Header.
 #[derive(Debug, Copy, Clone, Pod, Zeroable)]
 #[repr(C)]
@@ -124,6 +173,22 @@ pub struct IvfHeader {
     pub _reserved: [u8; 4],
 }
 
+impl IvfHeader {
+    /// Converts a frame timestamp, which is in units of the timebase, to
+    /// milliseconds.
+    pub fn timestamp_in_milliseconds(&self, timestamp: u64) -> i64 {
+        let milliseconds = timestamp as u128 * 1000 * self.timebase_numerator as u128 / self.timebase_denominator.max(1) as u128;
+        milliseconds as i64
+    }
+
+    /// Converts milliseconds to a frame timestamp in units of the timebase,
+    /// rounding down.
+    pub fn timestamp_from_milliseconds(&self, milliseconds: i64) -> u64 {
+        let timestamp = milliseconds.max(0) as u128 * self.timebase_denominator as u128 / (1000 * self.timebase_numerator.max(1) as u128);
+        timestamp as u64
+    }
+}
+
 /// Frame inside a video. Contains a bitstream packet.
 #[derive(Debug, Clone)]
 pub struct Frame {
@@ -314,4 +379,101 @@ mod tests {
 
         assert_eq!(count, 29);
     }
+
+    #[test]
+    fn build_ivf_index() {
+        let data = include_bytes!("../../testfile/test-420-8.ivf");
+        let mut ivf = Ivf::new(Cursor::new(data.as_slice())).unwrap();
+        let index = ivf.build_index().unwrap();
+
+        let timestamps: Vec<u64> = index.entries().iter().map(|entry| entry.timestamp).collect();
+        let keyframes: Vec<bool> = index.entries().iter().map(|entry| entry.keyframe).collect();
+        assert_eq!(timestamps, [0, 33, 67, 100, 133]);
+        assert_eq!(keyframes, [true, false, false, false, false]);
+
+        // Building the index doesn't change the next frame.
+        assert_eq!(ivf.read_frame().unwrap().unwrap().timestamp, 0);
+
+        // The frame count of the header is larger than the file.
+        let mut truncated = data.to_vec();
+        truncated[24..28].copy_from_slice(&u32::MAX.to_le_bytes());
+        truncated.truncate(size_of::<IvfHeader>());
+        let mut ivf = Ivf::new(Cursor::new(truncated.as_slice())).unwrap();
+        assert!(ivf.build_index().unwrap().is_empty());
+    }
+
+    #[test]
+    fn convert_timestamps() {
+        let data = include_bytes!("../../testfile/test-420-8.ivf");
+        let ivf = Ivf::new(Cursor::new(data.as_slice())).unwrap();
+        let header = ivf.header();
+
+        assert_eq!(header.timestamp_from_milliseconds(-10), 0);
+
+        for timestamp in [0, 33, 67, 100, 133] {
+            let milliseconds = header.timestamp_in_milliseconds(timestamp);
+            assert!(header.timestamp_from_milliseconds(milliseconds) <= timestamp);
+        }
+    }
+
+    #[test]
+    fn seek_ivf_frames() {
+        let data = include_bytes!("../../testfile/test-420-8.ivf");
+        let mut ivf = Ivf::new(Cursor::new(data.as_slice())).unwrap();
+        let index = ivf.build_index().unwrap();
+
+        for entry in index.entries().iter().rev() {
+            ivf.seek_to(entry).unwrap();
+            let frame = ivf.read_frame().unwrap().unwrap();
+
+            assert_eq!(frame.timestamp, entry.timestamp);
+            assert_eq!(frame.packet.len(), entry.size as usize);
+        }
+
+        assert_eq!(index.frame_at(0), Some(0));
+        assert_eq!(index.frame_at(80), Some(2));
+        assert_eq!(index.frame_at(1000), Some(4));
+        assert_eq!(index.keyframe_before(80), Some(0));
+        assert_eq!(IvfIndex::default().keyframe_before(80), None);
+    }
+
+    #[test]
+    fn find_keyframes() {
+        let entry = |timestamp, keyframe| IvfIndexEntry {
+            offset: 0,
+            size: 0,
+            timestamp,
+            keyframe,
+        };
+        let index = IvfIndex {
+            entries: vec![
+                entry(0, true),
+                entry(10, false),
+                entry(20, true),
+                entry(30, false),
+                entry(40, false),
+            ],
+        };
+
+        assert_eq!(index.keyframe_before(0), Some(0));
+        assert_eq!(index.keyframe_before(15), Some(0));
+        assert_eq!(index.keyframe_before(20), Some(2));
+        assert_eq!(index.keyframe_before(45), Some(2));
+    }
+
+    #[test]
+    fn detect_av1_keyframes() {
+        // Temporal delimiter, followed by a frame header OBU with the given first
+        // byte and a size field.
+        let temporal_unit = |frame_header: u8| vec![0x12, 0x00, 0x1A, 0x01, frame_header];
+
+        assert!(index::is_keyframe(&temporal_unit(0b0001_0000)));
+        // Inter frame.
+        assert!(!index::is_keyframe(&temporal_unit(0b0011_0000)));
+        // Shows an existing key frame, which can't be decoded on its own.
+        assert!(!index::is_keyframe(&temporal_unit(0b1000_0000)));
+        // Truncated OBU.
+        assert!(!index::is_keyframe(&[0x32, 0x05, 0x00]));
+        assert!(!index::is_keyframe(&[]));
+    }
 }
@@ -11,6 +11,7 @@
 
 /// Implements the IVF file format.
 pub mod ivf;
+mod sync;
 
 use std::ffi::{c_int, c_void};
 use std::{mem, ptr};
@@ -22,6 +23,8 @@ use rav1d::include::dav1d::picture::*;
 use rav1d::src::lib::*;
 use rav1d::src::send_sync_non_null::SendSyncNonNull;
 
+pub use self::sync::PlaybackClock;
+
 const fn dav1d_err(errno: c_int) -> c_int {
     if libc::EPERM < 0 { errno } else { -errno }
 }
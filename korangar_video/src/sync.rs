@@ -0,0 +1,225 @@
+//! Synchronization of video pictures with an audio track.
+
+/// Drift in milliseconds that is small enough to be ignored.
+const DRIFT_TOLERANCE: f64 = 15.0;
+/// Drift in milliseconds that is too large to be corrected smoothly. The clock
+/// jumps to the audio position instead.
+const MAXIMUM_DRIFT: f64 = 250.0;
+/// Fraction of the drift that is corrected with each update.
+const CORRECTION_RATE: f64 = 0.1;
+
+/// Clock that decides when a picture should be presented, in milliseconds.
+///
+/// Without an audio track the clock simply follows the frame time. With an
+/// audio track, the audio position is used as the reference and the clock is
+/// slowly sped up or slowed down, so that the video never stutters because of
+/// small inaccuracies of the audio position.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct PlaybackClock {
+    time: f64,
+}
+
+impl PlaybackClock {
+    /// Creates a new clock starting at zero.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The current time of the clock in milliseconds.
+    pub fn time(&self) -> f64 {
+        self.time
+    }
+
+    /// Resets the clock to zero, for example when the video loops.
+    pub fn reset(&mut self) {
+        self.time = 0.0;
+    }
+
+    /// Moves the clock to the given time in milliseconds, for example when
+    /// seeking.
+    pub fn set_time(&mut self, time: f64) {
+        self.time = time;
+    }
+
+    /// Difference between the audio position and the clock in milliseconds.
+    /// Positive values mean that the video is behind the audio.
+    ///
+    /// The audio position is expected to be in seconds.
+    pub fn drift(&self, audio_position: f64) -> f64 {
+        audio_position * 1000.0 - self.time
+    }
+
+    /// Advances the clock. The delta time and audio position are expected to
+    /// be in seconds.
+    pub fn advance(&mut self, delta_time: f64, audio_position: Option<f64>) {
+        let step = delta_time * 1000.0;
+
+        let Some(audio_position) = audio_position else {
+            self.time += step;
+            return;
+        };
+
+        // The audio position is already up to date, so we compare it with the
+        // time the clock would have after this update.
+        let drift = self.drift(audio_position) - step;
+
+        match drift.abs() {
+            drift_amount if drift_amount > MAXIMUM_DRIFT => self.time = audio_position * 1000.0,
+            drift_amount if drift_amount > DRIFT_TOLERANCE => self.time += (step + drift * CORRECTION_RATE).max(0.0),
+            _ => self.time += step,
+        }
+    }
+
+    /// Whether a picture with the given presentation timestamp in milliseconds
+    /// should be shown.
+    pub fn is_due(&self, timestamp: i64) -> bool {
+        self.time.floor() as i64 >= timestamp
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{DRIFT_TOLERANCE, PlaybackClock};
+    use crate::ivf::Ivf;
+
+    static TEST_FILE_420_8: &[u8] = include_bytes!("../testfile/test-420-8.ivf");
+    static TEST_FILE_420_12: &[u8] = include_bytes!("../testfile/test-420-12.ivf");
+
+    const FRAME_TIME: f64 = 1.0 / 60.0;
+
+    /// Presentation timestamps of all frames in milliseconds, which are the
+    /// timestamps the decoder returns with `Picture::timestamp`.
+    fn presentation_timestamps(file: &[u8]) -> Vec<i64> {
+        let mut ivf = Ivf::new(file).unwrap();
+        let header = *ivf.header();
+        let mut timestamps = Vec::new();
+
+        while let Ok(Some(frame)) = ivf.read_frame() {
+            timestamps.push(header.timestamp_in_milliseconds(frame.timestamp));
+        }
+
+        timestamps
+    }
+
+    /// Plays the file in a loop with an audio track that runs at the given
+    /// speed and returns the largest drift after the clock settled.
+    fn play_with_audio(file: &[u8], audio_speed: f64, loops: usize) -> f64 {
+        let timestamps = presentation_timestamps(file);
+        let frame_duration = timestamps[1] - timestamps[0];
+        let loop_duration = timestamps.last().unwrap() + frame_duration;
+
+        let mut clock = PlaybackClock::new();
+        let mut audio_position = 0.0;
+        let mut largest_drift: f64 = 0.0;
+        let mut presented = Vec::new();
+
+        for loop_index in 0..loops {
+            let offset = loop_index as i64 * loop_duration;
+            let mut pending = timestamps.iter().map(|timestamp| timestamp + offset).peekable();
+
+            while let Some(&timestamp) = pending.peek() {
+                audio_position += FRAME_TIME * audio_speed;
+                clock.advance(FRAME_TIME, Some(audio_position));
+
+                if clock.is_due(timestamp) {
+                    presented.push(timestamp);
+                    pending.next();
+                }
+
+                if loop_index > 0 {
+                    largest_drift = largest_drift.max(clock.drift(audio_position).abs());
+                }
+            }
+        }
+
+        // Every frame is presented exactly once and in order.
+        assert_eq!(presented.len(), timestamps.len() * loops);
+        assert!(presented.windows(2).all(|pair| pair[0] < pair[1]));
+
+        largest_drift
+    }
+
+    #[test]
+    fn ivf_presentation_timestamps() {
+        assert_eq!(presentation_timestamps(TEST_FILE_420_8), [0, 33, 67, 100, 133]);
+        assert_eq!(presentation_timestamps(TEST_FILE_420_12), [0, 33, 67, 100, 133]);
+    }
+
+    #[test]
+    fn follow_frame_time_without_audio() {
+        let mut clock = PlaybackClock::new();
+
+        for _ in 0..60 {
+            clock.advance(FRAME_TIME, None);
+        }
+
+        assert!((clock.time() - 1000.0).abs() < 0.001);
+        assert!(clock.is_due(133));
+    }
+
+    #[test]
+    fn ignore_small_drift() {
+        let mut clock = PlaybackClock::new();
+        clock.advance(0.1, Some(0.105));
+
+        assert!((clock.time() - 100.0).abs() < 0.001);
+    }
+
+    #[test]
+    fn correct_drift_gradually() {
+        let mut clock = PlaybackClock::new();
+        let mut audio_position = 0.1;
+        clock.advance(0.0, Some(0.0));
+
+        let mut previous_time = clock.time();
+        for _ in 0..30 {
+            audio_position += FRAME_TIME;
+            clock.advance(FRAME_TIME, Some(audio_position));
+
+            // The clock never jumps, it only runs faster.
+            assert!(clock.time() - previous_time < FRAME_TIME * 1000.0 * 2.0);
+            previous_time = clock.time();
+        }
+
+        assert!(clock.drift(audio_position).abs() <= DRIFT_TOLERANCE);
+    }
+
+    #[test]
+    fn jump_on_large_drift() {
+        let mut clock = PlaybackClock::new();
+        clock.advance(FRAME_TIME, Some(2.0));
+
+        assert_eq!(clock.time(), 2000.0);
+    }
+
+    #[test]
+    fn continue_after_seeking() {
+        let mut clock = PlaybackClock::new();
+        clock.set_time(100.0);
+        clock.advance(0.1, Some(0.2));
+
+        assert!((clock.time() - 200.0).abs() < 0.001);
+        assert!(clock.is_due(200));
+    }
+
+    #[test]
+    fn never_run_backwards() {
+        let mut clock = PlaybackClock::new();
+        clock.advance(0.2, None);
+        clock.advance(0.0, Some(0.05));
+
+        assert!(clock.time() >= 200.0);
+    }
+
+    #[test]
+    fn stay_in_sync_with_fast_audio() {
+        assert!(play_with_audio(TEST_FILE_420_8, 1.05, 20) <= DRIFT_TOLERANCE * 2.0);
+        assert!(play_with_audio(TEST_FILE_420_12, 1.05, 20) <= DRIFT_TOLERANCE * 2.0);
+    }
+
+    #[test]
+    fn stay_in_sync_with_slow_audio() {
+        assert!(play_with_audio(TEST_FILE_420_8, 0.95, 20) <= DRIFT_TOLERANCE * 2.0);
+        assert!(play_with_audio(TEST_FILE_420_12, 0.95, 20) <= DRIFT_TOLERANCE * 2.0);
+    }
+}
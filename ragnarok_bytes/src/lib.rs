@@ -15,7 +15,9 @@ pub use ragnarok_procedural::{ByteConvertable, FixedByteSize, FromBytes, ToBytes
 pub use self::error::{ConversionError, ConversionErrorType, ConversionResult, ConversionResultExt};
 pub use self::fixed::{FixedByteSize, FixedByteSizeCollection};
 pub use self::from_bytes::{FromBytes, FromBytesExt};
-pub use self::reader::ByteReader;
+#[doc(hidden)]
+pub use self::reader::FieldStart;
+pub use self::reader::{ByteReader, RecordedField};
 pub use self::to_bytes::{ToBytes, ToBytesExt};
 pub use self::writer::ByteWriter;
 
@@ -1,4 +1,5 @@
 use std::any::TypeId;
+use std::ops::Range;
 
 use encoding_rs::{EUC_KR, Encoding};
 
@@ -17,6 +18,28 @@ pub(crate) struct TemporaryLimit {
     old_limit: usize,
 }
 
+/// A field of a derived struct that was read while field recording was
+/// enabled with [`record_fields`](ByteReader::record_fields).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RecordedField {
+    /// Dot separated path of the field, starting at the outermost struct.
+    pub path: String,
+    /// Full type name of the field as given by [`std::any::type_name`].
+    pub type_name: &'static str,
+    /// Range of the bytes the field was read from.
+    pub range: Range<usize>,
+    /// Whether the field was read without recording any fields of its own.
+    pub is_leaf: bool,
+}
+
+/// Position of the reader at the start of a field. Only used by the derive
+/// macros.
+#[doc(hidden)]
+pub struct FieldStart {
+    offset: usize,
+    index: usize,
+}
+
 /// A reader of bytes that iterates over borrowed data. It can produce single
 /// bytes or slices of memory and carries metadata about the read operation (for
 /// example a version).
@@ -42,6 +65,7 @@ where
     offset: usize,
     limit: usize,
     metadata: Meta,
+    recorded_fields: Option<Vec<RecordedField>>,
 }
 
 impl<'a> ByteReader<'a, ()> {
@@ -75,6 +99,7 @@ where
             offset: 0,
             limit,
             metadata,
+            recorded_fields: None,
         }
     }
 
@@ -94,6 +119,44 @@ where
         self.offset
     }
 
+    /// Record the fields of all derived structs that are read from now on.
+    /// The recorded fields can be retrieved with
+    /// [`take_recorded_fields`](ByteReader::take_recorded_fields).
+    pub fn record_fields(&mut self) {
+        self.recorded_fields = Some(Vec::new());
+    }
+
+    /// Take all fields recorded so far, in the order they were read.
+    pub fn take_recorded_fields(&mut self) -> Vec<RecordedField> {
+        self.recorded_fields.as_mut().map(std::mem::take).unwrap_or_default()
+    }
+
+    #[doc(hidden)]
+    pub fn start_field(&self) -> FieldStart {
+        FieldStart {
+            offset: self.offset,
+            index: self.recorded_fields.as_ref().map_or(0, Vec::len),
+        }
+    }
+
+    #[doc(hidden)]
+    pub fn finish_field<Field: ?Sized>(&mut self, name: &'static str, start: FieldStart) {
+        let Some(recorded_fields) = self.recorded_fields.as_mut() else {
+            return;
+        };
+
+        let children = &mut recorded_fields[start.index..];
+        children.iter_mut().for_each(|child| child.path = format!("{name}.{}", child.path));
+
+        let is_leaf = children.is_empty();
+        recorded_fields.push(RecordedField {
+            path: name.to_owned(),
+            type_name: std::any::type_name::<Field>(),
+            range: start.offset..self.offset,
+            is_leaf,
+        });
+    }
+
     // TODO: Implement this only for readers with metadata that can not be mutated
     // while reading.
     //
@@ -236,6 +299,52 @@ mod save_point {
     }
 }
 
+#[cfg(test)]
+mod recorded_fields {
+    use crate::ByteReader;
+
+    fn read_field<Field>(byte_reader: &mut ByteReader, name: &'static str, size: usize) {
+        let start = byte_reader.start_field();
+        byte_reader.slice::<()>(size).unwrap();
+        byte_reader.finish_field::<Field>(name, start);
+    }
+
+    #[test]
+    fn disabled() {
+        let mut byte_reader = ByteReader::without_metadata(&[0; 4]);
+
+        read_field::<u32>(&mut byte_reader, "value", 4);
+
+        assert!(byte_reader.take_recorded_fields().is_empty());
+    }
+
+    #[test]
+    fn nested() {
+        let mut byte_reader = ByteReader::without_metadata(&[0; 6]);
+        byte_reader.record_fields();
+
+        read_field::<u16>(&mut byte_reader, "header", 2);
+        let start = byte_reader.start_field();
+        read_field::<u8>(&mut byte_reader, "x", 1);
+        read_field::<[u8; 3]>(&mut byte_reader, "rest", 3);
+        byte_reader.finish_field::<()>("inner", start);
+
+        let fields: Vec<_> = byte_reader
+            .take_recorded_fields()
+            .into_iter()
+            .map(|field| (field.path, field.type_name, field.range, field.is_leaf))
+            .collect();
+
+        assert_eq!(fields, [
+            ("header".to_owned(), "u16", 0..2, true),
+            ("inner.x".to_owned(), "u8", 2..3, true),
+            ("inner.rest".to_owned(), "[u8; 3]", 3..6, true),
+            ("inner".to_owned(), "()", 2..6, false),
+        ]);
+        assert!(byte_reader.take_recorded_fields().is_empty());
+    }
+}
+
 #[cfg(test)]
 mod temporary_limit {
     use crate::ByteReader;
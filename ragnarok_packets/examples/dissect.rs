@@ -0,0 +1,55 @@
+use std::fs::File;
+use std::io::{BufReader, BufWriter};
+
+use ragnarok_packets::dissector::{Dissector, OutputFormat, PacketFilter, RecordWriter, ServerType};
+
+const USAGE: &str =
+    "usage: dissect <capture.pcap> [--format json|ron] [--pings] [--port <port>:<login|character|map>] [selector | -selector]...";
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let mut arguments = std::env::args().skip(1);
+    let path = arguments.next().ok_or(USAGE)?;
+
+    let mut format = OutputFormat::Json;
+    let mut filter = PacketFilter {
+        hide_pings: true,
+        ..Default::default()
+    };
+    let mut server_ports = Vec::new();
+
+    while let Some(argument) = arguments.next() {
+        match argument.as_str() {
+            "--format" => format = arguments.next().ok_or(USAGE)?.parse()?,
+            "--pings" => filter.hide_pings = false,
+            "--port" => {
+                let value = arguments.next().ok_or(USAGE)?;
+                let (port, server_type) = value.split_once(':').ok_or(USAGE)?;
+                let server_type = match server_type {
+                    "login" => ServerType::Login,
+                    "character" => ServerType::Character,
+                    "map" => ServerType::Map,
+                    _ => return Err(USAGE.into()),
+                };
+
+                server_ports.push((port.parse()?, server_type));
+            }
+            _ => match argument.strip_prefix('-') {
+                Some(selector) => filter.exclude.push(selector.parse()?),
+                None => filter.include.push(argument.parse()?),
+            },
+        }
+    }
+
+    let mut dissector = Dissector::new(filter);
+
+    for (port, server_type) in server_ports {
+        dissector.add_server_port(port, server_type);
+    }
+
+    let reader = BufReader::new(File::open(path)?);
+    let mut writer = RecordWriter::new(BufWriter::new(std::io::stdout().lock()), format);
+
+    dissector.dissect(reader, |record| writer.write(&record))?;
+
+    Ok(())
+}
@@ -0,0 +1,340 @@
+//! Reading of `.pcap` capture files and extraction of TCP segments.
+
+use std::io::Read;
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr};
+
+use super::{DissectorError, Timestamp};
+
+const MAGIC_MICROSECONDS: u32 = 0xA1B2C3D4;
+const MAGIC_NANOSECONDS: u32 = 0xA1B23C4D;
+
+const LINK_TYPE_NULL: u32 = 0;
+const LINK_TYPE_ETHERNET: u32 = 1;
+const LINK_TYPE_RAW: u32 = 101;
+const LINK_TYPE_LINUX_SLL: u32 = 113;
+const LINK_TYPE_IPV4: u32 = 228;
+const LINK_TYPE_IPV6: u32 = 229;
+const LINK_TYPE_LINUX_SLL2: u32 = 276;
+
+const ETHER_TYPE_IPV4: u16 = 0x0800;
+const ETHER_TYPE_IPV6: u16 = 0x86DD;
+const ETHER_TYPE_VLAN: u16 = 0x8100;
+const ETHER_TYPE_QINQ: u16 = 0x88A8;
+
+const IP_PROTOCOL_TCP: u8 = 6;
+
+/// Sequential reader for classic (non-ng) `.pcap` files.
+pub struct PcapReader<R> {
+    reader: R,
+    big_endian: bool,
+    nanosecond_precision: bool,
+    link_type: u32,
+}
+
+impl<R: Read> PcapReader<R> {
+    /// Creates a new reader and validates the global header of the capture.
+    pub fn new(mut reader: R) -> Result<Self, DissectorError> {
+        let mut header = [0u8; 24];
+        reader.read_exact(&mut header)?;
+
+        let magic = u32::from_le_bytes(header[0..4].try_into().unwrap());
+
+        let (big_endian, nanosecond_precision) = match magic {
+            MAGIC_MICROSECONDS => (false, false),
+            MAGIC_NANOSECONDS => (false, true),
+            _ if magic.swap_bytes() == MAGIC_MICROSECONDS => (true, false),
+            _ if magic.swap_bytes() == MAGIC_NANOSECONDS => (true, true),
+            _ => return Err(DissectorError::InvalidMagic(magic)),
+        };
+
+        let link_type = read_u32(&header[20..24], big_endian) & 0x0FFF_FFFF;
+
+        match link_type {
+            LINK_TYPE_NULL | LINK_TYPE_ETHERNET | LINK_TYPE_RAW | LINK_TYPE_LINUX_SLL | LINK_TYPE_IPV4 | LINK_TYPE_IPV6
+            | LINK_TYPE_LINUX_SLL2 => {}
+            _ => return Err(DissectorError::UnsupportedLinkType(link_type)),
+        }
+
+        Ok(Self {
+            reader,
+            big_endian,
+            nanosecond_precision,
+            link_type,
+        })
+    }
+
+    /// The link type of all frames in this capture.
+    pub fn link_type(&self) -> u32 {
+        self.link_type
+    }
+
+    /// Reads the next frame from the capture. Returns `None` if the end of the
+    /// file has been reached.
+    pub fn read_frame(&mut self) -> Result<Option<CapturedFrame>, DissectorError> {
+        let mut header = [0u8; 16];
+
+        match self.reader.read_exact(&mut header) {
+            Ok(()) => {}
+            Err(error) if error.kind() == std::io::ErrorKind::UnexpectedEof => return Ok(None),
+            Err(error) => return Err(error.into()),
+        }
+
+        let seconds = read_u32(&header[0..4], self.big_endian);
+        let fraction = read_u32(&header[4..8], self.big_endian);
+        let captured_length = read_u32(&header[8..12], self.big_endian) as usize;
+
+        let nanoseconds = match self.nanosecond_precision {
+            true => fraction,
+            false => fraction.saturating_mul(1000),
+        };
+
+        let mut data = vec![0; captured_length];
+        self.reader
+            .read_exact(&mut data)
+            .map_err(|_| DissectorError::UnexpectedFileEnding)?;
+
+        Ok(Some(CapturedFrame {
+            timestamp: Timestamp {
+                seconds: seconds as u64,
+                nanoseconds,
+            },
+            data,
+        }))
+    }
+}
+
+/// A single link layer frame as stored in the capture file.
+#[derive(Debug, Clone)]
+pub struct CapturedFrame {
+    pub timestamp: Timestamp,
+    pub data: Vec<u8>,
+}
+
+/// The relevant parts of a TCP segment.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TcpSegment<'a> {
+    pub source: SocketAddr,
+    pub destination: SocketAddr,
+    pub sequence_number: u32,
+    pub syn: bool,
+    pub ack: bool,
+    pub fin: bool,
+    pub rst: bool,
+    pub payload: &'a [u8],
+}
+
+/// Extract the TCP segment from a link layer frame. Returns `None` for
+/// anything that is not an unfragmented TCP segment over IPv4 or IPv6.
+pub fn parse_tcp_segment(link_type: u32, data: &[u8]) -> Option<TcpSegment<'_>> {
+    let ip_packet = match link_type {
+        LINK_TYPE_NULL => {
+            // The address family is stored in the byte order of the capturing host.
+            let family = u32::from_le_bytes(data.get(0..4)?.try_into().ok()?);
+            let family = match family > 0xFFFF {
+                true => family.swap_bytes(),
+                false => family,
+            };
+
+            match family {
+                2 | 24 | 28 | 30 => data.get(4..)?,
+                _ => return None,
+            }
+        }
+        LINK_TYPE_ETHERNET => {
+            let mut offset = 12;
+            let mut ether_type = read_u16_be(data.get(offset..offset + 2)?);
+
+            while ether_type == ETHER_TYPE_VLAN || ether_type == ETHER_TYPE_QINQ {
+                offset += 4;
+                ether_type = read_u16_be(data.get(offset..offset + 2)?);
+            }
+
+            match ether_type {
+                ETHER_TYPE_IPV4 | ETHER_TYPE_IPV6 => data.get(offset + 2..)?,
+                _ => return None,
+            }
+        }
+        LINK_TYPE_LINUX_SLL => match read_u16_be(data.get(14..16)?) {
+            ETHER_TYPE_IPV4 | ETHER_TYPE_IPV6 => data.get(16..)?,
+            _ => return None,
+        },
+        LINK_TYPE_LINUX_SLL2 => match read_u16_be(data.get(0..2)?) {
+            ETHER_TYPE_IPV4 | ETHER_TYPE_IPV6 => data.get(20..)?,
+            _ => return None,
+        },
+        LINK_TYPE_RAW | LINK_TYPE_IPV4 | LINK_TYPE_IPV6 => data,
+        _ => return None,
+    };
+
+    let (source_address, destination_address, tcp_segment) = match ip_packet.first()? >> 4 {
+        4 => parse_ipv4(ip_packet)?,
+        6 => parse_ipv6(ip_packet)?,
+        _ => return None,
+    };
+
+    let data_offset = ((tcp_segment.get(12)? >> 4) as usize) * 4;
+    let flags = *tcp_segment.get(13)?;
+
+    if data_offset < 20 {
+        return None;
+    }
+
+    Some(TcpSegment {
+        source: SocketAddr::new(source_address, read_u16_be(tcp_segment.get(0..2)?)),
+        destination: SocketAddr::new(destination_address, read_u16_be(tcp_segment.get(2..4)?)),
+        sequence_number: u32::from_be_bytes(tcp_segment.get(4..8)?.try_into().ok()?),
+        fin: flags & 0x01 != 0,
+        syn: flags & 0x02 != 0,
+        rst: flags & 0x04 != 0,
+        ack: flags & 0x10 != 0,
+        payload: tcp_segment.get(data_offset..)?,
+    })
+}
+
+fn parse_ipv4(data: &[u8]) -> Option<(IpAddr, IpAddr, &[u8])> {
+    let header_length = ((data.first()? & 0x0F) as usize) * 4;
+    let total_length = read_u16_be(data.get(2..4)?) as usize;
+    let fragment = read_u16_be(data.get(6..8)?);
+
+    // More fragments flag or a fragment offset. Reassembling IP fragments is out
+    // of scope since Ragnarok Online packets are tiny.
+    if fragment & 0x3FFF != 0 || *data.get(9)? != IP_PROTOCOL_TCP {
+        return None;
+    }
+
+    let source: [u8; 4] = data.get(12..16)?.try_into().ok()?;
+    let destination: [u8; 4] = data.get(16..20)?.try_into().ok()?;
+
+    // Total length is used to strip the ethernet padding of small frames. Some
+    // capture setups with segmentation offloading report a length of zero.
+    let end = match total_length {
+        0 => data.len(),
+        length => length.min(data.len()),
+    };
+
+    Some((
+        IpAddr::V4(Ipv4Addr::from(source)),
+        IpAddr::V4(Ipv4Addr::from(destination)),
+        data.get(header_length..end)?,
+    ))
+}
+
+fn parse_ipv6(data: &[u8]) -> Option<(IpAddr, IpAddr, &[u8])> {
+    let payload_length = read_u16_be(data.get(4..6)?) as usize;
+
+    // Extension headers are not supported.
+    if *data.get(6)? != IP_PROTOCOL_TCP {
+        return None;
+    }
+
+    let source: [u8; 16] = data.get(8..24)?.try_into().ok()?;
+    let destination: [u8; 16] = data.get(24..40)?.try_into().ok()?;
+
+    let end = match payload_length {
+        0 => data.len(),
+        length => (40 + length).min(data.len()),
+    };
+
+    Some((
+        IpAddr::V6(Ipv6Addr::from(source)),
+        IpAddr::V6(Ipv6Addr::from(destination)),
+        data.get(40..end)?,
+    ))
+}
+
+fn read_u16_be(bytes: &[u8]) -> u16 {
+    u16::from_be_bytes([bytes[0], bytes[1]])
+}
+
+fn read_u32(bytes: &[u8], big_endian: bool) -> u32 {
+    let bytes = bytes.try_into().unwrap();
+
+    match big_endian {
+        true => u32::from_be_bytes(bytes),
+        false => u32::from_le_bytes(bytes),
+    }
+}
+
+#[cfg(test)]
+pub(super) mod tests {
+    use std::net::SocketAddr;
+
+    use super::{LINK_TYPE_ETHERNET, PcapReader, parse_tcp_segment};
+
+    pub(in crate::dissector) fn ethernet_frame(
+        source: SocketAddr,
+        destination: SocketAddr,
+        sequence_number: u32,
+        flags: u8,
+        payload: &[u8],
+    ) -> Vec<u8> {
+        let (SocketAddr::V4(source), SocketAddr::V4(destination)) = (source, destination) else {
+            panic!("only IPv4 is supported");
+        };
+
+        let mut frame = vec![0; 12];
+        frame.extend_from_slice(&0x0800u16.to_be_bytes());
+
+        let total_length = (20 + 20 + payload.len()) as u16;
+        frame.extend_from_slice(&[0x45, 0]);
+        frame.extend_from_slice(&total_length.to_be_bytes());
+        frame.extend_from_slice(&[0, 0, 0x40, 0, 64, 6, 0, 0]);
+        frame.extend_from_slice(&source.ip().octets());
+        frame.extend_from_slice(&destination.ip().octets());
+
+        frame.extend_from_slice(&source.port().to_be_bytes());
+        frame.extend_from_slice(&destination.port().to_be_bytes());
+        frame.extend_from_slice(&sequence_number.to_be_bytes());
+        frame.extend_from_slice(&[0, 0, 0, 0, 0x50, flags, 0xFF, 0xFF, 0, 0, 0, 0]);
+        frame.extend_from_slice(payload);
+
+        frame
+    }
+
+    pub(in crate::dissector) fn capture_file(frames: &[Vec<u8>]) -> Vec<u8> {
+        let mut file = Vec::new();
+        file.extend_from_slice(&0xA1B2C3D4u32.to_le_bytes());
+        file.extend_from_slice(&[2, 0, 4, 0]);
+        file.extend_from_slice(&[0; 8]);
+        file.extend_from_slice(&65535u32.to_le_bytes());
+        file.extend_from_slice(&LINK_TYPE_ETHERNET.to_le_bytes());
+
+        for (index, frame) in frames.iter().enumerate() {
+            file.extend_from_slice(&(1_700_000_000 + index as u32).to_le_bytes());
+            file.extend_from_slice(&250u32.to_le_bytes());
+            file.extend_from_slice(&(frame.len() as u32).to_le_bytes());
+            file.extend_from_slice(&(frame.len() as u32).to_le_bytes());
+            file.extend_from_slice(frame);
+        }
+
+        file
+    }
+
+    #[test]
+    fn read_frames() {
+        let client = "10.0.0.2:50000".parse().unwrap();
+        let server = "10.0.0.1:6900".parse().unwrap();
+        let frames = [ethernet_frame(client, server, 7, 0x18, &[1, 2, 3])];
+        let file = capture_file(&frames);
+
+        let mut reader = PcapReader::new(file.as_slice()).unwrap();
+        let frame = reader.read_frame().unwrap().unwrap();
+
+        assert_eq!(frame.timestamp.seconds, 1_700_000_000);
+        assert_eq!(frame.timestamp.nanoseconds, 250_000);
+        assert!(reader.read_frame().unwrap().is_none());
+
+        let segment = parse_tcp_segment(reader.link_type(), &frame.data).unwrap();
+
+        assert_eq!(segment.source, client);
+        assert_eq!(segment.destination, server);
+        assert_eq!(segment.sequence_number, 7);
+        assert!(segment.ack && !segment.syn);
+        assert_eq!(segment.payload, &[1, 2, 3]);
+    }
+
+    #[test]
+    fn invalid_magic() {
+        assert!(PcapReader::new([0u8; 24].as_slice()).is_err());
+    }
+}
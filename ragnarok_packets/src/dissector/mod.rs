@@ -0,0 +1,500 @@
+//! Offline dissection of Ragnarok Online traffic.
+//!
+//! The [`Dissector`] takes frames from `.pcap` captures (or any other source
+//! of TCP segments), reassembles the TCP streams, classifies every connection
+//! as login, character or map server traffic and decodes the packets with the
+//! regular [`PacketHandler`](crate::handler::PacketHandler). The result is a
+//! list of [`PacketRecord`]s that can be serialized to JSON or RON.
+
+mod capture;
+mod packets;
+mod record;
+mod stream;
+
+use std::collections::HashMap;
+use std::io::Read;
+use std::net::SocketAddr;
+
+use serde::Serialize;
+
+pub use self::capture::{CapturedFrame, PcapReader, TcpSegment, parse_tcp_segment};
+use self::packets::{DecodeResult, StreamDecoder};
+use self::record::format_packet_header;
+pub use self::record::{OutputFormat, PacketFilter, PacketRecord, PacketSelector, RecordKind, RecordWriter, Timestamp, hex_dump};
+use self::stream::{HalfStream, SegmentResult};
+pub use crate::handler::ServerType;
+
+pub const DEFAULT_LOGIN_SERVER_PORT: u16 = 6900;
+pub const DEFAULT_CHARACTER_SERVER_PORT: u16 = 6121;
+pub const DEFAULT_MAP_SERVER_PORT: u16 = 5121;
+
+/// Errors that can occur when dissecting a capture.
+#[derive(Debug)]
+pub enum DissectorError {
+    /// A std::io::Error.
+    IoError(std::io::Error),
+    /// The file is not a `.pcap` file. Note that `.pcapng` is not supported.
+    InvalidMagic(u32),
+    /// The link type of the capture is not supported.
+    UnsupportedLinkType(u32),
+    /// Unexpected file ending.
+    UnexpectedFileEnding,
+    /// A packet selector could not be parsed.
+    InvalidSelector(String),
+    /// An output format could not be parsed.
+    InvalidFormat(String),
+    /// A record could not be serialized.
+    Serialization(String),
+}
+
+impl std::fmt::Display for DissectorError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            DissectorError::IoError(error) => write!(f, "{error}"),
+            DissectorError::InvalidMagic(magic) => write!(f, "invalid pcap magic number 0x{magic:0>8x}"),
+            DissectorError::UnsupportedLinkType(link_type) => write!(f, "unsupported link type {link_type}"),
+            DissectorError::UnexpectedFileEnding => write!(f, "unexpected file ending"),
+            DissectorError::InvalidSelector(selector) => write!(f, "invalid packet selector {selector:?}"),
+            DissectorError::InvalidFormat(format) => write!(f, "invalid output format {format:?}"),
+            DissectorError::Serialization(message) => write!(f, "failed to serialize record: {message}"),
+        }
+    }
+}
+
+impl From<std::io::Error> for DissectorError {
+    fn from(error: std::io::Error) -> Self {
+        DissectorError::IoError(error)
+    }
+}
+
+impl std::error::Error for DissectorError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            DissectorError::IoError(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+/// Direction of a packet from the perspective of the client.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize)]
+pub enum PacketDirection {
+    /// Sent by the server.
+    Incoming,
+    /// Sent by the client.
+    Outgoing,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+struct ConnectionKey {
+    client: SocketAddr,
+    server: SocketAddr,
+}
+
+/// Everything needed to create a [`PacketRecord`] besides the content.
+#[derive(Clone, Copy)]
+struct RecordContext {
+    timestamp: Timestamp,
+    key: ConnectionKey,
+    server_type: ServerType,
+    direction: PacketDirection,
+}
+
+impl RecordContext {
+    fn create_record(&self, header: Option<String>, kind: RecordKind) -> PacketRecord {
+        PacketRecord {
+            timestamp: self.timestamp,
+            server_type: self.server_type,
+            direction: self.direction,
+            client_address: self.key.client,
+            server_address: self.key.server,
+            header,
+            kind,
+        }
+    }
+}
+
+struct Connection {
+    server_type: ServerType,
+    outgoing: HalfStream,
+    incoming: HalfStream,
+    outgoing_closed: bool,
+    incoming_closed: bool,
+}
+
+/// Reassembles and decodes Ragnarok Online traffic.
+///
+/// Connections are classified by the port of the server. In addition to the
+/// configured ports, ports announced by the login server (character servers)
+/// and the character server (map server) are picked up automatically.
+pub struct Dissector {
+    filter: PacketFilter,
+    server_ports: HashMap<u16, ServerType>,
+    connections: HashMap<ConnectionKey, Connection>,
+    decoders: HashMap<(ServerType, PacketDirection), StreamDecoder>,
+    last_timestamp: Timestamp,
+}
+
+impl Dissector {
+    /// Create a new dissector that uses the default ports of rAthena.
+    pub fn new(filter: PacketFilter) -> Self {
+        let server_ports = HashMap::from([
+            (DEFAULT_LOGIN_SERVER_PORT, ServerType::Login),
+            (DEFAULT_CHARACTER_SERVER_PORT, ServerType::Character),
+            (DEFAULT_MAP_SERVER_PORT, ServerType::Map),
+        ]);
+
+        let mut decoders = HashMap::new();
+
+        for server_type in [ServerType::Login, ServerType::Character, ServerType::Map] {
+            for direction in [PacketDirection::Incoming, PacketDirection::Outgoing] {
+                decoders.insert((server_type, direction), StreamDecoder::new(server_type, direction));
+            }
+        }
+
+        Self {
+            filter,
+            server_ports,
+            connections: HashMap::new(),
+            decoders,
+            last_timestamp: Timestamp::default(),
+        }
+    }
+
+    /// Treat connections to `port` as traffic of the given server type.
+    pub fn add_server_port(&mut self, port: u16, server_type: ServerType) {
+        self.server_ports.insert(port, server_type);
+    }
+
+    pub fn get_filter(&self) -> &PacketFilter {
+        &self.filter
+    }
+
+    pub fn set_filter(&mut self, filter: PacketFilter) {
+        self.filter = filter;
+    }
+
+    /// Read a complete `.pcap` capture and pass every record that matches the
+    /// filter to `callback`.
+    pub fn dissect<R: Read>(
+        &mut self,
+        reader: R,
+        mut callback: impl FnMut(PacketRecord) -> Result<(), DissectorError>,
+    ) -> Result<(), DissectorError> {
+        let mut pcap_reader = PcapReader::new(reader)?;
+        let link_type = pcap_reader.link_type();
+
+        while let Some(frame) = pcap_reader.read_frame()? {
+            for record in self.process_frame(link_type, &frame) {
+                callback(record)?;
+            }
+        }
+
+        for record in self.flush() {
+            callback(record)?;
+        }
+
+        Ok(())
+    }
+
+    /// Process a single link layer frame. Frames that don't contain a TCP
+    /// segment of a Ragnarok Online connection are ignored.
+    pub fn process_frame(&mut self, link_type: u32, frame: &CapturedFrame) -> Vec<PacketRecord> {
+        match parse_tcp_segment(link_type, &frame.data) {
+            Some(segment) => self.process_segment(frame.timestamp, &segment),
+            None => Vec::new(),
+        }
+    }
+
+    /// Process a single TCP segment.
+    pub fn process_segment(&mut self, timestamp: Timestamp, segment: &TcpSegment) -> Vec<PacketRecord> {
+        let Some((key, server_type, direction)) = self.classify(segment) else {
+            return Vec::new();
+        };
+
+        if segment.rst {
+            self.connections.remove(&key);
+            return Vec::new();
+        }
+
+        let connection = self.connections.entry(key).or_insert_with(|| Connection {
+            server_type,
+            outgoing: HalfStream::default(),
+            incoming: HalfStream::default(),
+            outgoing_closed: false,
+            incoming_closed: false,
+        });
+
+        let (stream, closed) = match direction {
+            PacketDirection::Outgoing => (&mut connection.outgoing, &mut connection.outgoing_closed),
+            PacketDirection::Incoming => (&mut connection.incoming, &mut connection.incoming_closed),
+        };
+
+        if segment.syn {
+            stream.synchronize(segment.sequence_number);
+            *closed = false;
+        }
+
+        let context = RecordContext {
+            timestamp,
+            key,
+            server_type: connection.server_type,
+            direction,
+        };
+        let mut records = Vec::new();
+        let mut announced_servers = Vec::new();
+
+        let appended = match stream.push_segment(segment.sequence_number, segment.payload) {
+            SegmentResult::Pending => false,
+            SegmentResult::Appended => true,
+            SegmentResult::GapSkipped(lost) => {
+                records.extend(trailing_bytes_record(&lost, context));
+                true
+            }
+        };
+
+        if appended {
+            let decoder = self.decoders.get_mut(&(connection.server_type, direction)).unwrap();
+            decode_stream(decoder, stream, context, &mut records, &mut announced_servers);
+        }
+
+        if segment.fin {
+            *closed = true;
+
+            if let Some(record) = trailing_record(stream, context) {
+                records.push(record);
+            }
+
+            if connection.outgoing_closed && connection.incoming_closed {
+                self.connections.remove(&key);
+            }
+        }
+
+        self.server_ports.extend(announced_servers);
+        self.last_timestamp = timestamp;
+
+        self.finish_records(records)
+    }
+
+    /// Emit all data that is still buffered, e.g. at the end of a capture.
+    pub fn flush(&mut self) -> Vec<PacketRecord> {
+        let timestamp = self.last_timestamp;
+        let mut records = Vec::new();
+
+        for (key, mut connection) in self.connections.drain() {
+            for (stream, direction) in [
+                (&mut connection.outgoing, PacketDirection::Outgoing),
+                (&mut connection.incoming, PacketDirection::Incoming),
+            ] {
+                let context = RecordContext {
+                    timestamp,
+                    key,
+                    server_type: connection.server_type,
+                    direction,
+                };
+
+                if let Some(record) = trailing_record(stream, context) {
+                    records.push(record);
+                }
+            }
+        }
+
+        self.finish_records(records)
+    }
+
+    fn finish_records(&self, records: Vec<PacketRecord>) -> Vec<PacketRecord> {
+        records.into_iter().filter(|record| self.filter.matches(record)).collect()
+    }
+
+    fn classify(&self, segment: &TcpSegment) -> Option<(ConnectionKey, ServerType, PacketDirection)> {
+        let outgoing_key = ConnectionKey {
+            client: segment.source,
+            server: segment.destination,
+        };
+        let incoming_key = ConnectionKey {
+            client: segment.destination,
+            server: segment.source,
+        };
+
+        // Known connections take precedence so that a new SYN on a reused port
+        // pair doesn't flip the direction.
+        if let Some(connection) = self.connections.get(&outgoing_key) {
+            return Some((outgoing_key, connection.server_type, PacketDirection::Outgoing));
+        }
+
+        if let Some(connection) = self.connections.get(&incoming_key) {
+            return Some((incoming_key, connection.server_type, PacketDirection::Incoming));
+        }
+
+        let source_type = self.server_ports.get(&segment.source.port()).copied();
+        let destination_type = self.server_ports.get(&segment.destination.port()).copied();
+
+        match (source_type, destination_type) {
+            // Both ports are server ports, so use the handshake to decide which side
+            // is the client. Only the server answers with SYN + ACK.
+            (Some(source_type), Some(_)) if segment.syn && segment.ack => Some((incoming_key, source_type, PacketDirection::Incoming)),
+            (_, Some(destination_type)) => Some((outgoing_key, destination_type, PacketDirection::Outgoing)),
+            (Some(source_type), None) => Some((incoming_key, source_type, PacketDirection::Incoming)),
+            (None, None) => None,
+        }
+    }
+}
+
+fn decode_stream(
+    decoder: &mut StreamDecoder,
+    stream: &mut HalfStream,
+    context: RecordContext,
+    records: &mut Vec<PacketRecord>,
+    announced_servers: &mut Vec<(u16, ServerType)>,
+) {
+    while !stream.buffer().is_empty() {
+        match decoder.decode(stream.buffer()) {
+            DecodeResult::Packet { packet, length } => {
+                stream.consume(length);
+                announced_servers.extend_from_slice(&packet.announced_servers);
+
+                records.push(
+                    context.create_record(Some(format_packet_header(packet.header)), RecordKind::Packet {
+                        name: packet.name.to_owned(),
+                        is_ping: packet.is_ping,
+                        content: packet.content,
+                    }),
+                );
+            }
+            DecodeResult::CutOff => break,
+            DecodeResult::Unknown => {
+                let bytes = stream.buffer();
+
+                records.push(context.create_record(PacketRecord::format_header(bytes), RecordKind::Unknown {
+                    hex_dump: hex_dump(bytes),
+                }));
+
+                // We can't know where the next packet starts, so the rest of the
+                // buffered data is lost.
+                stream.discard();
+            }
+            DecodeResult::Failed(error) => {
+                let bytes = stream.buffer();
+
+                records.push(context.create_record(PacketRecord::format_header(bytes), RecordKind::Failed {
+                    error,
+                    hex_dump: hex_dump(bytes),
+                }));
+
+                stream.discard();
+            }
+        }
+    }
+}
+
+fn trailing_record(stream: &mut HalfStream, context: RecordContext) -> Option<PacketRecord> {
+    let record = trailing_bytes_record(stream.buffer(), context);
+    stream.discard();
+    record
+}
+
+fn trailing_bytes_record(bytes: &[u8], context: RecordContext) -> Option<PacketRecord> {
+    if bytes.is_empty() {
+        return None;
+    }
+
+    Some(context.create_record(PacketRecord::format_header(bytes), RecordKind::Trailing {
+        hex_dump: hex_dump(bytes),
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::net::SocketAddr;
+
+    use super::capture::tests::{capture_file, ethernet_frame};
+    use super::{
+        Dissector, OutputFormat, PacketDirection, PacketFilter, PacketRecord, PacketSelector, RecordKind, RecordWriter, ServerType,
+    };
+
+    const SYN: u8 = 0x02;
+    const ACK: u8 = 0x10;
+    const PSH_ACK: u8 = 0x18;
+
+    fn dissect(frames: &[Vec<u8>], filter: PacketFilter) -> Vec<PacketRecord> {
+        let file = capture_file(frames);
+        let mut records = Vec::new();
+
+        Dissector::new(filter)
+            .dissect(file.as_slice(), |record| {
+                records.push(record);
+                Ok(())
+            })
+            .unwrap();
+
+        records
+    }
+
+    fn map_server_frames() -> Vec<Vec<u8>> {
+        let client: SocketAddr = "10.0.0.2:50000".parse().unwrap();
+        let server: SocketAddr = "10.0.0.1:5121".parse().unwrap();
+
+        vec![
+            ethernet_frame(client, server, 1000, SYN, &[]),
+            ethernet_frame(server, client, 5000, SYN | ACK, &[]),
+            // Server tick packet that is split over two segments, followed by an
+            // unknown packet.
+            ethernet_frame(server, client, 5001, PSH_ACK, &[0x7F, 0x00, 0x01, 0x00]),
+            ethernet_frame(server, client, 5005, PSH_ACK, &[0x00, 0x00, 0xFF, 0xFF, 0x01]),
+        ]
+    }
+
+    #[test]
+    fn reassemble_and_decode() {
+        let records = dissect(&map_server_frames(), PacketFilter::default());
+
+        assert_eq!(records.len(), 2);
+
+        assert_eq!(records[0].server_type, ServerType::Map);
+        assert_eq!(records[0].direction, PacketDirection::Incoming);
+        assert_eq!(records[0].header.as_deref(), Some("0x007f"));
+        assert_eq!(records[0].packet_name(), Some("ServerTickPacket"));
+        assert_eq!(records[0].timestamp.seconds, 1_700_000_003);
+
+        assert_eq!(records[1].header.as_deref(), Some("0xffff"));
+        assert!(matches!(&records[1].kind, RecordKind::Unknown { hex_dump } if hex_dump == "ff ff 01"));
+    }
+
+    #[test]
+    fn filter_by_packet() {
+        let filter = PacketFilter {
+            include: vec!["ServerTick".parse().unwrap()],
+            ..Default::default()
+        };
+        let records = dissect(&map_server_frames(), filter);
+
+        assert_eq!(records.len(), 1);
+        assert_eq!(records[0].packet_name(), Some("ServerTickPacket"));
+
+        let filter = PacketFilter {
+            exclude: vec!["0x007f".parse().unwrap(), PacketSelector::Undecoded],
+            ..Default::default()
+        };
+
+        assert!(dissect(&map_server_frames(), filter).is_empty());
+    }
+
+    #[test]
+    fn write_records() {
+        let records = dissect(&map_server_frames(), PacketFilter::default());
+
+        for format in [OutputFormat::Json, OutputFormat::Ron] {
+            let mut writer = RecordWriter::new(Vec::new(), format);
+
+            for record in &records {
+                writer.write(record).unwrap();
+            }
+
+            let output = String::from_utf8(writer.into_inner()).unwrap();
+
+            assert_eq!(output.lines().count(), records.len());
+            assert!(output.contains("ServerTickPacket"));
+            assert!(output.contains("ff ff 01"));
+        }
+    }
+}
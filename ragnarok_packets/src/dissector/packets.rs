@@ -0,0 +1,360 @@
+//! Packet handlers for every packet known to this crate, split by server
+//! and direction.
+
+use ragnarok_bytes::ByteReader;
+
+use super::{PacketDirection, ServerType};
+use crate::handler::{HandlerResult, NoPacketCallback, PacketHandler};
+use crate::*;
+
+macro_rules! register_packets {
+    ($handler:expr, [$($packet:ty),* $(,)?]) => {
+        $(
+            $handler.register(|packet: $packet| Some(DecodedPacket::new(&packet))).unwrap();
+        )*
+    };
+}
+
+/// A packet that was successfully read from the stream.
+#[derive(Debug, Clone)]
+pub struct DecodedPacket {
+    pub name: &'static str,
+    pub header: PacketHeader,
+    pub is_ping: bool,
+    pub content: String,
+    /// Server ports announced inside the packet, used to classify connections
+    /// that are established later in the capture.
+    pub announced_servers: Vec<(u16, ServerType)>,
+}
+
+impl DecodedPacket {
+    fn new<P: Packet>(packet: &P) -> Self {
+        let type_name = std::any::type_name::<P>();
+        let name = type_name.rsplit("::").next().unwrap_or(type_name);
+
+        Self {
+            name,
+            header: P::HEADER,
+            is_ping: P::IS_PING,
+            content: format!("{packet:?}"),
+            announced_servers: Vec::new(),
+        }
+    }
+}
+
+/// Result of decoding a single packet from a stream.
+pub enum DecodeResult {
+    Packet { packet: DecodedPacket, length: usize },
+    CutOff,
+    Unknown,
+    Failed(String),
+}
+
+/// Handler for one server type and direction.
+pub struct StreamDecoder {
+    packet_handler: PacketHandler<Option<DecodedPacket>, (), NoPacketCallback>,
+}
+
+impl StreamDecoder {
+    pub fn new(server_type: ServerType, direction: PacketDirection) -> Self {
+        let mut packet_handler = PacketHandler::default();
+
+        match (server_type, direction) {
+            (ServerType::Login, PacketDirection::Outgoing) => {
+                register_packets!(packet_handler, [LoginServerLoginPacket, LoginServerKeepalivePacket,]);
+            }
+            (ServerType::Login, PacketDirection::Incoming) => {
+                register_packets!(packet_handler, [LoginFailedPacket, LoginFailedPacket2,]);
+
+                packet_handler
+                    .register(|packet: LoginServerLoginSuccessPacket| {
+                        let mut decoded = DecodedPacket::new(&packet);
+                        decoded.announced_servers = packet
+                            .character_server_information
+                            .iter()
+                            .map(|information| (information.server_port, ServerType::Character))
+                            .collect();
+                        Some(decoded)
+                    })
+                    .unwrap();
+            }
+            (ServerType::Character, PacketDirection::Outgoing) => {
+                register_packets!(packet_handler, [
+                    CharacterServerLoginPacket,
+                    CreateCharacterPacket,
+                    RequestCharacterListPacket,
+                    DeleteCharacterPacket,
+                    SelectCharacterPacket,
+                    SwitchCharacterSlotPacket,
+                    CharacterServerKeepalivePacket,
+                ]);
+            }
+            (ServerType::Character, PacketDirection::Incoming) => {
+                register_packets!(packet_handler, [
+                    CharacterServerLoginSuccessPacket,
+                    CharacterListPacket,
+                    CharacterSlotPagePacket,
+                    CharacterBanListPacket,
+                    LoginPincodePacket,
+                    Packet0b18,
+                    LoginFailedPacket,
+                    MapServerUnavailablePacket,
+                    CharacterSelectionFailedPacket,
+                    CharacterCreationFailedPacket,
+                    CreateCharacterSuccessPacket,
+                    RequestCharacterListSuccessPacket,
+                    CharacterDeletionFailedPacket,
+                    CharacterDeletionSuccessPacket,
+                    SwitchCharacterSlotResponsePacket,
+                ]);
+
+                packet_handler
+                    .register(|packet: CharacterSelectionSuccessPacket| {
+                        let mut decoded = DecodedPacket::new(&packet);
+                        decoded.announced_servers = vec![(packet.map_server_port, ServerType::Map)];
+                        Some(decoded)
+                    })
+                    .unwrap();
+            }
+            (ServerType::Map, PacketDirection::Outgoing) => {
+                register_packets!(packet_handler, [
+                    MapServerLoginPacket,
+                    RequestPlayerMovePacket,
+                    RequestWarpToMapPacket,
+                    RequestDetailsPacket,
+                    RequestActionPacket,
+                    GlobalMessagePacket,
+                    RequestServerTickPacket,
+                    SetHotkeyData1Packet,
+                    SetHotkeyData2Packet,
+                    MapLoadedPacket,
+                    StartDialogPacket,
+                    NextDialogPacket,
+                    CloseDialogPacket,
+                    ChooseDialogOptionPacket,
+                    RequestEquipItemPacket,
+                    RequestUseItemPacket,
+                    RequestDropItemPacket,
+                    RequestPickUpItemPacket,
+                    RequestUnequipItemPacket,
+                    RestartPacket,
+                    RequestStatUpPacket,
+                    RequestSkillUpPacket,
+                    UseSkillAtIdPacket,
+                    UseSkillOnGroundPacket,
+                    StartUseSkillPacket,
+                    EndUseSkillPacket,
+                    AddFriendPacket,
+                    RemoveFriendPacket,
+                    FriendRequestResponsePacket,
+                    SelectBuyOrSellPacket,
+                    BuyItemsPacket,
+                    BuyShopItemsPacket,
+                    CloseShopPacket,
+                    SellItemsPacket,
+                    HomunculusCommandPacket,
+                    RenameHomunculusPacket,
+                    MoveCompanionPacket,
+                    CompanionAttackPacket,
+                    CompanionReturnToOwnerPacket,
+                    MercenaryCommandPacket,
+                    PetCommandPacket,
+                    RenamePetPacket,
+                    OpenVendingStorePacket,
+                    CloseVendingStorePacket,
+                    RequestVendingItemListPacket,
+                    BuyVendingItemsPacket,
+                    OpenBuyingStorePacket,
+                    CloseBuyingStorePacket,
+                    RequestBuyingStoreItemListPacket,
+                    SellToBuyingStorePacket,
+                    MoveItemToCartPacket,
+                    MoveItemFromCartPacket,
+                    RemoveCartPacket,
+                    OpenMailboxPacket,
+                    RequestMailListPacket,
+                    CloseMailboxPacket,
+                    ReadMailPacket,
+                    DeleteMailPacket,
+                    RequestMailZenyPacket,
+                    RequestMailItemsPacket,
+                    OpenWriteMailPacket,
+                    CancelWriteMailPacket,
+                    CheckMailRecipientPacket,
+                    AddItemToMailPacket,
+                    RemoveItemFromMailPacket,
+                    SendMailPacket,
+                ]);
+            }
+            (ServerType::Map, PacketDirection::Incoming) => {
+                register_packets!(packet_handler, [
+                    MapServerLoginSuccessPacket,
+                    Packet8302,
+                    MapServerPingPacket,
+                    EntityMovePacket,
+                    EntityStopMovePacket,
+                    PlayerMovePacket,
+                    ServerMessagePacket,
+                    RequestPlayerDetailsSuccessPacket,
+                    RequestEntityDetailsSuccessPacket,
+                    NewMailStatusPacket,
+                    AchievementUpdatePacket,
+                    AchievementListPacket,
+                    CriticalWeightUpdatePacket,
+                    SpriteChangePacket,
+                    InventoyStartPacket,
+                    InventoyEndPacket,
+                    RegularItemListPacket,
+                    EquippableItemListPacket,
+                    EquippableSwitchItemListPacket,
+                    MapTypePacket,
+                    Broadcast2MessagePacket,
+                    BroadcastMessagePacket,
+                    OverheadMessagePacket,
+                    EntityMessagePacket,
+                    DisplayEmotionPacket,
+                    UpdateStatusPacket,
+                    StatusChangeSequencePacket,
+                    InitialStatusPacket,
+                    UpdateStatusPacket1,
+                    UpdateStatusPacket2,
+                    UpdateStatusPacket3,
+                    RequestStatUpResponsePacket,
+                    UpdateSkillPacket,
+                    AddSkillPacket,
+                    UpdateAttackRangePacket,
+                    RequestPlayerAttackFailedPacket,
+                    UpdateEntityHealthPointsPacket,
+                    DamagePacket1,
+                    DamagePacket3,
+                    ServerTickPacket,
+                    ChangeMapPacket,
+                    EntityDisappearedPacket,
+                    MovingEntityAppearedPacket,
+                    ResurrectionPacket,
+                    EntityAppearedPacket,
+                    EntityAppeared2Packet,
+                    UpdateSkillTreePacket,
+                    UpdateHotkeysPacket,
+                    UpdatePartyInvitationStatePacket,
+                    UpdateShowEquipPacket,
+                    UpdateConfigurationPacket,
+                    NavigateToMonsterPacket,
+                    MarkMinimapPositionPacket,
+                    NextButtonPacket,
+                    CloseButtonPacket,
+                    DialogMenuPacket,
+                    DisplaySpecialEffectPacket,
+                    DisplaySkillCooldownPacket,
+                    DisplaySkillEffectAndDamagePacket,
+                    DisplayPlayerHealEffect,
+                    DisplaySkillEffectNoDamagePacket,
+                    StatusChangePacket,
+                    QuestNotificationPacket1,
+                    HuntingQuestNotificationPacket,
+                    HuntingQuestUpdateObjectivePacket,
+                    QuestRemovedPacket,
+                    QuestListPacket,
+                    VisualEffectPacket,
+                    DisplayGainedExperiencePacket,
+                    DisplayImagePacket,
+                    StateChangePacket,
+                    ItemPickupPacket,
+                    RemoveItemFromInventoryPacket,
+                    UseItemResultPacket,
+                    GroundItemAppearedPacket,
+                    GroundItemDroppedPacket,
+                    GroundItemDisappearedPacket,
+                    QuestEffectPacket,
+                    NpcDialogPacket,
+                    RequestEquipItemStatusPacket,
+                    RequestUnequipItemStatusPacket,
+                    ParameterChangePacket,
+                    RestartResponsePacket,
+                    DisconnectResponsePacket,
+                    UseSkillSuccessPacket,
+                    ToUseSkillSuccessPacket,
+                    NotifySkillUnitPacket,
+                    NotifyGroundSkillPacket,
+                    SkillUnitDisappearPacket,
+                    NotifyFriendRemovedPacket,
+                    FriendListPacket,
+                    FriendOnlineStatusPacket,
+                    FriendRequestPacket,
+                    FriendRequestResultPacket,
+                    PartyInvitePacket,
+                    ReputationPacket,
+                    ClanInfoPacket,
+                    ClanOnlineCountPacket,
+                    ChangeMapCellPacket,
+                    OpenMarketPacket,
+                    ShopItemListPacket,
+                    BuyOrSellPacket,
+                    BuyShopItemsResultPacket,
+                    SellListPacket,
+                    SellItemsResultPacket,
+                    HomunculusInformationPacket,
+                    HomunculusStatePacket,
+                    HomunculusFeedResultPacket,
+                    MercenaryInformationPacket,
+                    MercenaryParameterChangedPacket,
+                    PetInformationPacket,
+                    PetStatePacket,
+                    PetFeedResultPacket,
+                    VendingAvailablePacket,
+                    OpenVendingStoreResultPacket,
+                    OwnVendingItemListPacket,
+                    VendingStoreEntryPacket,
+                    VendingStoreDisappearedPacket,
+                    VendingItemListPacket,
+                    BuyVendingItemsResultPacket,
+                    VendingItemSoldPacket,
+                    BuyingStoreAvailablePacket,
+                    OpenBuyingStoreFailedPacket,
+                    OwnBuyingStoreItemListPacket,
+                    BuyingStoreEntryPacket,
+                    BuyingStoreDisappearedPacket,
+                    BuyingStoreItemListPacket,
+                    BuyingStoreTradeFailedPacket,
+                    BuyingStoreItemUpdatePacket,
+                    BuyingStoreItemSoldPacket,
+                    SellToBuyingStoreFailedPacket,
+                    CartInformationPacket,
+                    CartItemAddedPacket,
+                    CartItemRemovedPacket,
+                    CartRemovedPacket,
+                    AddItemToCartFailedPacket,
+                    MailListPacket,
+                    MailContentPacket,
+                    MailDeletedPacket,
+                    MailZenyResultPacket,
+                    MailItemsResultPacket,
+                    OpenWriteMailResultPacket,
+                    MailRecipientPacket,
+                    AddItemToMailResultPacket,
+                    RemoveItemFromMailResultPacket,
+                    SendMailResultPacket,
+                    Packet0b18,
+                ]);
+            }
+        }
+
+        Self { packet_handler }
+    }
+
+    /// Try to decode the packet at the start of `bytes`.
+    pub fn decode(&mut self, bytes: &[u8]) -> DecodeResult {
+        let mut byte_reader = ByteReader::without_metadata(bytes);
+
+        match self.packet_handler.process_one(&mut byte_reader) {
+            HandlerResult::Ok(packet) => DecodeResult::Packet {
+                // Handlers are never registered without output.
+                packet: packet.unwrap(),
+                length: byte_reader.get_offset(),
+            },
+            HandlerResult::PacketCutOff => DecodeResult::CutOff,
+            HandlerResult::UnhandledPacket => DecodeResult::Unknown,
+            HandlerResult::InternalError(error) => DecodeResult::Failed(format!("{error:?}")),
+        }
+    }
+}
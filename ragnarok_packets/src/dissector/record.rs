@@ -0,0 +1,195 @@
+//! Structured output of the dissector.
+
+use std::io::Write;
+use std::net::SocketAddr;
+use std::str::FromStr;
+
+use serde::Serialize;
+
+use super::{DissectorError, PacketDirection, ServerType};
+use crate::PacketHeader;
+
+/// Point in time a frame was captured at, relative to the Unix epoch.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+pub struct Timestamp {
+    pub seconds: u64,
+    pub nanoseconds: u32,
+}
+
+/// A single entry in the dissector output.
+#[derive(Debug, Clone, Serialize)]
+pub struct PacketRecord {
+    /// Capture time of the segment that completed the packet.
+    pub timestamp: Timestamp,
+    pub server_type: ServerType,
+    pub direction: PacketDirection,
+    pub client_address: SocketAddr,
+    pub server_address: SocketAddr,
+    /// Header of the packet formatted as hex, e.g. `0x0b1d`. Missing if there
+    /// are less than two bytes left in the stream.
+    pub header: Option<String>,
+    pub kind: RecordKind,
+}
+
+/// What was found in the stream.
+#[derive(Debug, Clone, Serialize)]
+pub enum RecordKind {
+    /// Packet was decoded successfully.
+    Packet { name: String, is_ping: bool, content: String },
+    /// No packet with this header is known. Since the length of unknown
+    /// packets can't be determined, the dump contains the rest of the data
+    /// that was available in the stream.
+    Unknown { hex_dump: String },
+    /// The packet is known but could not be decoded.
+    Failed { error: String, hex_dump: String },
+    /// Data that was left in the stream when the connection was closed.
+    Trailing { hex_dump: String },
+}
+
+impl PacketRecord {
+    /// The name of the packet, if it was decoded.
+    pub fn packet_name(&self) -> Option<&str> {
+        match &self.kind {
+            RecordKind::Packet { name, .. } => Some(name),
+            _ => None,
+        }
+    }
+
+    pub fn is_ping(&self) -> bool {
+        matches!(self.kind, RecordKind::Packet { is_ping: true, .. })
+    }
+
+    pub(super) fn format_header(bytes: &[u8]) -> Option<String> {
+        match bytes {
+            [low, high, ..] => Some(format_packet_header(PacketHeader(u16::from_le_bytes([*low, *high])))),
+            _ => None,
+        }
+    }
+}
+
+pub(super) fn format_packet_header(header: PacketHeader) -> String {
+    format!("0x{:0>4x}", header.0)
+}
+
+/// Format bytes as space separated hex pairs.
+pub fn hex_dump(bytes: &[u8]) -> String {
+    bytes.iter().map(|byte| format!("{byte:02x}")).collect::<Vec<_>>().join(" ")
+}
+
+/// Selects packets by name or header.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PacketSelector {
+    /// Matches the packet header, including unknown packets with that header.
+    Header(PacketHeader),
+    /// Matches the type name of a packet. The `Packet` suffix may be omitted.
+    Name(String),
+    /// Matches everything that could not be decoded.
+    Undecoded,
+}
+
+impl PacketSelector {
+    pub fn matches(&self, record: &PacketRecord) -> bool {
+        match self {
+            PacketSelector::Header(header) => record.header.as_deref() == Some(format_packet_header(*header).as_str()),
+            PacketSelector::Name(selector) => record
+                .packet_name()
+                .is_some_and(|name| name == selector || name.strip_suffix("Packet") == Some(selector)),
+            PacketSelector::Undecoded => !matches!(record.kind, RecordKind::Packet { .. }),
+        }
+    }
+}
+
+impl FromStr for PacketSelector {
+    type Err = DissectorError;
+
+    /// Parses `0x....` as a header, `undecoded` as
+    /// [`PacketSelector::Undecoded`] and anything else as a packet name.
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        if let Some(hex) = value.strip_prefix("0x").or_else(|| value.strip_prefix("0X")) {
+            return u16::from_str_radix(hex, 16)
+                .map(|header| PacketSelector::Header(PacketHeader(header)))
+                .map_err(|_| DissectorError::InvalidSelector(value.to_owned()));
+        }
+
+        match value {
+            "" => Err(DissectorError::InvalidSelector(value.to_owned())),
+            "undecoded" => Ok(PacketSelector::Undecoded),
+            name => Ok(PacketSelector::Name(name.to_owned())),
+        }
+    }
+}
+
+/// Runtime filter for the records emitted by the dissector.
+#[derive(Debug, Clone, Default)]
+pub struct PacketFilter {
+    /// If not empty, only records matching at least one selector are emitted.
+    pub include: Vec<PacketSelector>,
+    /// Records matching any of these selectors are never emitted.
+    pub exclude: Vec<PacketSelector>,
+    /// Hide packets that are marked as ping, e.g. keep-alive and tick packets.
+    pub hide_pings: bool,
+    /// If not empty, only records from these servers are emitted.
+    pub server_types: Vec<ServerType>,
+}
+
+impl PacketFilter {
+    pub fn matches(&self, record: &PacketRecord) -> bool {
+        (self.include.is_empty() || self.include.iter().any(|selector| selector.matches(record)))
+            && !self.exclude.iter().any(|selector| selector.matches(record))
+            && !(self.hide_pings && record.is_ping())
+            && (self.server_types.is_empty() || self.server_types.contains(&record.server_type))
+    }
+}
+
+/// Serialization format of the [`RecordWriter`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OutputFormat {
+    /// One JSON object per line (JSON Lines).
+    Json,
+    /// One RON value per line.
+    Ron,
+}
+
+impl FromStr for OutputFormat {
+    type Err = DissectorError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value.to_ascii_lowercase().as_str() {
+            "json" => Ok(OutputFormat::Json),
+            "ron" => Ok(OutputFormat::Ron),
+            _ => Err(DissectorError::InvalidFormat(value.to_owned())),
+        }
+    }
+}
+
+/// Writes records to any [`Write`] with one record per line.
+pub struct RecordWriter<W> {
+    writer: W,
+    format: OutputFormat,
+}
+
+impl<W: Write> RecordWriter<W> {
+    pub fn new(writer: W, format: OutputFormat) -> Self {
+        Self { writer, format }
+    }
+
+    pub fn write(&mut self, record: &PacketRecord) -> Result<(), DissectorError> {
+        match self.format {
+            OutputFormat::Json => {
+                serde_json::to_writer(&mut self.writer, record).map_err(|error| DissectorError::Serialization(error.to_string()))?
+            }
+            OutputFormat::Ron => {
+                let serialized = ron::to_string(record).map_err(|error| DissectorError::Serialization(error.to_string()))?;
+                self.writer.write_all(serialized.as_bytes())?;
+            }
+        }
+
+        self.writer.write_all(b"\n")?;
+
+        Ok(())
+    }
+
+    pub fn into_inner(self) -> W {
+        self.writer
+    }
+}
@@ -0,0 +1,247 @@
+//! Reassembly of TCP byte streams from individual segments.
+
+use std::collections::BTreeMap;
+
+/// Upper limit for data that is buffered out of order before we give up on a
+/// gap in the stream and skip ahead.
+const MAXIMUM_PENDING_BYTES: usize = 1 << 20;
+
+/// Result of adding a segment to a [`HalfStream`].
+#[derive(Debug, PartialEq, Eq)]
+pub enum SegmentResult {
+    /// No new bytes became available.
+    Pending,
+    /// New bytes were appended to the buffer.
+    Appended,
+    /// A gap in the stream was skipped. Contains the bytes that were buffered
+    /// before the gap, since they can't be continued anymore. The bytes after
+    /// the gap are in the buffer.
+    GapSkipped(Vec<u8>),
+}
+
+/// One direction of a TCP connection.
+#[derive(Debug, Default)]
+pub struct HalfStream {
+    /// Sequence number of the first byte of the stream.
+    initial_sequence_number: u32,
+    next_sequence_number: Option<u32>,
+    /// Segments that arrived out of order, keyed by their offset from the
+    /// start of the stream, so they stay ordered when the sequence number
+    /// wraps around.
+    pending: BTreeMap<u32, Vec<u8>>,
+    pending_bytes: usize,
+    buffer: Vec<u8>,
+}
+
+impl HalfStream {
+    /// Mark the start of the stream. The SYN flag consumes one sequence
+    /// number.
+    pub fn synchronize(&mut self, sequence_number: u32) {
+        self.initial_sequence_number = sequence_number.wrapping_add(1);
+        self.next_sequence_number = Some(self.initial_sequence_number);
+        self.pending.clear();
+        self.pending_bytes = 0;
+        self.buffer.clear();
+    }
+
+    /// Add a segment to the stream. Retransmitted data is discarded and
+    /// segments that arrive out of order are held back until the gap is
+    /// filled.
+    pub fn push_segment(&mut self, sequence_number: u32, payload: &[u8]) -> SegmentResult {
+        if payload.is_empty() {
+            return SegmentResult::Pending;
+        }
+
+        // Without a handshake we simply start at the first segment we see.
+        let next_sequence_number = match self.next_sequence_number {
+            Some(next_sequence_number) => next_sequence_number,
+            None => {
+                self.initial_sequence_number = sequence_number;
+                *self.next_sequence_number.insert(sequence_number)
+            }
+        };
+        let offset = sequence_number.wrapping_sub(next_sequence_number) as i32;
+
+        if offset > 0 {
+            let stream_offset = sequence_number.wrapping_sub(self.initial_sequence_number);
+            self.pending_bytes += payload.len();
+
+            // Retransmitted segments replace the data we already hold.
+            if let Some(replaced) = self.pending.insert(stream_offset, payload.to_vec()) {
+                self.pending_bytes -= replaced.len();
+            }
+
+            if self.pending_bytes > MAXIMUM_PENDING_BYTES {
+                // The missing data was most likely not captured, so skip the gap.
+                // The buffered bytes end in the gap, so they are handed back to the
+                // caller instead of being mixed with the data after the gap.
+                let (&stream_offset, _) = self.pending.iter().next().unwrap();
+                self.next_sequence_number = Some(self.initial_sequence_number.wrapping_add(stream_offset));
+                let lost = std::mem::take(&mut self.buffer);
+                self.drain_pending();
+
+                return SegmentResult::GapSkipped(lost);
+            }
+
+            return SegmentResult::Pending;
+        }
+
+        let already_received = offset.unsigned_abs() as usize;
+
+        if already_received >= payload.len() {
+            return SegmentResult::Pending;
+        }
+
+        self.append(&payload[already_received..]);
+        self.drain_pending();
+
+        SegmentResult::Appended
+    }
+
+    /// The contiguous bytes that have not been consumed yet.
+    pub fn buffer(&self) -> &[u8] {
+        &self.buffer
+    }
+
+    /// Remove the first `count` bytes from the buffer.
+    pub fn consume(&mut self, count: usize) {
+        self.buffer.drain(..count);
+    }
+
+    /// Remove all buffered bytes. Used when the stream can't be decoded
+    /// any further.
+    pub fn discard(&mut self) {
+        self.buffer.clear();
+    }
+
+    fn append(&mut self, bytes: &[u8]) {
+        self.buffer.extend_from_slice(bytes);
+        self.next_sequence_number = self.next_sequence_number.map(|number| number.wrapping_add(bytes.len() as u32));
+    }
+
+    fn drain_pending(&mut self) {
+        while let Some(next_sequence_number) = self.next_sequence_number {
+            let Some((&stream_offset, _)) = self.pending.iter().next() else {
+                break;
+            };
+
+            let sequence_number = self.initial_sequence_number.wrapping_add(stream_offset);
+            let offset = sequence_number.wrapping_sub(next_sequence_number) as i32;
+
+            if offset > 0 {
+                break;
+            }
+
+            let payload = self.pending.remove(&stream_offset).unwrap();
+            self.pending_bytes -= payload.len();
+
+            let already_received = offset.unsigned_abs() as usize;
+
+            if already_received < payload.len() {
+                self.append(&payload[already_received..]);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{HalfStream, MAXIMUM_PENDING_BYTES, SegmentResult};
+
+    #[test]
+    fn in_order() {
+        let mut stream = HalfStream::default();
+        stream.synchronize(99);
+
+        assert_eq!(SegmentResult::Appended, stream.push_segment(100, &[1, 2]));
+        assert_eq!(SegmentResult::Appended, stream.push_segment(102, &[3]));
+        assert_eq!(stream.buffer(), &[1, 2, 3]);
+
+        stream.consume(2);
+        assert_eq!(stream.buffer(), &[3]);
+    }
+
+    #[test]
+    fn out_of_order() {
+        let mut stream = HalfStream::default();
+        stream.synchronize(99);
+
+        assert_eq!(SegmentResult::Pending, stream.push_segment(102, &[3, 4]));
+        assert!(stream.buffer().is_empty());
+        assert_eq!(SegmentResult::Appended, stream.push_segment(100, &[1, 2]));
+        assert_eq!(stream.buffer(), &[1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn retransmission() {
+        let mut stream = HalfStream::default();
+        stream.synchronize(99);
+
+        assert_eq!(SegmentResult::Appended, stream.push_segment(100, &[1, 2]));
+        assert_eq!(SegmentResult::Pending, stream.push_segment(100, &[1, 2]));
+        assert_eq!(SegmentResult::Appended, stream.push_segment(101, &[2, 3]));
+        assert_eq!(stream.buffer(), &[1, 2, 3]);
+    }
+
+    #[test]
+    fn sequence_number_wraps() {
+        let mut stream = HalfStream::default();
+        stream.synchronize(u32::MAX - 1);
+
+        assert_eq!(SegmentResult::Appended, stream.push_segment(u32::MAX, &[1, 2]));
+        assert_eq!(SegmentResult::Appended, stream.push_segment(1, &[3]));
+        assert_eq!(stream.buffer(), &[1, 2, 3]);
+    }
+
+    #[test]
+    fn retransmission_out_of_order() {
+        let mut stream = HalfStream::default();
+        stream.synchronize(99);
+
+        for _ in 0..3 {
+            assert_eq!(SegmentResult::Pending, stream.push_segment(102, &[3, 4]));
+        }
+        assert_eq!(stream.pending_bytes, 2);
+
+        assert_eq!(SegmentResult::Appended, stream.push_segment(100, &[1, 2]));
+        assert_eq!(stream.buffer(), &[1, 2, 3, 4]);
+        assert_eq!(stream.pending_bytes, 0);
+    }
+
+    #[test]
+    fn out_of_order_across_wrap() {
+        let mut stream = HalfStream::default();
+        stream.synchronize(u32::MAX - 2);
+
+        // Both segments are pending, the one after the wrap has the smaller
+        // sequence number.
+        assert_eq!(SegmentResult::Pending, stream.push_segment(1, &[4]));
+        assert_eq!(SegmentResult::Pending, stream.push_segment(u32::MAX, &[2, 3]));
+        assert_eq!(SegmentResult::Appended, stream.push_segment(u32::MAX - 1, &[1]));
+        assert_eq!(stream.buffer(), &[1, 2, 3, 4]);
+        assert_eq!(stream.pending_bytes, 0);
+    }
+
+    #[test]
+    fn skip_gap_on_overflow() {
+        let mut stream = HalfStream::default();
+        stream.synchronize(99);
+
+        // A packet that is cut off by a segment that was never captured.
+        assert_eq!(SegmentResult::Appended, stream.push_segment(100, &[1, 2, 3]));
+
+        let segment = vec![7; MAXIMUM_PENDING_BYTES];
+        let first = 200;
+        let second = first + segment.len() as u32;
+        assert_eq!(SegmentResult::Pending, stream.push_segment(first, &segment));
+
+        // The reassembled bytes are handed back instead of being dropped, and the
+        // stream continues after the gap.
+        assert_eq!(SegmentResult::GapSkipped(vec![1, 2, 3]), stream.push_segment(second, &[8; 2]));
+        assert_eq!(stream.buffer().len(), segment.len() + 2);
+        assert_eq!(stream.buffer()[..segment.len()], segment[..]);
+
+        assert_eq!(SegmentResult::Appended, stream.push_segment(second + 2, &[9]));
+        assert_eq!(stream.buffer().last(), Some(&9));
+    }
+}
@@ -23,8 +23,35 @@ pub struct DuplicateHandlerError {
     pub packet_header: PacketHeader,
 }
 
+/// The server a connection belongs to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub enum ServerType {
+    Login,
+    Character,
+    Map,
+}
+
+impl ServerType {
+    pub fn name(self) -> &'static str {
+        match self {
+            ServerType::Login => "login",
+            ServerType::Character => "character",
+            ServerType::Map => "map",
+        }
+    }
+}
+
 /// Trait for monitoring the incoming and outgoing packets.
 pub trait PacketCallback: Clone + 'static {
+    /// Create the callback that is used for all packets of a connection to a
+    /// single server. This allows telling apart the packets of different
+    /// servers.
+    fn for_server(&self, server_type: ServerType) -> Self {
+        let _ = server_type;
+        self.clone()
+    }
+
     /// Called by the [`PacketHandler`] when a packet is received.
     fn incoming_packet<Packet>(&self, packet: &Packet)
     where
@@ -41,6 +68,12 @@ pub trait PacketCallback: Clone + 'static {
         let _ = packet;
     }
 
+    /// Called when raw bytes are sent that are not known to be a valid packet,
+    /// e.g. when replaying an edited packet.
+    fn outgoing_bytes(&self, bytes: &[u8]) {
+        let _ = bytes;
+    }
+
     /// Called by the [`PacketHandler`] when a packet arrives that doesn't have
     /// a handler registered.
     fn unknown_packet(&self, bytes: Vec<u8>) {
@@ -1,3 +1,5 @@
+#[cfg(feature = "dissector")]
+pub mod dissector;
 pub mod handler;
 mod position;
 
@@ -778,13 +780,6 @@ pub struct RequestEntityDetailsSuccessPacket {
     pub title: String,
 }
 
-#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
-#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
-#[header(0x09E7)]
-pub struct NewMailStatusPacket {
-    pub new_available: u8,
-}
-
 #[derive(Debug, Clone, ByteConvertable)]
 #[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
 pub struct AchievementData {
@@ -1152,7 +1147,15 @@ pub enum StatusType {
 
 impl FromBytes for StatusType {
     fn from_bytes<Meta>(byte_reader: &mut ByteReader<Meta>) -> ConversionResult<Self> {
-        let status = match u16::from_bytes(byte_reader).trace::<Self>()? {
+        let status_code = u16::from_bytes(byte_reader).trace::<Self>()?;
+
+        // Stats with a base and a bonus value are sent in [UpdateStatusPacket1],
+        // which uses four bytes for the status code instead of two.
+        if matches!(status_code, 13..=18 | 219..=224) {
+            u16::from_bytes(byte_reader).trace::<Self>()?;
+        }
+
+        let status = match status_code {
             0 => u32::from_bytes(byte_reader).map(Self::MovementSpeed),
             1 => u64::from_bytes(byte_reader).map(Self::BaseExperience),
             2 => u64::from_bytes(byte_reader).map(Self::JobExperience),
@@ -1258,8 +1261,8 @@ pub struct StatusChangeSequencePacket {
 }
 
 /// Sent by the character server to the client when loading onto a new map.
-/// This packet is ignored by Korangar since all of the provided values are set
-/// again individually using the UpdateStatusPackets.
+/// Bonus values for the base stats are sent afterwards using
+/// [UpdateStatusPacket1].
 #[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
 #[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
 #[header(0x00BD)]
@@ -1319,6 +1322,41 @@ pub struct UpdateStatusPacket3 {
     pub status_type: StatusType,
 }
 
+/// Base stats of a character that can be raised by spending status points.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ByteConvertable)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[numeric_type(u16)]
+pub enum StatType {
+    #[numeric_value(13)]
+    Strength,
+    Agility,
+    Vitality,
+    Intelligence,
+    Dexterity,
+    Luck,
+}
+
+/// Sent by the client to the map server when the player wants to spend
+/// status points to raise one of their base stats.
+#[derive(Debug, Clone, Packet, ClientPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x00BB)]
+pub struct RequestStatUpPacket {
+    pub stat_type: StatType,
+    pub amount: u8,
+}
+
+/// Sent by the map server as a response to [RequestStatUpPacket]. `value` is
+/// the new base value of the stat.
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x00BC)]
+pub struct RequestStatUpResponsePacket {
+    pub stat_type: StatType,
+    pub success: u8,
+    pub value: u8,
+}
+
 #[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
 #[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
 #[header(0x013A)]
@@ -1679,6 +1717,36 @@ pub struct UpdateSkillTreePacket {
     pub skill_information: Vec<SkillInformation>,
 }
 
+/// Sent by the client to the map server when the player wants to spend a
+/// skill point.
+#[derive(Debug, Clone, Packet, ClientPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x0112)]
+pub struct RequestSkillUpPacket {
+    pub skill_id: SkillId,
+}
+
+/// Sent by the map server when the level of one of the player's skills
+/// changed, for example as a response to [RequestSkillUpPacket].
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x010E)]
+pub struct UpdateSkillPacket {
+    pub skill_id: SkillId,
+    pub skill_level: SkillLevel,
+    pub spell_point_cost: u16,
+    pub attack_range: u16,
+    pub upgradable: u8,
+}
+
+/// Sent by the map server when the player can learn a new skill.
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x0111)]
+pub struct AddSkillPacket {
+    pub skill_information: SkillInformation,
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, ByteConvertable)]
 #[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
 pub struct HotkeyData {
@@ -2114,6 +2182,84 @@ pub struct RemoveItemFromInventoryPacket {
     pub amount: u16,
 }
 
+#[derive(Debug, Clone, Packet, ClientPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x0439)]
+pub struct RequestUseItemPacket {
+    pub index: InventoryIndex,
+    pub account_id: AccountId,
+}
+
+/// Sent by the map server when any entity in range used an item. `amount` is
+/// the number of items left after using one.
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x01C8)]
+pub struct UseItemResultPacket {
+    pub index: InventoryIndex,
+    pub item_id: ItemId,
+    pub entity_id: EntityId,
+    pub amount: u16,
+    pub success: u8,
+}
+
+#[derive(Debug, Clone, Packet, ClientPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x0363)]
+pub struct RequestDropItemPacket {
+    pub index: InventoryIndex,
+    pub amount: u16,
+}
+
+#[derive(Debug, Clone, Packet, ClientPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x0362)]
+pub struct RequestPickUpItemPacket {
+    pub entity_id: EntityId,
+}
+
+/// Sent by the map server for items that are already lying on the ground when
+/// they come into view.
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x009D)]
+pub struct GroundItemAppearedPacket {
+    pub entity_id: EntityId,
+    pub item_id: ItemId,
+    pub is_identified: u8,
+    pub position: TilePosition,
+    pub amount: u16,
+    /// Position inside the tile.
+    pub sub_x: u8,
+    pub sub_y: u8,
+}
+
+/// Sent by the map server when an item is dropped on the ground, for example
+/// by a player or a monster.
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x0ADD)]
+pub struct GroundItemDroppedPacket {
+    pub entity_id: EntityId,
+    pub item_id: ItemId,
+    pub item_type: u16,
+    pub is_identified: u8,
+    pub position: TilePosition,
+    /// Position inside the tile.
+    pub sub_x: u8,
+    pub sub_y: u8,
+    pub amount: u16,
+    pub show_drop_effect: u8,
+    pub drop_effect_mode: u16,
+}
+
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x00A1)]
+pub struct GroundItemDisappearedPacket {
+    pub entity_id: EntityId,
+}
+
 // TODO: improve names
 #[derive(Debug, Clone, ByteConvertable)]
 #[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
@@ -3030,3 +3176,1059 @@ pub enum SellItemsResult {
 pub struct SellItemsResultPacket {
     pub result: SellItemsResult,
 }
+
+/// Sent by the map server after using the vending skill. The client is
+/// expected to answer with an [`OpenVendingStorePacket`].
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x012D)]
+pub struct VendingAvailablePacket {
+    pub item_count: u16,
+}
+
+#[derive(Debug, Clone, FixedByteSize, ByteConvertable)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+pub struct OpenVendingStoreItemInformation {
+    pub cart_index: InventoryIndex,
+    pub amount: u16,
+    pub price: Price,
+}
+
+#[derive(Debug, Clone, Packet, ClientPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x01B2)]
+#[variable_length]
+pub struct OpenVendingStorePacket {
+    #[length(80)]
+    pub title: String,
+    /// 1 to open the store, 0 to cancel.
+    #[new_value(1)]
+    pub open: u8,
+    #[repeating_remaining]
+    pub items: Vec<OpenVendingStoreItemInformation>,
+}
+
+#[derive(Debug, Clone, Copy, ByteConvertable)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+pub enum OpenVendingStoreResult {
+    Success,
+    Failed,
+}
+
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x0A28)]
+pub struct OpenVendingStoreResultPacket {
+    pub result: OpenVendingStoreResult,
+}
+
+#[derive(Debug, Clone, FixedByteSize, ByteConvertable)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+pub struct OwnVendingItemInformation {
+    pub price: Price,
+    pub cart_index: InventoryIndex,
+    pub amount: u16,
+    pub item_type: u8,
+    pub item_id: ItemId,
+    pub identified: u8,
+    pub damaged: u8,
+    pub refinement_level: u8,
+    pub slot: [u32; 4],
+    pub option_data: [ItemOptions; 5],
+    pub enchantment_level: u8,
+}
+
+/// Sent to the vendor once the store is open.
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x0136)]
+#[variable_length]
+pub struct OwnVendingItemListPacket {
+    pub entity_id: EntityId,
+    #[repeating_remaining]
+    pub items: Vec<OwnVendingItemInformation>,
+}
+
+#[derive(Debug, Clone, Packet, ClientPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x012E)]
+pub struct CloseVendingStorePacket {}
+
+/// The title of a vending store that is displayed above the vendor.
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x0131)]
+pub struct VendingStoreEntryPacket {
+    pub entity_id: EntityId,
+    #[length(80)]
+    pub title: String,
+}
+
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x0132)]
+pub struct VendingStoreDisappearedPacket {
+    pub entity_id: EntityId,
+}
+
+#[derive(Debug, Clone, Packet, ClientPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x0130)]
+pub struct RequestVendingItemListPacket {
+    pub entity_id: EntityId,
+}
+
+#[derive(Debug, Clone, FixedByteSize, ByteConvertable)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+pub struct VendingItemInformation {
+    pub price: Price,
+    pub amount: u16,
+    pub cart_index: InventoryIndex,
+    pub item_type: u8,
+    pub item_id: ItemId,
+    pub identified: u8,
+    pub damaged: u8,
+    pub refinement_level: u8,
+    pub slot: [u32; 4],
+    pub option_data: [ItemOptions; 5],
+    pub location: u32,
+    pub view_sprite: u16,
+    pub enchantment_level: u8,
+}
+
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x0800)]
+#[variable_length]
+pub struct VendingItemListPacket {
+    pub entity_id: EntityId,
+    pub store_id: u32,
+    #[repeating_remaining]
+    pub items: Vec<VendingItemInformation>,
+}
+
+#[derive(Debug, Clone, FixedByteSize, ByteConvertable)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+pub struct BuyVendingItemInformation {
+    pub amount: u16,
+    pub cart_index: InventoryIndex,
+}
+
+#[derive(Debug, Clone, Packet, ClientPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x0801)]
+#[variable_length]
+pub struct BuyVendingItemsPacket {
+    pub entity_id: EntityId,
+    pub store_id: u32,
+    #[repeating_remaining]
+    pub items: Vec<BuyVendingItemInformation>,
+}
+
+#[derive(Debug, Clone, Copy, ByteConvertable)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+pub enum BuyVendingItemsResult {
+    #[numeric_value(0)]
+    Success,
+    #[numeric_value(1)]
+    NotEnoughZeny,
+    #[numeric_value(2)]
+    WeightLimitExceeded,
+    #[numeric_value(4)]
+    OutOfStock,
+    #[numeric_value(5)]
+    VendorIsTrading,
+    #[numeric_value(6)]
+    StoreChanged,
+    #[numeric_value(7)]
+    TooManyItems,
+}
+
+/// Only sent by the map server if buying an item failed.
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x0135)]
+pub struct BuyVendingItemsResultPacket {
+    pub cart_index: InventoryIndex,
+    pub amount: u16,
+    pub result: BuyVendingItemsResult,
+}
+
+/// Sent to the vendor when someone bought an item from their store.
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x09E5)]
+pub struct VendingItemSoldPacket {
+    pub cart_index: InventoryIndex,
+    pub amount: u16,
+    pub buyer_id: CharacterId,
+    pub time: u32,
+    pub zeny: u32,
+}
+
+/// Sent by the map server after using the buying store skill.
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x0810)]
+pub struct BuyingStoreAvailablePacket {
+    pub item_count: u8,
+}
+
+#[derive(Debug, Clone, FixedByteSize, ByteConvertable)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+pub struct OpenBuyingStoreItemInformation {
+    pub item_id: ItemId,
+    pub amount: u16,
+    pub price: Price,
+}
+
+#[derive(Debug, Clone, Packet, ClientPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x0811)]
+#[variable_length]
+pub struct OpenBuyingStorePacket {
+    pub zeny_limit: u32,
+    /// 1 to open the store, 0 to cancel.
+    #[new_value(1)]
+    pub open: u8,
+    #[length(80)]
+    pub title: String,
+    #[repeating_remaining]
+    pub items: Vec<OpenBuyingStoreItemInformation>,
+}
+
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x0812)]
+pub struct OpenBuyingStoreFailedPacket {
+    pub result: u16,
+    pub total_weight: u32,
+}
+
+#[derive(Debug, Clone, FixedByteSize, ByteConvertable)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+pub struct BuyingStoreItemInformation {
+    pub price: Price,
+    pub amount: u16,
+    pub item_type: u8,
+    pub item_id: ItemId,
+}
+
+/// Sent to the buyer once the store is open.
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x0813)]
+#[variable_length]
+pub struct OwnBuyingStoreItemListPacket {
+    pub entity_id: EntityId,
+    pub zeny_limit: u32,
+    #[repeating_remaining]
+    pub items: Vec<BuyingStoreItemInformation>,
+}
+
+/// The title of a buying store that is displayed above the buyer.
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x0814)]
+pub struct BuyingStoreEntryPacket {
+    pub entity_id: EntityId,
+    #[length(80)]
+    pub title: String,
+}
+
+#[derive(Debug, Clone, Packet, ClientPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x0815)]
+pub struct CloseBuyingStorePacket {}
+
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x0816)]
+pub struct BuyingStoreDisappearedPacket {
+    pub entity_id: EntityId,
+}
+
+#[derive(Debug, Clone, Packet, ClientPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x0817)]
+pub struct RequestBuyingStoreItemListPacket {
+    pub entity_id: EntityId,
+}
+
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x0818)]
+#[variable_length]
+pub struct BuyingStoreItemListPacket {
+    pub entity_id: EntityId,
+    pub store_id: u32,
+    pub zeny_limit: u32,
+    #[repeating_remaining]
+    pub items: Vec<BuyingStoreItemInformation>,
+}
+
+#[derive(Debug, Clone, FixedByteSize, ByteConvertable)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+pub struct SellToBuyingStoreItemInformation {
+    pub inventory_index: InventoryIndex,
+    pub item_id: ItemId,
+    pub amount: u16,
+}
+
+#[derive(Debug, Clone, Packet, ClientPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x0819)]
+#[variable_length]
+pub struct SellToBuyingStorePacket {
+    pub entity_id: EntityId,
+    pub store_id: u32,
+    #[repeating_remaining]
+    pub items: Vec<SellToBuyingStoreItemInformation>,
+}
+
+/// Sent to the buyer if a trade with their store failed.
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x081A)]
+pub struct BuyingStoreTradeFailedPacket {
+    pub result: u16,
+}
+
+/// Sent to the buyer when someone sold an item to their store.
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x081B)]
+pub struct BuyingStoreItemUpdatePacket {
+    pub item_id: ItemId,
+    pub amount: u16,
+    pub zeny_limit: u32,
+}
+
+/// Sent to the seller when an item was sold to a buying store. The item is
+/// not removed from the inventory by any other packet.
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x081C)]
+pub struct BuyingStoreItemSoldPacket {
+    pub inventory_index: InventoryIndex,
+    pub amount: u16,
+    pub zeny: u32,
+}
+
+/// Sent to the seller if selling an item to a buying store failed.
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x0824)]
+pub struct SellToBuyingStoreFailedPacket {
+    pub result: u16,
+    pub item_id: ItemId,
+}
+
+/// Sent by the map server to update the number of items and the weight of the
+/// player's cart.
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x0121)]
+pub struct CartInformationPacket {
+    pub item_count: u16,
+    pub maximum_item_count: u16,
+    pub weight: u32,
+    pub maximum_weight: u32,
+}
+
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x0B45)]
+pub struct CartItemAddedPacket {
+    pub index: InventoryIndex,
+    pub amount: u32,
+    pub item_id: ItemId,
+    pub item_type: u8,
+    pub is_identified: u8,
+    pub is_broken: u8,
+    pub cards: [u32; 4],
+    pub option_data: [ItemOptions; 5],
+    pub refinement_level: u8,
+    pub enchantment_level: u8,
+}
+
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x0125)]
+pub struct CartItemRemovedPacket {
+    pub index: InventoryIndex,
+    pub amount: u32,
+}
+
+#[derive(Debug, Clone, Packet, ClientPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x0126)]
+pub struct MoveItemToCartPacket {
+    pub index: InventoryIndex,
+    pub amount: u32,
+}
+
+#[derive(Debug, Clone, Packet, ClientPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x0127)]
+pub struct MoveItemFromCartPacket {
+    pub index: InventoryIndex,
+    pub amount: u32,
+}
+
+#[derive(Debug, Clone, Packet, ClientPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x012A)]
+pub struct RemoveCartPacket {}
+
+/// Sent by the map server when the player no longer has a cart.
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x012B)]
+pub struct CartRemovedPacket {}
+
+#[derive(Debug, Clone, Copy, ByteConvertable, PartialEq, Eq)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+pub enum AddItemToCartResult {
+    Overweight,
+    TooManyItems,
+}
+
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x012C)]
+pub struct AddItemToCartFailedPacket {
+    pub result: AddItemToCartResult,
+}
+
+bitflags::bitflags! {
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    #[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+    pub struct HomunculusFlags: u8 {
+        const RENAMED = 0b001;
+        const VAPORIZED = 0b010;
+        const ALIVE = 0b100;
+    }
+}
+
+impl FixedByteSize for HomunculusFlags {
+    fn size_in_bytes() -> usize {
+        <<Self as bitflags::Flags>::Bits as FixedByteSize>::size_in_bytes()
+    }
+}
+
+impl FromBytes for HomunculusFlags {
+    fn from_bytes<Meta>(byte_reader: &mut ByteReader<Meta>) -> ConversionResult<Self> {
+        <Self as bitflags::Flags>::Bits::from_bytes(byte_reader).map(Self::from_bits_truncate)
+    }
+}
+
+impl ToBytes for HomunculusFlags {
+    fn to_bytes(&self, byte_writer: &mut ByteWriter) -> ConversionResult<usize> {
+        self.bits().to_bytes(byte_writer)
+    }
+}
+
+/// Sent by the map server to update all properties of the homunculus of the
+/// player.
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x09F7)]
+pub struct HomunculusInformationPacket {
+    #[length(24)]
+    pub name: String,
+    pub flags: HomunculusFlags,
+    pub level: u16,
+    pub hunger: u16,
+    pub intimacy: u16,
+    pub accessory_id: u16,
+    pub attack: u16,
+    pub magic_attack: u16,
+    pub hit: u16,
+    pub critical: u16,
+    pub defense: u16,
+    pub magic_defense: u16,
+    pub flee: u16,
+    pub attack_speed: u16,
+    pub health_points: u32,
+    pub maximum_health_points: u32,
+    pub spell_points: u16,
+    pub maximum_spell_points: u16,
+    pub experience: u32,
+    pub next_experience: u32,
+    pub skill_points: u16,
+    pub attack_range: u16,
+}
+
+/// Sent by the map server when the homunculus of the player was spawned or
+/// when its intimacy or hunger changed. Servers use the `state` values `0`
+/// (spawned), `1` (intimacy) and `2` (hunger).
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x0230)]
+pub struct HomunculusStatePacket {
+    pub companion_type: u8,
+    pub state: u8,
+    pub entity_id: EntityId,
+    pub value: u32,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ByteConvertable)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+pub enum HomunculusCommand {
+    RequestInformation,
+    Feed,
+    Delete,
+}
+
+#[derive(Debug, Clone, Packet, ClientPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x022D)]
+pub struct HomunculusCommandPacket {
+    #[new_default]
+    pub companion_type: u16,
+    pub command: HomunculusCommand,
+}
+
+#[derive(Debug, Clone, Packet, ClientPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x0231)]
+pub struct RenameHomunculusPacket {
+    #[length(24)]
+    pub name: String,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ByteConvertable)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+pub enum FeedResult {
+    Failed,
+    Success,
+}
+
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x022F)]
+pub struct HomunculusFeedResultPacket {
+    pub result: FeedResult,
+    pub item_id: u16,
+}
+
+/// Sent by the client to the map server to move a companion (homunculus or
+/// mercenary) of the player to a specific position.
+#[derive(Debug, Clone, Packet, ClientPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x0232)]
+pub struct MoveCompanionPacket {
+    pub entity_id: EntityId,
+    pub position: WorldPosition,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ByteConvertable)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+pub enum CompanionAttackType {
+    Single,
+    Continuous,
+}
+
+/// Sent by the client to the map server to make a companion (homunculus or
+/// mercenary) of the player attack a target.
+#[derive(Debug, Clone, Packet, ClientPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x0233)]
+pub struct CompanionAttackPacket {
+    pub entity_id: EntityId,
+    pub target_entity_id: EntityId,
+    pub attack_type: CompanionAttackType,
+}
+
+/// Sent by the client to the map server to make a companion (homunculus or
+/// mercenary) of the player return to the player.
+#[derive(Debug, Clone, Packet, ClientPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x0234)]
+pub struct CompanionReturnToOwnerPacket {
+    pub entity_id: EntityId,
+}
+
+/// Sent by the map server to update all properties of the mercenary of the
+/// player.
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x029B)]
+pub struct MercenaryInformationPacket {
+    pub entity_id: EntityId,
+    pub attack: u16,
+    pub magic_attack: u16,
+    pub hit: u16,
+    pub critical: u16,
+    pub defense: u16,
+    pub magic_defense: u16,
+    pub flee: u16,
+    pub attack_speed: u16,
+    #[length(24)]
+    pub name: String,
+    pub level: u16,
+    pub health_points: u32,
+    pub maximum_health_points: u32,
+    pub spell_points: u32,
+    pub maximum_spell_points: u32,
+    pub expiration_time: u32,
+    pub loyalty: u16,
+    pub summon_count: u32,
+    pub kill_count: u32,
+    pub attack_range: u16,
+}
+
+/// Sent by the map server when a single property of the mercenary of the
+/// player changed. The `parameter` uses the same values as the
+/// [`StatusType`] ids and some mercenary specific ones.
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x02A2)]
+pub struct MercenaryParameterChangedPacket {
+    pub parameter: u16,
+    pub value: u32,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ByteConvertable)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+pub enum MercenaryCommand {
+    #[numeric_value(1)]
+    RequestInformation,
+    #[numeric_value(2)]
+    Dismiss,
+}
+
+#[derive(Debug, Clone, Packet, ClientPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x029F)]
+pub struct MercenaryCommandPacket {
+    pub command: MercenaryCommand,
+}
+
+/// Sent by the map server to update all properties of the pet of the player.
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x01A2)]
+pub struct PetInformationPacket {
+    #[length(24)]
+    pub name: String,
+    pub renamed: u8,
+    pub level: u16,
+    pub hunger: u16,
+    pub intimacy: u16,
+    pub accessory_id: u16,
+    pub job: u16,
+}
+
+/// Sent by the map server when a pet was spawned or one of its properties
+/// changed. Servers use the `state` values `0` (spawned), `1` (intimacy),
+/// `2` (hunger), `3` (accessory), `4` (performance) and `5` (hairstyle).
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x01A4)]
+pub struct PetStatePacket {
+    pub state: u8,
+    pub entity_id: EntityId,
+    pub value: u32,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ByteConvertable)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+pub enum PetCommand {
+    RequestInformation,
+    Feed,
+    Performance,
+    ReturnToEgg,
+    UnequipAccessory,
+}
+
+#[derive(Debug, Clone, Packet, ClientPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x01A1)]
+pub struct PetCommandPacket {
+    pub command: PetCommand,
+}
+
+#[derive(Debug, Clone, Packet, ClientPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x01A5)]
+pub struct RenamePetPacket {
+    #[length(24)]
+    pub name: String,
+}
+
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x01A3)]
+pub struct PetFeedResultPacket {
+    pub result: FeedResult,
+    pub item_id: u16,
+}
+
+#[derive(Clone, Copy, Debug, ByteConvertable, FixedByteSize, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+pub struct MailId(pub u64);
+
+/// The category of a mail. Mails of every category are listed in the same
+/// mailbox, but the category needs to be sent back when interacting with the
+/// mail.
+#[derive(Debug, Clone, Copy, ByteConvertable, FixedByteSize, PartialEq, Eq)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+pub enum MailType {
+    Character,
+    Account,
+    Returned,
+    Unset,
+}
+
+bitflags::bitflags! {
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    #[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+    pub struct MailFlags: u8 {
+        const TEXT = 0b0001;
+        const ZENY = 0b0010;
+        const ITEM = 0b0100;
+        const NPC = 0b1000;
+    }
+}
+
+impl FixedByteSize for MailFlags {
+    fn size_in_bytes() -> usize {
+        <<Self as bitflags::Flags>::Bits as FixedByteSize>::size_in_bytes()
+    }
+}
+
+impl FromBytes for MailFlags {
+    fn from_bytes<Meta>(byte_reader: &mut ByteReader<Meta>) -> ConversionResult<Self> {
+        <Self as bitflags::Flags>::Bits::from_bytes(byte_reader).map(Self::from_bits_truncate)
+    }
+}
+
+impl ToBytes for MailFlags {
+    fn to_bytes(&self, byte_writer: &mut ByteWriter) -> ConversionResult<usize> {
+        self.bits().to_bytes(byte_writer)
+    }
+}
+
+/// Sent by the map server when the player logs in or receives a new mail.
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x09E7)]
+pub struct NewMailStatusPacket {
+    pub new_available: u8,
+}
+
+/// Open the mailbox and request the newest mails.
+#[derive(Debug, Clone, Packet, ClientPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x0AC0)]
+pub struct OpenMailboxPacket {
+    #[new_value(MailId(0))]
+    pub mail_id: MailId,
+    #[new_default]
+    pub unused: [u8; 16],
+}
+
+/// Request the next page of mails, starting after the given mail.
+#[derive(Debug, Clone, Packet, ClientPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x0AC1)]
+pub struct RequestMailListPacket {
+    pub mail_id: MailId,
+    #[new_default]
+    pub unused: [u8; 16],
+}
+
+#[derive(Debug, Clone, Packet, ClientPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x09E9)]
+pub struct CloseMailboxPacket {}
+
+#[derive(Debug, Clone, ByteConvertable)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+pub struct MailInformation {
+    pub mail_type: MailType,
+    pub mail_id: MailId,
+    pub is_read: u8,
+    pub flags: MailFlags,
+    #[length(24)]
+    pub sender: String,
+    /// Seconds since the mail was sent.
+    pub sent_time: u32,
+    /// Seconds until the mail expires.
+    pub expiration_time: u32,
+    pub title_length: u16,
+    #[length(title_length)]
+    pub title: String,
+}
+
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x0AC2)]
+#[variable_length]
+pub struct MailListPacket {
+    pub is_end: u8,
+    #[length_remaining]
+    pub mails: Vec<MailInformation>,
+}
+
+#[derive(Debug, Clone, Packet, ClientPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x09EA)]
+pub struct ReadMailPacket {
+    pub mail_type: MailType,
+    pub mail_id: MailId,
+}
+
+#[derive(Debug, Clone, ByteConvertable, FixedByteSize)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+pub struct MailItemInformation {
+    pub amount: u16,
+    pub item_id: ItemId,
+    pub is_identified: u8,
+    pub is_broken: u8,
+    pub cards: [u32; 4],
+    pub location: u32,
+    pub item_type: u8,
+    pub view_sprite: u16,
+    pub bind_on_equip_type: u16,
+    pub option_data: [ItemOptions; 5],
+    pub refinement_level: u8,
+    pub enchantment_level: u8,
+}
+
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x09EB)]
+#[variable_length]
+pub struct MailContentPacket {
+    pub mail_type: MailType,
+    pub mail_id: MailId,
+    pub text_length: u16,
+    pub zeny: u64,
+    pub item_count: u8,
+    #[length(text_length)]
+    pub text: String,
+    #[repeating(item_count)]
+    pub items: Vec<MailItemInformation>,
+}
+
+#[derive(Debug, Clone, Packet, ClientPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x09F5)]
+pub struct DeleteMailPacket {
+    pub mail_type: MailType,
+    pub mail_id: MailId,
+}
+
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x09F6)]
+pub struct MailDeletedPacket {
+    pub mail_type: MailType,
+    pub mail_id: MailId,
+}
+
+#[derive(Debug, Clone, Packet, ClientPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x09F1)]
+pub struct RequestMailZenyPacket {
+    pub mail_id: MailId,
+    pub mail_type: MailType,
+}
+
+#[derive(Debug, Clone, Copy, ByteConvertable, PartialEq, Eq)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+pub enum MailAttachmentResult {
+    Success,
+    Failed,
+    Overweight,
+}
+
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x09F2)]
+pub struct MailZenyResultPacket {
+    pub mail_id: MailId,
+    pub mail_type: MailType,
+    pub result: MailAttachmentResult,
+}
+
+#[derive(Debug, Clone, Packet, ClientPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x09F3)]
+pub struct RequestMailItemsPacket {
+    pub mail_id: MailId,
+    pub mail_type: MailType,
+}
+
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x09F4)]
+pub struct MailItemsResultPacket {
+    pub mail_id: MailId,
+    pub mail_type: MailType,
+    pub result: MailAttachmentResult,
+}
+
+/// Start writing a new mail. The map server keeps track of the attached items
+/// until the mail is sent or writing is canceled.
+#[derive(Debug, Clone, Packet, ClientPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x0A08)]
+pub struct OpenWriteMailPacket {
+    #[length(24)]
+    pub recipient: String,
+}
+
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x0A12)]
+pub struct OpenWriteMailResultPacket {
+    #[length(24)]
+    pub recipient: String,
+    pub success: u8,
+}
+
+#[derive(Debug, Clone, Packet, ClientPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x0A03)]
+pub struct CancelWriteMailPacket {}
+
+#[derive(Debug, Clone, Packet, ClientPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x0A13)]
+pub struct CheckMailRecipientPacket {
+    #[length(24)]
+    pub name: String,
+}
+
+/// Response to [`CheckMailRecipientPacket`]. If no character with the name
+/// exists, the character id is 0.
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x0A51)]
+pub struct MailRecipientPacket {
+    pub character_id: CharacterId,
+    pub job: u16,
+    pub base_level: u16,
+    #[length(24)]
+    pub name: String,
+}
+
+#[derive(Debug, Clone, Packet, ClientPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x0A04)]
+pub struct AddItemToMailPacket {
+    pub index: InventoryIndex,
+    pub amount: u16,
+}
+
+#[derive(Debug, Clone, Copy, ByteConvertable, PartialEq, Eq)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+pub enum AddItemToMailResult {
+    Success,
+    Overweight,
+    Failed,
+    TooManyItems,
+    TradeRestricted,
+}
+
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x0A05)]
+pub struct AddItemToMailResultPacket {
+    pub result: AddItemToMailResult,
+    pub index: InventoryIndex,
+    pub amount: u16,
+    pub item_id: ItemId,
+    pub item_type: u8,
+    pub is_identified: u8,
+    pub is_broken: u8,
+    pub cards: [u32; 4],
+    pub option_data: [ItemOptions; 5],
+    /// Total weight of all attached items.
+    pub weight: u16,
+    pub favorite: u8,
+    pub location: u32,
+    pub refinement_level: u8,
+    pub enchantment_level: u8,
+}
+
+#[derive(Debug, Clone, Packet, ClientPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x0A06)]
+pub struct RemoveItemFromMailPacket {
+    pub index: InventoryIndex,
+    pub amount: u16,
+}
+
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x0A07)]
+pub struct RemoveItemFromMailResultPacket {
+    pub success: u8,
+    pub index: InventoryIndex,
+    pub amount: u16,
+    pub weight: u16,
+}
+
+#[derive(Debug, Clone, Packet, ClientPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x09EC)]
+#[variable_length]
+pub struct SendMailPacket {
+    #[length(24)]
+    pub recipient: String,
+    #[length(24)]
+    pub sender: String,
+    pub zeny: u64,
+    pub title_length: u16,
+    pub text_length: u16,
+    pub recipient_id: CharacterId,
+    #[length(title_length)]
+    pub title: String,
+    #[length(text_length)]
+    pub text: String,
+}
+
+#[derive(Debug, Clone, Copy, ByteConvertable, PartialEq, Eq)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+pub enum SendMailResult {
+    Success,
+    Failed,
+    RecipientNotFound,
+    NotEnoughZeny,
+}
+
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x09ED)]
+pub struct SendMailResultPacket {
+    pub result: SendMailResult,
+}
+
+#[cfg(test)]
+mod status_type {
+    use ragnarok_bytes::{ByteReader, FromBytes};
+
+    use super::StatusType;
+
+    #[test]
+    fn single_value() {
+        let mut byte_reader = ByteReader::without_metadata(&[0x14, 0x00, 0x10, 0x27, 0x00, 0x00]);
+
+        assert!(matches!(StatusType::from_bytes(&mut byte_reader), Ok(StatusType::Zeny(10000))));
+    }
+
+    #[test]
+    fn base_and_bonus_value() {
+        let mut byte_reader = ByteReader::without_metadata(&[0x0D, 0x00, 0x00, 0x00, 0x32, 0x00, 0x00, 0x00, 0x05, 0x00, 0x00, 0x00]);
+
+        assert!(matches!(
+            StatusType::from_bytes(&mut byte_reader),
+            Ok(StatusType::Strength(50, 5))
+        ));
+    }
+}
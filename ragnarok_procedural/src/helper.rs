@@ -182,6 +182,13 @@ pub fn byte_convertable_helper(data_struct: DataStruct) -> (TokenStream, Vec<Tok
             }
             None => quote!(let #field_variable = #from_implementation;),
         };
+
+        // record the bytes of the field if the reader asks for it
+        let from_implementation = quote! {
+            let __field_start = byte_reader.start_field();
+            #from_implementation
+            byte_reader.finish_field::<#field_type>(stringify!(#field_variable), __field_start);
+        };
         from_bytes_implementations.push(from_implementation);
 
         // base to byte implementation